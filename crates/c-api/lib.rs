@@ -5,6 +5,10 @@
 //! C bindings.
 
 #![allow(non_camel_case_types)]
+// All exported functions are called from C, where pointer validity is the caller's
+// responsibility and is documented per function in `resvg.h`.
+// Marking them as `unsafe` would not change anything for C users.
+#![allow(clippy::not_unsafe_ptr_arg_deref)]
 #![warn(missing_docs)]
 #![warn(missing_copy_implementations)]
 
@@ -76,7 +80,7 @@ pub struct svgr_transform {
 
 impl svgr_transform {
     #[inline]
-    fn to_tiny_skia(&self) -> tiny_skia::Transform {
+    fn to_tiny_skia(self) -> tiny_skia::Transform {
         tiny_skia::Transform::from_row(self.a, self.b, self.c, self.d, self.e, self.f)
     }
 }
@@ -113,7 +117,7 @@ pub extern "C" fn svgr_init_log() {
 /// Also, contains a fonts database used during text to path conversion.
 /// The database is empty by default.
 pub struct svgr_options {
-    options: usvgr::Options,
+    options: usvgr::Options<'static>,
     #[cfg(feature = "text")]
     fontdb: fontdb::Database,
 }
@@ -131,7 +135,7 @@ pub extern "C" fn svgr_options_create() -> *mut svgr_options {
 }
 
 #[inline]
-fn cast_opt(opt: *mut svgr_options) -> &'static mut usvgr::Options {
+fn cast_opt(opt: *mut svgr_options) -> &'static mut usvgr::Options<'static> {
     unsafe {
         assert!(!opt.is_null());
         &mut (*opt).options
@@ -171,7 +175,7 @@ pub extern "C" fn svgr_options_set_resources_dir(opt: *mut svgr_options, path: *
 /// Default: 96
 #[no_mangle]
 pub extern "C" fn svgr_options_set_dpi(opt: *mut svgr_options, dpi: f32) {
-    cast_opt(opt).dpi = dpi as f32;
+    cast_opt(opt).dpi = dpi;
 }
 
 /// @brief Sets the default font family.
@@ -854,6 +858,56 @@ fn convert_error(e: usvgr::Error) -> svgr_error {
     }
 }
 
+/// @brief A pixel format of a render target.
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub enum svgr_pixel_format {
+    /// Premultiplied RGBA8888.
+    RGBA_PREMULTIPLIED,
+    /// Premultiplied BGRA8888.
+    BGRA_PREMULTIPLIED,
+    /// RGBA8888 with straight alpha.
+    RGBA,
+    /// BGRA8888 with straight alpha.
+    BGRA,
+    /// Premultiplied native-endian 0xAARRGGBB words, like Cairo's ARGB32.
+    ARGB32_PREMULTIPLIED,
+    /// Native-endian 0xAARRGGBB words with straight alpha.
+    ARGB32,
+}
+
+impl svgr_pixel_format {
+    #[inline]
+    fn to_svgr(self) -> svgr::PixelFormat {
+        match self {
+            svgr_pixel_format::RGBA_PREMULTIPLIED => svgr::PixelFormat::RgbaPremultiplied,
+            svgr_pixel_format::BGRA_PREMULTIPLIED => svgr::PixelFormat::BgraPremultiplied,
+            svgr_pixel_format::RGBA => svgr::PixelFormat::Rgba,
+            svgr_pixel_format::BGRA => svgr::PixelFormat::Bgra,
+            svgr_pixel_format::ARGB32_PREMULTIPLIED => svgr::PixelFormat::Argb32Premultiplied,
+            svgr_pixel_format::ARGB32 => svgr::PixelFormat::Argb32,
+        }
+    }
+}
+
+/// Wraps a caller-owned buffer. Panics on an invalid buffer size.
+unsafe fn cast_buffer<'a>(
+    data: *mut c_char,
+    width: u32,
+    height: u32,
+    stride: u32,
+    format: svgr_pixel_format,
+) -> svgr::PixelBuffer<'a> {
+    assert!(!data.is_null());
+    assert!(width > 0 && height > 0);
+
+    let stride = stride as usize;
+    let row_len = width as usize * format.to_svgr().bytes_per_pixel();
+    let len = stride * (height as usize - 1) + row_len;
+    let data = slice::from_raw_parts_mut(data as *mut u8, len);
+    svgr::PixelBuffer::new(data, width, height, stride, format.to_svgr()).unwrap()
+}
+
 /// @brief Renders the #svgr_render_tree onto the pixmap.
 ///
 /// @param tree A render tree.
@@ -869,18 +923,55 @@ pub extern "C" fn svgr_render(
     width: u32,
     height: u32,
     pixmap: *mut c_char,
+) {
+    svgr_render_to_buffer(
+        tree,
+        transform,
+        width,
+        height,
+        width * tiny_skia::BYTES_PER_PIXEL as u32,
+        svgr_pixel_format::RGBA_PREMULTIPLIED,
+        pixmap,
+    );
+}
+
+/// @brief Renders the #svgr_render_tree onto a buffer with a custom stride and pixel format.
+///
+/// The buffer content is used as a background.
+///
+/// @param tree A render tree.
+/// @param transform A root SVG transform. Can be used to position SVG inside the `buffer`.
+/// @param width Buffer width.
+/// @param height Buffer height.
+/// @param stride Number of bytes between the starts of two consecutive rows.
+///               Should be at least width*4.
+/// @param format Buffer pixel format.
+/// @param buffer Buffer data. Should have stride*height size.
+#[no_mangle]
+pub extern "C" fn svgr_render_to_buffer(
+    tree: *const svgr_render_tree,
+    transform: svgr_transform,
+    width: u32,
+    height: u32,
+    stride: u32,
+    format: svgr_pixel_format,
+    buffer: *mut c_char,
 ) {
     let tree = unsafe {
         assert!(!tree.is_null());
         &*tree
     };
 
-    let pixmap_len = width as usize * height as usize * tiny_skia::BYTES_PER_PIXEL;
-    let pixmap: &mut [u8] =
-        unsafe { std::slice::from_raw_parts_mut(pixmap as *mut u8, pixmap_len) };
-    let mut pixmap = tiny_skia::PixmapMut::from_bytes(pixmap, width, height).unwrap();
-
-    svgr::render(&tree.0, transform.to_tiny_skia(), &mut pixmap)
+    let mut buffer = unsafe { cast_buffer(buffer, width, height, stride, format) };
+    let ctx = svgr::Context::new_from_size(buffer.size());
+    svgr::render_to_buffer(
+        &tree.0,
+        transform.to_tiny_skia(),
+        &mut buffer,
+        &mut svgr::SvgrCache::none(),
+        &svgr::PixmapPool::new(),
+        &ctx,
+    );
 }
 
 /// @brief Renders a Node by ID onto the image.
@@ -903,6 +994,45 @@ pub extern "C" fn svgr_render_node(
     width: u32,
     height: u32,
     pixmap: *mut c_char,
+) -> bool {
+    svgr_render_node_to_buffer(
+        tree,
+        id,
+        transform,
+        width,
+        height,
+        width * tiny_skia::BYTES_PER_PIXEL as u32,
+        svgr_pixel_format::RGBA_PREMULTIPLIED,
+        pixmap,
+    )
+}
+
+/// @brief Renders a Node by ID onto a buffer with a custom stride and pixel format.
+///
+/// The buffer content is used as a background.
+///
+/// @param tree A render tree.
+/// @param id Node's ID. Must not be NULL.
+/// @param transform A root SVG transform. Can be used to position SVG inside the `buffer`.
+/// @param width Buffer width.
+/// @param height Buffer height.
+/// @param stride Number of bytes between the starts of two consecutive rows.
+///               Should be at least width*4.
+/// @param format Buffer pixel format.
+/// @param buffer Buffer data. Should have stride*height size.
+/// @return `false` when `id` is not a non-empty UTF-8 string.
+/// @return `false` when the selected `id` is not present.
+/// @return `false` when an element has a zero bbox.
+#[no_mangle]
+pub extern "C" fn svgr_render_node_to_buffer(
+    tree: *const svgr_render_tree,
+    id: *const c_char,
+    transform: svgr_transform,
+    width: u32,
+    height: u32,
+    stride: u32,
+    format: svgr_pixel_format,
+    buffer: *mut c_char,
 ) -> bool {
     let tree = unsafe {
         assert!(!tree.is_null());
@@ -920,12 +1050,17 @@ pub extern "C" fn svgr_render_node(
     }
 
     if let Some(node) = tree.0.node_by_id(id) {
-        let pixmap_len = width as usize * height as usize * tiny_skia::BYTES_PER_PIXEL;
-        let pixmap: &mut [u8] =
-            unsafe { std::slice::from_raw_parts_mut(pixmap as *mut u8, pixmap_len) };
-        let mut pixmap = tiny_skia::PixmapMut::from_bytes(pixmap, width, height).unwrap();
-
-        svgr::render_node(node, transform.to_tiny_skia(), &mut pixmap).is_some()
+        let mut buffer = unsafe { cast_buffer(buffer, width, height, stride, format) };
+        let ctx = svgr::Context::new_from_size(buffer.size());
+        svgr::render_node_to_buffer(
+            node,
+            transform.to_tiny_skia(),
+            &mut buffer,
+            &mut svgr::SvgrCache::none(),
+            &svgr::PixmapPool::new(),
+            &ctx,
+        )
+        .is_some()
     } else {
         log::warn!("A node with '{}' ID wasn't found.", id);
         false
@@ -942,7 +1077,7 @@ impl log::Log for SimpleLogger {
 
     fn log(&self, record: &log::Record) {
         if self.enabled(record.metadata()) {
-            let target = if record.target().len() > 0 {
+            let target = if !record.target().is_empty() {
                 record.target()
             } else {
                 record.module_path().unwrap_or_default()
@@ -67,6 +67,36 @@ typedef enum {
     RESVG_IMAGE_RENDERING_OPTIMIZE_SPEED,
 } svgr_image_rendering;
 
+/**
+ * @brief A pixel format of a render target.
+ */
+typedef enum {
+    /**
+     * Premultiplied RGBA8888.
+     */
+    RESVG_PIXEL_FORMAT_RGBA_PREMULTIPLIED,
+    /**
+     * Premultiplied BGRA8888.
+     */
+    RESVG_PIXEL_FORMAT_BGRA_PREMULTIPLIED,
+    /**
+     * RGBA8888 with straight alpha.
+     */
+    RESVG_PIXEL_FORMAT_RGBA,
+    /**
+     * BGRA8888 with straight alpha.
+     */
+    RESVG_PIXEL_FORMAT_BGRA,
+    /**
+     * Premultiplied native-endian 0xAARRGGBB words, like Cairo's ARGB32.
+     */
+    RESVG_PIXEL_FORMAT_ARGB32_PREMULTIPLIED,
+    /**
+     * Native-endian 0xAARRGGBB words with straight alpha.
+     */
+    RESVG_PIXEL_FORMAT_ARGB32,
+} svgr_pixel_format;
+
 /**
  * @brief A shape rendering method.
  */
@@ -475,6 +505,28 @@ void svgr_render(const svgr_render_tree *tree,
                   uint32_t height,
                   char *pixmap);
 
+/**
+ * @brief Renders the #svgr_render_tree onto a buffer with a custom stride and pixel format.
+ *
+ * The buffer content is used as a background.
+ *
+ * @param tree A render tree.
+ * @param transform A root SVG transform. Can be used to position SVG inside the `buffer`.
+ * @param width Buffer width.
+ * @param height Buffer height.
+ * @param stride Number of bytes between the starts of two consecutive rows.
+ *               Should be at least width*4.
+ * @param format Buffer pixel format.
+ * @param buffer Buffer data. Should have stride*height size.
+ */
+void svgr_render_to_buffer(const svgr_render_tree *tree,
+                           svgr_transform transform,
+                           uint32_t width,
+                           uint32_t height,
+                           uint32_t stride,
+                           svgr_pixel_format format,
+                           char *buffer);
+
 /**
  * @brief Renders a Node by ID onto the image.
  *
@@ -496,6 +548,33 @@ bool svgr_render_node(const svgr_render_tree *tree,
                        uint32_t height,
                        char *pixmap);
 
+/**
+ * @brief Renders a Node by ID onto a buffer with a custom stride and pixel format.
+ *
+ * The buffer content is used as a background.
+ *
+ * @param tree A render tree.
+ * @param id Node's ID. Must not be NULL.
+ * @param transform A root SVG transform. Can be used to position SVG inside the `buffer`.
+ * @param width Buffer width.
+ * @param height Buffer height.
+ * @param stride Number of bytes between the starts of two consecutive rows.
+ *               Should be at least width*4.
+ * @param format Buffer pixel format.
+ * @param buffer Buffer data. Should have stride*height size.
+ * @return `false` when `id` is not a non-empty UTF-8 string.
+ * @return `false` when the selected `id` is not present.
+ * @return `false` when an element has a zero bbox.
+ */
+bool svgr_render_node_to_buffer(const svgr_render_tree *tree,
+                                const char *id,
+                                svgr_transform transform,
+                                uint32_t width,
+                                uint32_t height,
+                                uint32_t stride,
+                                svgr_pixel_format format,
+                                char *buffer);
+
 #ifdef __cplusplus
 } // extern "C"
 #endif // __cplusplus
@@ -0,0 +1,182 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use pdf_writer::types::MaskType;
+use pdf_writer::{Rect, Ref};
+
+use crate::context::{to_matrix, Canvas, Context};
+
+/// Returns clipping paths in the canvas coordinates
+/// when a clip path can be represented by PDF clipping paths exactly.
+///
+/// A PDF clipping path is a single path with a single fill rule
+/// and nested clip paths are intersections. So we can handle only
+/// clip paths with a single path child.
+pub fn clip_paths(
+    clip: &usvgr::ClipPath,
+    transform: tiny_skia::Transform,
+) -> Option<Vec<(tiny_skia::Path, usvgr::FillRule)>> {
+    let mut paths = Vec::new();
+    let mut clip = Some(clip);
+    while let Some(c) = clip {
+        let transform = transform.pre_concat(c.transform());
+        let (path, transform) = single_path(c.root(), transform)?;
+        let rule = path.fill()?.rule();
+        paths.push((path.data().clone().transform(transform)?, rule));
+        clip = c.clip_path();
+    }
+
+    Some(paths)
+}
+
+fn single_path(
+    group: &usvgr::Group,
+    transform: tiny_skia::Transform,
+) -> Option<(&usvgr::Path, tiny_skia::Transform)> {
+    match group.children() {
+        [usvgr::Node::Path(ref path)] if path.visibility() == usvgr::Visibility::Visible => {
+            Some((path, transform))
+        }
+        [usvgr::Node::Group(ref group)] if group.clip_path().is_none() => {
+            single_path(group, transform.pre_concat(group.transform()))
+        }
+        _ => None,
+    }
+}
+
+/// Returns a transparency group with a layer clipped by a clip path.
+pub fn clip_layer(
+    clip: &usvgr::ClipPath,
+    layer: Ref,
+    bbox: Rect,
+    ctx: &mut Context,
+    transform: tiny_skia::Transform,
+    base: tiny_skia::Transform,
+) -> Ref {
+    let mut canvas = Canvas::new(base);
+    canvas.content.save_state();
+    if let Some(paths) = clip_paths(clip, transform) {
+        for (path, rule) in paths {
+            crate::path::write_clip(&path, rule, &mut canvas.content);
+        }
+    } else {
+        let group = clip_mask(clip, bbox, ctx, transform, base);
+        let gs = ctx.soft_mask_state(group, MaskType::Alpha);
+        canvas.set_state(gs);
+    }
+    canvas.draw_x_object(layer);
+    canvas.content.restore_state();
+    canvas.finish_group(ctx, bbox)
+}
+
+/// Returns a transparency group with an opaque clip path area.
+fn clip_mask(
+    clip: &usvgr::ClipPath,
+    bbox: Rect,
+    ctx: &mut Context,
+    transform: tiny_skia::Transform,
+    base: tiny_skia::Transform,
+) -> Ref {
+    let mut canvas = Canvas::new(base);
+    draw_children(
+        clip.root(),
+        bbox,
+        ctx,
+        transform.pre_concat(clip.transform()),
+        &mut canvas,
+    );
+    let group = canvas.finish_group(ctx, bbox);
+
+    match clip.clip_path() {
+        Some(clip) => clip_layer(clip, group, bbox, ctx, transform, base),
+        None => group,
+    }
+}
+
+fn draw_children(
+    parent: &usvgr::Group,
+    bbox: Rect,
+    ctx: &mut Context,
+    transform: tiny_skia::Transform,
+    canvas: &mut Canvas,
+) {
+    for child in parent.children() {
+        match child {
+            usvgr::Node::Path(ref path) => {
+                let fill = match path.fill() {
+                    Some(fill) if path.visibility() == usvgr::Visibility::Visible => fill,
+                    _ => continue,
+                };
+
+                canvas.content.save_state();
+                canvas.content.transform(to_matrix(transform));
+                canvas.content.set_fill_gray(0.0);
+                crate::path::write_path(path.data(), &mut canvas.content);
+                match fill.rule() {
+                    usvgr::FillRule::NonZero => canvas.content.fill_nonzero(),
+                    usvgr::FillRule::EvenOdd => canvas.content.fill_even_odd(),
+                };
+                canvas.content.restore_state();
+            }
+            usvgr::Node::Text(ref text) => {
+                draw_children(text.flattened(), bbox, ctx, transform, canvas);
+            }
+            usvgr::Node::Group(ref group) => {
+                let transform = transform.pre_concat(group.transform());
+
+                if let Some(clip) = group.clip_path() {
+                    // If a `clipPath` child also has a `clip-path`
+                    // then we should render this child into a separate group,
+                    // clip it, and only then draw it to the `clipPath`.
+                    let mut layer = Canvas::new(canvas.base);
+                    draw_children(group, bbox, ctx, transform, &mut layer);
+                    let layer = layer.finish_group(ctx, bbox);
+                    let layer = clip_layer(clip, layer, bbox, ctx, transform, canvas.base);
+                    canvas.draw_x_object(layer);
+                } else {
+                    draw_children(group, bbox, ctx, transform, canvas);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Returns a transparency group with a layer masked by a mask.
+pub fn mask_layer(
+    mask: &usvgr::Mask,
+    layer: Ref,
+    bbox: Rect,
+    ctx: &mut Context,
+    transform: tiny_skia::Transform,
+    base: tiny_skia::Transform,
+) -> Ref {
+    let mut mask_canvas = Canvas::new(base);
+    mask_canvas.content.save_state();
+    let region = tiny_skia::PathBuilder::from_rect(mask.rect().to_rect());
+    if let Some(region) = region.transform(transform) {
+        crate::path::write_clip(&region, usvgr::FillRule::NonZero, &mut mask_canvas.content);
+    }
+    crate::render::render_nodes(mask.root(), ctx, transform, &mut mask_canvas);
+    mask_canvas.content.restore_state();
+    let group = mask_canvas.finish_group(ctx, bbox);
+
+    let kind = match mask.kind() {
+        usvgr::MaskType::Luminance => MaskType::Luminosity,
+        usvgr::MaskType::Alpha => MaskType::Alpha,
+    };
+
+    let mut canvas = Canvas::new(base);
+    canvas.content.save_state();
+    let gs = ctx.soft_mask_state(group, kind);
+    canvas.set_state(gs);
+    canvas.draw_x_object(layer);
+    canvas.content.restore_state();
+    let layer = canvas.finish_group(ctx, bbox);
+
+    match mask.mask() {
+        Some(mask) => mask_layer(mask, layer, bbox, ctx, transform, base),
+        None => layer,
+    }
+}
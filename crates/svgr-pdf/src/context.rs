@@ -0,0 +1,271 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::collections::HashMap;
+use std::io::Write;
+
+use pdf_writer::types::MaskType;
+use pdf_writer::{Chunk, Content, Filter, Finish, Name, Rect, Ref};
+
+use crate::Options;
+
+/// A document-wide conversion state.
+pub struct Context<'a> {
+    /// All indirect objects except the document structure.
+    pub chunk: Chunk,
+    pub options: &'a Options,
+    /// The area that can be visible in page points with a top-left origin.
+    ///
+    /// The page itself or a cell of the pattern that is being written.
+    pub visible_area: tiny_skia::NonZeroRect,
+    next_id: i32,
+    /// Image XObjects by the address of their data and the interpolation flag.
+    pub images: HashMap<(usize, bool), Ref>,
+    /// Opacity-only graphics states by the stroking flag and the alpha bits.
+    alpha_states: HashMap<(bool, u32), Ref>,
+}
+
+impl<'a> Context<'a> {
+    pub fn new(options: &'a Options, page: tiny_skia::NonZeroRect) -> Self {
+        Context {
+            chunk: Chunk::new(),
+            options,
+            visible_area: page,
+            next_id: 1,
+            images: HashMap::new(),
+            alpha_states: HashMap::new(),
+        }
+    }
+
+    /// Allocates an indirect object reference.
+    pub fn alloc(&mut self) -> Ref {
+        let id = Ref::new(self.next_id);
+        self.next_id += 1;
+        id
+    }
+
+    /// Compresses a stream when enabled.
+    ///
+    /// Returns the stream data and whether it was compressed.
+    pub fn stream_data(&self, data: Vec<u8>) -> (Vec<u8>, bool) {
+        if self.options.compress {
+            (deflate(&data), true)
+        } else {
+            (data, false)
+        }
+    }
+
+    /// Returns a graphics state that sets the fill or stroke opacity.
+    pub fn alpha_state(&mut self, alpha: f32, stroke: bool) -> Ref {
+        let key = (stroke, alpha.to_bits());
+        if let Some(id) = self.alpha_states.get(&key) {
+            return *id;
+        }
+
+        let id = self.alloc();
+        let mut gs = self.chunk.ext_graphics(id);
+        if stroke {
+            gs.stroking_alpha(alpha);
+        } else {
+            gs.non_stroking_alpha(alpha);
+        }
+        gs.finish();
+
+        self.alpha_states.insert(key, id);
+        id
+    }
+
+    /// Returns a graphics state that sets a soft mask.
+    ///
+    /// `group` must be a transparency group. The mask uses the coordinate system
+    /// that is current when the graphics state is set.
+    pub fn soft_mask_state(&mut self, group: Ref, kind: MaskType) -> Ref {
+        let id = self.alloc();
+        self.chunk
+            .ext_graphics(id)
+            .soft_mask()
+            .subtype(kind)
+            .group(group);
+        id
+    }
+}
+
+pub fn deflate(data: &[u8]) -> Vec<u8> {
+    let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+    // Writing into a `Vec` cannot fail.
+    encoder.write_all(data).unwrap();
+    encoder.finish().unwrap()
+}
+
+/// A content stream with its resources.
+///
+/// All canvases use the SVG coordinate system, i.e. with a top-left origin.
+pub struct Canvas {
+    pub content: Content,
+    /// Maps the canvas coordinates to page points.
+    ///
+    /// Used only to choose the resolution of rasterized content.
+    pub base: tiny_skia::Transform,
+    x_objects: Vec<Ref>,
+    patterns: Vec<Ref>,
+    shadings: Vec<Ref>,
+    ext_g_states: Vec<Ref>,
+}
+
+impl Canvas {
+    pub fn new(base: tiny_skia::Transform) -> Self {
+        Canvas {
+            content: Content::new(),
+            base,
+            x_objects: Vec::new(),
+            patterns: Vec::new(),
+            shadings: Vec::new(),
+            ext_g_states: Vec::new(),
+        }
+    }
+
+    /// Draws an XObject.
+    pub fn draw_x_object(&mut self, id: Ref) {
+        let name = resource_name("x", &mut self.x_objects, id);
+        self.content.x_object(Name(name.as_bytes()));
+    }
+
+    /// Paints the current clipping region with a shading.
+    pub fn draw_shading(&mut self, id: Ref) {
+        let name = resource_name("sh", &mut self.shadings, id);
+        self.content.shading(Name(name.as_bytes()));
+    }
+
+    /// Sets a graphics state.
+    pub fn set_state(&mut self, id: Ref) {
+        let name = resource_name("gs", &mut self.ext_g_states, id);
+        self.content.set_parameters(Name(name.as_bytes()));
+    }
+
+    /// Sets a pattern as the fill or stroke color.
+    pub fn set_pattern(&mut self, id: Ref, stroke: bool) {
+        let name = resource_name("p", &mut self.patterns, id);
+        let name = Name(name.as_bytes());
+        if stroke {
+            self.content
+                .set_stroke_color_space(pdf_writer::types::ColorSpaceOperand::Pattern);
+            self.content.set_stroke_pattern(None, name);
+        } else {
+            self.content
+                .set_fill_color_space(pdf_writer::types::ColorSpaceOperand::Pattern);
+            self.content.set_fill_pattern(None, name);
+        }
+    }
+
+    /// Writes the canvas as an isolated transparency group XObject.
+    pub fn finish_group(self, ctx: &mut Context, bbox: Rect) -> Ref {
+        let id = ctx.alloc();
+        let (data, compressed) = ctx.stream_data(self.content.finish());
+
+        let mut form = ctx.chunk.form_xobject(id, &data);
+        if compressed {
+            form.filter(Filter::FlateDecode);
+        }
+        form.bbox(bbox);
+        form.group()
+            .transparency()
+            .isolated(true)
+            .color_space()
+            .device_rgb();
+        write_resources(
+            form.resources(),
+            &self.x_objects,
+            &self.patterns,
+            &self.shadings,
+            &self.ext_g_states,
+        );
+        form.finish();
+
+        id
+    }
+
+    /// Writes the canvas as a colored tiling pattern with a cell at the origin.
+    pub fn finish_tiling_pattern(
+        self,
+        ctx: &mut Context,
+        width: f32,
+        height: f32,
+        matrix: tiny_skia::Transform,
+    ) -> Ref {
+        let id = ctx.alloc();
+        let (data, compressed) = ctx.stream_data(self.content.finish());
+
+        let mut pattern = ctx.chunk.tiling_pattern(id, &data);
+        if compressed {
+            pattern.filter(Filter::FlateDecode);
+        }
+        pattern
+            .paint_type(pdf_writer::types::PaintType::Colored)
+            .tiling_type(pdf_writer::types::TilingType::ConstantSpacing)
+            .bbox(Rect::new(0.0, 0.0, width, height))
+            .x_step(width)
+            .y_step(height)
+            .matrix(to_matrix(matrix));
+        write_resources(
+            pattern.resources(),
+            &self.x_objects,
+            &self.patterns,
+            &self.shadings,
+            &self.ext_g_states,
+        );
+        pattern.finish();
+
+        id
+    }
+}
+
+fn resource_name(prefix: &str, list: &mut Vec<Ref>, id: Ref) -> String {
+    let idx = match list.iter().position(|r| *r == id) {
+        Some(idx) => idx,
+        None => {
+            list.push(id);
+            list.len() - 1
+        }
+    };
+
+    format!("{}{}", prefix, idx)
+}
+
+fn write_resources(
+    mut resources: pdf_writer::writers::Resources,
+    x_objects: &[Ref],
+    patterns: &[Ref],
+    shadings: &[Ref],
+    ext_g_states: &[Ref],
+) {
+    if !x_objects.is_empty() {
+        write_names(resources.x_objects(), "x", x_objects);
+    }
+    if !patterns.is_empty() {
+        write_names(resources.patterns(), "p", patterns);
+    }
+    if !shadings.is_empty() {
+        write_names(resources.shadings(), "sh", shadings);
+    }
+    if !ext_g_states.is_empty() {
+        write_names(resources.ext_g_states(), "gs", ext_g_states);
+    }
+}
+
+fn write_names(mut dict: pdf_writer::Dict, prefix: &str, list: &[Ref]) {
+    for (i, id) in list.iter().enumerate() {
+        let name = format!("{}{}", prefix, i);
+        dict.pair(Name(name.as_bytes()), *id);
+    }
+}
+
+/// Converts a transform into a PDF matrix.
+pub fn to_matrix(ts: tiny_skia::Transform) -> [f32; 6] {
+    [ts.sx, ts.ky, ts.kx, ts.sy, ts.tx, ts.ty]
+}
+
+/// Converts a rect into a PDF one.
+pub fn to_pdf_rect(rect: tiny_skia::NonZeroRect) -> Rect {
+    Rect::new(rect.left(), rect.top(), rect.right(), rect.bottom())
+}
@@ -0,0 +1,88 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::context::{Canvas, Context};
+
+/// The max number of pixels in a rasterized filter.
+///
+/// The resolution is reduced for larger groups.
+const MAX_PIXELS: f32 = 25_000_000.0;
+
+/// Renders a group with filters using `svgr` and draws it as an image.
+///
+/// PDF has no filters, so the whole group is rasterized, including its clip path, mask and opacity.
+/// Filters that use `BackgroundImage` see only the group itself.
+pub fn render(
+    node: &usvgr::Node,
+    ctx: &mut Context,
+    transform: tiny_skia::Transform,
+    canvas: &mut Canvas,
+) -> Option<()> {
+    let group = match node {
+        usvgr::Node::Group(ref group) => group,
+        _ => return None,
+    };
+
+    // The number of pixels per canvas unit.
+    let (sx, sy) = canvas.base.get_scale();
+    let mut scale = ctx.options.raster_dpi / 72.0 * sx.max(sy);
+
+    let mut bbox = group
+        .layer_bounding_box()
+        .transform(transform.pre_concat(group.transform()))?;
+
+    // Huge filter regions are limited to the visible area with a margin,
+    // which keeps the resolution while preserving most of the filter input.
+    if bbox.width() * bbox.height() * scale * scale > MAX_PIXELS {
+        let visible = ctx.visible_area.transform(canvas.base.invert()?)?;
+        let visible = tiny_skia::NonZeroRect::from_xywh(
+            visible.x() - visible.width(),
+            visible.y() - visible.height(),
+            visible.width() * 3.0,
+            visible.height() * 3.0,
+        )?;
+        bbox = bbox
+            .to_rect()
+            .intersect(&visible.to_rect())?
+            .to_non_zero_rect()?;
+    }
+
+    let area = bbox.width() * bbox.height() * scale * scale;
+    if area > MAX_PIXELS {
+        scale *= (MAX_PIXELS / area).sqrt();
+    }
+
+    let ts = tiny_skia::Transform::from_scale(scale, scale).pre_concat(transform);
+    let rect = bbox
+        .transform(tiny_skia::Transform::from_scale(scale, scale))?
+        .to_int_rect();
+    let mut pixmap = tiny_skia::Pixmap::new(rect.width(), rect.height())?;
+
+    // `svgr::render_node` renders a node relative to its absolute layer bounding box.
+    let abs_bbox = node.abs_layer_bounding_box()?;
+    let ts = tiny_skia::Transform::from_translate(-rect.x() as f32, -rect.y() as f32)
+        .pre_concat(ts)
+        .pre_translate(abs_bbox.x(), abs_bbox.y());
+    let svgr_ctx = svgr::Context::new_from_pixmap(&pixmap);
+    svgr::render_node(
+        node,
+        ts,
+        &mut pixmap.as_mut(),
+        &mut svgr::SvgrCache::none(),
+        &svgr::PixmapPool::new(),
+        &svgr_ctx,
+    );
+
+    let id = crate::image::write_image(pixmap.data(), pixmap.width(), pixmap.height(), true, ctx);
+
+    // Images are drawn into a unit square with the first row at the top.
+    let (x, y) = (rect.x() as f32 / scale, rect.y() as f32 / scale);
+    let (w, h) = (rect.width() as f32 / scale, rect.height() as f32 / scale);
+    canvas.content.save_state();
+    canvas.content.transform([w, 0.0, 0.0, -h, x, y + h]);
+    canvas.draw_x_object(id);
+    canvas.content.restore_state();
+
+    Some(())
+}
@@ -0,0 +1,159 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::sync::Arc;
+
+use pdf_writer::{Filter, Finish, Ref};
+
+use crate::context::{to_matrix, Canvas, Context};
+
+pub fn render(
+    image: &usvgr::Image,
+    ctx: &mut Context,
+    transform: tiny_skia::Transform,
+    canvas: &mut Canvas,
+) -> Option<()> {
+    if image.visibility() != usvgr::Visibility::Visible {
+        return None;
+    }
+
+    let view_box = image.view_box();
+    let size = match image.kind() {
+        usvgr::ImageKind::DATA(ref data) => {
+            usvgr::Size::from_wh(data.width as f32, data.height as f32)?
+        }
+        usvgr::ImageKind::SVG { ref tree, .. } => tree.size(),
+    };
+    let rect = image_rect(&view_box, size);
+
+    canvas.content.save_state();
+    if view_box.aspect.slice {
+        let clip = tiny_skia::PathBuilder::from_rect(view_box.rect.to_rect());
+        if let Some(clip) = clip.transform(transform) {
+            crate::path::write_clip(&clip, usvgr::FillRule::NonZero, &mut canvas.content);
+        }
+    }
+
+    match image.kind() {
+        usvgr::ImageKind::DATA(ref data) => {
+            let interpolate = image.rendering_mode() == usvgr::ImageRendering::OptimizeQuality;
+            let id = write_preloaded(data, interpolate, ctx);
+
+            // Images are drawn into a unit square with the first row at the top.
+            let ts = tiny_skia::Transform::from_row(
+                rect.width(),
+                0.0,
+                0.0,
+                -rect.height(),
+                rect.x(),
+                rect.bottom(),
+            );
+            canvas
+                .content
+                .transform(to_matrix(transform.pre_concat(ts)));
+            canvas.draw_x_object(id);
+        }
+        usvgr::ImageKind::SVG { ref tree, .. } => {
+            let ts = transform
+                .pre_translate(rect.x(), rect.y())
+                .pre_scale(rect.width() / size.width(), rect.height() / size.height())
+                .pre_concat(tree.view_box().to_transform(size));
+            crate::render::render_nodes(tree.root(), ctx, ts, canvas);
+        }
+    }
+    canvas.content.restore_state();
+
+    Some(())
+}
+
+/// Calculates an image rect depending on the provided view box.
+fn image_rect(view_box: &usvgr::ViewBox, size: usvgr::Size) -> tiny_skia::NonZeroRect {
+    let r = view_box.rect;
+    let new_size = if view_box.aspect.align == usvgr::Align::None {
+        r.size()
+    } else if view_box.aspect.slice {
+        size.expand_to(r.size())
+    } else {
+        size.scale_to(r.size())
+    };
+
+    let (x, y) = usvgr::utils::aligned_pos(
+        view_box.aspect.align,
+        r.x(),
+        r.y(),
+        r.width() - new_size.width(),
+        r.height() - new_size.height(),
+    );
+
+    new_size.to_non_zero_rect(x, y)
+}
+
+fn write_preloaded(
+    data: &Arc<usvgr::PreloadedImageData>,
+    interpolate: bool,
+    ctx: &mut Context,
+) -> Ref {
+    let key = (Arc::as_ptr(data) as usize, interpolate);
+    if let Some(id) = ctx.images.get(&key) {
+        return *id;
+    }
+
+    let id = write_image(&data.data, data.width, data.height, interpolate, ctx);
+    ctx.images.insert(key, id);
+    id
+}
+
+/// Writes premultiplied RGBA pixels as an image XObject.
+///
+/// Alpha is stored as a separate soft mask image, when present.
+pub fn write_image(
+    rgba: &[u8],
+    width: u32,
+    height: u32,
+    interpolate: bool,
+    ctx: &mut Context,
+) -> Ref {
+    let mut rgb = Vec::with_capacity(rgba.len() / 4 * 3);
+    let mut alpha = Vec::with_capacity(rgba.len() / 4);
+    for p in rgba.chunks_exact(4) {
+        let a = p[3] as u32;
+        for &c in &p[..3] {
+            let c = (c as u32 * 255 + a / 2).checked_div(a).unwrap_or(0);
+            rgb.push(c.min(255) as u8);
+        }
+        alpha.push(p[3]);
+    }
+
+    let mask = if alpha.iter().any(|a| *a != 255) {
+        let id = ctx.alloc();
+        let (data, compressed) = ctx.stream_data(alpha);
+        let mut image = ctx.chunk.image_xobject(id, &data);
+        if compressed {
+            image.filter(Filter::FlateDecode);
+        }
+        image.width(width as i32).height(height as i32);
+        image.color_space().device_gray();
+        image.bits_per_component(8).interpolate(interpolate);
+        image.finish();
+        Some(id)
+    } else {
+        None
+    };
+
+    let id = ctx.alloc();
+    let (data, compressed) = ctx.stream_data(rgb);
+    let mut image = ctx.chunk.image_xobject(id, &data);
+    if compressed {
+        image.filter(Filter::FlateDecode);
+    }
+    image.width(width as i32).height(height as i32);
+    image.color_space().device_rgb();
+    image.bits_per_component(8).interpolate(interpolate);
+    if let Some(mask) = mask {
+        image.s_mask(mask);
+    }
+    image.finish();
+
+    id
+}
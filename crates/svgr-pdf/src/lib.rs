@@ -0,0 +1,124 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/*!
+`svgr-pdf` converts a [`usvgr::Tree`] into a single-page PDF document.
+
+Everything that PDF can express stays vector:
+
+- paths, including dashing, caps, joins and the paint order
+- solid colors, linear, radial and mesh gradients, patterns
+- groups with opacity, blend modes and isolation, as transparency groups
+- clip paths, as clipping paths or alpha soft masks when they cannot be represented exactly
+- masks, as luminosity and alpha soft masks
+- nested SVG images
+
+Text is embedded as glyph outlines, exactly as `svgr` renders it.
+Raster images are embedded losslessly, with an alpha channel as a soft mask.
+
+Filters have no PDF counterpart, so only groups with filters are rendered by `svgr`
+and embedded as images. The resolution is controlled by [`Options::raster_dpi`].
+*/
+
+#![forbid(unsafe_code)]
+#![warn(missing_docs)]
+#![allow(clippy::too_many_arguments)]
+
+mod clip;
+mod context;
+mod filter;
+mod image;
+mod paint;
+mod path;
+mod render;
+
+pub use svgr;
+pub use usvgr;
+
+use pdf_writer::{Content, Finish, Name, Pdf, Rect};
+
+use context::{Canvas, Context};
+
+/// PDF conversion options.
+#[derive(Clone, Debug)]
+pub struct Options {
+    /// The resolution of SVG user units.
+    ///
+    /// A PDF point is 1/72 of an inch, so the default maps one user unit to one point.
+    /// Use 96 to map user units to CSS pixels.
+    ///
+    /// Default: 72
+    pub dpi: f32,
+
+    /// The resolution of rasterized filters.
+    ///
+    /// Default: 300
+    pub raster_dpi: f32,
+
+    /// Compress streams using the Deflate algorithm.
+    ///
+    /// Default: true
+    pub compress: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            dpi: 72.0,
+            raster_dpi: 300.0,
+            compress: true,
+        }
+    }
+}
+
+/// Converts a tree into a PDF document.
+///
+/// The page size is the tree size converted using [`Options::dpi`].
+pub fn convert(tree: &usvgr::Tree, options: &Options) -> Vec<u8> {
+    let scale = 72.0 / options.dpi;
+    let width = tree.size().width() * scale;
+    let height = tree.size().height() * scale;
+
+    let page = tiny_skia::NonZeroRect::from_xywh(0.0, 0.0, width, height)
+        .unwrap_or_else(|| tiny_skia::NonZeroRect::from_xywh(0.0, 0.0, 1.0, 1.0).unwrap());
+    let mut ctx = Context::new(options, page);
+    let catalog_id = ctx.alloc();
+    let page_tree_id = ctx.alloc();
+    let page_id = ctx.alloc();
+    let content_id = ctx.alloc();
+    let media_box = Rect::new(0.0, 0.0, width, height);
+
+    // All content streams use the SVG coordinate system with a top-left origin.
+    // The page content only flips the root group.
+    let transform = tiny_skia::Transform::from_scale(scale, scale)
+        .pre_concat(tree.view_box().to_transform(tree.size()));
+    let mut canvas = Canvas::new(tiny_skia::Transform::default());
+    render::render_nodes(tree.root(), &mut ctx, transform, &mut canvas);
+    let root = canvas.finish_group(&mut ctx, media_box);
+
+    let mut content = Content::new();
+    content.transform([1.0, 0.0, 0.0, -1.0, 0.0, height]);
+    content.x_object(Name(b"x0"));
+    let (data, compressed) = ctx.stream_data(content.finish());
+
+    let mut pdf = Pdf::new();
+    pdf.catalog(catalog_id).pages(page_tree_id);
+    pdf.pages(page_tree_id).kids([page_id]).count(1);
+
+    let mut page = pdf.page(page_id);
+    page.media_box(media_box);
+    page.parent(page_tree_id);
+    page.contents(content_id);
+    page.resources().x_objects().pair(Name(b"x0"), root);
+    page.finish();
+
+    let mut stream = pdf.stream(content_id, &data);
+    if compressed {
+        stream.filter(pdf_writer::Filter::FlateDecode);
+    }
+    stream.finish();
+
+    pdf.extend(&ctx.chunk);
+    pdf.finish()
+}
@@ -0,0 +1,552 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use pdf_writer::types::{FunctionShadingType, MaskType};
+use pdf_writer::writers::StreamShadingType;
+use pdf_writer::{Filter, Finish, Name, Ref};
+use tiny_skia::Point;
+use usvgr::filter::ColorInterpolation;
+
+use crate::context::{to_matrix, Canvas, Context};
+
+/// The max number of gradient periods for the `reflect` and `repeat` spread methods.
+const MAX_PERIODS: f32 = 1000.0;
+
+/// The number of linear segments that approximate a `linearRGB` stops transition.
+const LINEAR_RGB_STEPS: usize = 16;
+
+/// Sets a paint as the current fill or stroke color.
+///
+/// `bbox` is the painted area in the canvas coordinates.
+/// Must be called before the path transform is applied.
+pub fn set(
+    paint: &usvgr::Paint,
+    opacity: usvgr::Opacity,
+    stroke: bool,
+    bbox: tiny_skia::NonZeroRect,
+    ctx: &mut Context,
+    transform: tiny_skia::Transform,
+    canvas: &mut Canvas,
+) -> Option<()> {
+    let mut alpha = opacity.get();
+    let pattern = match paint {
+        usvgr::Paint::Color(c) => {
+            let (r, g, b) = (
+                c.red as f32 / 255.0,
+                c.green as f32 / 255.0,
+                c.blue as f32 / 255.0,
+            );
+            if stroke {
+                canvas.content.set_stroke_rgb(r, g, b);
+            } else {
+                canvas.content.set_fill_rgb(r, g, b);
+            }
+            None
+        }
+        usvgr::Paint::LinearGradient(_) | usvgr::Paint::RadialGradient(_) => {
+            let gradient = Gradient::new(paint, transform, bbox)?;
+            let stops = gradient.base.stops();
+
+            // Uniform opacity doesn't require a soft mask.
+            let first = stops.first()?.opacity();
+            if stops.iter().all(|s| s.opacity() == first) {
+                alpha *= first.get();
+            } else {
+                let function = gradient.write_function(true, ctx);
+                let shading = ctx.alloc();
+                let mut writer = ctx.chunk.function_shading(shading);
+                writer.color_space().device_gray();
+                gradient.write(&mut writer, function);
+                writer.finish();
+
+                let mask = shading_mask(shading, gradient.matrix, bbox, ctx, canvas.base);
+                canvas.set_state(mask);
+            }
+
+            let function = gradient.write_function(false, ctx);
+            let id = ctx.alloc();
+            let mut pattern = ctx.chunk.shading_pattern(id);
+            let mut writer = pattern.function_shading();
+            writer.color_space().device_rgb();
+            gradient.write(&mut writer, function);
+            writer.finish();
+            pattern.matrix(to_matrix(gradient.matrix));
+            pattern.finish();
+
+            Some(id)
+        }
+        usvgr::Paint::MeshGradient(ref mg) => {
+            let matrix = transform.pre_concat(mg.transform());
+
+            if mg
+                .patches()
+                .iter()
+                .any(|p| p.opacities().iter().any(|o| o.get() != 1.0))
+            {
+                let shading = write_mesh_shading(mg, true, ctx);
+                let mask = shading_mask(shading, matrix, bbox, ctx, canvas.base);
+                canvas.set_state(mask);
+            }
+
+            let shading = write_mesh_shading(mg, false, ctx);
+            let id = ctx.alloc();
+            ctx.chunk
+                .shading_pattern(id)
+                .shading_ref(shading)
+                .matrix(to_matrix(matrix));
+
+            Some(id)
+        }
+        usvgr::Paint::Pattern(ref pattern) => {
+            Some(write_tiling_pattern(pattern, ctx, transform, canvas.base)?)
+        }
+    };
+
+    // Some readers capture the opacity when a pattern is selected,
+    // so it has to be set first.
+    if alpha != 1.0 {
+        let gs = ctx.alpha_state(alpha, stroke);
+        canvas.set_state(gs);
+    }
+
+    if let Some(id) = pattern {
+        canvas.set_pattern(id, stroke);
+    }
+
+    Some(())
+}
+
+/// A linear or a radial gradient mapped to a PDF shading.
+struct Gradient<'a> {
+    base: &'a usvgr::BaseGradient,
+    kind: FunctionShadingType,
+    coords: Vec<f32>,
+    /// The integer range of gradient periods.
+    ///
+    /// Always `[0, 1]` for the `pad` spread method, unless the gradient has sharp ends.
+    domain: [f32; 2],
+    /// Maps the gradient coordinates to the canvas ones.
+    matrix: tiny_skia::Transform,
+}
+
+impl<'a> Gradient<'a> {
+    fn new(
+        paint: &'a usvgr::Paint,
+        transform: tiny_skia::Transform,
+        bbox: tiny_skia::NonZeroRect,
+    ) -> Option<Self> {
+        let base: &usvgr::BaseGradient = match paint {
+            usvgr::Paint::LinearGradient(ref lg) => lg,
+            usvgr::Paint::RadialGradient(ref rg) => rg,
+            _ => return None,
+        };
+
+        let matrix = transform.pre_concat(base.transform());
+
+        // Corners of the painted area in the gradient coordinates.
+        let mut corners = [
+            Point::from_xy(bbox.left(), bbox.top()),
+            Point::from_xy(bbox.right(), bbox.top()),
+            Point::from_xy(bbox.right(), bbox.bottom()),
+            Point::from_xy(bbox.left(), bbox.bottom()),
+        ];
+        matrix.invert()?.map_points(&mut corners);
+
+        // PDF extends a shading with the colors at the domain ends, while SVG uses
+        // the first and the last stops. They differ only when stops share an end offset.
+        let unit_domain =
+            base.spread_method() == usvgr::SpreadMethod::Pad && !has_sharp_ends(base.stops());
+        let (kind, coords, domain) = match paint {
+            usvgr::Paint::LinearGradient(ref lg) => {
+                let start = Point::from_xy(lg.x1(), lg.y1());
+                let dir = Point::from_xy(lg.x2(), lg.y2()) - start;
+                let len = dir.length() * dir.length();
+
+                let mut domain = [0.0f32, 1.0];
+                if !unit_domain && len > 0.0 {
+                    for p in corners {
+                        let t = (p - start).dot(dir) / len;
+                        domain = [domain[0].min(t.floor()), domain[1].max(t.ceil())];
+                    }
+                    domain = clamp_periods(domain);
+                }
+
+                let p1 = point_at(start, dir, domain[0]);
+                let p2 = point_at(start, dir, domain[1]);
+                (
+                    FunctionShadingType::Axial,
+                    vec![p1.x, p1.y, p2.x, p2.y],
+                    domain,
+                )
+            }
+            usvgr::Paint::RadialGradient(ref rg) => {
+                let focal = Point::from_xy(rg.fx(), rg.fy());
+                let dir = Point::from_xy(rg.cx(), rg.cy()) - focal;
+                let r = rg.r().get();
+
+                // Circles start at the focal point and grow linearly, so with the focal point
+                // inside the end circle, each point is covered by a circle with
+                // `t <= distance / (r - |center - focal|)`.
+                let mut domain = [0.0f32, 1.0];
+                if !unit_domain && r > dir.length() {
+                    for p in corners {
+                        let t = focal.distance(p) / (r - dir.length());
+                        domain[1] = domain[1].max(t.ceil());
+                    }
+                    domain = clamp_periods(domain);
+                }
+
+                let center = point_at(focal, dir, domain[1]);
+                (
+                    FunctionShadingType::Radial,
+                    vec![focal.x, focal.y, 0.0, center.x, center.y, r * domain[1]],
+                    domain,
+                )
+            }
+            _ => return None,
+        };
+
+        Some(Gradient {
+            base,
+            kind,
+            coords,
+            domain,
+            matrix,
+        })
+    }
+
+    /// Writes a function that maps the shading domain to colors.
+    ///
+    /// Stop opacities are used instead of colors when `alpha` is set.
+    fn write_function(&self, alpha: bool, ctx: &mut Context) -> Ref {
+        let id = ctx.alloc();
+        write_stops_function(id, self.base, self.domain, alpha, ctx);
+        id
+    }
+
+    /// Writes shading attributes, except the color space.
+    fn write(&self, shading: &mut pdf_writer::writers::FunctionShading, function: Ref) {
+        shading.shading_type(self.kind);
+        shading.function(function);
+        shading.coords(self.coords.iter().copied());
+        shading.insert(Name(b"Domain")).array().items(self.domain);
+        shading.extend([true, true]);
+    }
+}
+
+/// Checks that a gradient starts or ends with a sharp transition.
+///
+/// Stops with equal offsets are already shifted apart by usvgr.
+fn has_sharp_ends(stops: &[usvgr::Stop]) -> bool {
+    let sharp = |a: &usvgr::Stop, b: &usvgr::Stop| {
+        b.offset().get() - a.offset().get() <= 2.0 * f32::EPSILON
+    };
+
+    match stops {
+        [a, b, ..] if a.offset().get() <= 0.0 && sharp(a, b) => true,
+        [.., a, b] => b.offset().get() >= 1.0 && sharp(a, b),
+        _ => false,
+    }
+}
+
+fn point_at(start: Point, dir: Point, t: f32) -> Point {
+    Point::from_xy(start.x + dir.x * t, start.y + dir.y * t)
+}
+
+fn clamp_periods(domain: [f32; 2]) -> [f32; 2] {
+    let start = domain[0].max(-MAX_PERIODS / 2.0);
+    let end = domain[1].min(start + MAX_PERIODS);
+    [start, end]
+}
+
+/// Writes a function that maps gradient periods to stop colors or opacities.
+fn write_stops_function(
+    id: Ref,
+    gradient: &usvgr::BaseGradient,
+    domain: [f32; 2],
+    alpha: bool,
+    ctx: &mut Context,
+) {
+    let stops = gradient.stops();
+    let period = if domain == [0.0, 1.0] {
+        id
+    } else {
+        ctx.alloc()
+    };
+
+    let values = |stop: &usvgr::Stop| {
+        if alpha {
+            vec![stop.opacity().get()]
+        } else {
+            let c = stop.color();
+            vec![
+                c.red as f32 / 255.0,
+                c.green as f32 / 255.0,
+                c.blue as f32 / 255.0,
+            ]
+        }
+    };
+
+    let mut points: Vec<(f32, Vec<f32>)> = stops
+        .iter()
+        .map(|s| (s.offset().get(), values(s)))
+        .collect();
+    if let Some((offset, v)) = points.first().cloned() {
+        if offset > 0.0 {
+            points.insert(0, (0.0, v));
+        }
+    }
+    if let Some((offset, v)) = points.last().cloned() {
+        if offset < 1.0 {
+            points.push((1.0, v));
+        }
+    }
+
+    // PDF interpolates colors in the shading color space,
+    // so `linearRGB` interpolation is approximated by shorter segments.
+    if !alpha && gradient.color_interpolation() == ColorInterpolation::LinearRGB {
+        points = linear_rgb_points(&points);
+    }
+
+    // Stops with the same offset produce a sharp transition, not an empty segment.
+    let segments: Vec<_> = points
+        .windows(2)
+        .filter(|w| w[1].0 > w[0].0)
+        .map(|w| (w[0].clone(), w[1].clone()))
+        .collect();
+
+    if segments.len() == 1 {
+        let ((_, c0), (_, c1)) = &segments[0];
+        write_exponential(period, c0, c1, ctx);
+    } else {
+        let mut functions = Vec::with_capacity(segments.len());
+        for ((_, c0), (_, c1)) in &segments {
+            let function = ctx.alloc();
+            write_exponential(function, c0, c1, ctx);
+            functions.push(function);
+        }
+
+        let bounds: Vec<f32> = segments
+            .iter()
+            .skip(1)
+            .map(|((offset, _), _)| *offset)
+            .collect();
+        ctx.chunk
+            .stitching_function(period)
+            .domain([0.0, 1.0])
+            .functions(functions.iter().copied())
+            .bounds(bounds)
+            .encode([0.0, 1.0].repeat(functions.len()));
+    }
+
+    if period != id && gradient.spread_method() == usvgr::SpreadMethod::Pad {
+        let mut functions = Vec::with_capacity(3);
+        let mut bounds = Vec::with_capacity(2);
+        if domain[0] < 0.0 {
+            let function = ctx.alloc();
+            let (_, v) = &points[0];
+            write_exponential(function, v, v, ctx);
+            functions.push(function);
+            bounds.push(0.0);
+        }
+        functions.push(period);
+        if domain[1] > 1.0 {
+            let function = ctx.alloc();
+            let (_, v) = &points[points.len() - 1];
+            write_exponential(function, v, v, ctx);
+            functions.push(function);
+            bounds.push(1.0);
+        }
+
+        ctx.chunk
+            .stitching_function(id)
+            .domain(domain)
+            .functions(functions.iter().copied())
+            .bounds(bounds)
+            .encode([0.0, 1.0].repeat(functions.len()));
+    } else if period != id {
+        let (start, end) = (domain[0] as i32, domain[1] as i32);
+        let reflect = gradient.spread_method() == usvgr::SpreadMethod::Reflect;
+        let encode = (start..end).flat_map(|i| {
+            if reflect && i.rem_euclid(2) == 1 {
+                [1.0, 0.0]
+            } else {
+                [0.0, 1.0]
+            }
+        });
+
+        ctx.chunk
+            .stitching_function(id)
+            .domain(domain)
+            .functions(std::iter::repeat_n(period, (end - start) as usize))
+            .bounds((start + 1..end).map(|i| i as f32))
+            .encode(encode);
+    }
+}
+
+fn linear_rgb_points(points: &[(f32, Vec<f32>)]) -> Vec<(f32, Vec<f32>)> {
+    let mut result = vec![points[0].clone()];
+    for w in points.windows(2) {
+        let ((o1, c1), (o2, c2)) = (&w[0], &w[1]);
+        let steps = if o2 > o1 { LINEAR_RGB_STEPS } else { 1 };
+        for i in 1..=steps {
+            let k = i as f32 / steps as f32;
+            let color = c1
+                .iter()
+                .zip(c2)
+                .map(|(a, b)| {
+                    let (a, b) = (srgb_to_linear(*a), srgb_to_linear(*b));
+                    linear_to_srgb(a + (b - a) * k)
+                })
+                .collect();
+            result.push((o1 + (o2 - o1) * k, color));
+        }
+    }
+
+    result
+}
+
+fn srgb_to_linear(v: f32) -> f32 {
+    if v <= 0.04045 {
+        v / 12.92
+    } else {
+        ((v + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(v: f32) -> f32 {
+    if v <= 0.0031308 {
+        v * 12.92
+    } else {
+        1.055 * v.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+fn write_exponential(id: Ref, c0: &[f32], c1: &[f32], ctx: &mut Context) {
+    ctx.chunk
+        .exponential_function(id)
+        .domain([0.0, 1.0])
+        .c0(c0.iter().copied())
+        .c1(c1.iter().copied())
+        .n(1.0);
+}
+
+/// Writes a Coons patch mesh shading.
+///
+/// PDF interpolates patch colors bilinearly, so bicubic meshes are approximated.
+/// Patch opacities are used instead of colors when `alpha` is set.
+fn write_mesh_shading(mg: &usvgr::MeshGradient, alpha: bool, ctx: &mut Context) -> Ref {
+    let mut min = Point::from_xy(f32::MAX, f32::MAX);
+    let mut max = Point::from_xy(f32::MIN, f32::MIN);
+    for p in mg.patches().iter().flat_map(|p| p.points()) {
+        min = Point::from_xy(min.x.min(p.x), min.y.min(p.y));
+        max = Point::from_xy(max.x.max(p.x), max.y.max(p.y));
+    }
+    if max.x <= min.x {
+        max.x = min.x + 1.0;
+    }
+    if max.y <= min.y {
+        max.y = min.y + 1.0;
+    }
+
+    let coord = |v: f32, min: f32, max: f32| {
+        let v = ((v - min) / (max - min)) as f64 * u32::MAX as f64;
+        (v.round() as u32).to_be_bytes()
+    };
+    let component = |v: f32| ((v.clamp(0.0, 1.0) * 65535.0).round() as u16).to_be_bytes();
+
+    let mut data = Vec::new();
+    for patch in mg.patches() {
+        // The edge flag of a patch that doesn't share an edge with the previous one.
+        data.push(0);
+        for p in patch.points() {
+            data.extend_from_slice(&coord(p.x, min.x, max.x));
+            data.extend_from_slice(&coord(p.y, min.y, max.y));
+        }
+
+        for (c, o) in patch.colors().iter().zip(patch.opacities()) {
+            if alpha {
+                data.extend_from_slice(&component(o.get()));
+            } else {
+                for v in [c.red, c.green, c.blue] {
+                    data.extend_from_slice(&component(v as f32 / 255.0));
+                }
+            }
+        }
+    }
+
+    let id = ctx.alloc();
+    let (data, compressed) = ctx.stream_data(data);
+    let mut shading = ctx.chunk.stream_shading(id, &data);
+    if compressed {
+        shading.filter(Filter::FlateDecode);
+    }
+    shading.shading_type(StreamShadingType::CoonsPatch);
+    if alpha {
+        shading.color_space().device_gray();
+    } else {
+        shading.color_space().device_rgb();
+    }
+    shading
+        .bits_per_coordinate(32)
+        .bits_per_component(16)
+        .bits_per_flag(8);
+
+    let channels = if alpha { 1 } else { 3 };
+    let mut decode = vec![min.x, max.x, min.y, max.y];
+    decode.extend([0.0, 1.0].repeat(channels));
+    shading.decode(decode);
+    shading.finish();
+
+    id
+}
+
+/// Returns a graphics state with a luminosity soft mask painted by a grayscale shading.
+fn shading_mask(
+    shading: Ref,
+    matrix: tiny_skia::Transform,
+    bbox: tiny_skia::NonZeroRect,
+    ctx: &mut Context,
+    base: tiny_skia::Transform,
+) -> Ref {
+    let mut canvas = Canvas::new(base);
+    canvas.content.save_state();
+    canvas.content.transform(to_matrix(matrix));
+    canvas.draw_shading(shading);
+    canvas.content.restore_state();
+
+    let bbox = pdf_writer::Rect::new(
+        bbox.left() - 1.0,
+        bbox.top() - 1.0,
+        bbox.right() + 1.0,
+        bbox.bottom() + 1.0,
+    );
+    let group = canvas.finish_group(ctx, bbox);
+    ctx.soft_mask_state(group, MaskType::Luminosity)
+}
+
+fn write_tiling_pattern(
+    pattern: &usvgr::Pattern,
+    ctx: &mut Context,
+    transform: tiny_skia::Transform,
+    base: tiny_skia::Transform,
+) -> Option<Ref> {
+    let rect = pattern.rect();
+    let matrix = transform
+        .pre_concat(pattern.transform())
+        .pre_translate(rect.x(), rect.y());
+
+    let mut ts = tiny_skia::Transform::default();
+    if let Some(vbox) = pattern.view_box() {
+        ts = vbox.to_transform(rect.size());
+    }
+
+    let base = base.pre_concat(matrix);
+    let cell = tiny_skia::NonZeroRect::from_xywh(0.0, 0.0, rect.width(), rect.height())?;
+    let visible_area = std::mem::replace(&mut ctx.visible_area, cell.transform(base)?);
+    let mut canvas = Canvas::new(base);
+    crate::render::render_nodes(pattern.root(), ctx, ts, &mut canvas);
+    ctx.visible_area = visible_area;
+    Some(canvas.finish_tiling_pattern(ctx, rect.width(), rect.height(), matrix))
+}
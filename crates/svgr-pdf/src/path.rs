@@ -0,0 +1,170 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use pdf_writer::types::{LineCapStyle, LineJoinStyle};
+use pdf_writer::Content;
+use tiny_skia::PathSegment;
+
+use crate::context::{to_matrix, Canvas, Context};
+
+pub fn render(
+    path: &usvgr::Path,
+    ctx: &mut Context,
+    transform: tiny_skia::Transform,
+    canvas: &mut Canvas,
+) {
+    if path.visibility() != usvgr::Visibility::Visible {
+        return;
+    }
+
+    if path.paint_order() == usvgr::PaintOrder::FillAndStroke {
+        fill_path(path, ctx, transform, canvas);
+        stroke_path(path, ctx, transform, canvas);
+    } else {
+        stroke_path(path, ctx, transform, canvas);
+        fill_path(path, ctx, transform, canvas);
+    }
+}
+
+fn fill_path(
+    path: &usvgr::Path,
+    ctx: &mut Context,
+    transform: tiny_skia::Transform,
+    canvas: &mut Canvas,
+) -> Option<()> {
+    let fill = path.fill()?;
+    let bbox = path
+        .bounding_box()
+        .to_non_zero_rect()?
+        .transform(transform)?;
+
+    canvas.content.save_state();
+    // Paint has to be set before the path transform,
+    // because soft masks use the current coordinate system.
+    crate::paint::set(
+        fill.paint(),
+        fill.opacity(),
+        false,
+        bbox,
+        ctx,
+        transform,
+        canvas,
+    );
+    canvas.content.transform(to_matrix(transform));
+    write_path(path.data(), &mut canvas.content);
+    match fill.rule() {
+        usvgr::FillRule::NonZero => canvas.content.fill_nonzero(),
+        usvgr::FillRule::EvenOdd => canvas.content.fill_even_odd(),
+    };
+    canvas.content.restore_state();
+
+    Some(())
+}
+
+fn stroke_path(
+    path: &usvgr::Path,
+    ctx: &mut Context,
+    transform: tiny_skia::Transform,
+    canvas: &mut Canvas,
+) -> Option<()> {
+    let stroke = path.stroke()?;
+    let bbox = path
+        .stroke_bounding_box()
+        .to_non_zero_rect()?
+        .transform(transform)?;
+
+    canvas.content.save_state();
+    crate::paint::set(
+        stroke.paint(),
+        stroke.opacity(),
+        true,
+        bbox,
+        ctx,
+        transform,
+        canvas,
+    );
+
+    if path.vector_effect() == usvgr::VectorEffect::NonScalingStroke {
+        // A non-scaling stroke is applied in the canvas coordinates.
+        // So we have to transform the path beforehand.
+        let data = path.data().clone().transform(transform)?;
+        write_path(&data, &mut canvas.content);
+    } else {
+        canvas.content.transform(to_matrix(transform));
+        write_path(path.data(), &mut canvas.content);
+    }
+
+    set_stroke(stroke, &mut canvas.content);
+    canvas.content.stroke();
+    canvas.content.restore_state();
+
+    Some(())
+}
+
+fn set_stroke(stroke: &usvgr::Stroke, content: &mut Content) {
+    content.set_line_width(stroke.width().get());
+    content.set_line_cap(match stroke.linecap() {
+        usvgr::LineCap::Butt => LineCapStyle::ButtCap,
+        usvgr::LineCap::Round => LineCapStyle::RoundCap,
+        usvgr::LineCap::Square => LineCapStyle::ProjectingSquareCap,
+    });
+    content.set_line_join(match stroke.linejoin() {
+        usvgr::LineJoin::Miter | usvgr::LineJoin::MiterClip => LineJoinStyle::MiterJoin,
+        usvgr::LineJoin::Round => LineJoinStyle::RoundJoin,
+        usvgr::LineJoin::Bevel => LineJoinStyle::BevelJoin,
+    });
+    content.set_miter_limit(stroke.miterlimit().get());
+
+    if let Some(dasharray) = stroke.dasharray() {
+        content.set_dash_pattern(dasharray.iter().copied(), stroke.dashoffset());
+    }
+}
+
+/// Writes path construction operators.
+///
+/// Quadratic curves are converted into cubic ones.
+pub fn write_path(path: &tiny_skia::Path, content: &mut Content) {
+    let mut last = tiny_skia::Point::zero();
+    for segment in path.segments() {
+        match segment {
+            PathSegment::MoveTo(p) => {
+                content.move_to(p.x, p.y);
+                last = p;
+            }
+            PathSegment::LineTo(p) => {
+                content.line_to(p.x, p.y);
+                last = p;
+            }
+            PathSegment::QuadTo(p1, p) => {
+                let c1 = (
+                    last.x + (p1.x - last.x) * 2.0 / 3.0,
+                    last.y + (p1.y - last.y) * 2.0 / 3.0,
+                );
+                let c2 = (
+                    p.x + (p1.x - p.x) * 2.0 / 3.0,
+                    p.y + (p1.y - p.y) * 2.0 / 3.0,
+                );
+                content.cubic_to(c1.0, c1.1, c2.0, c2.1, p.x, p.y);
+                last = p;
+            }
+            PathSegment::CubicTo(p1, p2, p) => {
+                content.cubic_to(p1.x, p1.y, p2.x, p2.y, p.x, p.y);
+                last = p;
+            }
+            PathSegment::Close => {
+                content.close_path();
+            }
+        }
+    }
+}
+
+/// Writes a clipping path and ends it without painting.
+pub fn write_clip(path: &tiny_skia::Path, rule: usvgr::FillRule, content: &mut Content) {
+    write_path(path, content);
+    match rule {
+        usvgr::FillRule::NonZero => content.clip_nonzero(),
+        usvgr::FillRule::EvenOdd => content.clip_even_odd(),
+    };
+    content.end_path();
+}
@@ -0,0 +1,133 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use pdf_writer::types::BlendMode;
+use pdf_writer::Finish;
+
+use crate::context::{to_pdf_rect, Canvas, Context};
+
+pub fn render_nodes(
+    parent: &usvgr::Group,
+    ctx: &mut Context,
+    transform: tiny_skia::Transform,
+    canvas: &mut Canvas,
+) {
+    for node in parent.children() {
+        render_node(node, ctx, transform, canvas);
+    }
+}
+
+pub fn render_node(
+    node: &usvgr::Node,
+    ctx: &mut Context,
+    transform: tiny_skia::Transform,
+    canvas: &mut Canvas,
+) {
+    match node {
+        usvgr::Node::Group(ref group) => {
+            if group.filters().is_empty() {
+                render_group(group, ctx, transform, canvas);
+            } else {
+                crate::filter::render(node, ctx, transform, canvas);
+            }
+        }
+        usvgr::Node::Path(ref path) => {
+            crate::path::render(path, ctx, transform, canvas);
+        }
+        usvgr::Node::Image(ref image) => {
+            crate::image::render(image, ctx, transform, canvas);
+        }
+        usvgr::Node::Text(ref text) => {
+            render_group(text.flattened(), ctx, transform, canvas);
+        }
+    }
+}
+
+fn render_group(
+    group: &usvgr::Group,
+    ctx: &mut Context,
+    transform: tiny_skia::Transform,
+    canvas: &mut Canvas,
+) -> Option<()> {
+    let transform = transform.pre_concat(group.transform());
+
+    if !group.should_isolate() {
+        render_nodes(group, ctx, transform, canvas);
+        return Some(());
+    }
+
+    let clip_paths = group
+        .clip_path()
+        .and_then(|clip| crate::clip::clip_paths(clip, transform));
+
+    // A clipping path alone doesn't require a transparency group.
+    let has_effects = group.opacity() != usvgr::Opacity::ONE
+        || group.blend_mode() != usvgr::BlendMode::Normal
+        || group.mask().is_some()
+        || group.isolate()
+        || group.enable_background();
+    if let (Some(ref paths), false) = (&clip_paths, has_effects) {
+        canvas.content.save_state();
+        for (path, rule) in paths {
+            crate::path::write_clip(path, *rule, &mut canvas.content);
+        }
+        render_nodes(group, ctx, transform, canvas);
+        canvas.content.restore_state();
+        return Some(());
+    }
+
+    let bbox = to_pdf_rect(group.layer_bounding_box().transform(transform)?);
+    let mut layer = Canvas::new(canvas.base);
+    render_nodes(group, ctx, transform, &mut layer);
+    let mut x_object = layer.finish_group(ctx, bbox);
+
+    if let (None, Some(clip)) = (&clip_paths, group.clip_path()) {
+        x_object = crate::clip::clip_layer(clip, x_object, bbox, ctx, transform, canvas.base);
+    }
+
+    if let Some(mask) = group.mask() {
+        x_object = crate::clip::mask_layer(mask, x_object, bbox, ctx, transform, canvas.base);
+    }
+
+    canvas.content.save_state();
+    for (path, rule) in clip_paths.iter().flatten() {
+        crate::path::write_clip(path, *rule, &mut canvas.content);
+    }
+
+    if group.opacity() != usvgr::Opacity::ONE || group.blend_mode() != usvgr::BlendMode::Normal {
+        let id = ctx.alloc();
+        let mut gs = ctx.chunk.ext_graphics(id);
+        gs.stroking_alpha(group.opacity().get())
+            .non_stroking_alpha(group.opacity().get())
+            .blend_mode(convert_blend_mode(group.blend_mode()));
+        gs.finish();
+        canvas.set_state(id);
+    }
+
+    canvas.draw_x_object(x_object);
+    canvas.content.restore_state();
+
+    Some(())
+}
+
+fn convert_blend_mode(mode: usvgr::BlendMode) -> BlendMode {
+    match mode {
+        usvgr::BlendMode::Normal => BlendMode::Normal,
+        usvgr::BlendMode::Multiply => BlendMode::Multiply,
+        usvgr::BlendMode::Screen => BlendMode::Screen,
+        usvgr::BlendMode::Overlay => BlendMode::Overlay,
+        usvgr::BlendMode::Darken => BlendMode::Darken,
+        usvgr::BlendMode::Lighten => BlendMode::Lighten,
+        usvgr::BlendMode::ColorDodge => BlendMode::ColorDodge,
+        usvgr::BlendMode::ColorBurn => BlendMode::ColorBurn,
+        usvgr::BlendMode::HardLight => BlendMode::HardLight,
+        usvgr::BlendMode::SoftLight => BlendMode::SoftLight,
+        usvgr::BlendMode::Difference => BlendMode::Difference,
+        usvgr::BlendMode::Exclusion => BlendMode::Exclusion,
+        usvgr::BlendMode::Hue => BlendMode::Hue,
+        usvgr::BlendMode::Saturation => BlendMode::Saturation,
+        usvgr::BlendMode::Color => BlendMode::Color,
+        usvgr::BlendMode::Luminosity => BlendMode::Luminosity,
+    }
+}
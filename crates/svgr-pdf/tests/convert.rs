@@ -0,0 +1,196 @@
+// The tree API requires a font database with the `text` feature.
+#![cfg(feature = "text")]
+
+use once_cell::sync::Lazy;
+
+static GLOBAL_FONTDB: Lazy<std::sync::Mutex<usvgr::fontdb::Database>> = Lazy::new(|| {
+    let mut fontdb = usvgr::fontdb::Database::new();
+    fontdb.load_fonts_dir("../svgr/tests/fonts");
+    fontdb.set_serif_family("Noto Serif");
+    fontdb.set_sans_serif_family("Noto Sans");
+    fontdb.set_cursive_family("Yellowtail");
+    fontdb.set_fantasy_family("Sedgwick Ave Display");
+    fontdb.set_monospace_family("Noto Mono");
+    std::sync::Mutex::new(fontdb)
+});
+
+fn convert(svg: &str) -> String {
+    let opt = svgr_pdf::Options {
+        compress: false,
+        ..svgr_pdf::Options::default()
+    };
+    convert_with(svg, &opt)
+}
+
+fn convert_with(svg: &str, opt: &svgr_pdf::Options) -> String {
+    let tree = {
+        let fontdb = GLOBAL_FONTDB.lock().unwrap();
+        usvgr::Tree::from_str(svg, &usvgr::Options::default(), &fontdb).unwrap()
+    };
+
+    let pdf = svgr_pdf::convert(&tree, opt);
+    assert!(pdf.starts_with(b"%PDF-"));
+    // Streams are uncompressed, but images are still binary.
+    String::from_utf8_lossy(&pdf).into_owned()
+}
+
+#[test]
+fn page_size() {
+    let svg = "<svg viewBox='0 0 200 100' xmlns='http://www.w3.org/2000/svg'/>";
+    assert!(convert(svg).contains("/MediaBox [0 0 200 100]"));
+
+    let opt = svgr_pdf::Options {
+        dpi: 96.0,
+        compress: false,
+        ..svgr_pdf::Options::default()
+    };
+    assert!(convert_with(svg, &opt).contains("/MediaBox [0 0 150 75]"));
+}
+
+#[test]
+fn compressed_streams() {
+    let svg = "
+    <svg viewBox='0 0 200 200' xmlns='http://www.w3.org/2000/svg'>
+        <rect x='20' y='20' width='160' height='160' fill='green'/>
+    </svg>";
+
+    let tree = usvgr::Tree::from_str(
+        svg,
+        &usvgr::Options::default(),
+        &usvgr::fontdb::Database::new(),
+    )
+    .unwrap();
+    let pdf = svgr_pdf::convert(&tree, &svgr_pdf::Options::default());
+    assert!(String::from_utf8_lossy(&pdf).contains("/Filter /FlateDecode"));
+}
+
+#[test]
+fn path() {
+    let pdf = convert(
+        "
+    <svg viewBox='0 0 200 200' xmlns='http://www.w3.org/2000/svg'>
+        <path d='M 20 20 Q 100 180 180 20' fill='green' fill-rule='evenodd'
+              stroke='black' stroke-width='4' stroke-dasharray='5 10'/>
+    </svg>",
+    );
+
+    assert!(pdf.contains("0 0.5019608 0 rg"));
+    assert!(pdf.contains("\nf*\n"));
+    assert!(pdf.contains("4 w"));
+    assert!(pdf.contains("[5 10] 0 d"));
+    assert!(!pdf.contains("/Subtype /Image"));
+}
+
+#[test]
+fn gradients_stay_vector() {
+    let pdf = convert(
+        "
+    <svg viewBox='0 0 200 200' xmlns='http://www.w3.org/2000/svg'>
+        <linearGradient id='lg1' spreadMethod='reflect' x2='0.5'>
+            <stop offset='0' stop-color='white'/>
+            <stop offset='1' stop-color='black'/>
+        </linearGradient>
+        <radialGradient id='rg1'>
+            <stop offset='0' stop-color='white'/>
+            <stop offset='1' stop-color='black' stop-opacity='0.5'/>
+        </radialGradient>
+        <rect x='20' y='20' width='160' height='70' fill='url(#lg1)'/>
+        <rect x='20' y='110' width='160' height='70' fill='url(#rg1)'/>
+    </svg>",
+    );
+
+    assert!(pdf.contains("/ShadingType 2"));
+    assert!(pdf.contains("/ShadingType 3"));
+    // Reflected periods.
+    assert!(pdf.contains("/Encode [0 1 1 0"));
+    // Non-uniform stop opacity.
+    assert!(pdf.contains("/S /Luminosity"));
+    assert!(!pdf.contains("/Subtype /Image"));
+}
+
+#[test]
+fn pattern() {
+    let pdf = convert(
+        "
+    <svg viewBox='0 0 200 200' xmlns='http://www.w3.org/2000/svg'>
+        <pattern id='patt1' patternUnits='userSpaceOnUse' width='20' height='20'>
+            <rect width='10' height='10' fill='green'/>
+        </pattern>
+        <rect x='20' y='20' width='160' height='160' fill='url(#patt1)'/>
+    </svg>",
+    );
+
+    assert!(pdf.contains("/PatternType 1"));
+    assert!(pdf.contains("/XStep 20"));
+    assert!(pdf.contains("/Pattern cs"));
+}
+
+#[test]
+fn group_opacity_and_blend_mode() {
+    let pdf = convert(
+        "
+    <svg viewBox='0 0 200 200' xmlns='http://www.w3.org/2000/svg'>
+        <g opacity='0.5' style='mix-blend-mode:multiply'>
+            <rect x='20' y='20' width='100' height='100' fill='green'/>
+            <rect x='80' y='80' width='100' height='100' fill='blue'/>
+        </g>
+    </svg>",
+    );
+
+    assert!(pdf.contains("/S /Transparency"));
+    assert!(pdf.contains("/BM /Multiply"));
+    assert!(pdf.contains("/ca 0.5"));
+}
+
+#[test]
+fn clip_path_and_mask() {
+    let pdf = convert(
+        "
+    <svg viewBox='0 0 200 200' xmlns='http://www.w3.org/2000/svg'>
+        <clipPath id='clip1'>
+            <circle cx='100' cy='100' r='80'/>
+        </clipPath>
+        <mask id='mask1'>
+            <rect width='200' height='100' fill='white'/>
+        </mask>
+        <rect width='200' height='200' fill='green' clip-path='url(#clip1)'/>
+        <rect width='200' height='200' fill='blue' mask='url(#mask1)'/>
+    </svg>",
+    );
+
+    assert!(pdf.contains("\nW\nn\n"));
+    assert!(pdf.contains("/S /Luminosity"));
+}
+
+#[test]
+fn text_as_outlines() {
+    let pdf = convert(
+        "
+    <svg viewBox='0 0 200 200' xmlns='http://www.w3.org/2000/svg'>
+        <text x='20' y='100' font-family='Noto Sans' font-size='48'>Text</text>
+    </svg>",
+    );
+
+    assert!(!pdf.contains("/Font"));
+    assert!(pdf.contains(" c\n"));
+}
+
+#[test]
+fn filter_is_rasterized() {
+    let pdf = convert(
+        "
+    <svg viewBox='0 0 200 200' xmlns='http://www.w3.org/2000/svg'>
+        <filter id='filter1'>
+            <feGaussianBlur stdDeviation='5'/>
+        </filter>
+        <rect x='20' y='20' width='160' height='160' fill='green' filter='url(#filter1)'/>
+        <rect x='80' y='80' width='40' height='40' fill='blue'/>
+    </svg>",
+    );
+
+    assert!(pdf.contains("/Subtype /Image"));
+    // Blurred edges require an alpha channel.
+    assert!(pdf.contains("/SMask"));
+    // Content without filters stays vector.
+    assert!(pdf.contains("0 0 1 rg"));
+}
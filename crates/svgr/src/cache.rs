@@ -1,8 +1,10 @@
 use lru::LruCache;
+use std::any::Any;
 use std::cell::UnsafeCell;
 use std::collections::VecDeque;
 use std::hash::{BuildHasher, Hash, Hasher};
-use tiny_skia::{IntSize, Pixmap, BYTES_PER_PIXEL};
+use std::sync::Arc;
+use tiny_skia::{IntRect, IntSize, Pixmap, BYTES_PER_PIXEL};
 use usvgr::{
     ahash::{self},
     lru,
@@ -11,13 +13,44 @@ use usvgr::{
 #[derive(Debug)]
 struct SvgrCacheInternal<HashBuilder: BuildHasher = ahash::RandomState> {
     lru: LruCache<u64, Pixmap>,
+    filter_lru: LruCache<u64, CachedFilterResult>,
+    gradient_lut_lru: LruCache<u64, Arc<[[f32; 4]]>>,
     hash_builder: HashBuilder,
+    stats: CacheStats,
+}
+
+/// Cache lookup statistics.
+///
+/// Collected since the cache creation or the last [`SvgrCache::reset_stats`] call.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct CacheStats {
+    /// Number of nodes and groups served from the cache.
+    pub node_hits: u64,
+    /// Number of nodes and groups that had to be rendered.
+    pub node_misses: u64,
+    /// Number of filter primitive results served from the cache.
+    pub filter_hits: u64,
+    /// Number of filter primitive results that had to be computed.
+    pub filter_misses: u64,
+}
+
+/// A filter primitive result stored in the cache.
+#[derive(Debug)]
+pub(crate) struct CachedFilterResult {
+    /// A `Pixmap` or a floating point image, depending on the filter precision.
+    pub(crate) image: Box<dyn Any + Send>,
+    pub(crate) region: IntRect,
+    pub(crate) color_space: usvgr::filter::ColorInterpolation,
 }
 
 /// Defines rendering LRU cache. Each individual node and group will be cached separately.
 /// Make sure that in most cases it will require saving of the whole canvas which may lead to significant memory usage.
 /// So it is recommended to set the cache size to a reasonable value.
 ///
+/// Results of expensive filter primitives, like blurs, lighting and turbulence, are cached
+/// separately with the same capacity and are reused while the primitive and its inputs stay unchanged.
+/// The same goes for color lookup tables of manually rendered gradients.
+///
 /// Pass &mut SvgrCache::none() if you don't need caching.
 #[derive(Debug)]
 pub struct SvgrCache<RandomState: BuildHasher = ahash::RandomState> {
@@ -153,7 +186,10 @@ impl<THashBuilder: BuildHasher + Default> SvgrCache<THashBuilder> {
             Self {
                 cache: Some(SvgrCacheInternal {
                     lru: LruCache::new(std::num::NonZeroUsize::new(size).unwrap()),
+                    filter_lru: LruCache::new(std::num::NonZeroUsize::new(size).unwrap()),
+                    gradient_lut_lru: LruCache::new(std::num::NonZeroUsize::new(size).unwrap()),
                     hash_builder: THashBuilder::default(),
+                    stats: CacheStats::default(),
                 }),
             }
         } else {
@@ -161,6 +197,23 @@ impl<THashBuilder: BuildHasher + Default> SvgrCache<THashBuilder> {
         }
     }
 
+    /// Returns lookup statistics.
+    ///
+    /// Always zero when the cache is disabled.
+    pub fn stats(&self) -> CacheStats {
+        self.cache
+            .as_ref()
+            .map(|cache| cache.stats)
+            .unwrap_or_default()
+    }
+
+    /// Resets lookup statistics.
+    pub fn reset_stats(&mut self) {
+        if let Some(cache) = self.cache.as_mut() {
+            cache.stats = CacheStats::default();
+        }
+    }
+
     fn lru(&mut self) -> Option<&mut LruCache<u64, Pixmap>> {
         self.cache.as_mut().map(|cache| &mut cache.lru)
     }
@@ -182,6 +235,61 @@ impl<THashBuilder: BuildHasher + Default> SvgrCache<THashBuilder> {
         Some(Hasher::finish(&hasher))
     }
 
+    /// Returns a hasher for filter primitive keys or `None` when the cache is disabled.
+    pub(crate) fn filter_hasher(&self) -> Option<THashBuilder::Hasher> {
+        Some(self.cache.as_ref()?.hash_builder.build_hasher())
+    }
+
+    pub(crate) fn filter_result(&mut self, key: u64) -> Option<&CachedFilterResult> {
+        let cache = self.cache.as_mut()?;
+        let result = cache.filter_lru.get(&key);
+        if result.is_some() {
+            cache.stats.filter_hits += 1;
+        } else {
+            cache.stats.filter_misses += 1;
+        }
+
+        result
+    }
+
+    pub(crate) fn insert_filter_result(
+        &mut self,
+        key: u64,
+        result: CachedFilterResult,
+        pixmap_pool: &PixmapPool,
+    ) {
+        if let Some(cache) = self.cache.as_mut() {
+            if let Some((_, evicted)) = cache.filter_lru.push(key, result) {
+                if let Ok(pixmap) = evicted.image.downcast::<Pixmap>() {
+                    pixmap_pool.release(*pixmap);
+                }
+            }
+        }
+    }
+
+    /// Returns a gradient color lookup table for the specified opacity,
+    /// building it with `f` when it is not cached yet.
+    pub(crate) fn gradient_lut<F: FnOnce() -> Vec<[f32; 4]>>(
+        &mut self,
+        gradient: &usvgr::BaseGradient,
+        opacity: usvgr::Opacity,
+        f: F,
+    ) -> Arc<[[f32; 4]]> {
+        let Some(cache) = self.cache.as_mut() else {
+            return f().into();
+        };
+
+        let mut hasher = cache.hash_builder.build_hasher();
+        gradient.hash(&mut hasher);
+        opacity.get().to_bits().hash(&mut hasher);
+        let key = hasher.finish();
+
+        cache
+            .gradient_lut_lru
+            .get_or_insert(key, || f().into())
+            .clone()
+    }
+
     pub(crate) fn with_subpixmap_cache<'a, F: FnOnce(Pixmap, &mut Self) -> Option<Pixmap>>(
         &'a mut self,
         node: &impl Hash,
@@ -199,7 +307,16 @@ impl<THashBuilder: BuildHasher + Default> SvgrCache<THashBuilder> {
 
         let hash = self.hash(size, transform, node)?;
 
-        if !self.lru()?.contains(&hash) {
+        let hit = self.lru()?.contains(&hash);
+        if let Some(cache) = self.cache.as_mut() {
+            if hit {
+                cache.stats.node_hits += 1;
+            } else {
+                cache.stats.node_misses += 1;
+            }
+        }
+
+        if !hit {
             let pixmap = pixmap_pool.take_or_allocate(size.width(), size.height())?;
             let pixmap = { f(pixmap, self) }?;
 
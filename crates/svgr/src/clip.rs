@@ -7,22 +7,80 @@ use tiny_skia::IntSize;
 
 pub fn apply(
     clip: &usvgr::ClipPath,
+    ctx: &Context,
     transform: tiny_skia::Transform,
     pixmap: &mut tiny_skia::Pixmap,
     cache: &mut cache::SvgrCache,
     pixmap_pool: &cache::PixmapPool,
 ) {
+    let size = IntSize::from_wh(pixmap.width(), pixmap.height()).unwrap();
+    let mask = own_mask(clip, ctx, transform, size, cache, pixmap_pool);
+
+    if let Some(clip) = clip.clip_path() {
+        // here we are handling the recurision on self, and while we hold the reference to the
+        // cache lru instance this will OVERWRITE the existing pixmpa or cache entry
+        apply(
+            clip,
+            ctx,
+            transform,
+            pixmap,
+            &mut SvgrCache::none(),
+            &PixmapPool::new(),
+        );
+    }
+
+    pixmap.apply_mask(&mask);
+}
+
+/// Returns a clip path coverage, including the clip paths of the clip path itself.
+pub fn mask(
+    clip: &usvgr::ClipPath,
+    ctx: &Context,
+    transform: tiny_skia::Transform,
+    size: IntSize,
+    cache: &mut cache::SvgrCache,
+    pixmap_pool: &cache::PixmapPool,
+) -> tiny_skia::Mask {
+    let mut mask = own_mask(clip, ctx, transform, size, cache, pixmap_pool);
+
+    if let Some(clip) = clip.clip_path() {
+        let parent = self::mask(
+            clip,
+            ctx,
+            transform,
+            size,
+            &mut SvgrCache::none(),
+            &PixmapPool::new(),
+        );
+
+        for (a, b) in mask.data_mut().iter_mut().zip(parent.data()) {
+            *a = ((*a as u16 * *b as u16 + 127) / 255) as u8;
+        }
+    }
+
+    mask
+}
+
+fn own_mask(
+    clip: &usvgr::ClipPath,
+    ctx: &Context,
+    transform: tiny_skia::Transform,
+    size: IntSize,
+    cache: &mut cache::SvgrCache,
+    pixmap_pool: &cache::PixmapPool,
+) -> tiny_skia::Mask {
     let clip_pixmap = cache
         .with_subpixmap_cache(
             clip,
             transform,
             pixmap_pool,
-            IntSize::from_wh(pixmap.width(), pixmap.height()).unwrap(),
+            size,
             |mut clip_pixmap, cache| {
                 clip_pixmap.fill(tiny_skia::Color::BLACK);
 
                 draw_children(
                     clip.root(),
+                    ctx,
                     tiny_skia::BlendMode::Clear,
                     transform.pre_concat(clip.transform()),
                     &mut clip_pixmap.as_mut(),
@@ -35,25 +93,14 @@ pub fn apply(
         )
         .expect("failed to allocate pixmap for clip");
 
-    if let Some(clip) = clip.clip_path() {
-        // here we are handling the recurision on self, and while we hold the reference to the
-        // cache lru instance this will OVERWRITE the existing pixmpa or cache entry
-        apply(
-            clip,
-            transform,
-            pixmap,
-            &mut SvgrCache::none(),
-            &PixmapPool::new(),
-        );
-    }
-
     let mut mask = tiny_skia::Mask::from_pixmap(clip_pixmap.as_ref(), tiny_skia::MaskType::Alpha);
     mask.invert();
-    pixmap.apply_mask(&mask);
+    mask
 }
 
 fn draw_children(
     parent: &usvgr::Group,
+    ctx: &Context,
     mode: tiny_skia::BlendMode,
     transform: tiny_skia::Transform,
     pixmap: &mut tiny_skia::PixmapMut,
@@ -70,6 +117,11 @@ fn draw_children(
                 let ctx = Context {
                     // We could use any values here. They will not be used anyway.
                     max_bbox: tiny_skia::IntRect::from_xywh(0, 0, 1, 1).unwrap(),
+                    gradient_dithering: ctx.gradient_dithering,
+                    custom_filters: None,
+                    filter_precision: crate::FilterPrecision::U8,
+                    background: None,
+                    hdr: false,
                 };
 
                 crate::path::fill_path(path, mode, &ctx, transform, pixmap, cache, pixmap_pool);
@@ -77,6 +129,7 @@ fn draw_children(
             usvgr::Node::Text(ref text) => {
                 draw_children(
                     text.flattened(),
+                    ctx,
                     mode,
                     transform,
                     pixmap,
@@ -91,9 +144,9 @@ fn draw_children(
                     // If a `clipPath` child also has a `clip-path`
                     // then we should render this child on a new canvas,
                     // clip it, and only then draw it to the `clipPath`.
-                    clip_group(group, clip, transform, pixmap, cache, pixmap_pool);
+                    clip_group(group, clip, ctx, transform, pixmap, cache, pixmap_pool);
                 } else {
-                    draw_children(group, mode, transform, pixmap, cache, pixmap_pool);
+                    draw_children(group, ctx, mode, transform, pixmap, cache, pixmap_pool);
                 }
             }
             _ => {}
@@ -104,6 +157,7 @@ fn draw_children(
 fn clip_group(
     children: &usvgr::Group,
     clip: &usvgr::ClipPath,
+    ctx: &Context,
     transform: tiny_skia::Transform,
     pixmap: &mut tiny_skia::PixmapMut,
     cache: &mut cache::SvgrCache,
@@ -115,13 +169,14 @@ fn clip_group(
 
     draw_children(
         children,
+        ctx,
         tiny_skia::BlendMode::SourceOver,
         transform,
         &mut clip_pixmap.as_mut(),
         cache,
         pixmap_pool,
     );
-    apply(clip, transform, &mut clip_pixmap, cache, pixmap_pool);
+    apply(clip, ctx, transform, &mut clip_pixmap, cache, pixmap_pool);
 
     let mut paint = tiny_skia::PixmapPaint::default();
     paint.blend_mode = tiny_skia::BlendMode::Xor;
@@ -6,8 +6,8 @@
 
 #![allow(clippy::needless_range_loop)]
 
-use super::ImageRefMut;
-use rgb::RGBA8;
+use super::{parallel, ImageRefMut};
+use rgb::{RGBA, RGBA8};
 use std::cmp;
 
 const STEPS: usize = 5;
@@ -18,19 +18,23 @@ const STEPS: usize = 5;
 ///
 /// A negative or zero `sigma_x`/`sigma_y` will disable the blur along that axis.
 ///
+/// Both passes keep exact integer sums, so splitting the image into bands
+/// for parallel processing doesn't affect the result.
+///
 /// # Allocations
 ///
 /// This method will allocate a copy of the `src` image as a back buffer.
-pub fn apply(sigma_x: f64, sigma_y: f64, mut src: ImageRefMut) {
+pub fn apply(sigma_x: f64, sigma_y: f64, src: ImageRefMut) {
     let boxes_horz = create_box_gauss(sigma_x as f32);
     let boxes_vert = create_box_gauss(sigma_y as f32);
     let mut backbuf = src.data.to_vec();
-    let mut backbuf = ImageRefMut::new(src.width, src.height, &mut backbuf);
+    let width = src.width as usize;
 
     for (box_size_horz, box_size_vert) in boxes_horz.iter().zip(boxes_vert.iter()) {
         let radius_horz = ((box_size_horz - 1) / 2) as usize;
         let radius_vert = ((box_size_vert - 1) / 2) as usize;
-        box_blur_impl(radius_horz, radius_vert, &mut backbuf, &mut src);
+        box_blur_vert(radius_vert, width, src.data, &mut backbuf);
+        box_blur_horz(radius_horz, width, &backbuf, src.data);
     }
 }
 
@@ -71,267 +75,163 @@ fn create_box_gauss(sigma: f32) -> [i32; STEPS] {
     }
 }
 
-#[inline]
-fn box_blur_impl(
-    blur_radius_horz: usize,
-    blur_radius_vert: usize,
-    backbuf: &mut ImageRefMut,
-    frontbuf: &mut ImageRefMut,
-) {
-    box_blur_vert(blur_radius_vert, frontbuf, backbuf);
-    box_blur_horz(blur_radius_horz, backbuf, frontbuf);
-}
-
-#[inline]
-fn box_blur_vert(blur_radius: usize, backbuf: &ImageRefMut, frontbuf: &mut ImageRefMut) {
+/// Blurs columns of `src` into `dest`.
+///
+/// Rows are processed top to bottom, keeping a running sum for each column,
+/// so the memory is accessed sequentially.
+fn box_blur_vert(blur_radius: usize, width: usize, src: &[RGBA8], dest: &mut [RGBA8]) {
     if blur_radius == 0 {
-        frontbuf.data.copy_from_slice(backbuf.data);
+        dest.copy_from_slice(src);
         return;
     }
 
-    let width = backbuf.width as usize;
-    let height = backbuf.height as usize;
-
+    let height = src.len() / width;
     let iarr = 1.0 / (blur_radius + blur_radius + 1) as f32;
-    let blur_radius_prev = blur_radius as isize - height as isize;
-    let blur_radius_next = blur_radius as isize + 1;
-
-    for i in 0..width {
-        let col_start = i; //inclusive
-        let col_end = i + width * (height - 1); //inclusive
-        let mut ti = i;
-        let mut li = ti;
-        let mut ri = ti + blur_radius * width;
-
-        let fv = RGBA8::default();
-        let lv = RGBA8::default();
-
-        let mut val_r = blur_radius_next * (fv.r as isize);
-        let mut val_g = blur_radius_next * (fv.g as isize);
-        let mut val_b = blur_radius_next * (fv.b as isize);
-        let mut val_a = blur_radius_next * (fv.a as isize);
-
-        // Get the pixel at the specified index, or the first pixel of the column
-        // if the index is beyond the top edge of the image
-        let get_top = |i| {
-            if i < col_start {
-                fv
-            } else {
-                backbuf.data[i]
+    let row = |y: usize| &src[y * width..(y + 1) * width];
+
+    // Each band has to sum up its initial window first, so they shouldn't be too small.
+    let band_rows = cmp::max(
+        height.div_ceil(parallel::band_count(src.len())),
+        blur_radius * 2 + 1,
+    );
+
+    parallel::for_each_chunk(dest, band_rows * width, |band_idx, band| {
+        let y0 = band_idx * band_rows;
+        let mut sums = vec![Sums::default(); width];
+
+        // Pixels outside the image are transparent black.
+        for y in y0.saturating_sub(blur_radius)..cmp::min(y0 + blur_radius, height) {
+            for (sum, p) in sums.iter_mut().zip(row(y)) {
+                sum.add(*p);
             }
-        };
-
-        // Get the pixel at the specified index, or the last pixel of the column
-        // if the index is beyond the bottom edge of the image
-        let get_bottom = |i| {
-            if i > col_end {
-                lv
-            } else {
-                backbuf.data[i]
-            }
-        };
-
-        for j in 0..cmp::min(blur_radius, height) {
-            let bb = backbuf.data[ti + j * width];
-            val_r += bb.r as isize;
-            val_g += bb.g as isize;
-            val_b += bb.b as isize;
-            val_a += bb.a as isize;
-        }
-        if blur_radius > height {
-            val_r += blur_radius_prev * (lv.r as isize);
-            val_g += blur_radius_prev * (lv.g as isize);
-            val_b += blur_radius_prev * (lv.b as isize);
-            val_a += blur_radius_prev * (lv.a as isize);
         }
 
-        for _ in 0..cmp::min(height, blur_radius + 1) {
-            let bb = get_bottom(ri);
-            ri += width;
-            val_r += sub(bb.r, fv.r);
-            val_g += sub(bb.g, fv.g);
-            val_b += sub(bb.b, fv.b);
-            val_a += sub(bb.a, fv.a);
-
-            frontbuf.data[ti] = RGBA8 {
-                r: round(val_r as f32 * iarr) as u8,
-                g: round(val_g as f32 * iarr) as u8,
-                b: round(val_b as f32 * iarr) as u8,
-                a: round(val_a as f32 * iarr) as u8,
-            };
-            ti += width;
-        }
+        for (i, dest_row) in band.chunks_exact_mut(width).enumerate() {
+            let y = y0 + i;
 
-        if height <= blur_radius {
-            // otherwise `(height - blur_radius)` will underflow
-            continue;
-        }
+            if y + blur_radius < height {
+                for (sum, p) in sums.iter_mut().zip(row(y + blur_radius)) {
+                    sum.add(*p);
+                }
+            }
 
-        for _ in (blur_radius + 1)..(height - blur_radius) {
-            let bb1 = backbuf.data[ri];
-            ri += width;
-            let bb2 = backbuf.data[li];
-            li += width;
-
-            val_r += sub(bb1.r, bb2.r);
-            val_g += sub(bb1.g, bb2.g);
-            val_b += sub(bb1.b, bb2.b);
-            val_a += sub(bb1.a, bb2.a);
-
-            frontbuf.data[ti] = RGBA8 {
-                r: round(val_r as f32 * iarr) as u8,
-                g: round(val_g as f32 * iarr) as u8,
-                b: round(val_b as f32 * iarr) as u8,
-                a: round(val_a as f32 * iarr) as u8,
-            };
-            ti += width;
-        }
+            for (d, sum) in dest_row.iter_mut().zip(&sums) {
+                *d = sum.average(iarr);
+            }
 
-        for _ in 0..cmp::min(height - blur_radius - 1, blur_radius) {
-            let bb = get_top(li);
-            li += width;
-
-            val_r += sub(lv.r, bb.r);
-            val_g += sub(lv.g, bb.g);
-            val_b += sub(lv.b, bb.b);
-            val_a += sub(lv.a, bb.a);
-
-            frontbuf.data[ti] = RGBA8 {
-                r: round(val_r as f32 * iarr) as u8,
-                g: round(val_g as f32 * iarr) as u8,
-                b: round(val_b as f32 * iarr) as u8,
-                a: round(val_a as f32 * iarr) as u8,
-            };
-            ti += width;
+            if y >= blur_radius {
+                for (sum, p) in sums.iter_mut().zip(row(y - blur_radius)) {
+                    sum.sub(*p);
+                }
+            }
         }
-    }
+    });
 }
 
-#[inline]
-fn box_blur_horz(blur_radius: usize, backbuf: &ImageRefMut, frontbuf: &mut ImageRefMut) {
+/// Blurs rows of `src` into `dest`.
+fn box_blur_horz(blur_radius: usize, width: usize, src: &[RGBA8], dest: &mut [RGBA8]) {
     if blur_radius == 0 {
-        frontbuf.data.copy_from_slice(backbuf.data);
+        dest.copy_from_slice(src);
         return;
     }
 
-    let width = backbuf.width as usize;
-    let height = backbuf.height as usize;
-
+    let height = src.len() / width;
     let iarr = 1.0 / (blur_radius + blur_radius + 1) as f32;
-    let blur_radius_prev = blur_radius as isize - width as isize;
-    let blur_radius_next = blur_radius as isize + 1;
-
-    for i in 0..height {
-        let row_start = i * width; // inclusive
-        let row_end = (i + 1) * width - 1; // inclusive
-        let mut ti = i * width; // VERTICAL: $i;
-        let mut li = ti;
-        let mut ri = ti + blur_radius;
-
-        let fv = RGBA8::default();
-        let lv = RGBA8::default();
-
-        let mut val_r = blur_radius_next * (fv.r as isize);
-        let mut val_g = blur_radius_next * (fv.g as isize);
-        let mut val_b = blur_radius_next * (fv.b as isize);
-        let mut val_a = blur_radius_next * (fv.a as isize);
-
-        // Get the pixel at the specified index, or the first pixel of the row
-        // if the index is beyond the left edge of the image
-        let get_left = |i| {
-            if i < row_start {
-                fv
-            } else {
-                backbuf.data[i]
+    let band_rows = height.div_ceil(parallel::band_count(src.len()));
+
+    parallel::for_each_chunk(dest, band_rows * width, |band_idx, band| {
+        let y0 = band_idx * band_rows;
+
+        for (i, dest_row) in band.chunks_exact_mut(width).enumerate() {
+            let row = &src[(y0 + i) * width..(y0 + i + 1) * width];
+
+            // Pixels outside the image are transparent black.
+            let mut sum = Sums::default();
+            for p in &row[..cmp::min(blur_radius, width)] {
+                sum.add(*p);
             }
-        };
 
-        // Get the pixel at the specified index, or the last pixel of the row
-        // if the index is beyond the right edge of the image
-        let get_right = |i| {
-            if i > row_end {
-                lv
-            } else {
-                backbuf.data[i]
+            for x in 0..width {
+                if x + blur_radius < width {
+                    sum.add(row[x + blur_radius]);
+                }
+
+                dest_row[x] = sum.average(iarr);
+
+                if x >= blur_radius {
+                    sum.sub(row[x - blur_radius]);
+                }
             }
-        };
-
-        for j in 0..cmp::min(blur_radius, width) {
-            let bb = backbuf.data[ti + j]; // VERTICAL: ti + j * width
-            val_r += bb.r as isize;
-            val_g += bb.g as isize;
-            val_b += bb.b as isize;
-            val_a += bb.a as isize;
         }
-        if blur_radius > width {
-            val_r += blur_radius_prev * (lv.r as isize);
-            val_g += blur_radius_prev * (lv.g as isize);
-            val_b += blur_radius_prev * (lv.b as isize);
-            val_a += blur_radius_prev * (lv.a as isize);
+    });
+}
+
+/// Applies a box blur to a floating point image.
+///
+/// Input image pixels should have a **premultiplied alpha**.
+///
+/// A negative or zero `sigma_x`/`sigma_y` will disable the blur along that axis.
+///
+/// # Allocations
+///
+/// This method will allocate a copy of the `src` image as a back buffer.
+pub fn apply_f32(sigma_x: f64, sigma_y: f64, src: ImageRefMut<RGBA<f32>>) {
+    let boxes_horz = create_box_gauss(sigma_x as f32);
+    let boxes_vert = create_box_gauss(sigma_y as f32);
+    let mut backbuf = src.data.to_vec();
+
+    let width = src.width as usize;
+    let height = src.height as usize;
+
+    for (box_size_horz, box_size_vert) in boxes_horz.iter().zip(boxes_vert.iter()) {
+        let radius_horz = ((box_size_horz - 1) / 2) as usize;
+        let radius_vert = ((box_size_vert - 1) / 2) as usize;
+
+        for x in 0..width {
+            box_blur_line_f32(radius_vert, x, width, height, src.data, &mut backbuf);
         }
 
-        // Process the left side where we need pixels from beyond the left edge
-        for _ in 0..cmp::min(width, blur_radius + 1) {
-            let bb = get_right(ri);
-            ri += 1;
-            val_r += sub(bb.r, fv.r);
-            val_g += sub(bb.g, fv.g);
-            val_b += sub(bb.b, fv.b);
-            val_a += sub(bb.a, fv.a);
-
-            frontbuf.data[ti] = RGBA8 {
-                r: round(val_r as f32 * iarr) as u8,
-                g: round(val_g as f32 * iarr) as u8,
-                b: round(val_b as f32 * iarr) as u8,
-                a: round(val_a as f32 * iarr) as u8,
-            };
-            ti += 1; // VERTICAL : ti += width, same with the other areas
+        for y in 0..height {
+            box_blur_line_f32(radius_horz, y * width, 1, width, &backbuf, src.data);
         }
+    }
+}
 
-        if width <= blur_radius {
-            // otherwise `(width - blur_radius)` will underflow
-            continue;
+/// Blurs `len` pixels starting at `start` with the `step` offset between them.
+///
+/// Pixels outside the line are transparent black.
+fn box_blur_line_f32(
+    blur_radius: usize,
+    start: usize,
+    step: usize,
+    len: usize,
+    src: &[RGBA<f32>],
+    dest: &mut [RGBA<f32>],
+) {
+    if blur_radius == 0 {
+        for i in 0..len {
+            dest[start + i * step] = src[start + i * step];
         }
 
-        // Process the middle where we know we won't bump into borders
-        // without the extra indirection of get_left/get_right. This is faster.
-        for _ in (blur_radius + 1)..(width - blur_radius) {
-            let bb1 = backbuf.data[ri];
-            ri += 1;
-            let bb2 = backbuf.data[li];
-            li += 1;
-
-            val_r += sub(bb1.r, bb2.r);
-            val_g += sub(bb1.g, bb2.g);
-            val_b += sub(bb1.b, bb2.b);
-            val_a += sub(bb1.a, bb2.a);
-
-            frontbuf.data[ti] = RGBA8 {
-                r: round(val_r as f32 * iarr) as u8,
-                g: round(val_g as f32 * iarr) as u8,
-                b: round(val_b as f32 * iarr) as u8,
-                a: round(val_a as f32 * iarr) as u8,
-            };
-            ti += 1;
+        return;
+    }
+
+    let iarr = 1.0 / (blur_radius + blur_radius + 1) as f32;
+    let mut sum = RGBA::new(0.0, 0.0, 0.0, 0.0);
+
+    for i in 0..cmp::min(blur_radius, len) {
+        sum += src[start + i * step];
+    }
+
+    for i in 0..len {
+        if i + blur_radius < len {
+            sum += src[start + (i + blur_radius) * step];
         }
 
-        // Process the right side where we need pixels from beyond the right edge
-        for _ in 0..cmp::min(width - blur_radius - 1, blur_radius) {
-            let bb = get_left(li);
-            li += 1;
-
-            val_r += sub(lv.r, bb.r);
-            val_g += sub(lv.g, bb.g);
-            val_b += sub(lv.b, bb.b);
-            val_a += sub(lv.a, bb.a);
-
-            frontbuf.data[ti] = RGBA8 {
-                r: round(val_r as f32 * iarr) as u8,
-                g: round(val_g as f32 * iarr) as u8,
-                b: round(val_b as f32 * iarr) as u8,
-                a: round(val_a as f32 * iarr) as u8,
-            };
-            ti += 1;
+        dest[start + i * step] = sum * iarr;
+
+        if i >= blur_radius {
+            sum -= src[start + (i - blur_radius) * step];
         }
     }
 }
@@ -340,6 +240,7 @@ fn box_blur_horz(blur_radius: usize, backbuf: &ImageRefMut, frontbuf: &mut Image
 /// This is orders of magnitude faster than built-in rounding intrinsic.
 ///
 /// Source: https://stackoverflow.com/a/42386149/585725
+#[cfg(not(target_arch = "x86_64"))]
 #[inline]
 fn round(mut x: f32) -> f32 {
     x += 12582912.0;
@@ -347,7 +248,83 @@ fn round(mut x: f32) -> f32 {
     x
 }
 
-#[inline]
-fn sub(c1: u8, c2: u8) -> isize {
-    c1 as isize - c2 as isize
+/// Running sums of pixel channels.
+///
+/// Uses SSE2, which is always available on x86_64, and plain integers elsewhere.
+#[cfg(target_arch = "x86_64")]
+#[derive(Clone, Copy)]
+struct Sums(std::arch::x86_64::__m128i);
+
+#[cfg(target_arch = "x86_64")]
+impl Default for Sums {
+    #[inline]
+    fn default() -> Self {
+        // Safety: SSE2 is a part of the x86_64 baseline.
+        unsafe { Sums(std::arch::x86_64::_mm_setzero_si128()) }
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+impl Sums {
+    #[inline]
+    fn add(&mut self, p: RGBA8) {
+        use std::arch::x86_64::*;
+        unsafe { self.0 = _mm_add_epi32(self.0, Self::widen(p)) }
+    }
+
+    #[inline]
+    fn sub(&mut self, p: RGBA8) {
+        use std::arch::x86_64::*;
+        unsafe { self.0 = _mm_sub_epi32(self.0, Self::widen(p)) }
+    }
+
+    /// Same as the scalar `round(sum as f32 * iarr) as u8` for each channel,
+    /// including the rounding trick.
+    #[inline]
+    fn average(&self, iarr: f32) -> RGBA8 {
+        use std::arch::x86_64::*;
+        unsafe {
+            let v = _mm_mul_ps(_mm_cvtepi32_ps(self.0), _mm_set1_ps(iarr));
+            let magic = _mm_set1_ps(12582912.0);
+            let v = _mm_cvttps_epi32(_mm_sub_ps(_mm_add_ps(v, magic), magic));
+            let v = _mm_packus_epi16(_mm_packs_epi32(v, v), v);
+            let [r, g, b, a] = _mm_cvtsi128_si32(v).to_le_bytes();
+            RGBA8 { r, g, b, a }
+        }
+    }
+
+    #[inline]
+    unsafe fn widen(p: RGBA8) -> std::arch::x86_64::__m128i {
+        use std::arch::x86_64::*;
+        let zero = _mm_setzero_si128();
+        let v = _mm_cvtsi32_si128(i32::from_le_bytes([p.r, p.g, p.b, p.a]));
+        _mm_unpacklo_epi16(_mm_unpacklo_epi8(v, zero), zero)
+    }
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+#[derive(Clone, Copy, Default)]
+struct Sums([i32; 4]);
+
+#[cfg(not(target_arch = "x86_64"))]
+impl Sums {
+    #[inline]
+    fn add(&mut self, p: RGBA8) {
+        for (s, c) in self.0.iter_mut().zip([p.r, p.g, p.b, p.a]) {
+            *s += c as i32;
+        }
+    }
+
+    #[inline]
+    fn sub(&mut self, p: RGBA8) {
+        for (s, c) in self.0.iter_mut().zip([p.r, p.g, p.b, p.a]) {
+            *s -= c as i32;
+        }
+    }
+
+    #[inline]
+    fn average(&self, iarr: f32) -> RGBA8 {
+        let [r, g, b, a] = self.0.map(|s| round(s as f32 * iarr) as u8);
+        RGBA8 { r, g, b, a }
+    }
 }
@@ -3,7 +3,7 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
 use super::{f32_bound, ImageRefMut};
-use rgb::RGBA8;
+use rgb::{RGBA, RGBA8};
 use usvgr::filter::ColorMatrixKind as ColorMatrix;
 
 /// Applies a color matrix filter.
@@ -27,18 +27,7 @@ pub fn apply(matrix: &ColorMatrix, src: ImageRefMut) {
             }
         }
         ColorMatrix::Saturate(v) => {
-            let v = v.get().max(0.0);
-            let m = [
-                0.213 + 0.787 * v,
-                0.715 - 0.715 * v,
-                0.072 - 0.072 * v,
-                0.213 - 0.213 * v,
-                0.715 + 0.285 * v,
-                0.072 - 0.072 * v,
-                0.213 - 0.213 * v,
-                0.715 - 0.715 * v,
-                0.072 + 0.928 * v,
-            ];
+            let m = saturate_matrix(v.get());
 
             for pixel in src.data {
                 let (r, g, b, _) = to_normalized_components(*pixel);
@@ -53,20 +42,7 @@ pub fn apply(matrix: &ColorMatrix, src: ImageRefMut) {
             }
         }
         ColorMatrix::HueRotate(angle) => {
-            let angle = angle.to_radians();
-            let a1 = angle.cos();
-            let a2 = angle.sin();
-            let m = [
-                0.213 + 0.787 * a1 - 0.213 * a2,
-                0.715 - 0.715 * a1 - 0.715 * a2,
-                0.072 - 0.072 * a1 + 0.928 * a2,
-                0.213 - 0.213 * a1 + 0.143 * a2,
-                0.715 + 0.285 * a1 + 0.140 * a2,
-                0.072 - 0.072 * a1 - 0.283 * a2,
-                0.213 - 0.213 * a1 - 0.787 * a2,
-                0.715 - 0.715 * a1 + 0.715 * a2,
-                0.072 + 0.928 * a1 + 0.072 * a2,
-            ];
+            let m = hue_rotate_matrix(*angle);
 
             for pixel in src.data {
                 let (r, g, b, _) = to_normalized_components(*pixel);
@@ -95,6 +71,84 @@ pub fn apply(matrix: &ColorMatrix, src: ImageRefMut) {
     }
 }
 
+/// Applies a color matrix filter to a floating point image.
+///
+/// Input image pixels should have an **unpremultiplied alpha**.
+pub fn apply_f32(matrix: &ColorMatrix, src: ImageRefMut<RGBA<f32>>) {
+    let rgb = |m: &[f32; 9], pixel: &mut RGBA<f32>| {
+        let RGBA { r, g, b, .. } = *pixel;
+        pixel.r = f32_bound(0.0, r * m[0] + g * m[1] + b * m[2], 1.0);
+        pixel.g = f32_bound(0.0, r * m[3] + g * m[4] + b * m[5], 1.0);
+        pixel.b = f32_bound(0.0, r * m[6] + g * m[7] + b * m[8], 1.0);
+    };
+
+    match matrix {
+        ColorMatrix::Matrix(m) => {
+            for pixel in src.data {
+                let RGBA { r, g, b, a } = *pixel;
+                pixel.r = f32_bound(0.0, r * m[0] + g * m[1] + b * m[2] + a * m[3] + m[4], 1.0);
+                pixel.g = f32_bound(0.0, r * m[5] + g * m[6] + b * m[7] + a * m[8] + m[9], 1.0);
+                pixel.b = f32_bound(
+                    0.0,
+                    r * m[10] + g * m[11] + b * m[12] + a * m[13] + m[14],
+                    1.0,
+                );
+                pixel.a = f32_bound(
+                    0.0,
+                    r * m[15] + g * m[16] + b * m[17] + a * m[18] + m[19],
+                    1.0,
+                );
+            }
+        }
+        ColorMatrix::Saturate(v) => {
+            let m = saturate_matrix(v.get());
+            src.data.iter_mut().for_each(|pixel| rgb(&m, pixel));
+        }
+        ColorMatrix::HueRotate(angle) => {
+            let m = hue_rotate_matrix(*angle);
+            src.data.iter_mut().for_each(|pixel| rgb(&m, pixel));
+        }
+        ColorMatrix::LuminanceToAlpha => {
+            for pixel in src.data {
+                let a = pixel.r * 0.2125 + pixel.g * 0.7154 + pixel.b * 0.0721;
+                *pixel = RGBA::new(0.0, 0.0, 0.0, f32_bound(0.0, a, 1.0));
+            }
+        }
+    }
+}
+
+fn saturate_matrix(v: f32) -> [f32; 9] {
+    let v = v.max(0.0);
+    [
+        0.213 + 0.787 * v,
+        0.715 - 0.715 * v,
+        0.072 - 0.072 * v,
+        0.213 - 0.213 * v,
+        0.715 + 0.285 * v,
+        0.072 - 0.072 * v,
+        0.213 - 0.213 * v,
+        0.715 - 0.715 * v,
+        0.072 + 0.928 * v,
+    ]
+}
+
+fn hue_rotate_matrix(angle: f32) -> [f32; 9] {
+    let angle = angle.to_radians();
+    let a1 = angle.cos();
+    let a2 = angle.sin();
+    [
+        0.213 + 0.787 * a1 - 0.213 * a2,
+        0.715 - 0.715 * a1 - 0.715 * a2,
+        0.072 - 0.072 * a1 + 0.928 * a2,
+        0.213 - 0.213 * a1 + 0.143 * a2,
+        0.715 + 0.285 * a1 + 0.140 * a2,
+        0.072 - 0.072 * a1 - 0.283 * a2,
+        0.213 - 0.213 * a1 - 0.787 * a2,
+        0.715 - 0.715 * a1 + 0.715 * a2,
+        0.072 + 0.928 * a1 + 0.072 * a2,
+    ]
+}
+
 #[inline]
 fn to_normalized_components(pixel: RGBA8) -> (f32, f32, f32, f32) {
     (
@@ -3,6 +3,7 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
 use super::{f32_bound, ImageRefMut};
+use rgb::RGBA;
 use usvgr::filter::{ComponentTransfer, TransferFunction};
 
 /// Applies component transfer functions for each `src` image channel.
@@ -28,6 +29,31 @@ pub fn apply(fe: &ComponentTransfer, src: ImageRefMut) {
     }
 }
 
+/// Applies component transfer functions for each `src` floating point image channel.
+///
+/// Input image pixels should have an **unpremultiplied alpha**.
+pub fn apply_f32(fe: &ComponentTransfer, src: ImageRefMut<RGBA<f32>>) {
+    let transfer = |func, c| f32_bound(0.0, transfer_normalized(func, c), 1.0);
+
+    for pixel in src.data {
+        if !is_dummy(fe.func_r()) {
+            pixel.r = transfer(fe.func_r(), pixel.r);
+        }
+
+        if !is_dummy(fe.func_b()) {
+            pixel.b = transfer(fe.func_b(), pixel.b);
+        }
+
+        if !is_dummy(fe.func_g()) {
+            pixel.g = transfer(fe.func_g(), pixel.g);
+        }
+
+        if !is_dummy(fe.func_a()) {
+            pixel.a = transfer(fe.func_a(), pixel.a);
+        }
+    }
+}
+
 fn is_dummy(func: &TransferFunction) -> bool {
     match func {
         TransferFunction::Identity => true,
@@ -39,8 +65,12 @@ fn is_dummy(func: &TransferFunction) -> bool {
 }
 
 fn transfer(func: &TransferFunction, c: u8) -> u8 {
-    let c = c as f32 / 255.0;
-    let c = match func {
+    let c = transfer_normalized(func, c as f32 / 255.0);
+    (f32_bound(0.0, c, 1.0) * 255.0) as u8
+}
+
+fn transfer_normalized(func: &TransferFunction, c: f32) -> f32 {
+    match func {
         TransferFunction::Identity => c,
         TransferFunction::Table(values) => {
             let n = values.len() - 1;
@@ -67,7 +97,5 @@ fn transfer(func: &TransferFunction, c: u8) -> u8 {
             exponent,
             offset,
         } => amplitude * c.powf(*exponent) + offset,
-    };
-
-    (f32_bound(0.0, c, 1.0) * 255.0) as u8
+    }
 }
@@ -3,7 +3,7 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
 use super::{f32_bound, ImageRef, ImageRefMut};
-use rgb::RGBA8;
+use rgb::{RGBA, RGBA8};
 use usvgr::ApproxZeroUlps;
 
 /// Performs an arithmetic composition.
@@ -51,3 +51,40 @@ pub fn arithmetic(
         i += 1;
     }
 }
+
+/// Performs an arithmetic composition of floating point images.
+///
+/// - `src1` and `src2` image pixels should have a **premultiplied alpha**.
+/// - `dest` image pixels will have a **premultiplied alpha**.
+///
+/// # Panics
+///
+/// When `src1`, `src2` and `dest` have different sizes.
+pub fn arithmetic_f32(
+    k1: f32,
+    k2: f32,
+    k3: f32,
+    k4: f32,
+    src1: ImageRef<RGBA<f32>>,
+    src2: ImageRef<RGBA<f32>>,
+    dest: ImageRefMut<RGBA<f32>>,
+) {
+    debug_assert!(src1.width == src2.width && src1.width == dest.width);
+    debug_assert!(src1.height == src2.height && src1.height == dest.height);
+
+    let calc = |i1: f32, i2: f32, max| f32_bound(0.0, k1 * i1 * i2 + k2 * i1 + k3 * i2 + k4, max);
+
+    for ((c1, c2), p) in src1.data.iter().zip(src2.data).zip(dest.data.iter_mut()) {
+        let a = calc(c1.a, c2.a, 1.0);
+        if a.approx_zero_ulps(4) {
+            continue;
+        }
+
+        *p = RGBA {
+            r: calc(c1.r, c2.r, a),
+            g: calc(c1.g, c2.g, a),
+            b: calc(c1.b, c2.b, a),
+            a,
+        };
+    }
+}
@@ -2,8 +2,8 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use super::{f32_bound, ImageRefMut};
-use rgb::RGBA8;
+use super::{f32_bound, Channel, ImageRefMut};
+use rgb::RGBA;
 use usvgr::filter::{ConvolveMatrix, EdgeMode};
 
 /// Applies a convolve matrix.
@@ -13,7 +13,7 @@ use usvgr::filter::{ConvolveMatrix, EdgeMode};
 /// # Allocations
 ///
 /// This method will allocate a copy of the `src` image as a back buffer.
-pub fn apply(matrix: &ConvolveMatrix, src: ImageRefMut) {
+pub fn apply<T: Channel>(matrix: &ConvolveMatrix, src: ImageRefMut<RGBA<T>>) {
     fn bound(min: i32, val: i32, max: i32) -> i32 {
         core::cmp::max(min, core::cmp::min(max, val))
     }
@@ -21,7 +21,7 @@ pub fn apply(matrix: &ConvolveMatrix, src: ImageRefMut) {
     let width_max = src.width as i32 - 1;
     let height_max = src.height as i32 - 1;
 
-    let mut buf = vec![RGBA8::default(); src.data.len()];
+    let mut buf = vec![RGBA::<T>::default(); src.data.len()];
     let mut buf = ImageRefMut::new(src.width, src.height, &mut buf);
     let mut x = 0;
     let mut y = 0;
@@ -64,18 +64,18 @@ pub fn apply(matrix: &ConvolveMatrix, src: ImageRefMut) {
                 );
 
                 let p = src.pixel_at(tx as u32, ty as u32);
-                new_r += (p.r as f32) / 255.0 * k;
-                new_g += (p.g as f32) / 255.0 * k;
-                new_b += (p.b as f32) / 255.0 * k;
+                new_r += p.r.normalized() * k;
+                new_g += p.g.normalized() * k;
+                new_b += p.b.normalized() * k;
 
                 if !matrix.preserve_alpha() {
-                    new_a += (p.a as f32) / 255.0 * k;
+                    new_a += p.a.normalized() * k;
                 }
             }
         }
 
         if matrix.preserve_alpha() {
-            new_a = in_p.a as f32 / 255.0;
+            new_a = in_p.a.normalized();
         } else {
             new_a = new_a / matrix.divisor().get() + matrix.bias();
         }
@@ -91,14 +91,14 @@ pub fn apply(matrix: &ConvolveMatrix, src: ImageRefMut) {
                 f32_bound(0.0, x, bounded_new_a)
             };
 
-            (x * 255.0 + 0.5) as u8
+            T::from_normalized(x)
         };
 
         let out_p = buf.pixel_at_mut(x, y);
         out_p.r = calc(new_r);
         out_p.g = calc(new_g);
         out_p.b = calc(new_b);
-        out_p.a = (bounded_new_a * 255.0 + 0.5) as u8;
+        out_p.a = T::from_normalized(bounded_new_a);
 
         x += 1;
         if x == src.width {
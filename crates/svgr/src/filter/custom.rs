@@ -0,0 +1,81 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// A user-defined filter primitive.
+///
+/// Referenced from SVG by a `feCustom` element with a matching `name` attribute
+/// or by a `custom(name, args)` filter function.
+pub trait CustomFilter: Send + Sync {
+    /// Renders the primitive into `output`.
+    ///
+    /// All pixmaps have the size of the filter region and store premultiplied RGBA pixels
+    /// in the primitive's `color-interpolation-filters` color space.
+    /// With [`FilterPrecision::F32`](crate::FilterPrecision::F32) the inputs are converted
+    /// to 8 bits for this call and the output is converted back.
+    /// `output` is transparent and will be clipped to the primitive subregion afterwards.
+    ///
+    /// `args` are the `values` attribute or the `custom()` function arguments as is.
+    /// `ts` is the canvas transform, which can be used to scale length-like arguments.
+    fn apply(
+        &self,
+        args: &[f32],
+        input1: &tiny_skia::Pixmap,
+        input2: Option<&tiny_skia::Pixmap>,
+        ts: tiny_skia::Transform,
+        output: &mut tiny_skia::Pixmap,
+    );
+}
+
+impl<F> CustomFilter for F
+where
+    F: Fn(
+            &[f32],
+            &tiny_skia::Pixmap,
+            Option<&tiny_skia::Pixmap>,
+            tiny_skia::Transform,
+            &mut tiny_skia::Pixmap,
+        ) + Send
+        + Sync,
+{
+    fn apply(
+        &self,
+        args: &[f32],
+        input1: &tiny_skia::Pixmap,
+        input2: Option<&tiny_skia::Pixmap>,
+        ts: tiny_skia::Transform,
+        output: &mut tiny_skia::Pixmap,
+    ) {
+        self(args, input1, input2, ts, output)
+    }
+}
+
+/// A registry of user-defined filter primitives.
+///
+/// Primitives that reference an unregistered name pass their first input through.
+#[derive(Clone, Default)]
+pub struct CustomFilters {
+    filters: HashMap<String, Arc<dyn CustomFilter>>,
+}
+
+impl CustomFilters {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a filter primitive under the provided name.
+    ///
+    /// Replaces a previously registered primitive with the same name.
+    pub fn register<T: CustomFilter + 'static>(&mut self, name: impl Into<String>, filter: T) {
+        self.filters.insert(name.into(), Arc::new(filter));
+    }
+
+    /// Returns a filter primitive by name.
+    pub fn get(&self, name: &str) -> Option<&dyn CustomFilter> {
+        self.filters.get(name).map(|f| f.as_ref())
+    }
+}
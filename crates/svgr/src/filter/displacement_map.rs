@@ -2,7 +2,8 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use super::{ImageRef, ImageRefMut};
+use super::{Channel, ImageRef, ImageRefMut};
+use rgb::RGBA;
 use usvgr::filter::{ColorChannel, DisplacementMap};
 
 /// Applies a displacement map.
@@ -15,13 +16,13 @@ use usvgr::filter::{ColorChannel, DisplacementMap};
 /// # Panics
 ///
 /// When `src`, `map` and `dest` have different sizes.
-pub fn apply(
+pub fn apply<T: Channel>(
     fe: &DisplacementMap,
     sx: f32,
     sy: f32,
-    src: ImageRef,
-    map: ImageRef,
-    dest: ImageRefMut,
+    src: ImageRef<RGBA<T>>,
+    map: ImageRef<RGBA<T>>,
+    dest: ImageRefMut<RGBA<T>>,
 ) {
     debug_assert!(src.width == map.width && src.width == dest.width);
     debug_assert!(src.height == map.height && src.height == dest.height);
@@ -40,7 +41,7 @@ pub fn apply(
                 ColorChannel::A => pixel.a,
             };
 
-            c as f32 / 255.0 - 0.5
+            c.normalized() - 0.5
         };
 
         let dx = calc_offset(fe.x_channel_selector());
@@ -26,8 +26,9 @@
 
 // TODO: Blurs right and bottom sides twice for some reason.
 
-use super::ImageRefMut;
-use rgb::ComponentSlice;
+use super::{parallel, ImageRefMut};
+use rgb::{ComponentSlice, RGBA};
+use std::cmp;
 
 struct BlurData {
     width: usize,
@@ -66,6 +67,40 @@ pub fn apply(sigma_x: f64, sigma_y: f64, src: ImageRefMut) {
     gaussian_channel(data, &d, 3, buf);
 }
 
+/// Applies an IIR blur to a floating point image.
+///
+/// Input image pixels should have a **premultiplied alpha**.
+///
+/// A negative or zero `sigma_x`/`sigma_y` will disable the blur along that axis.
+///
+/// # Allocations
+///
+/// This method will allocate a 2x `src` buffer.
+pub fn apply_f32(sigma_x: f64, sigma_y: f64, src: ImageRefMut<RGBA<f32>>) {
+    let mut buf = vec![0.0; (src.width * src.height) as usize];
+
+    let d = BlurData {
+        width: src.width as usize,
+        height: src.height as usize,
+        sigma_x,
+        sigma_y,
+        steps: 4,
+    };
+
+    let data = ComponentSlice::as_mut_slice(src.data);
+    for channel in 0..4 {
+        for i in 0..buf.len() {
+            buf[i] = data[i * 4 + channel] as f64;
+        }
+
+        gaussianiir2d(&d, &mut buf);
+
+        for i in 0..buf.len() {
+            data[i * 4 + channel] = buf[i] as f32;
+        }
+    }
+}
+
 fn gaussian_channel(data: &mut [u8], d: &BlurData, channel: usize, buf: &mut Vec<f64>) {
     for i in 0..data.len() / 4 {
         buf[i] = data[i * 4 + channel] as f64 / 255.0;
@@ -79,28 +114,28 @@ fn gaussian_channel(data: &mut [u8], d: &BlurData, channel: usize, buf: &mut Vec
 }
 
 fn gaussianiir2d(d: &BlurData, buf: &mut [f64]) {
+    let band_count = parallel::band_count(buf.len());
+
     // Filter horizontally along each row.
     let (lambda_x, dnu_x) = if d.sigma_x > 0.0 {
         let (lambda, dnu) = gen_coefficients(d.sigma_x, d.steps);
-
-        for y in 0..d.height {
-            for _ in 0..d.steps {
-                let idx = d.width * y;
-
-                // Filter rightwards.
-                for x in 1..d.width {
-                    buf[idx + x] += dnu * buf[idx + x - 1];
-                }
-
-                let mut x = d.width - 1;
-
-                // Filter leftwards.
-                while x > 0 {
-                    buf[idx + x - 1] += dnu * buf[idx + x];
-                    x -= 1;
+        let band_rows = d.height.div_ceil(band_count);
+
+        parallel::for_each_chunk(buf, band_rows * d.width, |_, band| {
+            for row in band.chunks_exact_mut(d.width) {
+                for _ in 0..d.steps {
+                    // Filter rightwards.
+                    for x in 1..d.width {
+                        row[x] += dnu * row[x - 1];
+                    }
+
+                    // Filter leftwards.
+                    for x in (1..d.width).rev() {
+                        row[x - 1] += dnu * row[x];
+                    }
                 }
             }
-        }
+        });
 
         (lambda, dnu)
     } else {
@@ -110,23 +145,30 @@ fn gaussianiir2d(d: &BlurData, buf: &mut [f64]) {
     // Filter vertically along each column.
     let (lambda_y, dnu_y) = if d.sigma_y > 0.0 {
         let (lambda, dnu) = gen_coefficients(d.sigma_y, d.steps);
-        for x in 0..d.width {
-            for _ in 0..d.steps {
-                let idx = x;
-
-                // Filter downwards.
-                let mut y = d.width;
-                while y < buf.len() {
-                    buf[idx + y] += dnu * buf[idx + y - d.width];
-                    y += d.width;
-                }
 
-                y = buf.len() - d.width;
-
-                // Filter upwards.
-                while y > 0 {
-                    buf[idx + y - d.width] += dnu * buf[idx + y];
-                    y -= d.width;
+        if band_count == 1 {
+            filter_columns(buf, d.width, dnu, d.steps);
+        } else {
+            // Split columns into bands and filter each one in a separate buffer.
+            let band_width = d.width.div_ceil(band_count);
+            let src: &[f64] = buf;
+            let bands = parallel::map(d.width.div_ceil(band_width), |i| {
+                let x0 = i * band_width;
+                let x1 = cmp::min(x0 + band_width, d.width);
+                let mut band: Vec<f64> = src
+                    .chunks_exact(d.width)
+                    .flat_map(|row| &row[x0..x1])
+                    .copied()
+                    .collect();
+                filter_columns(&mut band, x1 - x0, dnu, d.steps);
+                band
+            });
+
+            for (i, band) in bands.iter().enumerate() {
+                let x0 = i * band_width;
+                let width = band.len() / d.height;
+                for (row, band_row) in buf.chunks_exact_mut(d.width).zip(band.chunks_exact(width)) {
+                    row[x0..x0 + width].copy_from_slice(band_row);
                 }
             }
         }
@@ -141,6 +183,34 @@ fn gaussianiir2d(d: &BlurData, buf: &mut [f64]) {
     buf.iter_mut().for_each(|v| *v *= post_scale);
 }
 
+/// Filters columns of `buf` with the provided row `width`.
+///
+/// Processes whole rows at a time, so the memory is accessed sequentially
+/// and the inner loops can be vectorized.
+fn filter_columns(buf: &mut [f64], width: usize, dnu: f64, steps: usize) {
+    let height = buf.len() / width;
+
+    for _ in 0..steps {
+        // Filter downwards.
+        for y in 1..height {
+            let (prev, next) = buf.split_at_mut(y * width);
+            let prev = &prev[(y - 1) * width..];
+            for (v, p) in next[..width].iter_mut().zip(prev) {
+                *v += dnu * *p;
+            }
+        }
+
+        // Filter upwards.
+        for y in (1..height).rev() {
+            let (prev, next) = buf.split_at_mut(y * width);
+            let prev = &mut prev[(y - 1) * width..];
+            for (v, n) in prev.iter_mut().zip(&next[..width]) {
+                *v += dnu * *n;
+            }
+        }
+    }
+}
+
 fn gen_coefficients(sigma: f64, steps: usize) -> (f64, f64) {
     let lambda = (sigma * sigma) / (2.0 * steps as f64);
     let dnu = (1.0 + 2.0 * lambda - (1.0 + 4.0 * lambda).sqrt()) / (2.0 * lambda);
@@ -2,8 +2,8 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use super::{f32_bound, ImageRef, ImageRefMut};
-use rgb::RGBA8;
+use super::{f32_bound, Channel, ImageRef, ImageRefMut};
+use rgb::RGBA;
 use usvgr::filter::{DiffuseLighting, LightSource, SpecularLighting};
 use usvgr::{ApproxEqUlps, ApproxZeroUlps, Color};
 
@@ -114,10 +114,10 @@ struct Normal {
 
 impl Normal {
     #[inline]
-    fn new(factor_x: f32, factor_y: f32, nx: i16, ny: i16) -> Self {
+    fn new(factor_x: f32, factor_y: f32, nx: f32, ny: f32) -> Self {
         Normal {
             factor: Vector2::new(factor_x, factor_y),
-            normal: Vector2::new(-nx as f32, -ny as f32),
+            normal: Vector2::new(-nx, -ny),
         }
     }
 }
@@ -132,11 +132,11 @@ impl Normal {
 /// # Panics
 ///
 /// - When `src` and `dest` have different sizes.
-pub fn diffuse_lighting(
+pub fn diffuse_lighting<T: Channel>(
     fe: &DiffuseLighting,
     light_source: LightSource,
-    src: ImageRef,
-    dest: ImageRefMut,
+    src: ImageRef<RGBA<T>>,
+    dest: ImageRefMut<RGBA<T>>,
 ) {
     assert!(src.width == dest.width && src.height == dest.height);
 
@@ -177,11 +177,11 @@ pub fn diffuse_lighting(
 /// # Panics
 ///
 /// - When `src` and `dest` have different sizes.
-pub fn specular_lighting(
+pub fn specular_lighting<T: Channel>(
     fe: &SpecularLighting,
     light_source: LightSource,
-    src: ImageRef,
-    dest: ImageRefMut,
+    src: ImageRef<RGBA<T>>,
+    dest: ImageRefMut<RGBA<T>>,
 ) {
     if src.width != dest.width || src.height != dest.height {
         log::error!("Specular lighting: source and destination images must have the same size for it to behave proprerly.");
@@ -231,14 +231,14 @@ pub fn specular_lighting(
     );
 }
 
-fn apply(
+fn apply<T: Channel>(
     light_source: LightSource,
     surface_scale: f32,
     lighting_color: Color,
     light_factor: &dyn Fn(Normal, Vector3) -> f32,
-    calc_alpha: fn(u8, u8, u8) -> u8,
-    src: ImageRef,
-    mut dest: ImageRefMut,
+    calc_alpha: fn(T, T, T) -> T,
+    src: ImageRef<RGBA<T>>,
+    mut dest: ImageRefMut<RGBA<T>>,
 ) {
     if src.width < 3 || src.height < 3 {
         return;
@@ -265,13 +265,13 @@ fn apply(
         match light_source {
             LightSource::DistantLight(_) => {}
             LightSource::PointLight(ref light) => {
-                let nz = src.alpha_at(nx, ny) as f32 / 255.0 * surface_scale;
+                let nz = src.alpha_at(nx, ny) / 255.0 * surface_scale;
                 let origin = Vector3::new(light.x, light.y, light.z);
                 let v = origin - Vector3::new(nx as f32, ny as f32, nz);
                 light_vector = v.normalized().unwrap_or(v);
             }
             LightSource::SpotLight(ref light) => {
-                let nz = src.alpha_at(nx, ny) as f32 / 255.0 * surface_scale;
+                let nz = src.alpha_at(nx, ny) / 255.0 * surface_scale;
                 let origin = Vector3::new(light.x, light.y, light.z);
                 let v = origin - Vector3::new(nx as f32, ny as f32, nz);
                 light_vector = v.normalized().unwrap_or(v);
@@ -281,14 +281,14 @@ fn apply(
         let light_color = light_color(&light_source, lighting_color, light_vector);
         let factor = light_factor(normal, light_vector);
 
-        let compute = |x| (f32_bound(0.0, x as f32 * factor, 255.0) + 0.5) as u8;
+        let compute = |x| T::from_scaled(f32_bound(0.0, x as f32 * factor, 255.0));
 
         let r = compute(light_color.red);
         let g = compute(light_color.green);
         let b = compute(light_color.blue);
         let a = calc_alpha(r, g, b);
 
-        *dest.pixel_at_mut(nx, ny) = RGBA8 { b, g, r, a };
+        *dest.pixel_at_mut(nx, ny) = RGBA { r, g, b, a };
     };
 
     calc(0, 0, top_left_normal(src));
@@ -344,7 +344,7 @@ fn light_color(light: &LightSource, lighting_color: Color, light_vector: Vector3
     }
 }
 
-fn top_left_normal(img: ImageRef) -> Normal {
+fn top_left_normal<T: Channel>(img: ImageRef<RGBA<T>>) -> Normal {
     let center = img.alpha_at(0, 0);
     let right = img.alpha_at(1, 0);
     let bottom = img.alpha_at(0, 1);
@@ -353,12 +353,12 @@ fn top_left_normal(img: ImageRef) -> Normal {
     Normal::new(
         FACTOR_2_3,
         FACTOR_2_3,
-        -2 * center + 2 * right - bottom + bottom_right,
-        -2 * center - right + 2 * bottom + bottom_right,
+        -2.0 * center + 2.0 * right - bottom + bottom_right,
+        -2.0 * center - right + 2.0 * bottom + bottom_right,
     )
 }
 
-fn top_right_normal(img: ImageRef) -> Normal {
+fn top_right_normal<T: Channel>(img: ImageRef<RGBA<T>>) -> Normal {
     let left = img.alpha_at(img.width - 2, 0);
     let center = img.alpha_at(img.width - 1, 0);
     let bottom_left = img.alpha_at(img.width - 2, 1);
@@ -367,12 +367,12 @@ fn top_right_normal(img: ImageRef) -> Normal {
     Normal::new(
         FACTOR_2_3,
         FACTOR_2_3,
-        -2 * left + 2 * center - bottom_left + bottom,
-        -left - 2 * center + bottom_left + 2 * bottom,
+        -2.0 * left + 2.0 * center - bottom_left + bottom,
+        -left - 2.0 * center + bottom_left + 2.0 * bottom,
     )
 }
 
-fn bottom_left_normal(img: ImageRef) -> Normal {
+fn bottom_left_normal<T: Channel>(img: ImageRef<RGBA<T>>) -> Normal {
     let top = img.alpha_at(0, img.height - 2);
     let top_right = img.alpha_at(1, img.height - 2);
     let center = img.alpha_at(0, img.height - 1);
@@ -381,12 +381,12 @@ fn bottom_left_normal(img: ImageRef) -> Normal {
     Normal::new(
         FACTOR_2_3,
         FACTOR_2_3,
-        -top + top_right - 2 * center + 2 * right,
-        -2 * top - top_right + 2 * center + right,
+        -top + top_right - 2.0 * center + 2.0 * right,
+        -2.0 * top - top_right + 2.0 * center + right,
     )
 }
 
-fn bottom_right_normal(img: ImageRef) -> Normal {
+fn bottom_right_normal<T: Channel>(img: ImageRef<RGBA<T>>) -> Normal {
     let top_left = img.alpha_at(img.width - 2, img.height - 2);
     let top = img.alpha_at(img.width - 1, img.height - 2);
     let left = img.alpha_at(img.width - 2, img.height - 1);
@@ -395,12 +395,12 @@ fn bottom_right_normal(img: ImageRef) -> Normal {
     Normal::new(
         FACTOR_2_3,
         FACTOR_2_3,
-        -top_left + top - 2 * left + 2 * center,
-        -top_left - 2 * top + left + 2 * center,
+        -top_left + top - 2.0 * left + 2.0 * center,
+        -top_left - 2.0 * top + left + 2.0 * center,
     )
 }
 
-fn top_row_normal(img: ImageRef, x: u32) -> Normal {
+fn top_row_normal<T: Channel>(img: ImageRef<RGBA<T>>, x: u32) -> Normal {
     let left = img.alpha_at(x - 1, 0);
     let center = img.alpha_at(x, 0);
     let right = img.alpha_at(x + 1, 0);
@@ -411,12 +411,12 @@ fn top_row_normal(img: ImageRef, x: u32) -> Normal {
     Normal::new(
         FACTOR_1_3,
         FACTOR_1_2,
-        -2 * left + 2 * right - bottom_left + bottom_right,
-        -left - 2 * center - right + bottom_left + 2 * bottom + bottom_right,
+        -2.0 * left + 2.0 * right - bottom_left + bottom_right,
+        -left - 2.0 * center - right + bottom_left + 2.0 * bottom + bottom_right,
     )
 }
 
-fn bottom_row_normal(img: ImageRef, x: u32) -> Normal {
+fn bottom_row_normal<T: Channel>(img: ImageRef<RGBA<T>>, x: u32) -> Normal {
     let top_left = img.alpha_at(x - 1, img.height - 2);
     let top = img.alpha_at(x, img.height - 2);
     let top_right = img.alpha_at(x + 1, img.height - 2);
@@ -427,12 +427,12 @@ fn bottom_row_normal(img: ImageRef, x: u32) -> Normal {
     Normal::new(
         FACTOR_1_3,
         FACTOR_1_2,
-        -top_left + top_right - 2 * left + 2 * right,
-        -top_left - 2 * top - top_right + left + 2 * center + right,
+        -top_left + top_right - 2.0 * left + 2.0 * right,
+        -top_left - 2.0 * top - top_right + left + 2.0 * center + right,
     )
 }
 
-fn left_column_normal(img: ImageRef, y: u32) -> Normal {
+fn left_column_normal<T: Channel>(img: ImageRef<RGBA<T>>, y: u32) -> Normal {
     let top = img.alpha_at(0, y - 1);
     let top_right = img.alpha_at(1, y - 1);
     let center = img.alpha_at(0, y);
@@ -443,12 +443,12 @@ fn left_column_normal(img: ImageRef, y: u32) -> Normal {
     Normal::new(
         FACTOR_1_2,
         FACTOR_1_3,
-        -top + top_right - 2 * center + 2 * right - bottom + bottom_right,
-        -2 * top - top_right + 2 * bottom + bottom_right,
+        -top + top_right - 2.0 * center + 2.0 * right - bottom + bottom_right,
+        -2.0 * top - top_right + 2.0 * bottom + bottom_right,
     )
 }
 
-fn right_column_normal(img: ImageRef, y: u32) -> Normal {
+fn right_column_normal<T: Channel>(img: ImageRef<RGBA<T>>, y: u32) -> Normal {
     let top_left = img.alpha_at(img.width - 2, y - 1);
     let top = img.alpha_at(img.width - 1, y - 1);
     let left = img.alpha_at(img.width - 2, y);
@@ -459,12 +459,12 @@ fn right_column_normal(img: ImageRef, y: u32) -> Normal {
     Normal::new(
         FACTOR_1_2,
         FACTOR_1_3,
-        -top_left + top - 2 * left + 2 * center - bottom_left + bottom,
-        -top_left - 2 * top + bottom_left + 2 * bottom,
+        -top_left + top - 2.0 * left + 2.0 * center - bottom_left + bottom,
+        -top_left - 2.0 * top + bottom_left + 2.0 * bottom,
     )
 }
 
-fn interior_normal(img: ImageRef, x: u32, y: u32) -> Normal {
+fn interior_normal<T: Channel>(img: ImageRef<RGBA<T>>, x: u32, y: u32) -> Normal {
     let top_left = img.alpha_at(x - 1, y - 1);
     let top = img.alpha_at(x, y - 1);
     let top_right = img.alpha_at(x + 1, y - 1);
@@ -477,16 +477,16 @@ fn interior_normal(img: ImageRef, x: u32, y: u32) -> Normal {
     Normal::new(
         FACTOR_1_4,
         FACTOR_1_4,
-        -top_left + top_right - 2 * left + 2 * right - bottom_left + bottom_right,
-        -top_left - 2 * top - top_right + bottom_left + 2 * bottom + bottom_right,
+        -top_left + top_right - 2.0 * left + 2.0 * right - bottom_left + bottom_right,
+        -top_left - 2.0 * top - top_right + bottom_left + 2.0 * bottom + bottom_right,
     )
 }
 
-fn calc_diffuse_alpha(_: u8, _: u8, _: u8) -> u8 {
-    255
+fn calc_diffuse_alpha<T: Channel>(_: T, _: T, _: T) -> T {
+    T::MAX
 }
 
-fn calc_specular_alpha(r: u8, g: u8, b: u8) -> u8 {
-    use core::cmp::max;
+fn calc_specular_alpha<T: Channel>(r: T, g: T, b: T) -> T {
+    let max = |a: T, b: T| if b > a { b } else { a };
     max(max(r, g), b)
 }
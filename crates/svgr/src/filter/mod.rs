@@ -2,9 +2,10 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
+use std::hash::{Hash, Hasher};
 use std::rc::Rc;
 
-use rgb::{FromSlice, RGBA8};
+use rgb::{FromSlice, RGBA, RGBA8};
 use tiny_skia::IntRect;
 use usvgr::{ApproxEqUlps, ApproxZeroUlps};
 
@@ -13,12 +14,35 @@ mod color_matrix;
 mod component_transfer;
 mod composite;
 mod convolve_matrix;
+mod custom;
 mod displacement_map;
+mod float;
 mod iir_blur;
 mod lighting;
 mod morphology;
+mod parallel;
 mod turbulence;
 
+pub use custom::{CustomFilter, CustomFilters};
+
+pub(crate) use float::{blend, PixmapF32, RGBAF32};
+
+/// A pixel format for intermediate filter results.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum FilterPrecision {
+    /// Premultiplied 8-bit RGBA.
+    ///
+    /// Each primitive result and each color space conversion is rounded to 8 bits,
+    /// which can produce banding in long filter chains.
+    #[default]
+    U8,
+    /// Premultiplied 32-bit floating point RGBA.
+    ///
+    /// The filter result is converted to 8 bits only once, when it is drawn onto the canvas.
+    /// Slower and uses 4x more memory.
+    F32,
+}
+
 // TODO: apply single primitive filters in-place
 
 /// An image reference.
@@ -28,44 +52,47 @@ mod turbulence;
 /// Some filters will require premultipled channels, some not.
 /// See specific filter documentation for details.
 #[derive(Clone, Copy)]
-pub struct ImageRef<'a> {
-    data: &'a [RGBA8],
+pub struct ImageRef<'a, T = RGBA8> {
+    data: &'a [T],
     width: u32,
     height: u32,
 }
 
-impl<'a> ImageRef<'a> {
+impl<'a, T> ImageRef<'a, T> {
     /// Creates a new image reference.
     ///
     /// Doesn't clone the provided data.
     #[inline]
-    pub fn new(width: u32, height: u32, data: &'a [RGBA8]) -> Self {
+    pub fn new(width: u32, height: u32, data: &'a [T]) -> Self {
         ImageRef {
             data,
             width,
             height,
         }
     }
+}
 
+impl<T: Channel> ImageRef<'_, RGBA<T>> {
+    /// Returns the pixel alpha in the 0..=255 range.
     #[inline]
-    fn alpha_at(&self, x: u32, y: u32) -> i16 {
-        self.data[(self.width * y + x) as usize].a as i16
+    fn alpha_at(&self, x: u32, y: u32) -> f32 {
+        self.data[(self.width * y + x) as usize].a.scaled()
     }
 }
 
 /// A mutable `ImageRef` variant.
-pub struct ImageRefMut<'a> {
-    data: &'a mut [RGBA8],
+pub struct ImageRefMut<'a, T = RGBA8> {
+    data: &'a mut [T],
     width: u32,
     height: u32,
 }
 
-impl<'a> ImageRefMut<'a> {
+impl<'a, T: Copy> ImageRefMut<'a, T> {
     /// Creates a new mutable image reference.
     ///
     /// Doesn't clone the provided data.
     #[inline]
-    pub fn new(width: u32, height: u32, data: &'a mut [RGBA8]) -> Self {
+    pub fn new(width: u32, height: u32, data: &'a mut [T]) -> Self {
         ImageRefMut {
             data,
             width,
@@ -74,16 +101,85 @@ impl<'a> ImageRefMut<'a> {
     }
 
     #[inline]
-    fn pixel_at(&self, x: u32, y: u32) -> RGBA8 {
+    fn pixel_at(&self, x: u32, y: u32) -> T {
         self.data[(self.width * y + x) as usize]
     }
 
     #[inline]
-    fn pixel_at_mut(&mut self, x: u32, y: u32) -> &mut RGBA8 {
+    fn pixel_at_mut(&mut self, x: u32, y: u32) -> &mut T {
         &mut self.data[(self.width * y + x) as usize]
     }
 }
 
+/// A color channel of an image pixel.
+///
+/// Allows primitives that do their math in floating point
+/// to work with both 8-bit and floating point images.
+pub trait Channel: Copy + Default + PartialOrd {
+    /// The value of a fully opaque alpha.
+    const MAX: Self;
+
+    /// Returns the value in the 0..=1 range.
+    fn normalized(self) -> f32;
+
+    /// Returns the value in the 0..=255 range.
+    fn scaled(self) -> f32;
+
+    /// Creates a value from the 0..=1 range. Rounds 8-bit values.
+    fn from_normalized(v: f32) -> Self;
+
+    /// Creates a value from the 0..=255 range. Rounds 8-bit values.
+    fn from_scaled(v: f32) -> Self;
+}
+
+impl Channel for u8 {
+    const MAX: Self = 255;
+
+    #[inline]
+    fn normalized(self) -> f32 {
+        self as f32 / 255.0
+    }
+
+    #[inline]
+    fn scaled(self) -> f32 {
+        self as f32
+    }
+
+    #[inline]
+    fn from_normalized(v: f32) -> Self {
+        (v * 255.0 + 0.5) as u8
+    }
+
+    #[inline]
+    fn from_scaled(v: f32) -> Self {
+        (v + 0.5) as u8
+    }
+}
+
+impl Channel for f32 {
+    const MAX: Self = 1.0;
+
+    #[inline]
+    fn normalized(self) -> f32 {
+        self
+    }
+
+    #[inline]
+    fn scaled(self) -> f32 {
+        self * 255.0
+    }
+
+    #[inline]
+    fn from_normalized(v: f32) -> Self {
+        v
+    }
+
+    #[inline]
+    fn from_scaled(v: f32) -> Self {
+        v / 255.0
+    }
+}
+
 #[derive(Debug)]
 pub(crate) enum Error {
     InvalidRegion,
@@ -91,14 +187,14 @@ pub(crate) enum Error {
 }
 
 trait PixmapExt: Sized {
-    fn copy_region(&self, region: IntRect) -> Result<tiny_skia::Pixmap, Error>;
+    fn copy_region(&self, region: IntRect) -> Result<Self, Error>;
     fn clear(&mut self);
     fn into_srgb(&mut self);
     fn into_linear_rgb(&mut self);
 }
 
 impl PixmapExt for tiny_skia::Pixmap {
-    fn copy_region(&self, region: IntRect) -> Result<tiny_skia::Pixmap, Error> {
+    fn copy_region(&self, region: IntRect) -> Result<Self, Error> {
         let rect = IntRect::from_xywh(region.x(), region.y(), region.width(), region.height())
             .ok_or(Error::InvalidRegion)?;
         self.clone_rect(rect).ok_or(Error::InvalidRegion)
@@ -250,11 +346,11 @@ fn f32_bound(min: f32, val: f32, max: f32) -> f32 {
 }
 
 #[derive(Clone)]
-struct Image {
+struct Image<P = tiny_skia::Pixmap> {
     /// Filter primitive result.
     ///
     /// All images have the same size which is equal to the current filter region.
-    image: Rc<tiny_skia::Pixmap>,
+    image: Rc<P>,
 
     /// Image's region that has actual data.
     ///
@@ -269,11 +365,8 @@ struct Image {
     color_space: usvgr::filter::ColorInterpolation,
 }
 
-impl Image {
-    fn from_image(
-        image: tiny_skia::Pixmap,
-        color_space: usvgr::filter::ColorInterpolation,
-    ) -> Self {
+impl<P: FilterPixmap> Image<P> {
+    fn from_image(image: P, color_space: usvgr::filter::ColorInterpolation) -> Self {
         let (w, h) = (image.width(), image.height());
         Image {
             image: Rc::new(image),
@@ -306,7 +399,7 @@ impl Image {
         }
     }
 
-    fn take(self) -> Result<tiny_skia::Pixmap, Error> {
+    fn take(self) -> Result<P, Error> {
         match Rc::try_unwrap(self.image) {
             Ok(v) => Ok(v),
             Err(v) => Ok((*v).clone()),
@@ -321,53 +414,224 @@ impl Image {
         self.image.height()
     }
 
-    fn as_ref(&self) -> &tiny_skia::Pixmap { &self.image
+    fn as_ref(&self) -> &P {
+        &self.image
     }
 }
 
-struct FilterResult {
+struct FilterResult<P = tiny_skia::Pixmap> {
     name: String,
-    image: Image,
+    image: Image<P>,
+    /// A hash of the primitive and its inputs. `None` when caching is disabled.
+    key: Option<u64>,
+}
+
+/// A pixel storage filter primitives can be evaluated in.
+trait FilterPixmap: PixmapExt + Clone + Send + 'static {
+    fn from_pixmap(pixmap: tiny_skia::Pixmap) -> Self;
+    fn into_pixmap(self) -> tiny_skia::Pixmap;
+    fn width(&self) -> u32;
+    fn height(&self) -> u32;
+
+    /// Creates a transparent black image.
+    fn transparent(width: u32, height: u32) -> Option<Self>;
+
+    /// Sets RGB to black. Keeps alpha as is.
+    fn clear_rgb(&mut self);
+
+    /// Clears the pixels outside the `region`.
+    fn clear_outside(&mut self, region: IntRect);
+
+    /// Copies the image, reusing the pool memory when possible.
+    fn pooled_copy(&self, pixmap_pool: &crate::cache::PixmapPool) -> Option<Self>;
+
+    fn hash_pixels<H: Hasher>(&self, state: &mut H);
+
+    fn apply_primitive(
+        primitive: &usvgr::filter::Primitive,
+        ctx: &crate::render::Context,
+        ts: usvgr::Transform,
+        region: IntRect,
+        subregion: IntRect,
+        source: &Self,
+        inputs: &Inputs<Self>,
+        results: &[FilterResult<Self>],
+        cache: &mut crate::cache::SvgrCache,
+        pixmap_pool: &crate::cache::PixmapPool,
+    ) -> Result<Image<Self>, Error>;
+}
+
+/// Images a filter primitive can use besides `SourceGraphic` and other primitive results.
+struct Inputs<'a, P = tiny_skia::Pixmap> {
+    background: Option<&'a P>,
+    fill_paint: Option<P>,
+    stroke_paint: Option<P>,
 }
 
 pub fn apply(
     filter: &usvgr::filter::Filter,
+    group: &usvgr::Group,
+    ctx: &crate::render::Context,
     ts: tiny_skia::Transform,
     source: &mut tiny_skia::Pixmap,
+    background: Option<&tiny_skia::Pixmap>,
     cache: &mut crate::cache::SvgrCache,
     pixmap_pool: &crate::cache::PixmapPool,
 ) {
-    let result = apply_inner(filter, ts, source, cache, pixmap_pool);
-    let result = result.and_then(|image| apply_to_canvas(image, source));
+    let fill_paint = if filter.has_input(&usvgr::filter::Input::FillPaint) {
+        render_paint_input(
+            group.filter_fill(),
+            filter,
+            ctx,
+            ts,
+            source,
+            cache,
+            pixmap_pool,
+        )
+    } else {
+        None
+    };
+
+    let stroke_paint = if filter.has_input(&usvgr::filter::Input::StrokePaint) {
+        render_paint_input(
+            group.filter_stroke(),
+            filter,
+            ctx,
+            ts,
+            source,
+            cache,
+            pixmap_pool,
+        )
+    } else {
+        None
+    };
+
+    let result = match ctx.filter_precision {
+        FilterPrecision::U8 => {
+            let inputs = Inputs {
+                background,
+                fill_paint,
+                stroke_paint,
+            };
+
+            apply_inner::<tiny_skia::Pixmap>(filter, ctx, ts, source, &inputs, cache, pixmap_pool)
+                .and_then(|image| apply_to_canvas(image, source))
+        }
+        FilterPrecision::F32 => {
+            let background = background.map(|p| PixmapF32::from_pixmap(p.clone()));
+            let inputs = Inputs {
+                background: background.as_ref(),
+                fill_paint: fill_paint.map(PixmapF32::from_pixmap),
+                stroke_paint: stroke_paint.map(PixmapF32::from_pixmap),
+            };
+
+            let source_f32 = PixmapF32::from_pixmap(source.clone());
+            apply_inner::<PixmapF32>(filter, ctx, ts, &source_f32, &inputs, cache, pixmap_pool)
+                .and_then(|image| apply_to_canvas(image, source))
+        }
+    };
 
     // Clear on error.
-    if result.is_err() {
+    if let Err(e) = result {
         source.fill(tiny_skia::Color::TRANSPARENT);
+        log_error(e);
+    }
+}
+
+/// Applies a filter to a floating point image.
+///
+/// Unlike [`apply`], `FillPaint` and `StrokePaint` have to be rendered by the caller.
+pub(crate) fn apply_f32(
+    filter: &usvgr::filter::Filter,
+    ctx: &crate::render::Context,
+    ts: tiny_skia::Transform,
+    source: &mut PixmapF32,
+    background: Option<&PixmapF32>,
+    fill_paint: Option<PixmapF32>,
+    stroke_paint: Option<PixmapF32>,
+    cache: &mut crate::cache::SvgrCache,
+    pixmap_pool: &crate::cache::PixmapPool,
+) {
+    let inputs = Inputs {
+        background,
+        fill_paint,
+        stroke_paint,
+    };
+
+    let result = apply_inner::<PixmapF32>(filter, ctx, ts, source, &inputs, cache, pixmap_pool)
+        .and_then(|image| {
+            let image = image
+                .into_color_space(usvgr::filter::ColorInterpolation::SRGB)?
+                .take()?;
+            source.clear();
+            source.draw_over(&image, 0, 0);
+            Ok(())
+        });
+
+    // Clear on error.
+    if let Err(e) = result {
+        source.clear();
+        log_error(e);
     }
+}
 
-    match result {
-        Ok(_) => {}
-        Err(Error::InvalidRegion) => {
+fn log_error(error: Error) {
+    match error {
+        Error::InvalidRegion => {
             log::warn!("Filter has an invalid region.");
         }
-        Err(Error::NoResults) => {}
+        Error::NoResults => {}
     }
 }
 
-fn apply_inner(
+// `FillPaint` and `StrokePaint` are an infinite paint, so we fill the whole filter region.
+fn render_paint_input(
+    paint: Option<&usvgr::Paint>,
     filter: &usvgr::filter::Filter,
+    ctx: &crate::render::Context,
+    ts: tiny_skia::Transform,
+    source: &tiny_skia::Pixmap,
+    cache: &mut crate::cache::SvgrCache,
+    pixmap_pool: &crate::cache::PixmapPool,
+) -> Option<tiny_skia::Pixmap> {
+    let paint = paint?;
+    let mut pixmap = pixmap_pool.take_or_allocate(source.width(), source.height())?;
+    let rect = filter.rect().to_rect();
+    crate::path::fill_with_paint(
+        &tiny_skia::PathBuilder::from_rect(rect),
+        paint,
+        usvgr::Opacity::ONE,
+        tiny_skia::FillRule::Winding,
+        true,
+        tiny_skia::BlendMode::SourceOver,
+        rect,
+        ctx,
+        ts,
+        &mut pixmap.as_mut(),
+        cache,
+        pixmap_pool,
+    );
+
+    Some(pixmap)
+}
+
+fn apply_inner<P: FilterPixmap>(
+    filter: &usvgr::filter::Filter,
+    ctx: &crate::render::Context,
     ts: usvgr::Transform,
-    source: &mut tiny_skia::Pixmap,
+    source: &P,
+    inputs: &Inputs<P>,
     cache: &mut crate::cache::SvgrCache,
     pixmap_pool: &crate::cache::PixmapPool,
-) -> Result<Image, Error> {
+) -> Result<Image<P>, Error> {
     let region = filter
         .rect()
         .transform(ts)
         .map(|r| r.to_int_rect())
         .ok_or(Error::InvalidRegion)?;
 
-    let mut results: Vec<FilterResult> = Vec::new();
+    let mut results: Vec<FilterResult<P>> = Vec::new();
+    let mut input_keys = InputKeys::default();
 
     for primitive in filter.primitives() {
         let mut subregion = primitive
@@ -385,172 +649,469 @@ fn apply_inner(
             }
         }
 
+        let key = primitive_key(
+            primitive,
+            ts,
+            region,
+            subregion,
+            source,
+            inputs,
+            &results,
+            &mut input_keys,
+            cache,
+        );
+        let cached = match key {
+            Some(key) if is_expensive(primitive.kind()) => load_cached(key, cache, pixmap_pool),
+            _ => None,
+        };
+
+        if let Some(image) = cached {
+            results.push(FilterResult {
+                name: primitive.result().to_string(),
+                image,
+                key,
+            });
+            continue;
+        }
+
+        let mut result = P::apply_primitive(
+            primitive,
+            ctx,
+            ts,
+            region,
+            subregion,
+            source,
+            inputs,
+            &results,
+            cache,
+            pixmap_pool,
+        )?;
+
+        if region != subregion {
+            // Clip result.
+
+            // TODO: explain
+            let subregion2 = if let usvgr::filter::Kind::Offset(..) = primitive.kind() {
+                // We do not support clipping on feOffset.
+                region.translate_to(0, 0)
+            } else {
+                subregion.translate(-region.x(), -region.y())
+            }
+            .unwrap();
+
+            let color_space = result.color_space;
+
+            let mut pixmap = result.take()?;
+            pixmap.clear_outside(subregion2);
+
+            result = Image {
+                image: Rc::new(pixmap),
+                region: subregion,
+                color_space,
+            };
+        }
+
+        let key = match key {
+            Some(key) => {
+                if is_expensive(primitive.kind()) {
+                    store_cached(key, &result, cache, pixmap_pool);
+                }
+
+                Some(key)
+            }
+            // Results that cannot be keyed upfront are keyed by their pixels instead.
+            None => cache.filter_hasher().map(|mut hasher| {
+                result.as_ref().hash_pixels(&mut hasher);
+                result.color_space.hash(&mut hasher);
+                hash_rect(result.region, &mut hasher);
+                hasher.finish()
+            }),
+        };
+
+        results.push(FilterResult {
+            name: primitive.result().to_string(),
+            image: result,
+            key,
+        });
+    }
+
+    if let Some(res) = results.pop() {
+        Ok(res.image)
+    } else {
+        Err(Error::NoResults)
+    }
+}
+
+/// Checks that a primitive is worth caching.
+///
+/// Other primitives are cheaper to recompute than to copy from the cache.
+fn is_expensive(kind: &usvgr::filter::Kind) -> bool {
+    matches!(
+        kind,
+        usvgr::filter::Kind::GaussianBlur(..)
+            | usvgr::filter::Kind::DropShadow(..)
+            | usvgr::filter::Kind::Morphology(..)
+            | usvgr::filter::Kind::ConvolveMatrix(..)
+            | usvgr::filter::Kind::Turbulence(..)
+            | usvgr::filter::Kind::DiffuseLighting(..)
+            | usvgr::filter::Kind::SpecularLighting(..)
+    )
+}
+
+/// Returns a cache key of the primitive result.
+///
+/// Returns `None` when caching is disabled or when the primitive result depends on
+/// something besides its attributes and inputs, like `feImage` and user-defined primitives.
+fn primitive_key<P: FilterPixmap>(
+    primitive: &usvgr::filter::Primitive,
+    ts: usvgr::Transform,
+    region: IntRect,
+    subregion: IntRect,
+    source: &P,
+    inputs: &Inputs<P>,
+    results: &[FilterResult<P>],
+    keys: &mut InputKeys,
+    cache: &crate::cache::SvgrCache,
+) -> Option<u64> {
+    use usvgr::filter::Kind;
+    use usvgr::hashers::CustomHash;
+
+    let primitive_inputs: Vec<&usvgr::filter::Input> = match primitive.kind() {
+        Kind::Image(..) | Kind::Custom(..) => return None,
+        Kind::Flood(..) | Kind::Turbulence(..) => Vec::new(),
+        Kind::Blend(ref fe) => vec![fe.input1(), fe.input2()],
+        Kind::Composite(ref fe) => vec![fe.input1(), fe.input2()],
+        Kind::DisplacementMap(ref fe) => vec![fe.input1(), fe.input2()],
+        Kind::Merge(ref fe) => fe.inputs().iter().collect(),
+        Kind::ColorMatrix(ref fe) => vec![fe.input()],
+        Kind::ComponentTransfer(ref fe) => vec![fe.input()],
+        Kind::ConvolveMatrix(ref fe) => vec![fe.input()],
+        Kind::DiffuseLighting(ref fe) => vec![fe.input()],
+        Kind::DropShadow(ref fe) => vec![fe.input()],
+        Kind::GaussianBlur(ref fe) => vec![fe.input()],
+        Kind::Morphology(ref fe) => vec![fe.input()],
+        Kind::Offset(ref fe) => vec![fe.input()],
+        Kind::SpecularLighting(ref fe) => vec![fe.input()],
+        Kind::Tile(ref fe) => vec![fe.input()],
+    };
+
+    let mut hasher = cache.filter_hasher()?;
+    std::any::TypeId::of::<P>().hash(&mut hasher);
+    primitive.hash(&mut hasher);
+    ts.custom_hash(&mut hasher);
+    hash_rect(region, &mut hasher);
+    hash_rect(subregion, &mut hasher);
+
+    for input in primitive_inputs {
+        input_key(input, source, inputs, results, keys, cache)?.hash(&mut hasher);
+    }
+
+    Some(hasher.finish())
+}
+
+/// Hashes of the images primitives can use besides other primitive results.
+///
+/// Computed on first use, since hashing a whole image is not free.
+#[derive(Default)]
+struct InputKeys {
+    source: Option<u64>,
+    background: Option<u64>,
+    fill_paint: Option<u64>,
+    stroke_paint: Option<u64>,
+}
+
+fn input_key<P: FilterPixmap>(
+    input: &usvgr::filter::Input,
+    source: &P,
+    inputs: &Inputs<P>,
+    results: &[FilterResult<P>],
+    keys: &mut InputKeys,
+    cache: &crate::cache::SvgrCache,
+) -> Option<u64> {
+    let hash = |pixmap: Option<&P>| {
+        let mut hasher = cache.filter_hasher()?;
+        pixmap.is_some().hash(&mut hasher);
+        if let Some(pixmap) = pixmap {
+            pixmap.hash_pixels(&mut hasher);
+        }
+        Some(hasher.finish())
+    };
+
+    // The input kind itself is already hashed as a part of the primitive.
+    match input {
+        usvgr::filter::Input::SourceGraphic | usvgr::filter::Input::SourceAlpha => {
+            keys.source = keys.source.or_else(|| hash(Some(source)));
+            keys.source
+        }
+        usvgr::filter::Input::BackgroundImage | usvgr::filter::Input::BackgroundAlpha
+            if inputs.background.is_none() =>
+        {
+            keys.source = keys.source.or_else(|| hash(Some(source)));
+            keys.source
+        }
+        usvgr::filter::Input::BackgroundImage | usvgr::filter::Input::BackgroundAlpha => {
+            keys.background = keys.background.or_else(|| hash(inputs.background));
+            keys.background
+        }
+        usvgr::filter::Input::FillPaint => {
+            keys.fill_paint = keys.fill_paint.or_else(|| hash(inputs.fill_paint.as_ref()));
+            keys.fill_paint
+        }
+        usvgr::filter::Input::StrokePaint => {
+            keys.stroke_paint = keys
+                .stroke_paint
+                .or_else(|| hash(inputs.stroke_paint.as_ref()));
+            keys.stroke_paint
+        }
+        usvgr::filter::Input::Reference(ref name) => {
+            match results.iter().rev().find(|v| v.name == *name) {
+                Some(v) => v.key,
+                None => input_key(
+                    &usvgr::filter::Input::SourceGraphic,
+                    source,
+                    inputs,
+                    results,
+                    keys,
+                    cache,
+                ),
+            }
+        }
+    }
+}
+
+fn hash_rect<H: Hasher>(rect: IntRect, state: &mut H) {
+    (rect.x(), rect.y(), rect.width(), rect.height()).hash(state);
+}
+
+fn load_cached<P: FilterPixmap>(
+    key: u64,
+    cache: &mut crate::cache::SvgrCache,
+    pixmap_pool: &crate::cache::PixmapPool,
+) -> Option<Image<P>> {
+    let cached = cache.filter_result(key)?;
+    let image = cached.image.downcast_ref::<P>()?.pooled_copy(pixmap_pool)?;
+
+    Some(Image {
+        image: Rc::new(image),
+        region: cached.region,
+        color_space: cached.color_space,
+    })
+}
+
+fn store_cached<P: FilterPixmap>(
+    key: u64,
+    image: &Image<P>,
+    cache: &mut crate::cache::SvgrCache,
+    pixmap_pool: &crate::cache::PixmapPool,
+) {
+    if let Some(copy) = image.as_ref().pooled_copy(pixmap_pool) {
+        let cached = crate::cache::CachedFilterResult {
+            image: Box::new(copy),
+            region: image.region,
+            color_space: image.color_space,
+        };
+
+        cache.insert_filter_result(key, cached, pixmap_pool);
+    }
+}
+
+impl FilterPixmap for tiny_skia::Pixmap {
+    fn from_pixmap(pixmap: tiny_skia::Pixmap) -> Self {
+        pixmap
+    }
+
+    fn into_pixmap(self) -> tiny_skia::Pixmap {
+        self
+    }
+
+    fn width(&self) -> u32 {
+        tiny_skia::Pixmap::width(self)
+    }
+
+    fn height(&self) -> u32 {
+        tiny_skia::Pixmap::height(self)
+    }
+
+    fn transparent(width: u32, height: u32) -> Option<Self> {
+        tiny_skia::Pixmap::new(width, height)
+    }
+
+    fn clear_rgb(&mut self) {
+        for p in self.data_mut().as_rgba_mut() {
+            p.r = 0;
+            p.g = 0;
+            p.b = 0;
+        }
+    }
+
+    fn clear_outside(&mut self, region: IntRect) {
+        // This is cropping by clearing the pixels outside the region.
+        let mut paint = tiny_skia::Paint::default();
+        paint.set_color(tiny_skia::Color::BLACK);
+        paint.blend_mode = tiny_skia::BlendMode::Clear;
+
+        let w = self.width() as f32;
+        let h = self.height() as f32;
+
+        if let Some(rect) = tiny_skia::Rect::from_xywh(0.0, 0.0, w, region.y() as f32) {
+            self.fill_rect(rect, &paint, tiny_skia::Transform::identity(), None);
+        }
+
+        if let Some(rect) = tiny_skia::Rect::from_xywh(0.0, 0.0, region.x() as f32, h) {
+            self.fill_rect(rect, &paint, tiny_skia::Transform::identity(), None);
+        }
+
+        if let Some(rect) = tiny_skia::Rect::from_xywh(region.right() as f32, 0.0, w, h) {
+            self.fill_rect(rect, &paint, tiny_skia::Transform::identity(), None);
+        }
+
+        if let Some(rect) = tiny_skia::Rect::from_xywh(0.0, region.bottom() as f32, w, h) {
+            self.fill_rect(rect, &paint, tiny_skia::Transform::identity(), None);
+        }
+    }
+
+    fn pooled_copy(&self, pixmap_pool: &crate::cache::PixmapPool) -> Option<Self> {
+        let mut pixmap = pixmap_pool.take_or_allocate(self.width(), self.height())?;
+        pixmap.data_mut().copy_from_slice(self.data());
+        Some(pixmap)
+    }
+
+    fn hash_pixels<H: Hasher>(&self, state: &mut H) {
+        self.data().hash(state);
+    }
+
+    fn apply_primitive(
+        primitive: &usvgr::filter::Primitive,
+        ctx: &crate::render::Context,
+        ts: usvgr::Transform,
+        region: IntRect,
+        subregion: IntRect,
+        source: &tiny_skia::Pixmap,
+        inputs: &Inputs,
+        results: &[FilterResult],
+        cache: &mut crate::cache::SvgrCache,
+        pixmap_pool: &crate::cache::PixmapPool,
+    ) -> Result<Image, Error> {
         let cs = primitive.color_interpolation();
 
-        let mut result = match primitive.kind() {
+        match primitive.kind() {
             usvgr::filter::Kind::Blend(ref fe) => {
-                let input1 = get_input(fe.input1(), region, source, &results)?;
-                let input2 = get_input(fe.input2(), region, source, &results)?;
+                let input1 = get_input(fe.input1(), region, source, inputs, results)?;
+                let input2 = get_input(fe.input2(), region, source, inputs, results)?;
                 apply_blend(fe, cs, region, input1, input2, pixmap_pool)
             }
             usvgr::filter::Kind::DropShadow(ref fe) => {
-                let input = get_input(fe.input(), region, source, &results)?;
+                let input = get_input(fe.input(), region, source, inputs, results)?;
                 apply_drop_shadow(fe, cs, ts, input, pixmap_pool)
             }
             usvgr::filter::Kind::Flood(ref fe) => apply_flood(fe, region, pixmap_pool),
             usvgr::filter::Kind::GaussianBlur(ref fe) => {
-                let input = get_input(fe.input(), region, source, &results)?;
+                let input = get_input(fe.input(), region, source, inputs, results)?;
                 apply_blur(fe, cs, ts, input)
             }
             usvgr::filter::Kind::Offset(ref fe) => {
-                let input = get_input(fe.input(), region, source, &results)?;
+                let input = get_input(fe.input(), region, source, inputs, results)?;
                 apply_offset(fe, ts, input, pixmap_pool)
             }
             usvgr::filter::Kind::Composite(ref fe) => {
-                let input1 = get_input(fe.input1(), region, source, &results)?;
-                let input2 = get_input(fe.input2(), region, source, &results)?;
+                let input1 = get_input(fe.input1(), region, source, inputs, results)?;
+                let input2 = get_input(fe.input2(), region, source, inputs, results)?;
                 apply_composite(fe, cs, region, input1, input2, pixmap_pool)
             }
             usvgr::filter::Kind::Merge(ref fe) => {
-                apply_merge(fe, cs, region, source, &results, pixmap_pool)
+                apply_merge(fe, cs, region, source, inputs, results, pixmap_pool)
             }
             usvgr::filter::Kind::Tile(ref fe) => {
-                let input = get_input(fe.input(), region, source, &results)?;
+                let input = get_input(fe.input(), region, source, inputs, results)?;
                 apply_tile(input, region, pixmap_pool)
             }
             usvgr::filter::Kind::Image(ref fe) => {
-                apply_image(fe, region, subregion, ts, cache, pixmap_pool)
+                apply_image(fe, ctx, region, subregion, ts, cache, pixmap_pool)
             }
             usvgr::filter::Kind::ComponentTransfer(ref fe) => {
-                let input = get_input(fe.input(), region, source, &results)?;
+                let input = get_input(fe.input(), region, source, inputs, results)?;
                 apply_component_transfer(fe, cs, input)
             }
             usvgr::filter::Kind::ColorMatrix(ref fe) => {
-                let input = get_input(fe.input(), region, source, &results)?;
+                let input = get_input(fe.input(), region, source, inputs, results)?;
                 apply_color_matrix(fe, cs, input)
             }
             usvgr::filter::Kind::ConvolveMatrix(ref fe) => {
-                let input = get_input(fe.input(), region, source, &results)?;
+                let input = get_input(fe.input(), region, source, inputs, results)?;
                 apply_convolve_matrix(fe, cs, input)
             }
+            usvgr::filter::Kind::Custom(ref fe) => {
+                let input1 = get_input(fe.input1(), region, source, inputs, results)?;
+                let input2 = match fe.input2() {
+                    Some(input) => Some(get_input(input, region, source, inputs, results)?),
+                    None => None,
+                };
+                apply_custom(fe, ctx, cs, ts, region, input1, input2, pixmap_pool)
+            }
             usvgr::filter::Kind::Morphology(ref fe) => {
-                let input = get_input(fe.input(), region, source, &results)?;
+                let input = get_input(fe.input(), region, source, inputs, results)?;
                 apply_morphology(fe, cs, ts, input)
             }
             usvgr::filter::Kind::DisplacementMap(ref fe) => {
-                let input1 = get_input(fe.input1(), region, source, &results)?;
-                let input2 = get_input(fe.input2(), region, source, &results)?;
+                let input1 = get_input(fe.input1(), region, source, inputs, results)?;
+                let input2 = get_input(fe.input2(), region, source, inputs, results)?;
                 apply_displacement_map(fe, region, cs, ts, input1, input2, pixmap_pool)
             }
             usvgr::filter::Kind::Turbulence(ref fe) => {
                 apply_turbulence(fe, region, cs, ts, pixmap_pool)
             }
             usvgr::filter::Kind::DiffuseLighting(ref fe) => {
-                let input = get_input(fe.input(), region, source, &results)?;
+                let input = get_input(fe.input(), region, source, inputs, results)?;
                 apply_diffuse_lighting(fe, region, cs, ts, input, pixmap_pool)
             }
             usvgr::filter::Kind::SpecularLighting(ref fe) => {
-                let input = get_input(fe.input(), region, source, &results)?;
+                let input = get_input(fe.input(), region, source, inputs, results)?;
                 apply_specular_lighting(fe, region, cs, ts, input, pixmap_pool)
             }
-        }?;
-
-        if region != subregion {
-            // Clip result.
-
-            // TODO: explain
-            let subregion2 = if let usvgr::filter::Kind::Offset(..) = primitive.kind() {
-                // We do not support clipping on feOffset.
-                region.translate_to(0, 0)
-            } else {
-                subregion.translate(-region.x(), -region.y())
-            }
-            .unwrap();
-
-            let color_space = result.color_space;
-
-            let pixmap = {
-                // This is cropping by clearing the pixels outside the region.
-                let mut paint = tiny_skia::Paint::default();
-                paint.set_color(tiny_skia::Color::BLACK);
-                paint.blend_mode = tiny_skia::BlendMode::Clear;
-
-                let mut pixmap = result.take()?;
-                let w = pixmap.width() as f32;
-                let h = pixmap.height() as f32;
-
-                if let Some(rect) = tiny_skia::Rect::from_xywh(0.0, 0.0, w, subregion2.y() as f32) {
-                    pixmap.fill_rect(rect, &paint, tiny_skia::Transform::identity(), None);
-                }
-
-                if let Some(rect) = tiny_skia::Rect::from_xywh(0.0, 0.0, subregion2.x() as f32, h) {
-                    pixmap.fill_rect(rect, &paint, tiny_skia::Transform::identity(), None);
-                }
-
-                if let Some(rect) = tiny_skia::Rect::from_xywh(subregion2.right() as f32, 0.0, w, h)
-                {
-                    pixmap.fill_rect(rect, &paint, tiny_skia::Transform::identity(), None);
-                }
-
-                if let Some(rect) =
-                    tiny_skia::Rect::from_xywh(0.0, subregion2.bottom() as f32, w, h)
-                {
-                    pixmap.fill_rect(rect, &paint, tiny_skia::Transform::identity(), None);
-                }
-
-                pixmap
-            };
-
-            result = Image {
-                image: Rc::new(pixmap),
-                region: subregion,
-                color_space,
-            };
         }
-
-        results.push(FilterResult {
-            name: primitive.result().to_string(),
-            image: result,
-        });
-    }
-
-    if let Some(res) = results.pop() {
-        Ok(res.image)
-    } else {
-        Err(Error::NoResults)
     }
 }
 
-fn get_input(
+fn get_input<P: FilterPixmap>(
     input: &usvgr::filter::Input,
     region: IntRect,
-    source: &tiny_skia::Pixmap,
-    results: &[FilterResult],
-) -> Result<Image, Error> {
-    match input {
-        usvgr::filter::Input::SourceGraphic => {
-            let image = source.clone();
+    source: &P,
+    inputs: &Inputs<P>,
+    results: &[FilterResult<P>],
+) -> Result<Image<P>, Error> {
+    let new_image = |image: P| Image {
+        image: Rc::new(image),
+        region,
+        color_space: usvgr::filter::ColorInterpolation::SRGB,
+    };
 
-            Ok(Image {
-                image: Rc::new(image),
-                region,
-                color_space: usvgr::filter::ColorInterpolation::SRGB,
-            })
-        }
-        usvgr::filter::Input::SourceAlpha => {
-            let mut image = source.clone();
-            // Set RGB to black. Keep alpha as is.
-            for p in image.data_mut().as_rgba_mut() {
-                p.r = 0;
-                p.g = 0;
-                p.b = 0;
-            }
+    // A missing paint is a transparent black image.
+    let extra_input = |image: Option<&P>| match image {
+        Some(image) => Ok(image.clone()),
+        None => P::transparent(source.width(), source.height()).ok_or(Error::InvalidRegion),
+    };
 
-            Ok(Image {
-                image: Rc::new(image),
-                region,
-                color_space: usvgr::filter::ColorInterpolation::SRGB,
-            })
+    let into_alpha = |mut image: P| {
+        image.clear_rgb();
+        image
+    };
+
+    match input {
+        usvgr::filter::Input::SourceGraphic => Ok(new_image(source.clone())),
+        usvgr::filter::Input::SourceAlpha => Ok(new_image(into_alpha(source.clone()))),
+        // Without an `enable-background="new"` ancestor there is no background image,
+        // so we fallback to the source graphic, like for an unsupported input.
+        usvgr::filter::Input::BackgroundImage => {
+            let background = inputs.background.unwrap_or(source);
+            Ok(new_image(background.clone()))
+        }
+        usvgr::filter::Input::BackgroundAlpha => {
+            let background = inputs.background.unwrap_or(source);
+            Ok(new_image(into_alpha(background.clone())))
+        }
+        usvgr::filter::Input::FillPaint => Ok(new_image(extra_input(inputs.fill_paint.as_ref())?)),
+        usvgr::filter::Input::StrokePaint => {
+            Ok(new_image(extra_input(inputs.stroke_paint.as_ref())?))
         }
         usvgr::filter::Input::Reference(ref name) => {
             if let Some(v) = results.iter().rev().find(|v| v.name == *name) {
@@ -562,6 +1123,7 @@ fn get_input(
                     &usvgr::filter::Input::SourceGraphic,
                     region,
                     source,
+                    inputs,
                     results,
                 )
             }
@@ -744,6 +1306,45 @@ fn apply_blend(
     Ok(Image::from_image(pixmap, cs))
 }
 
+fn apply_custom(
+    fe: &usvgr::filter::Custom,
+    ctx: &crate::render::Context,
+    cs: usvgr::filter::ColorInterpolation,
+    ts: usvgr::Transform,
+    region: IntRect,
+    input1: Image,
+    input2: Option<Image>,
+    pixmap_pool: &crate::cache::PixmapPool,
+) -> Result<Image, Error> {
+    let filter = match ctx.custom_filters.as_ref().and_then(|f| f.get(fe.name())) {
+        Some(filter) => filter,
+        None => {
+            log::warn!("Custom filter '{}' is not registered.", fe.name());
+            return Ok(input1);
+        }
+    };
+
+    let input1 = input1.into_color_space(cs)?;
+    let input2 = match input2 {
+        Some(input) => Some(input.into_color_space(cs)?),
+        None => None,
+    };
+
+    let mut pixmap = pixmap_pool
+        .take_or_allocate(region.width(), region.height())
+        .ok_or(Error::NoResults)?;
+
+    filter.apply(
+        fe.args(),
+        input1.as_ref(),
+        input2.as_ref().map(|i| i.as_ref()),
+        ts,
+        &mut pixmap,
+    );
+
+    Ok(Image::from_image(pixmap, cs))
+}
+
 fn apply_composite(
     fe: &usvgr::filter::Composite,
     cs: usvgr::filter::ColorInterpolation,
@@ -816,6 +1417,7 @@ fn apply_merge(
     cs: usvgr::filter::ColorInterpolation,
     region: IntRect,
     source: &tiny_skia::Pixmap,
+    inputs: &Inputs,
     results: &[FilterResult],
     pixmap_pool: &crate::cache::PixmapPool,
 ) -> Result<Image, Error> {
@@ -824,7 +1426,7 @@ fn apply_merge(
         .ok_or(Error::NoResults)?;
 
     for input in fe.inputs() {
-        let input = get_input(input, region, source, results)?;
+        let input = get_input(input, region, source, inputs, results)?;
         let input = input.into_color_space(cs)?;
         pixmap.draw_pixmap(
             0,
@@ -894,6 +1496,7 @@ fn apply_tile(
 
 fn apply_image(
     fe: &usvgr::filter::Image,
+    ctx: &crate::render::Context,
     region: IntRect,
     subregion: IntRect,
     ts: usvgr::Transform,
@@ -923,6 +1526,7 @@ fn apply_image(
             crate::image::render_inner(
                 kind,
                 view_box,
+                ctx,
                 transform,
                 fe.rendering_mode(),
                 &mut pixmap.as_mut(),
@@ -945,6 +1549,11 @@ fn apply_image(
             let ctx = crate::render::Context {
                 max_bbox: tiny_skia::IntRect::from_xywh(0, 0, region.width(), region.height())
                     .unwrap(),
+                gradient_dithering: ctx.gradient_dithering,
+                custom_filters: ctx.custom_filters.clone(),
+                filter_precision: ctx.filter_precision,
+                background: None,
+                hdr: ctx.hdr,
             };
 
             crate::render::render_nodes(
@@ -1185,14 +1794,18 @@ fn transform_light_source(
     source
 }
 
-fn apply_to_canvas(input: Image, pixmap: &mut tiny_skia::Pixmap) -> Result<(), Error> {
+fn apply_to_canvas<P: FilterPixmap>(
+    input: Image<P>,
+    pixmap: &mut tiny_skia::Pixmap,
+) -> Result<(), Error> {
     let input = input.into_color_space(usvgr::filter::ColorInterpolation::SRGB)?;
+    let input = input.take()?.into_pixmap();
 
     pixmap.fill(tiny_skia::Color::TRANSPARENT);
     pixmap.draw_pixmap(
         0,
         0,
-        input.as_ref().as_ref(),
+        input.as_ref(),
         &tiny_skia::PixmapPaint::default(),
         tiny_skia::Transform::identity(),
         None,
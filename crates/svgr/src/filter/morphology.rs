@@ -2,8 +2,8 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use super::ImageRefMut;
-use rgb::RGBA8;
+use super::{Channel, ImageRefMut};
+use rgb::RGBA;
 use usvgr::filter::MorphologyOperator;
 
 /// Applies a morphology filter.
@@ -13,7 +13,12 @@ use usvgr::filter::MorphologyOperator;
 /// # Allocations
 ///
 /// This method will allocate a copy of the `src` image as a back buffer.
-pub fn apply(operator: MorphologyOperator, rx: f32, ry: f32, src: ImageRefMut) {
+pub fn apply<T: Channel>(
+    operator: MorphologyOperator,
+    rx: f32,
+    ry: f32,
+    src: ImageRefMut<RGBA<T>>,
+) {
     // No point in making matrix larger than image.
     let columns = std::cmp::min(rx.ceil() as u32 * 2, src.width);
     let rows = std::cmp::min(ry.ceil() as u32 * 2, src.height);
@@ -23,17 +28,17 @@ pub fn apply(operator: MorphologyOperator, rx: f32, ry: f32, src: ImageRefMut) {
     let width_max = src.width as i32 - 1;
     let height_max = src.height as i32 - 1;
 
-    let mut buf = vec![RGBA8::default(); src.data.len()];
+    let mut buf = vec![RGBA::<T>::default(); src.data.len()];
     let mut buf = ImageRefMut::new(src.width, src.height, &mut buf);
     let mut x = 0;
     let mut y = 0;
     for _ in src.data.iter() {
-        let mut new_p = RGBA8::default();
+        let mut new_p = RGBA::<T>::default();
         if operator == MorphologyOperator::Erode {
-            new_p.r = 255;
-            new_p.g = 255;
-            new_p.b = 255;
-            new_p.a = 255;
+            new_p.r = T::MAX;
+            new_p.g = T::MAX;
+            new_p.b = T::MAX;
+            new_p.a = T::MAX;
         }
 
         for oy in 0..rows {
@@ -47,15 +52,15 @@ pub fn apply(operator: MorphologyOperator, rx: f32, ry: f32, src: ImageRefMut) {
 
                 let p = src.pixel_at(tx as u32, ty as u32);
                 if operator == MorphologyOperator::Erode {
-                    new_p.r = std::cmp::min(p.r, new_p.r);
-                    new_p.g = std::cmp::min(p.g, new_p.g);
-                    new_p.b = std::cmp::min(p.b, new_p.b);
-                    new_p.a = std::cmp::min(p.a, new_p.a);
+                    new_p.r = min(p.r, new_p.r);
+                    new_p.g = min(p.g, new_p.g);
+                    new_p.b = min(p.b, new_p.b);
+                    new_p.a = min(p.a, new_p.a);
                 } else {
-                    new_p.r = std::cmp::max(p.r, new_p.r);
-                    new_p.g = std::cmp::max(p.g, new_p.g);
-                    new_p.b = std::cmp::max(p.b, new_p.b);
-                    new_p.a = std::cmp::max(p.a, new_p.a);
+                    new_p.r = max(p.r, new_p.r);
+                    new_p.g = max(p.g, new_p.g);
+                    new_p.b = max(p.b, new_p.b);
+                    new_p.a = max(p.a, new_p.a);
                 }
             }
         }
@@ -72,3 +77,21 @@ pub fn apply(operator: MorphologyOperator, rx: f32, ry: f32, src: ImageRefMut) {
     // Do not use `mem::swap` because `data` referenced via FFI.
     src.data.copy_from_slice(buf.data);
 }
+
+#[inline]
+fn min<T: Channel>(a: T, b: T) -> T {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+#[inline]
+fn max<T: Channel>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
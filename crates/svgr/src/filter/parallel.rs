@@ -0,0 +1,58 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// Helpers for splitting filter passes between threads.
+//
+// Without the `parallel` feature everything runs on the current thread.
+
+/// Images smaller than this number of pixels are always processed on the current thread.
+#[cfg(feature = "parallel")]
+const PARALLEL_THRESHOLD: usize = 256 * 256;
+
+/// Returns the number of bands an image with the provided number of pixels
+/// should be split into.
+pub fn band_count(pixels: usize) -> usize {
+    #[cfg(feature = "parallel")]
+    if pixels >= PARALLEL_THRESHOLD {
+        return rayon::current_num_threads();
+    }
+
+    let _ = pixels;
+    1
+}
+
+/// Calls `f` for each `chunk_len` long chunk of `data` along with the chunk index.
+pub fn for_each_chunk<T, F>(data: &mut [T], chunk_len: usize, f: F)
+where
+    T: Send,
+    F: Fn(usize, &mut [T]) + Send + Sync,
+{
+    #[cfg(feature = "parallel")]
+    if data.len() > chunk_len {
+        use rayon::prelude::*;
+        data.par_chunks_mut(chunk_len)
+            .enumerate()
+            .for_each(|(i, chunk)| f(i, chunk));
+        return;
+    }
+
+    data.chunks_mut(chunk_len)
+        .enumerate()
+        .for_each(|(i, chunk)| f(i, chunk));
+}
+
+/// Collects `f(0)..f(count)`.
+pub fn map<T, F>(count: usize, f: F) -> Vec<T>
+where
+    T: Send,
+    F: Fn(usize) -> T + Send + Sync,
+{
+    #[cfg(feature = "parallel")]
+    if count > 1 {
+        use rayon::prelude::*;
+        return (0..count).into_par_iter().map(f).collect();
+    }
+
+    (0..count).map(f).collect()
+}
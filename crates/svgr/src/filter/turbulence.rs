@@ -4,7 +4,8 @@
 
 #![allow(clippy::needless_range_loop)]
 
-use super::{f32_bound, ImageRefMut};
+use super::{f32_bound, Channel, ImageRefMut};
+use rgb::RGBA;
 use usvgr::ApproxZeroUlps;
 
 const RAND_M: i32 = 2147483647; // 2**31 - 1
@@ -31,7 +32,7 @@ struct StitchInfo {
 ///
 /// - `offset_x` and `offset_y` indicate filter region offset.
 /// - `sx` and `sy` indicate canvas scale.
-pub fn apply(
+pub fn apply<T: Channel>(
     offset_x: f64,
     offset_y: f64,
     sx: f64,
@@ -42,7 +43,7 @@ pub fn apply(
     seed: i32,
     stitch_tiles: bool,
     fractal_noise: bool,
-    dest: ImageRefMut,
+    dest: ImageRefMut<RGBA<T>>,
 ) {
     let (lattice_selector, gradient) = init(seed);
     let width = dest.width;
@@ -75,7 +76,7 @@ pub fn apply(
                 n * 255.0
             };
 
-            (f32_bound(0.0, n as f32, 255.0) + 0.5) as u8
+            T::from_scaled(f32_bound(0.0, n as f32, 255.0))
         };
 
         pixel.r = turb(0);
@@ -30,6 +30,23 @@ pub fn fit_to_rect(
     tiny_skia::IntRect::from_ltrb(left, top, right, bottom)
 }
 
+/// Returns the group layer bounding box in the coordinates of `transform`.
+///
+/// Non-scaling strokes are not affected by the transform,
+/// so their outset is applied afterwards.
+pub fn layer_bbox(
+    group: &usvgr::Group,
+    transform: tiny_skia::Transform,
+) -> Option<tiny_skia::NonZeroRect> {
+    let bbox = group.layer_bounding_box().transform(transform)?;
+    let outset = group.non_scaling_stroke_outset();
+    if outset > 0.0 {
+        bbox.to_rect().outset(outset, outset)?.to_non_zero_rect()
+    } else {
+        Some(bbox)
+    }
+}
+
 /// Converts `viewBox` to `Transform` with an optional clip rectangle.
 ///
 /// Unlike `view_box_to_transform`, returns an optional clip rectangle
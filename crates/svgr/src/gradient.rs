@@ -0,0 +1,472 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::sync::OnceLock;
+
+use usvgr::filter::ColorInterpolation;
+
+use crate::render::Context;
+
+/// A number of precomputed gradient colors.
+const LUT_SIZE: usize = 4096;
+
+/// A blue noise texture side.
+const BLUE_NOISE_SIZE: usize = 32;
+
+/// A gradient dithering mode.
+///
+/// Dithering hides the banding of long and subtle gradients,
+/// which becomes especially visible after a lossy video compression.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum GradientDithering {
+    /// No dithering.
+    #[default]
+    None,
+    /// An 8x8 Bayer matrix.
+    ///
+    /// Has a visible regular pattern, but compresses well.
+    Ordered,
+    /// A 32x32 blue noise texture.
+    ///
+    /// Has no visible pattern.
+    BlueNoise,
+}
+
+/// Checks that a gradient cannot be rendered by `tiny-skia` directly.
+///
+/// `tiny-skia` supports neither the `linearRGB` interpolation nor dithering.
+pub fn needs_manual_rendering(gradient: &usvgr::BaseGradient, ctx: &Context) -> bool {
+    gradient.color_interpolation() == ColorInterpolation::LinearRGB
+        || ctx.gradient_dithering != GradientDithering::None
+}
+
+/// Renders a linear or a radial gradient into a pixmap that can be used as a pattern shader.
+///
+/// Only the `bbox` part of the canvas is rendered, which must be in the device coordinates.
+pub fn render_gradient_pixmap<'a>(
+    paint: &usvgr::Paint,
+    opacity: usvgr::Opacity,
+    ctx: &Context,
+    transform: tiny_skia::Transform,
+    bbox: tiny_skia::Rect,
+    canvas: tiny_skia::IntSize,
+    cache: &mut crate::cache::SvgrCache,
+    pixmap_pool: &'a crate::cache::PixmapPool,
+) -> Option<(&'a tiny_skia::Pixmap, tiny_skia::Transform)> {
+    let (base, shape) = gradient_shape(paint)?;
+
+    let canvas_rect = canvas.to_int_rect(0, 0).to_rect();
+    let region = bbox.intersect(&canvas_rect)?.round_out()?;
+
+    let ts = transform.pre_concat(base.transform()).invert()?;
+    let lut = cache.gradient_lut(base, opacity, || build_lut(base, opacity));
+
+    let mut pixmap = pixmap_pool.take_or_allocate(region.width(), region.height())?;
+    let width = region.width() as usize;
+    let data = pixmap.data_mut();
+    for y in 0..region.height() as i32 {
+        for x in 0..region.width() as i32 {
+            let dx = region.x() + x;
+            let dy = region.y() + y;
+
+            let mut p = tiny_skia::Point::from_xy(dx as f32 + 0.5, dy as f32 + 0.5);
+            ts.map_point(&mut p);
+
+            let t = match shape.offset(p) {
+                Some(t) => apply_spread_method(t, base.spread_method()),
+                None => continue,
+            };
+
+            let color = lut[(t * (LUT_SIZE - 1) as f32).round() as usize];
+            let noise = match ctx.gradient_dithering {
+                GradientDithering::None => 0.0,
+                GradientDithering::Ordered => bayer_threshold(dx, dy) - 0.5,
+                GradientDithering::BlueNoise => blue_noise_threshold(dx, dy) - 0.5,
+            };
+
+            let a = quantize(color[3], noise, 255.0);
+            let idx = (y as usize * width + x as usize) * 4;
+            for c in 0..3 {
+                data[idx + c] = quantize(color[c], noise, a as f32);
+            }
+            data[idx + 3] = a;
+        }
+    }
+
+    let pixmap = pixmap_pool.release(pixmap);
+    let ts = transform
+        .invert()?
+        .pre_translate(region.x() as f32, region.y() as f32);
+
+    Some((pixmap, ts))
+}
+
+/// Evaluates a linear or a radial gradient without rounding colors to 8 bits.
+pub struct GradientSampler {
+    shape: Shape,
+    stops: Vec<(f32, [f32; 4])>,
+    is_linear_rgb: bool,
+    spread_method: usvgr::SpreadMethod,
+    /// Maps device coordinates to the gradient ones.
+    ts: tiny_skia::Transform,
+}
+
+impl GradientSampler {
+    /// Creates a sampler for a gradient paint rendered with the specified transform.
+    ///
+    /// Returns `None` for other paints and non-invertible transforms.
+    pub fn new(
+        paint: &usvgr::Paint,
+        opacity: usvgr::Opacity,
+        transform: tiny_skia::Transform,
+    ) -> Option<Self> {
+        let (base, shape) = gradient_shape(paint)?;
+
+        Some(GradientSampler {
+            shape,
+            stops: gradient_stops(base, opacity),
+            is_linear_rgb: base.color_interpolation() == ColorInterpolation::LinearRGB,
+            spread_method: base.spread_method(),
+            ts: transform.pre_concat(base.transform()).invert()?,
+        })
+    }
+
+    /// Returns a premultiplied sRGB color at the center of a device pixel.
+    pub fn color_at(&self, x: i32, y: i32) -> [f32; 4] {
+        let mut p = tiny_skia::Point::from_xy(x as f32 + 0.5, y as f32 + 0.5);
+        self.ts.map_point(&mut p);
+
+        match self.shape.offset(p) {
+            Some(t) => interpolate(
+                &self.stops,
+                apply_spread_method(t, self.spread_method),
+                self.is_linear_rgb,
+            ),
+            None => [0.0; 4],
+        }
+    }
+}
+
+fn gradient_shape(paint: &usvgr::Paint) -> Option<(&usvgr::BaseGradient, Shape)> {
+    match paint {
+        usvgr::Paint::LinearGradient(ref lg) => Some((
+            lg.as_ref() as &usvgr::BaseGradient,
+            Shape::Linear {
+                p1: tiny_skia::Point::from_xy(lg.x1(), lg.y1()),
+                p2: tiny_skia::Point::from_xy(lg.x2(), lg.y2()),
+            },
+        )),
+        usvgr::Paint::RadialGradient(ref rg) => Some((
+            rg.as_ref() as &usvgr::BaseGradient,
+            Shape::Radial {
+                center: tiny_skia::Point::from_xy(rg.cx(), rg.cy()),
+                focal: tiny_skia::Point::from_xy(rg.fx(), rg.fy()),
+                r: rg.r().get(),
+            },
+        )),
+        _ => None,
+    }
+}
+
+enum Shape {
+    Linear {
+        p1: tiny_skia::Point,
+        p2: tiny_skia::Point,
+    },
+    Radial {
+        center: tiny_skia::Point,
+        focal: tiny_skia::Point,
+        r: f32,
+    },
+}
+
+impl Shape {
+    /// Returns a gradient offset at the specified point in the gradient coordinates.
+    fn offset(&self, p: tiny_skia::Point) -> Option<f32> {
+        match *self {
+            Shape::Linear { p1, p2 } => {
+                let d = p2 - p1;
+                let len2 = d.dot(d);
+                if len2 == 0.0 {
+                    return None;
+                }
+
+                Some((p - p1).dot(d) / len2)
+            }
+            Shape::Radial { center, focal, r } => {
+                // Circles are interpolated from the focal point with a zero radius
+                // to the center with the `r` radius. So we have to find the largest `t`,
+                // for which `|p - (focal + t * d)| = t * r`.
+                let d = center - focal;
+                let q = p - focal;
+                let a = d.dot(d) - r * r;
+                let b = q.dot(d);
+                let c = q.dot(q);
+
+                let t = if a.abs() < f32::EPSILON {
+                    if b == 0.0 {
+                        return None;
+                    }
+
+                    c / (2.0 * b)
+                } else {
+                    let discriminant = b * b - a * c;
+                    if discriminant < 0.0 {
+                        return None;
+                    }
+
+                    let sqrt = discriminant.sqrt();
+                    ((b + sqrt) / a).max((b - sqrt) / a)
+                };
+
+                if t < 0.0 {
+                    None
+                } else {
+                    Some(t)
+                }
+            }
+        }
+    }
+}
+
+fn apply_spread_method(t: f32, spread_method: usvgr::SpreadMethod) -> f32 {
+    match spread_method {
+        usvgr::SpreadMethod::Pad => t.clamp(0.0, 1.0),
+        usvgr::SpreadMethod::Repeat => t - t.floor(),
+        usvgr::SpreadMethod::Reflect => {
+            let t = (t * 0.5 - (t * 0.5).floor()) * 2.0;
+            if t > 1.0 {
+                2.0 - t
+            } else {
+                t
+            }
+        }
+    }
+}
+
+/// Precomputes premultiplied sRGB colors for offsets in the 0..1 range.
+fn build_lut(gradient: &usvgr::BaseGradient, opacity: usvgr::Opacity) -> Vec<[f32; 4]> {
+    let is_linear_rgb = gradient.color_interpolation() == ColorInterpolation::LinearRGB;
+    let stops = gradient_stops(gradient, opacity);
+
+    (0..LUT_SIZE)
+        .map(|i| {
+            let t = i as f32 / (LUT_SIZE - 1) as f32;
+            interpolate(&stops, t, is_linear_rgb)
+        })
+        .collect()
+}
+
+/// Returns stop offsets and non-premultiplied colors in the interpolation color space.
+fn gradient_stops(gradient: &usvgr::BaseGradient, opacity: usvgr::Opacity) -> Vec<(f32, [f32; 4])> {
+    let is_linear_rgb = gradient.color_interpolation() == ColorInterpolation::LinearRGB;
+    gradient
+        .stops()
+        .iter()
+        .map(|stop| {
+            let c = stop.color();
+            let mut rgb = [c.red, c.green, c.blue].map(|v| v as f32 / 255.0);
+            if is_linear_rgb {
+                rgb = rgb.map(srgb_to_linear);
+            }
+
+            let a = stop.opacity().get() * opacity.get();
+            (stop.offset().get(), [rgb[0], rgb[1], rgb[2], a])
+        })
+        .collect()
+}
+
+/// Returns a premultiplied sRGB color at the offset in the 0..1 range.
+fn interpolate(stops: &[(f32, [f32; 4])], t: f32, is_linear_rgb: bool) -> [f32; 4] {
+    let next = stops.iter().position(|(offset, _)| *offset > t);
+    let mut color = match next {
+        Some(0) => stops[0].1,
+        None => stops[stops.len() - 1].1,
+        Some(idx) => {
+            let (o1, c1) = stops[idx - 1];
+            let (o2, c2) = stops[idx];
+            let k = (t - o1) / (o2 - o1);
+            std::array::from_fn(|c| c1[c] + (c2[c] - c1[c]) * k)
+        }
+    };
+
+    if is_linear_rgb {
+        for c in &mut color[0..3] {
+            *c = linear_to_srgb(*c);
+        }
+    }
+
+    for c in 0..3 {
+        color[c] *= color[3];
+    }
+
+    color
+}
+
+fn srgb_to_linear(v: f32) -> f32 {
+    if v <= 0.04045 {
+        v / 12.92
+    } else {
+        ((v + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(v: f32) -> f32 {
+    if v <= 0.0031308 {
+        v * 12.92
+    } else {
+        1.055 * v.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Converts a 0..1 value into 8 bits, adding a dithering noise in the -0.5..0.5 range.
+fn quantize(v: f32, noise: f32, max: f32) -> u8 {
+    (v * 255.0 + noise).round().clamp(0.0, max) as u8
+}
+
+/// Returns an 8x8 Bayer matrix threshold in the 0..1 range.
+fn bayer_threshold(x: i32, y: i32) -> f32 {
+    let (x, y) = (x as u32 & 7, y as u32 & 7);
+
+    // Interleave the bits of `x ^ y` and `y` in reverse order.
+    let mut v = 0;
+    for bit in 0..3 {
+        let shift = 2 * (2 - bit);
+        v |= (((x ^ y) >> bit) & 1) << (shift + 1);
+        v |= ((y >> bit) & 1) << shift;
+    }
+
+    (v as f32 + 0.5) / 64.0
+}
+
+fn blue_noise_threshold(x: i32, y: i32) -> f32 {
+    static TEXTURE: OnceLock<Vec<f32>> = OnceLock::new();
+    let texture = TEXTURE.get_or_init(generate_blue_noise);
+
+    let size = BLUE_NOISE_SIZE as i32;
+    texture[(y.rem_euclid(size) * size + x.rem_euclid(size)) as usize]
+}
+
+/// Generates a tileable blue noise texture using the void-and-cluster method.
+///
+/// Robert Ulichney, "Void-and-cluster method for dither array generation", 1993.
+fn generate_blue_noise() -> Vec<f32> {
+    const SIGMA: f32 = 1.5;
+    const LEN: usize = BLUE_NOISE_SIZE * BLUE_NOISE_SIZE;
+
+    // A Gaussian weight for each toroidal offset.
+    let kernel: Vec<f32> = (0..LEN)
+        .map(|i| {
+            let wrap = |v: usize| {
+                let v = v as f32;
+                v.min(BLUE_NOISE_SIZE as f32 - v)
+            };
+            let (dx, dy) = (wrap(i % BLUE_NOISE_SIZE), wrap(i / BLUE_NOISE_SIZE));
+            (-(dx * dx + dy * dy) / (2.0 * SIGMA * SIGMA)).exp()
+        })
+        .collect();
+
+    let offset = |a: usize, b: usize| {
+        let dx = (a % BLUE_NOISE_SIZE + BLUE_NOISE_SIZE - b % BLUE_NOISE_SIZE) % BLUE_NOISE_SIZE;
+        let dy = (a / BLUE_NOISE_SIZE + BLUE_NOISE_SIZE - b / BLUE_NOISE_SIZE) % BLUE_NOISE_SIZE;
+        dy * BLUE_NOISE_SIZE + dx
+    };
+
+    let update = |energy: &mut [f32], idx: usize, sign: f32| {
+        for (i, e) in energy.iter_mut().enumerate() {
+            *e += sign * kernel[offset(i, idx)];
+        }
+    };
+
+    // Finds a candidate point with the highest (tightest cluster)
+    // or the lowest (largest void) energy.
+    let find = |candidates: &dyn Fn(usize) -> bool, energy: &[f32], highest: bool| -> usize {
+        let mut best: Option<(usize, f32)> = None;
+        for (i, e) in energy.iter().copied().enumerate() {
+            if !candidates(i) {
+                continue;
+            }
+
+            let is_better = match best {
+                Some((_, best_e)) => (highest && e > best_e) || (!highest && e < best_e),
+                None => true,
+            };
+            if is_better {
+                best = Some((i, e));
+            }
+        }
+
+        best.map(|(i, _)| i).unwrap_or(0)
+    };
+
+    // An initial pattern with about 10% of points, placed by a simple LCG.
+    let mut pattern = vec![false; LEN];
+    let mut energy = vec![0.0; LEN];
+    let mut seed: u32 = 1;
+    let mut ones = 0;
+    while ones < LEN / 10 {
+        seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+        let idx = (seed >> 16) as usize % LEN;
+        if !pattern[idx] {
+            pattern[idx] = true;
+            update(&mut energy, idx, 1.0);
+            ones += 1;
+        }
+    }
+
+    // Distribute the initial points evenly.
+    loop {
+        let cluster = find(&|i| pattern[i], &energy, true);
+        pattern[cluster] = false;
+        update(&mut energy, cluster, -1.0);
+
+        let void = find(&|i| !pattern[i], &energy, false);
+        pattern[void] = true;
+        update(&mut energy, void, 1.0);
+
+        if void == cluster {
+            break;
+        }
+    }
+
+    let mut ranks = vec![0; LEN];
+
+    // Phase 1: rank the initial points by removing the tightest clusters.
+    {
+        let mut pattern = pattern.clone();
+        let mut energy = energy.clone();
+        for rank in (0..ones).rev() {
+            let cluster = find(&|i| pattern[i], &energy, true);
+            pattern[cluster] = false;
+            update(&mut energy, cluster, -1.0);
+            ranks[cluster] = rank;
+        }
+    }
+
+    // Phase 2: fill the largest voids up to the half.
+    for rank in ones..LEN / 2 {
+        let void = find(&|i| !pattern[i], &energy, false);
+        pattern[void] = true;
+        update(&mut energy, void, 1.0);
+        ranks[void] = rank;
+    }
+
+    // Phase 3: the remaining empty points are the minority now,
+    // so we are filling the tightest clusters of them.
+    let mut energy = vec![0.0; LEN];
+    for (i, _) in pattern.iter().enumerate().filter(|(_, v)| !**v) {
+        update(&mut energy, i, 1.0);
+    }
+    for rank in LEN / 2..LEN {
+        let cluster = find(&|i| !pattern[i], &energy, true);
+        pattern[cluster] = true;
+        update(&mut energy, cluster, -1.0);
+        ranks[cluster] = rank;
+    }
+
+    ranks
+        .into_iter()
+        .map(|rank| (rank as f32 + 0.5) / LEN as f32)
+        .collect()
+}
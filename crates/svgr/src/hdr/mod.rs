@@ -0,0 +1,310 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! A floating point render target.
+//!
+//! Pixels are stored as premultiplied sRGB-encoded `f32` values, so compositing
+//! produces the same results as the 8-bit renderer, just without the intermediate rounding.
+//! The transfer function is applied only on output.
+
+mod paint;
+mod render;
+
+pub(crate) use render::{render_filter_image, render_node, render_nodes};
+
+use std::io::Write;
+
+use crate::filter::PixmapF32;
+
+/// A sample format of an encoded [`HdrPixmap`].
+///
+/// All formats store four little-endian samples per pixel in the RGBA order.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum HdrFormat {
+    /// 16-bit unsigned integers.
+    #[default]
+    Rgba16,
+    /// IEEE 754 half-precision floats.
+    RgbaF16,
+    /// IEEE 754 single-precision floats.
+    RgbaF32,
+}
+
+impl HdrFormat {
+    /// Returns the number of bytes per pixel.
+    pub fn bytes_per_pixel(&self) -> usize {
+        match self {
+            HdrFormat::Rgba16 | HdrFormat::RgbaF16 => 8,
+            HdrFormat::RgbaF32 => 16,
+        }
+    }
+}
+
+/// An output transfer function.
+///
+/// Color primaries are always BT.709 (sRGB). Alpha is always linear.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum TransferFunction {
+    /// The sRGB curve, same as the 8-bit output.
+    #[default]
+    Srgb,
+    /// Linear light.
+    Linear,
+    /// SMPTE ST 2084 (PQ).
+    ///
+    /// sRGB white is mapped to [`HdrOutput::reference_white`].
+    Pq,
+    /// ARIB STD-B67 (HLG).
+    ///
+    /// sRGB white is mapped to the 75% signal level, as recommended by ITU-R BT.2408.
+    Hlg,
+}
+
+/// An [`HdrPixmap`] encoding options.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct HdrOutput {
+    /// Sample format.
+    ///
+    /// Default: [`HdrFormat::Rgba16`]
+    pub format: HdrFormat,
+
+    /// Transfer function of color channels.
+    ///
+    /// Default: [`TransferFunction::Srgb`]
+    pub transfer: TransferFunction,
+
+    /// Whether color channels are multiplied by alpha.
+    ///
+    /// Colors are multiplied after the transfer function was applied.
+    ///
+    /// Default: `false`
+    pub premultiplied: bool,
+
+    /// Luminance of sRGB white in nits. Used only by [`TransferFunction::Pq`].
+    ///
+    /// Default: 203, as recommended by ITU-R BT.2408
+    pub reference_white: f32,
+}
+
+impl Default for HdrOutput {
+    fn default() -> Self {
+        HdrOutput {
+            format: HdrFormat::default(),
+            transfer: TransferFunction::default(),
+            premultiplied: false,
+            reference_white: 203.0,
+        }
+    }
+}
+
+/// A high bit depth render target.
+///
+/// Used by [`render_hdr`](crate::render_hdr) and [`render_node_hdr`](crate::render_node_hdr).
+/// Compositing, filters and image drawing keep full `f32` precision.
+#[derive(Clone)]
+pub struct HdrPixmap(pub(crate) PixmapF32);
+
+impl HdrPixmap {
+    /// Creates a transparent pixmap.
+    ///
+    /// Returns `None` when the size is zero or too large.
+    pub fn new(width: u32, height: u32) -> Option<Self> {
+        let size = tiny_skia::IntSize::from_wh(width, height)?;
+        PixmapF32::try_new(size.width(), size.height()).map(HdrPixmap)
+    }
+
+    /// Returns the pixmap width.
+    pub fn width(&self) -> u32 {
+        self.0.width
+    }
+
+    /// Returns the pixmap height.
+    pub fn height(&self) -> u32 {
+        self.0.height
+    }
+
+    /// Returns the pixmap size.
+    pub fn size(&self) -> tiny_skia::IntSize {
+        tiny_skia::IntSize::from_wh(self.0.width, self.0.height).unwrap()
+    }
+
+    /// Fills the pixmap with a color.
+    pub fn fill(&mut self, color: tiny_skia::Color) {
+        let c = color.premultiply();
+        let c = rgb::RGBA::new(c.red(), c.green(), c.blue(), c.alpha());
+        self.0.data.fill(c);
+    }
+
+    /// Returns a premultiplied sRGB color of a pixel.
+    pub fn pixel(&self, x: u32, y: u32) -> Option<[f32; 4]> {
+        if x >= self.0.width || y >= self.0.height {
+            return None;
+        }
+
+        let c = self.0.data[self.0.index(x, y)];
+        Some([c.r, c.g, c.b, c.a])
+    }
+
+    /// Rounds the pixmap to 8 bits.
+    pub fn to_pixmap(&self) -> tiny_skia::Pixmap {
+        self.0.to_pixmap()
+    }
+
+    /// Encodes pixels row by row without padding.
+    pub fn encode(&self, output: &HdrOutput) -> Vec<u8> {
+        let mut data = Vec::with_capacity(self.0.data.len() * output.format.bytes_per_pixel());
+        // Writing into a `Vec` cannot fail.
+        self.write(output, &mut data).unwrap();
+        data
+    }
+
+    /// Writes encoded pixels row by row without padding.
+    pub fn write<W: Write>(&self, output: &HdrOutput, writer: &mut W) -> std::io::Result<()> {
+        let mut row = Vec::with_capacity(self.0.width as usize * output.format.bytes_per_pixel());
+        for pixels in self.0.data.chunks(self.0.width as usize) {
+            row.clear();
+            for p in pixels {
+                for v in encode_pixel(*p, output) {
+                    match output.format {
+                        HdrFormat::Rgba16 => {
+                            let v = (v.clamp(0.0, 1.0) * 65535.0 + 0.5) as u16;
+                            row.extend_from_slice(&v.to_le_bytes());
+                        }
+                        HdrFormat::RgbaF16 => {
+                            row.extend_from_slice(&f32_to_f16(v).to_le_bytes());
+                        }
+                        HdrFormat::RgbaF32 => {
+                            row.extend_from_slice(&v.to_le_bytes());
+                        }
+                    }
+                }
+            }
+
+            writer.write_all(&row)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for HdrPixmap {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("HdrPixmap")
+            .field("width", &self.0.width)
+            .field("height", &self.0.height)
+            .finish()
+    }
+}
+
+/// Converts a premultiplied sRGB pixel into the output color encoding.
+fn encode_pixel(p: crate::filter::RGBAF32, output: &HdrOutput) -> [f32; 4] {
+    let a = p.a.clamp(0.0, 1.0);
+    if a == 0.0 {
+        return [0.0; 4];
+    }
+
+    let mut c = [p.r, p.g, p.b].map(|v| {
+        let v = (v / a).clamp(0.0, 1.0);
+        match output.transfer {
+            TransferFunction::Srgb => v,
+            TransferFunction::Linear => srgb_to_linear(v),
+            TransferFunction::Pq => pq_oetf(srgb_to_linear(v) * output.reference_white / 10000.0),
+            TransferFunction::Hlg => hlg_oetf(srgb_to_linear(v) * hlg_inverse_oetf(0.75)),
+        }
+    });
+
+    if output.premultiplied {
+        c = c.map(|v| v * a);
+    }
+
+    [c[0], c[1], c[2], a]
+}
+
+fn srgb_to_linear(v: f32) -> f32 {
+    if v <= 0.04045 {
+        v / 12.92
+    } else {
+        ((v + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Converts a linear value, where 1 is 10000 nits, into a PQ signal.
+fn pq_oetf(v: f32) -> f32 {
+    const M1: f32 = 2610.0 / 16384.0;
+    const M2: f32 = 2523.0 / 4096.0 * 128.0;
+    const C1: f32 = 3424.0 / 4096.0;
+    const C2: f32 = 2413.0 / 4096.0 * 32.0;
+    const C3: f32 = 2392.0 / 4096.0 * 32.0;
+
+    let p = v.max(0.0).powf(M1);
+    ((C1 + C2 * p) / (1.0 + C3 * p)).powf(M2)
+}
+
+const HLG_A: f32 = 0.17883277;
+const HLG_B: f32 = 0.28466892;
+const HLG_C: f32 = 0.559_910_7;
+
+/// Converts a scene-linear value in the 0..=1 range into an HLG signal.
+fn hlg_oetf(v: f32) -> f32 {
+    let v = v.max(0.0);
+    if v <= 1.0 / 12.0 {
+        (3.0 * v).sqrt()
+    } else {
+        HLG_A * (12.0 * v - HLG_B).ln() + HLG_C
+    }
+}
+
+fn hlg_inverse_oetf(v: f32) -> f32 {
+    if v <= 0.5 {
+        v * v / 3.0
+    } else {
+        (((v - HLG_C) / HLG_A).exp() + HLG_B) / 12.0
+    }
+}
+
+/// Converts a float into a half-precision one, rounding to nearest even.
+fn f32_to_f16(v: f32) -> u16 {
+    let bits = v.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exp = ((bits >> 23) & 0xff) as i32;
+    let mantissa = bits & 0x7f_ffff;
+
+    // Infinity and NaN.
+    if exp == 0xff {
+        let nan = if mantissa != 0 { 0x200 } else { 0 };
+        return sign | 0x7c00 | nan;
+    }
+
+    let exp = exp - 127 + 15;
+    if exp >= 0x1f {
+        return sign | 0x7c00;
+    }
+
+    // Subnormal or zero.
+    if exp <= 0 {
+        if exp < -10 {
+            return sign;
+        }
+
+        let mantissa = mantissa | 0x80_0000;
+        let shift = (14 - exp) as u32;
+        let half = 1 << (shift - 1);
+        let rest = mantissa & ((1 << shift) - 1);
+        let mut v = mantissa >> shift;
+        if rest > half || (rest == half && v & 1 == 1) {
+            v += 1;
+        }
+
+        return sign | v as u16;
+    }
+
+    // Rounding can carry into the exponent, which is still correct.
+    let mut v = ((exp as u32) << 10) | (mantissa >> 13);
+    let rest = mantissa & 0x1fff;
+    if rest > 0x1000 || (rest == 0x1000 && v & 1 == 1) {
+        v += 1;
+    }
+
+    sign | v as u16
+}
@@ -0,0 +1,336 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use rgb::RGBA;
+use tiny_skia::{FilterQuality, IntRect, Point};
+
+use crate::filter::{PixmapF32, RGBAF32};
+use crate::render::Context;
+
+/// A source of premultiplied colors for device pixels.
+pub enum Shader {
+    Solid(RGBAF32),
+    Gradient(crate::gradient::GradientSampler),
+    Image(ImageShader),
+}
+
+impl Shader {
+    /// Creates a shader for a paint rendered with the specified transform.
+    pub fn new(
+        paint: &usvgr::Paint,
+        opacity: usvgr::Opacity,
+        ctx: &Context,
+        transform: tiny_skia::Transform,
+        cache: &mut crate::cache::SvgrCache,
+        pixmap_pool: &crate::cache::PixmapPool,
+    ) -> Option<Self> {
+        match paint {
+            usvgr::Paint::Color(c) => {
+                let a = opacity.get();
+                Some(Shader::Solid(RGBA::new(
+                    c.red as f32 / 255.0 * a,
+                    c.green as f32 / 255.0 * a,
+                    c.blue as f32 / 255.0 * a,
+                    a,
+                )))
+            }
+            usvgr::Paint::LinearGradient(_) | usvgr::Paint::RadialGradient(_) => {
+                crate::gradient::GradientSampler::new(paint, opacity, transform)
+                    .map(Shader::Gradient)
+            }
+            usvgr::Paint::MeshGradient(ref mg) => {
+                let (image, ts) = crate::mesh_gradient::render_mesh_pixmap_f32(mg, transform)?;
+                let shader = ImageShader::new(
+                    image,
+                    transform.pre_concat(ts),
+                    Spread::Pad,
+                    FilterQuality::Bilinear,
+                    opacity.get(),
+                )?;
+                Some(Shader::Image(shader))
+            }
+            usvgr::Paint::Pattern(ref pattern) => {
+                let (image, ts) =
+                    super::render::render_pattern(pattern, ctx, transform, cache, pixmap_pool)?;
+                let shader = ImageShader::new(
+                    image,
+                    transform.pre_concat(ts),
+                    Spread::Repeat,
+                    FilterQuality::Bicubic,
+                    opacity.get(),
+                )?;
+                Some(Shader::Image(shader))
+            }
+        }
+    }
+
+    fn color_at(&self, x: i32, y: i32) -> RGBAF32 {
+        match self {
+            Shader::Solid(c) => *c,
+            Shader::Gradient(gradient) => {
+                let [r, g, b, a] = gradient.color_at(x, y);
+                RGBA::new(r, g, b, a)
+            }
+            Shader::Image(image) => image.color_at(x, y),
+        }
+    }
+}
+
+/// How an image is extended outside its bounds.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Spread {
+    Pad,
+    Repeat,
+}
+
+pub struct ImageShader {
+    image: PixmapF32,
+    /// Maps device coordinates to the image ones.
+    ts: tiny_skia::Transform,
+    spread: Spread,
+    quality: FilterQuality,
+    opacity: f32,
+}
+
+impl ImageShader {
+    /// Creates an image shader.
+    ///
+    /// `transform` maps the image to the device coordinates.
+    pub fn new(
+        image: PixmapF32,
+        transform: tiny_skia::Transform,
+        spread: Spread,
+        mut quality: FilterQuality,
+        opacity: f32,
+    ) -> Option<Self> {
+        let ts = transform.invert()?;
+
+        // Same as in `tiny-skia`, filtering is not used for translated images.
+        // The check is done on the inverted transform, since it can be exact
+        // even when the original one isn't.
+        if ts.is_translate() || ts.is_identity() {
+            quality = FilterQuality::Nearest;
+        }
+
+        Some(ImageShader {
+            image,
+            ts,
+            spread,
+            quality,
+            opacity,
+        })
+    }
+
+    fn color_at(&self, x: i32, y: i32) -> RGBAF32 {
+        let mut p = Point::from_xy(x as f32 + 0.5, y as f32 + 0.5);
+        self.ts.map_point(&mut p);
+        sample(&self.image, p, self.spread, self.quality) * self.opacity
+    }
+}
+
+/// Samples an image at a point in the image coordinates.
+fn sample(image: &PixmapF32, p: Point, spread: Spread, quality: FilterQuality) -> RGBAF32 {
+    match quality {
+        FilterQuality::Nearest => pixel_at(image, p.x.floor() as i32, p.y.floor() as i32, spread),
+        FilterQuality::Bilinear => {
+            let (x, y) = (p.x - 0.5, p.y - 0.5);
+            let (x0, y0) = (x.floor(), y.floor());
+            let (fx, fy) = (x - x0, y - y0);
+            let (x0, y0) = (x0 as i32, y0 as i32);
+
+            let top = pixel_at(image, x0, y0, spread) * (1.0 - fx)
+                + pixel_at(image, x0 + 1, y0, spread) * fx;
+            let bottom = pixel_at(image, x0, y0 + 1, spread) * (1.0 - fx)
+                + pixel_at(image, x0 + 1, y0 + 1, spread) * fx;
+            top * (1.0 - fy) + bottom * fy
+        }
+        FilterQuality::Bicubic => {
+            let (x, y) = (p.x - 0.5, p.y - 0.5);
+            let (x0, y0) = (x.floor(), y.floor());
+            let (fx, fy) = (x - x0, y - y0);
+            let (x0, y0) = (x0 as i32, y0 as i32);
+
+            let wx = [1.0 + fx, fx, 1.0 - fx, 2.0 - fx].map(mitchell);
+            let wy = [1.0 + fy, fy, 1.0 - fy, 2.0 - fy].map(mitchell);
+
+            let mut c = RGBAF32::default();
+            for (j, wy) in wy.iter().enumerate() {
+                for (i, wx) in wx.iter().enumerate() {
+                    let p = pixel_at(image, x0 - 1 + i as i32, y0 - 1 + j as i32, spread);
+                    c += p * (wx * wy);
+                }
+            }
+
+            // Bicubic filtering can overshoot.
+            let a = c.a.clamp(0.0, 1.0);
+            RGBA::new(c.r.clamp(0.0, a), c.g.clamp(0.0, a), c.b.clamp(0.0, a), a)
+        }
+    }
+}
+
+/// The Mitchell-Netravali filter with `B = C = 1/3`, same as in `tiny-skia`.
+fn mitchell(t: f32) -> f32 {
+    let t = t.abs();
+    if t < 1.0 {
+        (7.0 * t * t * t - 12.0 * t * t + 16.0 / 3.0) / 6.0
+    } else if t < 2.0 {
+        (-7.0 / 3.0 * t * t * t + 12.0 * t * t - 20.0 * t + 32.0 / 3.0) / 6.0
+    } else {
+        0.0
+    }
+}
+
+fn pixel_at(image: &PixmapF32, x: i32, y: i32, spread: Spread) -> RGBAF32 {
+    let (w, h) = (image.width as i32, image.height as i32);
+    let (x, y) = match spread {
+        Spread::Pad => (x.clamp(0, w - 1), y.clamp(0, h - 1)),
+        Spread::Repeat => (x.rem_euclid(w), y.rem_euclid(h)),
+    };
+
+    image.data[image.index(x as u32, y as u32)]
+}
+
+/// Fills a path with a shader using the source-over blending.
+pub fn fill_path(
+    canvas: &mut PixmapF32,
+    path: &tiny_skia::Path,
+    rule: tiny_skia::FillRule,
+    anti_alias: bool,
+    transform: tiny_skia::Transform,
+    shader: &Shader,
+) -> Option<()> {
+    let rect = coverage_rect(canvas, path.bounds(), transform)?;
+
+    let mut mask = tiny_skia::Mask::new(rect.width(), rect.height())?;
+    mask.fill_path(path, rule, anti_alias, to_rect_origin(rect, transform));
+
+    draw_coverage(canvas, rect, mask.data().iter().copied(), shader);
+    Some(())
+}
+
+/// Strokes a path with a shader using the source-over blending.
+pub fn stroke_path(
+    canvas: &mut PixmapF32,
+    path: &tiny_skia::Path,
+    stroke: &tiny_skia::Stroke,
+    anti_alias: bool,
+    transform: tiny_skia::Transform,
+    shader: &Shader,
+) -> Option<()> {
+    let res_scale = tiny_skia::PathStroker::compute_resolution_scale(&transform);
+    let outline = path.stroke(stroke, res_scale)?;
+    let rect = coverage_rect(canvas, outline.bounds(), transform)?;
+
+    // Thin strokes are drawn as modulated hairlines by `tiny-skia`,
+    // which `Mask` doesn't support. So we render the coverage as an alpha channel instead.
+    let mut pixmap = tiny_skia::Pixmap::new(rect.width(), rect.height())?;
+    let mut paint = tiny_skia::Paint::default();
+    paint.anti_alias = anti_alias;
+    pixmap.stroke_path(path, &paint, stroke, to_rect_origin(rect, transform), None);
+
+    let alpha = pixmap.data().chunks_exact(4).map(|p| p[3]);
+    draw_coverage(canvas, rect, alpha, shader);
+    Some(())
+}
+
+/// Returns the part of the canvas that the transformed bounds touch.
+fn coverage_rect(
+    canvas: &PixmapF32,
+    bounds: tiny_skia::Rect,
+    transform: tiny_skia::Transform,
+) -> Option<IntRect> {
+    let bounds = bounds.transform(transform)?;
+    // Anti-aliasing can touch an extra pixel on each side.
+    let rect = IntRect::from_ltrb(
+        bounds.left().floor() as i32 - 1,
+        bounds.top().floor() as i32 - 1,
+        bounds.right().ceil() as i32 + 1,
+        bounds.bottom().ceil() as i32 + 1,
+    )?;
+
+    rect.intersect(&IntRect::from_xywh(0, 0, canvas.width, canvas.height)?)
+}
+
+fn to_rect_origin(rect: IntRect, transform: tiny_skia::Transform) -> tiny_skia::Transform {
+    tiny_skia::Transform::from_translate(-rect.x() as f32, -rect.y() as f32).pre_concat(transform)
+}
+
+/// Draws a shader over the canvas rect using the 8-bit coverage of each pixel.
+fn draw_coverage(
+    canvas: &mut PixmapF32,
+    rect: IntRect,
+    coverage: impl Iterator<Item = u8>,
+    shader: &Shader,
+) {
+    let width = rect.width() as usize;
+    for (i, c) in coverage.enumerate() {
+        if c == 0 {
+            continue;
+        }
+
+        let x = rect.x() + (i % width) as i32;
+        let y = rect.y() + (i / width) as i32;
+        let s = shader.color_at(x, y) * (c as f32 / 255.0);
+        let idx = canvas.index(x as u32, y as u32);
+        let d = &mut canvas.data[idx];
+        *d = s + *d * (1.0 - s.a);
+    }
+}
+
+/// Multiplies pixels by a canvas-sized coverage.
+pub fn apply_coverage(canvas: &mut PixmapF32, mask: &tiny_skia::Mask) {
+    for (p, &c) in canvas.data.iter_mut().zip(mask.data()) {
+        *p *= c as f32 / 255.0;
+    }
+}
+
+/// Returns a canvas-sized coverage of a rectangle.
+pub fn rect_coverage(
+    canvas: &PixmapF32,
+    rect: tiny_skia::Rect,
+    transform: tiny_skia::Transform,
+) -> Option<tiny_skia::Mask> {
+    let mut mask = tiny_skia::Mask::new(canvas.width, canvas.height)?;
+    mask.fill_path(
+        &tiny_skia::PathBuilder::from_rect(rect),
+        tiny_skia::FillRule::Winding,
+        true,
+        transform,
+    );
+
+    Some(mask)
+}
+
+/// Draws a layer onto the canvas with an integer offset.
+pub fn draw_layer(
+    canvas: &mut PixmapF32,
+    layer: &PixmapF32,
+    dx: i32,
+    dy: i32,
+    opacity: f32,
+    blend_mode: usvgr::BlendMode,
+) {
+    for y in 0..layer.height as i32 {
+        let cy = y + dy;
+        if cy < 0 || cy >= canvas.height as i32 {
+            continue;
+        }
+
+        for x in 0..layer.width as i32 {
+            let cx = x + dx;
+            if cx < 0 || cx >= canvas.width as i32 {
+                continue;
+            }
+
+            let s = layer.data[layer.index(x as u32, y as u32)] * opacity;
+            if s.a == 0.0 {
+                continue;
+            }
+
+            let idx = canvas.index(cx as u32, cy as u32);
+            let d = &mut canvas.data[idx];
+            *d = crate::filter::blend(blend_mode, s, *d);
+        }
+    }
+}
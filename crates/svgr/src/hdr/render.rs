@@ -0,0 +1,565 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::sync::Arc;
+
+use tiny_skia::{IntRect, IntSize};
+
+use super::paint::{self, ImageShader, Shader, Spread};
+use crate::cache::{PixmapPool, SvgrCache};
+use crate::filter::PixmapF32;
+use crate::render::{Background, Context};
+
+pub fn render_nodes(
+    parent: &usvgr::Group,
+    ctx: &Context,
+    transform: tiny_skia::Transform,
+    canvas: &mut PixmapF32,
+    cache: &mut SvgrCache,
+    pixmap_pool: &PixmapPool,
+) {
+    for node in parent.children() {
+        render_node(node, ctx, transform, canvas, cache, pixmap_pool);
+    }
+}
+
+pub fn render_node(
+    node: &usvgr::Node,
+    ctx: &Context,
+    transform: tiny_skia::Transform,
+    canvas: &mut PixmapF32,
+    cache: &mut SvgrCache,
+    pixmap_pool: &PixmapPool,
+) {
+    match node {
+        usvgr::Node::Group(ref group) => {
+            render_group(group, ctx, transform, canvas, cache, pixmap_pool);
+        }
+        usvgr::Node::Path(ref path) => {
+            render_path(path, ctx, transform, canvas, cache, pixmap_pool);
+        }
+        usvgr::Node::Image(ref image) => {
+            if image.visibility() == usvgr::Visibility::Visible {
+                render_image(
+                    image.kind(),
+                    image.view_box(),
+                    ctx,
+                    image.rendering_mode(),
+                    transform,
+                    canvas,
+                    pixmap_pool,
+                );
+            }
+        }
+        usvgr::Node::Text(ref text) => {
+            render_group(text.flattened(), ctx, transform, canvas, cache, pixmap_pool);
+        }
+    }
+}
+
+/// Renders a group.
+///
+/// Unlike the 8-bit renderer, isolated groups are always rendered into a new layer
+/// and layers are not cached, since the cache stores 8-bit pixmaps only.
+fn render_group(
+    group: &usvgr::Group,
+    ctx: &Context,
+    transform: tiny_skia::Transform,
+    canvas: &mut PixmapF32,
+    cache: &mut SvgrCache,
+    pixmap_pool: &PixmapPool,
+) -> Option<()> {
+    let final_transform = transform.pre_concat(group.transform());
+
+    if !group.should_isolate() {
+        render_nodes(group, ctx, final_transform, canvas, cache, pixmap_pool);
+        return Some(());
+    }
+
+    let final_bbox = crate::geom::layer_bbox(group, final_transform)?;
+    let mut ibbox = if group.filters().is_empty() {
+        IntRect::from_xywh(
+            final_bbox.x().floor() as i32 - 2,
+            final_bbox.y().floor() as i32 - 2,
+            final_bbox.width().ceil() as u32 + 4,
+            final_bbox.height().ceil() as u32 + 4,
+        )?
+    } else {
+        final_bbox.to_int_rect()
+    };
+    ibbox = crate::geom::fit_to_rect(ibbox, ctx.max_bbox)?;
+
+    let ts = tiny_skia::Transform::from_translate(-(ibbox.x() as f32), -(ibbox.y() as f32))
+        .pre_concat(final_transform);
+
+    // See `render_isolated_group_with_effects` for details.
+    let background = match ctx.background {
+        Some(Background::F32(ref ancestors)) if crate::render::needs_background(group) => {
+            let mut background = PixmapF32::new(ibbox.width(), ibbox.height());
+            background.draw_over(ancestors, -ibbox.x(), -ibbox.y());
+            background.draw_over(canvas, -ibbox.x(), -ibbox.y());
+            Some(Arc::new(background))
+        }
+        _ => None,
+    };
+
+    let children_background = if group.enable_background() {
+        let empty = PixmapF32::new(ibbox.width(), ibbox.height());
+        Some(Background::F32(Arc::new(empty)))
+    } else if group.has_background_users() {
+        background.clone().map(Background::F32)
+    } else {
+        None
+    };
+
+    let mut layer = PixmapF32::new(ibbox.width(), ibbox.height());
+    render_nodes(
+        group,
+        &ctx.with_background(children_background),
+        ts,
+        &mut layer,
+        cache,
+        pixmap_pool,
+    );
+
+    for filter in group.filters() {
+        let fill_paint = if filter.has_input(&usvgr::filter::Input::FillPaint) {
+            render_paint_input(
+                group.filter_fill(),
+                filter,
+                ctx,
+                ts,
+                &layer,
+                cache,
+                pixmap_pool,
+            )
+        } else {
+            None
+        };
+
+        let stroke_paint = if filter.has_input(&usvgr::filter::Input::StrokePaint) {
+            render_paint_input(
+                group.filter_stroke(),
+                filter,
+                ctx,
+                ts,
+                &layer,
+                cache,
+                pixmap_pool,
+            )
+        } else {
+            None
+        };
+
+        crate::filter::apply_f32(
+            filter,
+            ctx,
+            ts,
+            &mut layer,
+            background.as_deref(),
+            fill_paint,
+            stroke_paint,
+            cache,
+            pixmap_pool,
+        );
+    }
+
+    if let Some(clip_path) = group.clip_path() {
+        let mask = crate::clip::mask(clip_path, ctx, ts, ibbox.size(), cache, pixmap_pool);
+        paint::apply_coverage(&mut layer, &mask);
+    }
+
+    if let Some(mask) = group.mask() {
+        apply_mask(mask, ctx, ts, &mut layer, cache, pixmap_pool);
+    }
+
+    paint::draw_layer(
+        canvas,
+        &layer,
+        ibbox.x(),
+        ibbox.y(),
+        group.opacity().get(),
+        group.blend_mode(),
+    );
+
+    Some(())
+}
+
+// `FillPaint` and `StrokePaint` are an infinite paint, so we fill the whole filter region.
+fn render_paint_input(
+    paint: Option<&usvgr::Paint>,
+    filter: &usvgr::filter::Filter,
+    ctx: &Context,
+    ts: tiny_skia::Transform,
+    source: &PixmapF32,
+    cache: &mut SvgrCache,
+    pixmap_pool: &PixmapPool,
+) -> Option<PixmapF32> {
+    let shader = Shader::new(paint?, usvgr::Opacity::ONE, ctx, ts, cache, pixmap_pool)?;
+    let mut image = PixmapF32::new(source.width, source.height);
+    paint::fill_path(
+        &mut image,
+        &tiny_skia::PathBuilder::from_rect(filter.rect().to_rect()),
+        tiny_skia::FillRule::Winding,
+        true,
+        ts,
+        &shader,
+    );
+
+    Some(image)
+}
+
+fn apply_mask(
+    mask: &usvgr::Mask,
+    ctx: &Context,
+    transform: tiny_skia::Transform,
+    layer: &mut PixmapF32,
+    cache: &mut SvgrCache,
+    pixmap_pool: &PixmapPool,
+) {
+    if mask.root().children().is_empty() {
+        layer.data.fill(Default::default());
+        return;
+    }
+
+    let mut content = PixmapF32::new(layer.width, layer.height);
+    render_nodes(
+        mask.root(),
+        &ctx.with_background(None),
+        transform,
+        &mut content,
+        cache,
+        pixmap_pool,
+    );
+
+    // Mask has to be clipped by mask.region
+    if let Some(region) = paint::rect_coverage(&content, mask.rect().to_rect(), transform) {
+        paint::apply_coverage(&mut content, &region);
+    }
+
+    if let Some(mask) = mask.mask() {
+        apply_mask(mask, ctx, transform, layer, cache, pixmap_pool);
+    }
+
+    for (p, m) in layer.data.iter_mut().zip(&content.data) {
+        let coverage = match mask.kind() {
+            // Luminance of premultiplied values is the same as
+            // luminance of demultiplied values multiplied by alpha.
+            usvgr::MaskType::Luminance => 0.2126 * m.r + 0.7152 * m.g + 0.0722 * m.b,
+            usvgr::MaskType::Alpha => m.a,
+        };
+
+        *p *= coverage.clamp(0.0, 1.0);
+    }
+}
+
+fn render_path(
+    path: &usvgr::Path,
+    ctx: &Context,
+    transform: tiny_skia::Transform,
+    canvas: &mut PixmapF32,
+    cache: &mut SvgrCache,
+    pixmap_pool: &PixmapPool,
+) {
+    if path.visibility() != usvgr::Visibility::Visible {
+        return;
+    }
+
+    if path.paint_order() == usvgr::PaintOrder::FillAndStroke {
+        fill_path(path, ctx, transform, canvas, cache, pixmap_pool);
+        stroke_path(path, ctx, transform, canvas, cache, pixmap_pool);
+    } else {
+        stroke_path(path, ctx, transform, canvas, cache, pixmap_pool);
+        fill_path(path, ctx, transform, canvas, cache, pixmap_pool);
+    }
+}
+
+fn fill_path(
+    path: &usvgr::Path,
+    ctx: &Context,
+    transform: tiny_skia::Transform,
+    canvas: &mut PixmapF32,
+    cache: &mut SvgrCache,
+    pixmap_pool: &PixmapPool,
+) -> Option<()> {
+    let fill = path.fill()?;
+
+    // Horizontal and vertical lines cannot be filled. Skip.
+    if path.data().bounds().width() == 0.0 || path.data().bounds().height() == 0.0 {
+        return None;
+    }
+
+    let rule = match fill.rule() {
+        usvgr::FillRule::NonZero => tiny_skia::FillRule::Winding,
+        usvgr::FillRule::EvenOdd => tiny_skia::FillRule::EvenOdd,
+    };
+
+    let shader = Shader::new(
+        fill.paint(),
+        fill.opacity(),
+        ctx,
+        transform,
+        cache,
+        pixmap_pool,
+    )?;
+
+    paint::fill_path(
+        canvas,
+        path.data(),
+        rule,
+        path.rendering_mode().use_shape_antialiasing(),
+        transform,
+        &shader,
+    )
+}
+
+fn stroke_path(
+    path: &usvgr::Path,
+    ctx: &Context,
+    transform: tiny_skia::Transform,
+    canvas: &mut PixmapF32,
+    cache: &mut SvgrCache,
+    pixmap_pool: &PixmapPool,
+) -> Option<()> {
+    let stroke = path.stroke()?;
+
+    // The paint is always mapped with the path transform,
+    // even when the stroke itself is built in the canvas coordinates.
+    let shader = Shader::new(
+        stroke.paint(),
+        stroke.opacity(),
+        ctx,
+        transform,
+        cache,
+        pixmap_pool,
+    )?;
+
+    let (data, transform) = if path.vector_effect() == usvgr::VectorEffect::NonScalingStroke {
+        let data = path.data().clone().transform(transform)?;
+        (data, tiny_skia::Transform::default())
+    } else {
+        (path.data().clone(), transform)
+    };
+
+    paint::stroke_path(
+        canvas,
+        &data,
+        &stroke.to_tiny_skia(),
+        path.rendering_mode().use_shape_antialiasing(),
+        transform,
+        &shader,
+    )
+}
+
+fn render_image(
+    kind: &usvgr::ImageKind,
+    view_box: usvgr::ViewBox,
+    ctx: &Context,
+    rendering_mode: usvgr::ImageRendering,
+    transform: tiny_skia::Transform,
+    canvas: &mut PixmapF32,
+    pixmap_pool: &PixmapPool,
+) {
+    match kind {
+        usvgr::ImageKind::SVG { ref tree, .. } => {
+            render_vector(tree, &view_box, ctx, transform, canvas, pixmap_pool);
+        }
+        usvgr::ImageKind::DATA(ref data) => {
+            draw_raster(data, &view_box, rendering_mode, transform, canvas);
+        }
+    }
+}
+
+fn render_vector(
+    tree: &usvgr::Tree,
+    view_box: &usvgr::ViewBox,
+    ctx: &Context,
+    transform: tiny_skia::Transform,
+    canvas: &mut PixmapF32,
+    pixmap_pool: &PixmapPool,
+) -> Option<()> {
+    let img_size = tree.size().to_int_size();
+    let (ts, clip) = crate::geom::view_box_to_transform_with_clip(view_box, img_size);
+
+    let mut ctx = Context::new_from_size(IntSize::from_wh(canvas.width, canvas.height)?)
+        .with_gradient_dithering(ctx.gradient_dithering);
+    ctx.hdr = true;
+
+    let mut layer = PixmapF32::new(canvas.width, canvas.height);
+    let root_transform = transform
+        .pre_concat(ts)
+        .pre_concat(tree.view_box().to_transform(tree.size()));
+    render_nodes(
+        tree.root(),
+        &ctx,
+        root_transform,
+        &mut layer,
+        &mut SvgrCache::none(),
+        pixmap_pool,
+    );
+
+    if let Some(mask) =
+        clip.and_then(|clip| paint::rect_coverage(&layer, clip.to_rect(), transform))
+    {
+        paint::apply_coverage(&mut layer, &mask);
+    }
+
+    canvas.draw_over(&layer, 0, 0);
+
+    Some(())
+}
+
+fn draw_raster(
+    img: &Arc<usvgr::PreloadedImageData>,
+    view_box: &usvgr::ViewBox,
+    rendering_mode: usvgr::ImageRendering,
+    transform: tiny_skia::Transform,
+    canvas: &mut PixmapF32,
+) -> Option<()> {
+    let img_size = IntSize::from_wh(img.width, img.height)?;
+    let rect = crate::image::image_rect(view_box, img_size);
+
+    let image_ts = tiny_skia::Transform::from_row(
+        rect.width() / img.width as f32,
+        0.0,
+        0.0,
+        rect.height() / img.height as f32,
+        rect.x(),
+        rect.y(),
+    );
+
+    let mut quality = tiny_skia::FilterQuality::Bicubic;
+    if rendering_mode == usvgr::ImageRendering::OptimizeSpeed {
+        quality = tiny_skia::FilterQuality::Nearest;
+    }
+
+    let mut draw_rect = rect.to_rect();
+    if view_box.aspect.slice {
+        draw_rect = draw_rect.intersect(&view_box.rect.to_rect())?;
+    }
+
+    let shader = Shader::Image(ImageShader::new(
+        PixmapF32::from_rgba8(img.width, img.height, &img.data),
+        transform.pre_concat(image_ts),
+        Spread::Pad,
+        quality,
+        1.0,
+    )?);
+
+    paint::fill_path(
+        canvas,
+        &tiny_skia::PathBuilder::from_rect(draw_rect),
+        tiny_skia::FillRule::Winding,
+        true,
+        transform,
+        &shader,
+    )
+}
+
+/// Renders a pattern tile.
+///
+/// Returns the tile and its transform in the user space.
+pub fn render_pattern(
+    pattern: &usvgr::Pattern,
+    ctx: &Context,
+    transform: tiny_skia::Transform,
+    cache: &mut SvgrCache,
+    pixmap_pool: &PixmapPool,
+) -> Option<(PixmapF32, tiny_skia::Transform)> {
+    let (sx, sy) = transform.pre_concat(pattern.transform()).get_scale();
+
+    let rect = pattern.rect();
+    let img_size = IntSize::from_wh(
+        (rect.width() * sx).round() as u32,
+        (rect.height() * sy).round() as u32,
+    )?;
+
+    let mut tile_ts = tiny_skia::Transform::from_scale(sx, sy);
+    if let Some(vbox) = pattern.view_box() {
+        tile_ts = tile_ts.pre_concat(vbox.to_transform(rect.size()));
+    }
+
+    let mut tile = PixmapF32::new(img_size.width(), img_size.height());
+    render_nodes(
+        pattern.root(),
+        &ctx.with_background(None),
+        tile_ts,
+        &mut tile,
+        cache,
+        pixmap_pool,
+    );
+
+    let ts = pattern
+        .transform()
+        .pre_translate(rect.x(), rect.y())
+        .pre_scale(1.0 / sx, 1.0 / sy);
+
+    Some((tile, ts))
+}
+
+/// Renders an `feImage` into an image of the filter region size.
+pub fn render_filter_image(
+    fe: &usvgr::filter::Image,
+    ctx: &Context,
+    region: IntRect,
+    subregion: IntRect,
+    ts: usvgr::Transform,
+    cache: &mut SvgrCache,
+    pixmap_pool: &PixmapPool,
+) -> PixmapF32 {
+    let mut image = PixmapF32::new(region.width(), region.height());
+
+    match fe.data() {
+        usvgr::filter::ImageKind::Image(ref kind) => {
+            let dx = (subregion.x() - region.x()) as f32;
+            let dy = (subregion.y() - region.y()) as f32;
+            let transform = tiny_skia::Transform::from_translate(dx, dy);
+
+            let view_box = usvgr::ViewBox {
+                rect: subregion
+                    .translate_to(0, 0)
+                    .unwrap()
+                    .to_rect()
+                    .to_non_zero_rect()
+                    .unwrap(),
+                aspect: fe.aspect(),
+            };
+
+            render_image(
+                kind,
+                view_box,
+                ctx,
+                fe.rendering_mode(),
+                transform,
+                &mut image,
+                pixmap_pool,
+            );
+        }
+        usvgr::filter::ImageKind::Use(ref node) => {
+            let (sx, sy) = ts.get_scale();
+
+            let transform = tiny_skia::Transform::from_row(
+                sx,
+                0.0,
+                0.0,
+                sy,
+                subregion.x() as f32,
+                subregion.y() as f32,
+            );
+
+            let ctx = Context {
+                max_bbox: IntRect::from_xywh(0, 0, region.width(), region.height()).unwrap(),
+                gradient_dithering: ctx.gradient_dithering,
+                custom_filters: ctx.custom_filters.clone(),
+                filter_precision: ctx.filter_precision,
+                background: None,
+                hdr: true,
+            };
+
+            render_nodes(node, &ctx, transform, &mut image, cache, pixmap_pool);
+        }
+    }
+
+    image
+}
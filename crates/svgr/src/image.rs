@@ -8,6 +8,7 @@ use std::sync::Arc;
 
 pub fn render(
     image: &usvgr::Image,
+    ctx: &crate::render::Context,
     transform: tiny_skia::Transform,
     pixmap: &mut tiny_skia::PixmapMut,
     cache: &mut crate::cache::SvgrCache,
@@ -20,6 +21,7 @@ pub fn render(
     render_inner(
         image.kind(),
         image.view_box(),
+        ctx,
         transform,
         image.rendering_mode(),
         pixmap,
@@ -31,6 +33,7 @@ pub fn render(
 pub fn render_inner(
     image_kind: &usvgr::ImageKind,
     view_box: usvgr::ViewBox,
+    ctx: &crate::render::Context,
     transform: tiny_skia::Transform,
     #[allow(unused_variables)] rendering_mode: usvgr::ImageRendering,
     pixmap: &mut tiny_skia::PixmapMut,
@@ -46,6 +49,7 @@ pub fn render_inner(
                 tree,
                 original_href,
                 &view_box,
+                ctx,
                 transform,
                 pixmap,
                 cache,
@@ -62,6 +66,7 @@ fn render_vector(
     tree: &usvgr::Tree,
     original_href: &str,
     view_box: &usvgr::ViewBox,
+    ctx: &crate::render::Context,
     transform: tiny_skia::Transform,
     pixmap: &mut tiny_skia::PixmapMut,
     cache: &mut crate::cache::SvgrCache,
@@ -78,7 +83,8 @@ fn render_vector(
 
             let source_transform = transform;
             let transform = transform.pre_concat(ts);
-            let ctx = crate::render::Context::new_from_pixmap(&sub_pixmap);
+            let ctx = crate::render::Context::new_from_pixmap(&sub_pixmap)
+                .with_gradient_dithering(ctx.gradient_dithering);
 
             let pixmap_mut = &mut sub_pixmap.as_mut();
             crate::render(
@@ -113,7 +119,10 @@ fn render_vector(
 }
 
 /// Calculates an image rect depending on the provided view box.
-fn image_rect(view_box: &usvgr::ViewBox, img_size: tiny_skia::IntSize) -> tiny_skia::NonZeroRect {
+pub fn image_rect(
+    view_box: &usvgr::ViewBox,
+    img_size: tiny_skia::IntSize,
+) -> tiny_skia::NonZeroRect {
     let new_size = crate::geom::fit_view_box(img_size.to_size(), view_box);
     let (x, y) = usvgr::utils::aligned_pos(
         view_box.aspect.align,
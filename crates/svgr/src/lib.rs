@@ -22,12 +22,24 @@ mod cache;
 mod clip;
 mod filter;
 mod geom;
+mod gradient;
+mod hdr;
 mod image;
 mod mask;
+mod mesh_gradient;
+mod options;
+mod output;
 mod path;
 mod render;
 
 pub use cache::*;
+pub use filter::{CustomFilter, CustomFilters, FilterPrecision};
+pub use gradient::GradientDithering;
+pub use hdr::{HdrFormat, HdrOutput, HdrPixmap, TransferFunction};
+pub use options::{
+    render_to_pixmap, trim_pixmap, ContextStrategy, ExportArea, FitTo, RenderError, RenderOptions,
+};
+pub use output::{write_raw, PixelBuffer, PixelFormat, Y4mChroma, Y4mWriter};
 pub use render::Context;
 
 /// Renders a tree onto the pixmap.
@@ -74,3 +86,104 @@ pub fn render_node(
     render::render_node(node, ctx, transform, pixmap, cache, pixmap_pool);
     Some(())
 }
+
+/// Renders a tree into a caller-owned buffer.
+///
+/// Works like [`render`], but the buffer can have any stride and [`PixelFormat`].
+/// The buffer content is used as a background and is converted back
+/// to the buffer format row by row after rendering.
+///
+/// `ctx` can be created by [`Context::new_from_size`] using [`PixelBuffer::size`].
+///
+/// Returns `None` when a temporary pixmap cannot be allocated.
+pub fn render_to_buffer(
+    tree: &usvgr::Tree,
+    transform: tiny_skia::Transform,
+    buffer: &mut PixelBuffer,
+    cache: &mut cache::SvgrCache,
+    pixmap_pool: &cache::PixmapPool,
+    ctx: &render::Context,
+) -> Option<()> {
+    buffer.with_pixmap(pixmap_pool, |pixmap| {
+        render(tree, transform, pixmap, cache, pixmap_pool, ctx)
+    })
+}
+
+/// Renders a node into a caller-owned buffer.
+///
+/// Works like [`render_node`], but the buffer can have any stride and [`PixelFormat`].
+///
+/// Returns `None` when `node` has a zero size or a temporary pixmap cannot be allocated.
+pub fn render_node_to_buffer(
+    node: &usvgr::Node,
+    transform: tiny_skia::Transform,
+    buffer: &mut PixelBuffer,
+    cache: &mut cache::SvgrCache,
+    pixmap_pool: &cache::PixmapPool,
+    ctx: &render::Context,
+) -> Option<()> {
+    // Do not convert the buffer when there is nothing to render.
+    node.abs_layer_bounding_box()?;
+
+    buffer.with_pixmap(pixmap_pool, |pixmap| {
+        render_node(node, transform, pixmap, cache, pixmap_pool, ctx)
+    })?
+}
+
+/// Renders a tree onto a high bit depth pixmap.
+///
+/// Works like [`render`], but compositing, filters and image drawing
+/// are done in floating point. Use [`HdrPixmap::encode`] to get 16-bit or float samples
+/// with the required transfer function.
+///
+/// Groups are not cached, while filter results and clip paths still use `cache`.
+pub fn render_hdr(
+    tree: &usvgr::Tree,
+    transform: tiny_skia::Transform,
+    pixmap: &mut HdrPixmap,
+    cache: &mut cache::SvgrCache,
+    pixmap_pool: &cache::PixmapPool,
+    ctx: &render::Context,
+) {
+    let ctx = render::Context {
+        hdr: true,
+        ..ctx.clone()
+    };
+
+    let ts = tree.view_box().to_transform(tree.size());
+    let root_transform = transform.pre_concat(ts);
+
+    hdr::render_nodes(
+        tree.root(),
+        &ctx,
+        root_transform,
+        &mut pixmap.0,
+        cache,
+        pixmap_pool,
+    );
+}
+
+/// Renders a node onto a high bit depth pixmap.
+///
+/// Works like [`render_node`], but uses the same pipeline as [`render_hdr`].
+///
+/// Returns `None` when `node` has a zero size.
+pub fn render_node_hdr(
+    node: &usvgr::Node,
+    mut transform: tiny_skia::Transform,
+    pixmap: &mut HdrPixmap,
+    cache: &mut cache::SvgrCache,
+    pixmap_pool: &cache::PixmapPool,
+    ctx: &render::Context,
+) -> Option<()> {
+    let ctx = render::Context {
+        hdr: true,
+        ..ctx.clone()
+    };
+
+    let bbox = node.abs_layer_bounding_box()?;
+    transform = transform.pre_translate(-bbox.x(), -bbox.y());
+
+    hdr::render_node(node, &ctx, transform, &mut pixmap.0, cache, pixmap_pool);
+    Some(())
+}
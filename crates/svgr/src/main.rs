@@ -5,12 +5,55 @@
 #![allow(clippy::uninlined_format_args)]
 
 use std::path;
-use svgr::PixmapPool;
+use svgr::FitTo;
 
 fn main() {
     if let Err(e) = process() {
         eprintln!("Error: {}.", e);
-        std::process::exit(1);
+        std::process::exit(e.exit_code());
+    }
+}
+
+/// A CLI error.
+///
+/// Each kind has its own exit code, so callers can tell a broken input
+/// from a rendering failure.
+enum Error {
+    /// Invalid arguments or an I/O failure.
+    Other(String),
+    /// The input is not a valid SVG.
+    Parse(String),
+    /// The SVG was parsed, but couldn't be rendered.
+    Render(String),
+}
+
+impl Error {
+    fn exit_code(&self) -> i32 {
+        match self {
+            Error::Other(_) => 1,
+            Error::Parse(_) => 2,
+            Error::Render(_) => 3,
+        }
+    }
+}
+
+impl From<String> for Error {
+    fn from(e: String) -> Self {
+        Error::Other(e)
+    }
+}
+
+impl From<&str> for Error {
+    fn from(e: &str) -> Self {
+        Error::Other(e.to_string())
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::Other(e) | Error::Parse(e) | Error::Render(e) => write!(f, "{}", e),
+        }
     }
 }
 
@@ -22,18 +65,18 @@ where
     let result = f();
     if perf {
         let elapsed = now.elapsed().as_micros() as f64 / 1000.0;
-        println!("{}: {:.2}ms", name, elapsed);
+        eprintln!("{}: {:.2}ms", name, elapsed);
     }
 
     result
 }
 
-fn process() -> Result<(), String> {
-    let mut args = match parse_args() {
+fn process() -> Result<(), Error> {
+    let args = match parse_args() {
         Ok(args) => args,
         Err(e) => {
-            println!("{}", HELP);
-            return Err(e);
+            eprintln!("{}", HELP);
+            return Err(Error::Other(e));
         }
     };
 
@@ -46,8 +89,68 @@ fn process() -> Result<(), String> {
         }
     }
 
-    let mut svg_data = timed(args.perf, "Reading", || -> Result<Vec<u8>, &str> {
-        if let InputFrom::File(ref file) = args.in_svg {
+    let fonts = Fonts::default();
+
+    if let Some(ref batch) = args.batch {
+        return process_batch(&args, batch, &fonts);
+    }
+
+    let tree = load_tree(&args, &args.in_svg, &fonts, args.perf)?;
+
+    if args.query_all {
+        return query_all(&tree).map_err(Error::Other);
+    }
+
+    let mut cache = svgr::SvgrCache::new(args.cache_size);
+    let pixmap_pool = new_pixmap_pool(&args);
+
+    // Render.
+    let img =
+        render_svg(&args, &tree, &mut cache, &pixmap_pool, args.perf).map_err(Error::Render)?;
+
+    if args.perf {
+        print_cache_stats(cache.stats());
+    }
+
+    // Unwrap is safe, because `out_png` is required without `--query-all`.
+    save_output(&args, &img, args.out_png.as_ref().unwrap(), args.perf)
+}
+
+fn encode_output(args: &Args, img: &tiny_skia::Pixmap) -> Result<Vec<u8>, String> {
+    match args.format {
+        OutputFormat::Png => img.encode_png().map_err(|e| e.to_string()),
+        OutputFormat::Raw(format) => {
+            let mut buf = Vec::new();
+            svgr::write_raw(img, format, &mut buf).map_err(|e| e.to_string())?;
+            Ok(buf)
+        }
+        OutputFormat::Y4m(chroma) => {
+            let size = tiny_skia::IntSize::from_wh(img.width(), img.height()).unwrap();
+            let mut writer = svgr::Y4mWriter::new(Vec::new(), size, args.fps, chroma)
+                .map_err(|e| e.to_string())?;
+            writer.write_frame(img).map_err(|e| e.to_string())?;
+            writer.finish().map_err(|e| e.to_string())
+        }
+    }
+}
+
+/// A font database shared between all inputs.
+///
+/// Fonts are loaded only once, when the first input with text is parsed.
+#[derive(Default)]
+struct Fonts {
+    #[cfg(feature = "text")]
+    db: std::sync::OnceLock<usvgr::fontdb::Database>,
+}
+
+fn load_tree(
+    args: &Args,
+    in_svg: &InputFrom,
+    fonts: &Fonts,
+    perf: bool,
+) -> Result<usvgr::Tree, Error> {
+    let mut svg_data = timed(perf, "Reading", || -> Result<Vec<u8>, &str> {
+        if let InputFrom::File(ref file) = in_svg {
             std::fs::read(file).map_err(|_| "failed to open the provided file")
         } else {
             use std::io::Read;
@@ -62,89 +165,75 @@ fn process() -> Result<(), String> {
     })?;
 
     if svg_data.starts_with(&[0x1f, 0x8b]) {
-        svg_data = timed(args.perf, "SVGZ Decoding", || {
-            usvgr::decompress_svgz(&svg_data).map_err(|e| e.to_string())
+        svg_data = timed(perf, "SVGZ Decoding", || {
+            usvgr::decompress_svgz(&svg_data).map_err(|e| Error::Parse(e.to_string()))
         })?;
     };
 
     let svg_string = std::str::from_utf8(&svg_data)
-        .map_err(|_| "provided data has not an UTF-8 encoding".to_string())?;
+        .map_err(|_| Error::Parse("provided data has not an UTF-8 encoding".to_string()))?;
 
-    let xml_tree = timed(args.perf, "XML Parsing", || {
+    let xml_tree = timed(perf, "XML Parsing", || {
         let xml_opt = usvgr::roxmltree::ParsingOptions {
             allow_dtd: true,
             ..Default::default()
         };
         usvgr::roxmltree::Document::parse_with_options(svg_string, xml_opt)
-            .map_err(|e| e.to_string())
+            .map_err(|e| Error::Parse(e.to_string()))
     })?;
 
+    #[cfg(feature = "text")]
+    let empty_fontdb;
     #[cfg(feature = "text")]
     let fontdb = {
         let has_text_nodes = xml_tree
             .descendants()
             .any(|n| n.has_tag_name(("http://www.w3.org/2000/svg", "text")));
 
-        let mut fontdb = usvgr::fontdb::Database::new();
         if has_text_nodes {
-            timed(args.perf, "FontDB", || load_fonts(&mut args, &mut fontdb));
-            if args.list_fonts {
-                for face in fontdb.faces() {
-                    if let usvgr::fontdb::Source::File(ref path) = &face.source {
-                        let families: Vec<_> = face
-                            .families
-                            .iter()
-                            .map(|f| {
-                                format!("{} ({}, {})", f.0, f.1.primary_language(), f.1.region())
-                            })
-                            .collect();
-
-                        println!(
-                            "{}: '{}', {}, {:?}, {:?}, {:?}",
-                            path.display(),
-                            families.join("', '"),
-                            face.index,
-                            face.style,
-                            face.weight.0,
-                            face.stretch
-                        );
-                    }
-                }
-            }
+            fonts
+                .db
+                .get_or_init(|| timed(perf, "FontDB", || load_fonts(args)))
+        } else {
+            empty_fontdb = usvgr::fontdb::Database::new();
+            &empty_fontdb
         }
-
-        fontdb
     };
 
-    let tree = timed(args.perf, "SVG Parsing", || {
+    #[cfg(not(feature = "text"))]
+    let _ = fonts;
+
+    let opt = args.usvgr_options(in_svg);
+    timed(perf, "SVG Parsing", || {
         usvgr::Tree::from_xmltree(
             &xml_tree,
-            &args.usvgr,
+            &opt,
             #[cfg(feature = "text")]
-            &fontdb,
+            fontdb,
         )
-        .map_err(|e| e.to_string())
-    })?;
-
-    if args.query_all {
-        return query_all(&tree);
-    }
+        .map_err(|e| Error::Parse(e.to_string()))
+    })
+}
 
-    // Render.
-    let img = render_svg(&args, &tree)?;
+fn save_output(
+    args: &Args,
+    img: &tiny_skia::Pixmap,
+    out_png: &OutputTo,
+    perf: bool,
+) -> Result<(), Error> {
+    let buf = timed(perf, "Encoding", || encode_output(args, img))?;
 
-    match args.out_png.unwrap() {
+    timed(perf, "Writing", || match out_png {
         OutputTo::Stdout => {
             use std::io::Write;
-            let buf = img.encode_png().map_err(|e| e.to_string())?;
-            std::io::stdout().write_all(&buf).unwrap();
+            std::io::stdout()
+                .write_all(&buf)
+                .map_err(|_| "failed to write to stdout".to_string())
         }
         OutputTo::File(ref file) => {
-            timed(args.perf, "Saving", || {
-                img.save_png(file).map_err(|e| e.to_string())
-            })?;
+            std::fs::write(file, &buf).map_err(|_| "failed to write the output file".to_string())
         }
-    };
+    })?;
 
     Ok(())
 }
@@ -157,15 +246,17 @@ USAGE:
   svgr [OPTIONS] <in-svg> -c         # from file to stdout
   svgr [OPTIONS] - <out-png>         # from stdin to file
   svgr [OPTIONS] - -c                # from stdin to stdout
+  svgr [OPTIONS] --out-pattern <pattern> <in-svg|dir>...  # batch rendering
 
   svgr in.svg out.png
   svgr -z 4 in.svg out.png
   svgr --query-all in.svg
+  svgr -j 8 --out-pattern out/%05d.png frames/
 
 OPTIONS:
       --help                    Prints this help
   -V, --version                 Prints version
-  -c                            Prints the output PNG to the stdout
+  -c                            Prints the output to the stdout
 
   -w, --width LENGTH            Sets the width in pixels
   -h, --height LENGTH           Sets the height in pixels
@@ -235,12 +326,55 @@ OPTIONS:
   --export-area-drawing         Use drawing's tight bounding box instead of image size.
                                 Used during normal rendering and not during --export-id
 
-  --perf                        Prints performance stats
+  --cache-size SIZE             Sets the rendering cache capacity.
+                                Use 0 to disable caching
+                                [default: 10]
+  --pool-capacity CAPACITY      Reserves space for the specified number of
+                                pixmaps in each pixmap pool size class
+                                [default: 0]
+
+  --format FORMAT               Sets the output format
+                                [default: png] [possible values: png, rgba, bgra,
+                                rgba-premultiplied, bgra-premultiplied, y4m]
+                                'rgba' and 'bgra' are raw pixels with straight alpha.
+                                'y4m' is a YUV4MPEG2 stream, with transparent areas
+                                rendered as black unless --background is set
+  --chroma SUBSAMPLING          Sets the y4m chroma subsampling
+                                [default: 420] [possible values: 420, 444]
+  --fps RATE                    Sets the y4m frame rate
+                                Examples: 30, 30000/1001
+                                [default: 30]
+
+  --out-pattern PATTERN         Enables batch rendering and sets the output path
+                                pattern. '%d' or '%05d' is replaced with
+                                the zero-based input index, '%s' with
+                                the input file name without an extension
+                                and '%%' with '%'. Each input must have
+                                its own output path.
+                                With raw and y4m formats, a pattern without
+                                placeholders writes all frames into a single
+                                stream in the input order, '-c' to the stdout
+  --manifest PATH               Reads batch inputs from a file, one path per line.
+                                Relative paths are resolved against
+                                the manifest directory. Empty lines and lines
+                                starting with '#' are ignored
+  -j, --jobs N                  Sets the number of batch rendering threads
+                                [default: number of CPUs]
+
+  --perf                        Prints performance stats to the stderr,
+                                including cache hit rates
   --quiet                       Disables warnings
 
 ARGS:
   <in-svg>                      Input file
   <out-png>                     Output file
+  <dir>                         A directory with SVG files to render in batch mode
+
+EXIT STATUS:
+  0                             Success
+  1                             Invalid arguments or an I/O error
+  2                             The input is not a valid SVG
+  3                             The SVG could not be rendered
 ";
 
 #[derive(Debug)]
@@ -275,11 +409,21 @@ struct CliArgs {
 
     export_area_drawing: bool,
 
+    cache_size: usize,
+    pool_capacity: usize,
+
+    format: OutputFormat,
+    chroma: svgr::Y4mChroma,
+    fps: (u32, u32),
+
+    out_pattern: Option<String>,
+    manifest: Option<path::PathBuf>,
+    jobs: Option<usize>,
+
     perf: bool,
     quiet: bool,
 
-    input: String,
-    output: Option<String>,
+    free: Vec<String>,
 }
 
 fn collect_args() -> Result<CliArgs, pico_args::Error> {
@@ -338,11 +482,34 @@ fn collect_args() -> Result<CliArgs, pico_args::Error> {
 
         export_area_drawing: input.contains("--export-area-drawing"),
 
+        cache_size: input.opt_value_from_str("--cache-size")?.unwrap_or(10),
+        pool_capacity: input.opt_value_from_str("--pool-capacity")?.unwrap_or(0),
+
+        format: input
+            .opt_value_from_fn("--format", parse_format)?
+            .unwrap_or(OutputFormat::Png),
+        chroma: input
+            .opt_value_from_fn("--chroma", parse_chroma)?
+            .unwrap_or_default(),
+        fps: input
+            .opt_value_from_fn("--fps", parse_fps)?
+            .unwrap_or((30, 1)),
+
+        out_pattern: input.opt_value_from_str("--out-pattern")?,
+        manifest: input.opt_value_from_str("--manifest")?,
+        jobs: input.opt_value_from_fn(["-j", "--jobs"], parse_jobs)?,
+
         perf: input.contains("--perf"),
         quiet: input.contains("--quiet"),
 
-        input: input.free_from_str()?,
-        output: input.opt_free_from_str()?,
+        free: input
+            .finish()
+            .into_iter()
+            .map(|s| {
+                s.into_string()
+                    .map_err(|_| pico_args::Error::NonUtf8Argument)
+            })
+            .collect::<Result<_, _>>()?,
     })
 }
 
@@ -376,6 +543,49 @@ fn parse_zoom(s: &str) -> Result<f32, String> {
     }
 }
 
+fn parse_format(s: &str) -> Result<OutputFormat, String> {
+    match s {
+        "png" => Ok(OutputFormat::Png),
+        "rgba" => Ok(OutputFormat::Raw(svgr::PixelFormat::Rgba)),
+        "bgra" => Ok(OutputFormat::Raw(svgr::PixelFormat::Bgra)),
+        "rgba-premultiplied" => Ok(OutputFormat::Raw(svgr::PixelFormat::RgbaPremultiplied)),
+        "bgra-premultiplied" => Ok(OutputFormat::Raw(svgr::PixelFormat::BgraPremultiplied)),
+        // The chroma is set by `--chroma` later.
+        "y4m" => Ok(OutputFormat::Y4m(svgr::Y4mChroma::I420)),
+        _ => Err("invalid output format".to_string()),
+    }
+}
+
+fn parse_chroma(s: &str) -> Result<svgr::Y4mChroma, String> {
+    match s {
+        "420" => Ok(svgr::Y4mChroma::I420),
+        "444" => Ok(svgr::Y4mChroma::I444),
+        _ => Err("invalid chroma subsampling".to_string()),
+    }
+}
+
+fn parse_fps(s: &str) -> Result<(u32, u32), String> {
+    let (num, den) = s.split_once('/').unwrap_or((s, "1"));
+    let num: u32 = num.trim().parse().map_err(|_| "invalid frame rate")?;
+    let den: u32 = den.trim().parse().map_err(|_| "invalid frame rate")?;
+
+    if num > 0 && den > 0 {
+        Ok((num, den))
+    } else {
+        Err("frame rate should be positive".to_string())
+    }
+}
+
+fn parse_jobs(s: &str) -> Result<usize, String> {
+    let n: usize = s.parse().map_err(|_| "invalid number")?;
+
+    if n > 0 {
+        Ok(n)
+    } else {
+        Err("JOBS cannot be zero".to_string())
+    }
+}
+
 fn parse_font_size(s: &str) -> Result<u32, String> {
     let n: u32 = s.parse().map_err(|_| "invalid number")?;
 
@@ -412,56 +622,54 @@ enum OutputTo {
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
-enum FitTo {
-    /// Keep original size.
-    Original,
-    /// Scale to width.
-    Width(u32),
-    /// Scale to height.
-    Height(u32),
-    /// Scale to size.
-    Size(u32, u32),
-    /// Zoom by factor.
-    Zoom(f32),
-}
-
-impl FitTo {
-    fn fit_to_size(&self, size: tiny_skia::IntSize) -> Option<tiny_skia::IntSize> {
-        match *self {
-            FitTo::Original => Some(size),
-            FitTo::Width(w) => size.scale_to_width(w),
-            FitTo::Height(h) => size.scale_to_height(h),
-            FitTo::Size(w, h) => tiny_skia::IntSize::from_wh(w, h).map(|s| size.scale_to(s)),
-            FitTo::Zoom(z) => size.scale_by(z),
-        }
-    }
-
-    fn fit_to_transform(&self, size: tiny_skia::IntSize) -> tiny_skia::Transform {
-        let size1 = size.to_size();
-        let size2 = match self.fit_to_size(size) {
-            Some(v) => v.to_size(),
-            None => return tiny_skia::Transform::default(),
-        };
-        tiny_skia::Transform::from_scale(
-            size2.width() / size1.width(),
-            size2.height() / size1.height(),
-        )
+enum OutputFormat {
+    Png,
+    Raw(svgr::PixelFormat),
+    Y4m(svgr::Y4mChroma),
+}
+
+impl OutputFormat {
+    /// Checks that frames can be concatenated into a single stream.
+    fn is_stream(&self) -> bool {
+        !matches!(self, OutputFormat::Png)
     }
 }
 
+/// Batch rendering settings.
+struct Batch {
+    inputs: Vec<path::PathBuf>,
+    manifest: Option<path::PathBuf>,
+    out_pattern: String,
+    jobs: usize,
+}
+
 struct Args {
     in_svg: InputFrom,
     out_png: Option<OutputTo>,
+    batch: Option<Batch>,
     query_all: bool,
     export_id: Option<String>,
     export_area_page: bool,
     export_area_drawing: bool,
+    cache_size: usize,
+    pool_capacity: usize,
+    format: OutputFormat,
+    fps: (u32, u32),
     perf: bool,
     quiet: bool,
-    usvgr: usvgr::Options<'static>,
     fit_to: FitTo,
     background: Option<svgrtypes::Color>,
 
+    dpi: u32,
+    languages: Vec<String>,
+    shape_rendering: usvgr::ShapeRendering,
+    text_rendering: usvgr::TextRendering,
+    image_rendering: usvgr::ImageRendering,
+    resources_dir: Option<path::PathBuf>,
+    default_size: usvgr::Size,
+
+    font_family: String,
+    font_size: u32,
     serif_family: Option<String>,
     sans_serif_family: Option<String>,
     cursive_family: Option<String>,
@@ -473,11 +681,92 @@ struct Args {
     list_fonts: bool,
 }
 
+impl Args {
+    fn usvgr_options(&self, in_svg: &InputFrom) -> usvgr::Options<'static> {
+        let resources_dir = match (&self.resources_dir, in_svg) {
+            (Some(v), _) => Some(v.clone()),
+            (None, InputFrom::File(ref file)) => {
+                // Get input file absolute directory.
+                std::fs::canonicalize(file)
+                    .ok()
+                    .and_then(|p| p.parent().map(|p| p.to_path_buf()))
+            }
+            (None, InputFrom::Stdin) => None,
+        };
+
+        usvgr::Options {
+            resources_dir,
+            dpi: self.dpi as f32,
+            font_family: self.font_family.clone(),
+            font_size: self.font_size as f32,
+            languages: self.languages.clone(),
+            shape_rendering: self.shape_rendering,
+            text_rendering: self.text_rendering,
+            image_rendering: self.image_rendering,
+            default_size: self.default_size,
+            text_grouping: usvgr::TextGrouping::default(),
+            font_fallback: Vec::new(),
+            image_data: None,
+            sub_svg_data: None,
+        }
+    }
+}
+
 fn parse_args() -> Result<Args, String> {
-    let mut args = collect_args().map_err(|e| e.to_string())?;
+    let args = collect_args().map_err(|e| e.to_string())?;
+
+    if let Some(arg) = args
+        .free
+        .iter()
+        .find(|a| a.starts_with('-') && *a != "-" && *a != "-c")
+    {
+        return Err(format!("unknown option '{}'", arg));
+    }
+
+    let batch = if let Some(out_pattern) = args.out_pattern {
+        if args.query_all {
+            return Err("--query-all cannot be used in batch mode".to_string());
+        }
+
+        if args.free.is_empty() && args.manifest.is_none() {
+            return Err("<in-svg> or --manifest must be set".to_string());
+        }
 
-    let (in_svg, out_png) = {
-        let in_svg = args.input.as_str();
+        if args.free.iter().any(|a| a == "-" || a == "-c") {
+            return Err("stdin and stdout cannot be used in batch mode".to_string());
+        }
+
+        let jobs = args.jobs.unwrap_or_else(|| {
+            std::thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(1)
+        });
+
+        Some(Batch {
+            inputs: args.free.iter().map(path::PathBuf::from).collect(),
+            manifest: args.manifest,
+            out_pattern,
+            jobs,
+        })
+    } else {
+        if args.manifest.is_some() || args.jobs.is_some() {
+            return Err("--manifest and --jobs require --out-pattern".to_string());
+        }
+
+        if args.free.len() > 2 {
+            return Err("multiple inputs require --out-pattern".to_string());
+        }
+
+        None
+    };
+
+    let (in_svg, out_png) = if batch.is_some() {
+        (InputFrom::Stdin, None)
+    } else {
+        let in_svg = match args.free.first() {
+            Some(v) => v.as_str(),
+            None => return Err("<in-svg> must be set".to_string()),
+        };
 
         let svg_from = if in_svg == "-" {
             InputFrom::Stdin
@@ -487,37 +776,40 @@ fn parse_args() -> Result<Args, String> {
             InputFrom::File(in_svg.into())
         };
 
-        let out_png = if let Some(ref out_png) = args.output {
+        let out_png = args.free.get(1).map(|out_png| {
             if out_png == "-c" {
-                Some(OutputTo::Stdout)
+                OutputTo::Stdout
             } else {
-                Some(OutputTo::File(out_png.into()))
+                OutputTo::File(out_png.into())
             }
-        } else {
-            None
-        };
+        });
 
-        (svg_from, out_png)
-    };
+        if !args.query_all && out_png.is_none() {
+            return Err("<out-png> must be set".to_string());
+        }
 
-    if !args.query_all && out_png.is_none() {
-        return Err("<out-png> must be set".to_string());
-    }
+        if svg_from == InputFrom::Stdin && args.resources_dir.is_none() {
+            eprintln!("Warning: Make sure to set --resources-dir when reading SVG from stdin.");
+        }
 
-    if args.input == "-" && args.resources_dir.is_none() {
-        println!("Warning: Make sure to set --resources-dir when reading SVG from stdin.");
-    }
+        (svg_from, out_png)
+    };
 
     if args.export_area_page && args.export_id.is_none() {
-        println!("Warning: --export-area-page has no effect without --export-id.");
+        eprintln!("Warning: --export-area-page has no effect without --export-id.");
     }
 
     if args.export_area_drawing && args.export_id.is_some() {
-        println!("Warning: --export-area-drawing has no effect when --export-id is set.");
+        eprintln!("Warning: --export-area-drawing has no effect when --export-id is set.");
     }
 
     let export_id = args.export_id.as_ref().map(|v| v.to_string());
 
+    let format = match args.format {
+        OutputFormat::Y4m(_) => OutputFormat::Y4m(args.chroma),
+        format => format,
+    };
+
     let mut fit_to = FitTo::Original;
     let mut default_size = usvgr::Size::from_wh(100.0, 100.0).unwrap();
     if let (Some(w), Some(h)) = (args.width, args.height) {
@@ -533,46 +825,33 @@ fn parse_args() -> Result<Args, String> {
         fit_to = FitTo::Zoom(z);
     }
 
-    let resources_dir = match args.resources_dir {
-        Some(v) => Some(v),
-        None if args.input != "-" => {
-            // Get input file absolute directory.
-            std::fs::canonicalize(args.input)
-                .ok()
-                .and_then(|p| p.parent().map(|p| p.to_path_buf()))
-        }
-        None => None,
-    };
-
-    let usvgr = usvgr::Options {
-        resources_dir,
-        dpi: args.dpi as f32,
-        font_family: args
-            .font_family
-            .take()
-            .unwrap_or_else(|| "Times New Roman".to_string()),
-        font_size: args.font_size as f32,
-        languages: args.languages,
-        shape_rendering: args.shape_rendering,
-        text_rendering: args.text_rendering,
-        image_rendering: args.image_rendering,
-        default_size,
-        image_data: None,
-        sub_svg_data: None,
-    };
-
     Ok(Args {
         in_svg,
         out_png,
+        batch,
         query_all: args.query_all,
         export_id,
         export_area_page: args.export_area_page,
         export_area_drawing: args.export_area_drawing,
+        cache_size: args.cache_size,
+        pool_capacity: args.pool_capacity,
+        format,
+        fps: args.fps,
         perf: args.perf,
         quiet: args.quiet,
-        usvgr,
         fit_to,
         background: args.background,
+        dpi: args.dpi,
+        languages: args.languages,
+        shape_rendering: args.shape_rendering,
+        text_rendering: args.text_rendering,
+        image_rendering: args.image_rendering,
+        resources_dir: args.resources_dir,
+        default_size,
+        font_family: args
+            .font_family
+            .unwrap_or_else(|| "Times New Roman".to_string()),
+        font_size: args.font_size,
         serif_family: args.serif_family,
         sans_serif_family: args.sans_serif_family,
         cursive_family: args.cursive_family,
@@ -586,7 +865,8 @@ fn parse_args() -> Result<Args, String> {
 }
 
 #[cfg(feature = "text")]
-fn load_fonts(args: &mut Args, fontdb: &mut usvgr::fontdb::Database) {
+fn load_fonts(args: &Args) -> usvgr::fontdb::Database {
+    let mut fontdb = usvgr::fontdb::Database::new();
     if !args.skip_system_fonts {
         fontdb.load_system_fonts();
     }
@@ -601,14 +881,39 @@ fn load_fonts(args: &mut Args, fontdb: &mut usvgr::fontdb::Database) {
         fontdb.load_fonts_dir(path);
     }
 
-    let take_or =
-        |family: Option<String>, fallback: &str| family.unwrap_or_else(|| fallback.to_string());
+    let family_or = |family: &Option<String>, fallback: &str| {
+        family.clone().unwrap_or_else(|| fallback.to_string())
+    };
 
-    fontdb.set_serif_family(take_or(args.serif_family.take(), "Times New Roman"));
-    fontdb.set_sans_serif_family(take_or(args.sans_serif_family.take(), "Arial"));
-    fontdb.set_cursive_family(take_or(args.cursive_family.take(), "Comic Sans MS"));
-    fontdb.set_fantasy_family(take_or(args.fantasy_family.take(), "Impact"));
-    fontdb.set_monospace_family(take_or(args.monospace_family.take(), "Courier New"));
+    fontdb.set_serif_family(family_or(&args.serif_family, "Times New Roman"));
+    fontdb.set_sans_serif_family(family_or(&args.sans_serif_family, "Arial"));
+    fontdb.set_cursive_family(family_or(&args.cursive_family, "Comic Sans MS"));
+    fontdb.set_fantasy_family(family_or(&args.fantasy_family, "Impact"));
+    fontdb.set_monospace_family(family_or(&args.monospace_family, "Courier New"));
+
+    if args.list_fonts {
+        for face in fontdb.faces() {
+            if let usvgr::fontdb::Source::File(ref path) = &face.source {
+                let families: Vec<_> = face
+                    .families
+                    .iter()
+                    .map(|f| format!("{} ({}, {})", f.0, f.1.primary_language(), f.1.region()))
+                    .collect();
+
+                println!(
+                    "{}: '{}', {}, {:?}, {:?}, {:?}",
+                    path.display(),
+                    families.join("', '"),
+                    face.index,
+                    face.style,
+                    face.weight.0,
+                    face.stretch
+                );
+            }
+        }
+    }
+
+    fontdb
 }
 
 fn query_all(tree: &usvgr::Tree) -> Result<(), String> {
@@ -659,159 +964,458 @@ fn query_all_impl(parent: &usvgr::Group) -> usize {
     count
 }
 
-fn render_svg(args: &Args, tree: &usvgr::Tree) -> Result<tiny_skia::Pixmap, String> {
-    let now = std::time::Instant::now();
+fn render_svg(
+    args: &Args,
+    tree: &usvgr::Tree,
+    cache: &mut svgr::SvgrCache,
+    pixmap_pool: &svgr::PixmapPool,
+    perf: bool,
+) -> Result<tiny_skia::Pixmap, String> {
+    let area = if let Some(ref id) = args.export_id {
+        if args.export_area_page {
+            svgr::ExportArea::NodeOnPage(id.clone())
+        } else {
+            svgr::ExportArea::Node(id.clone())
+        }
+    } else if args.export_area_drawing {
+        svgr::ExportArea::Drawing
+    } else {
+        svgr::ExportArea::Page
+    };
 
-    let img = if let Some(ref id) = args.export_id {
-        let node = match tree.node_by_id(id) {
-            Some(node) => node,
-            None => return Err(format!("SVG doesn't have '{}' ID", id)),
-        };
+    let options = svgr::RenderOptions {
+        fit_to: args.fit_to,
+        background: args.background.map(svg_to_skia_color),
+        area,
+        ..svgr::RenderOptions::default()
+    };
 
-        let bbox = node
-            .abs_layer_bounding_box()
-            .ok_or_else(|| "node has zero size".to_string())?;
+    timed(perf, "Rendering", || {
+        svgr::render_to_pixmap(tree, &options, cache, pixmap_pool).map_err(|e| e.to_string())
+    })
+}
 
-        let size = args
-            .fit_to
-            .fit_to_size(bbox.size().to_int_size())
-            .ok_or_else(|| "target size is zero".to_string())?;
+fn new_pixmap_pool(args: &Args) -> svgr::PixmapPool {
+    if args.pool_capacity > 0 {
+        svgr::PixmapPool::new_with_capacity(args.pool_capacity)
+    } else {
+        svgr::PixmapPool::new()
+    }
+}
 
-        // Unwrap is safe, because `size` is already valid.
-        let mut pixmap = tiny_skia::Pixmap::new(size.width(), size.height()).unwrap();
+fn print_cache_stats(stats: svgr::CacheStats) {
+    print_hit_rate("Node cache", stats.node_hits, stats.node_misses);
+    print_hit_rate("Filter cache", stats.filter_hits, stats.filter_misses);
+}
 
-        if !args.export_area_page {
-            if let Some(background) = args.background {
-                pixmap.fill(svg_to_skia_color(background));
-            }
-        }
+fn print_hit_rate(name: &str, hits: u64, misses: u64) {
+    let total = hits + misses;
+    let rate = if total > 0 {
+        hits as f64 / total as f64 * 100.0
+    } else {
+        0.0
+    };
 
-        let mut cache = svgr::SvgrCache::none();
-        let pixmap_pool = svgr::PixmapPool::new();
-        let ts = args.fit_to.fit_to_transform(tree.size().to_int_size());
+    eprintln!("{}: {}/{} hits ({:.1}%)", name, hits, total, rate);
+}
 
-        let ctx = svgr::Context::new_from_pixmap(&pixmap);
-        svgr::render_node(
-            node,
-            ts,
-            &mut pixmap.as_mut(),
-            &mut cache,
-            &pixmap_pool,
-            &ctx,
-        );
+/// Renders all batch inputs using `batch.jobs` threads.
+///
+/// Each thread keeps its own cache and pixmap pool for all the files it renders,
+/// while the font database is shared. A failed file doesn't stop the batch.
+fn process_batch(args: &Args, batch: &Batch, fonts: &Fonts) -> Result<(), Error> {
+    let inputs = collect_batch_inputs(batch)?;
+    if inputs.is_empty() {
+        return Err(Error::Other("no SVG files to render".to_string()));
+    }
 
-        if args.export_area_page {
-            // TODO: add offset support to render_node() so we would not need an additional pixmap
+    let jobs = batch.jobs.min(inputs.len());
+    let has_placeholders = format_out_pattern(&batch.out_pattern, 0, "").1;
+    let stream = if args.format.is_stream() && !has_placeholders {
+        Some(SharedFrameStream::new(
+            FrameStream::new(args, &batch.out_pattern)?,
+            2 * jobs,
+        ))
+    } else if inputs.len() > 1 && !has_placeholders {
+        return Err(Error::Other(
+            "--out-pattern must contain '%d' or '%s' to render multiple files".to_string(),
+        ));
+    } else {
+        check_batch_outputs(&inputs, &batch.out_pattern)?;
+        None
+    };
 
-            let size = args
-                .fit_to
-                .fit_to_size(tree.size().to_int_size())
-                .ok_or_else(|| "target size is zero".to_string())?;
+    let now = std::time::Instant::now();
+    let next = std::sync::atomic::AtomicUsize::new(0);
+
+    let results: Vec<_> = std::thread::scope(|s| {
+        let workers: Vec<_> = (0..jobs)
+            .map(|_| {
+                s.spawn(|| {
+                    let mut cache = svgr::SvgrCache::new(args.cache_size);
+                    let pixmap_pool = new_pixmap_pool(args);
+                    let mut failed = Vec::new();
+
+                    loop {
+                        let idx = next.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                        let Some(path) = inputs.get(idx) else {
+                            break;
+                        };
+
+                        if let Some(ref stream) = stream {
+                            stream.wait_for_turn(idx);
+                        }
+
+                        let res = render_batch_input(args, fonts, path, &mut cache, &pixmap_pool)
+                            .and_then(|img| match stream {
+                                Some(ref stream) => {
+                                    stream.push(idx, Some(img));
+                                    Ok(())
+                                }
+                                None => save_batch_output(args, batch, idx, path, &img),
+                            });
+
+                        if let Err(e) = res {
+                            eprintln!("Error: {}: {}.", path.display(), e);
+                            failed.push((idx, e));
+
+                            // Let the following frames through.
+                            if let Some(ref stream) = stream {
+                                stream.push(idx, None);
+                            }
+                        }
+                    }
 
-            // Unwrap is safe, because `size` is already valid.
-            let mut page_pixmap = tiny_skia::Pixmap::new(size.width(), size.height()).unwrap();
+                    (failed, cache.stats())
+                })
+            })
+            .collect();
 
-            if let Some(background) = args.background {
-                page_pixmap.fill(svg_to_skia_color(background));
-            }
+        workers.into_iter().map(|w| w.join().unwrap()).collect()
+    });
+
+    let mut failed = Vec::new();
+    if let Some(stream) = stream {
+        let stream_failed = stream.into_inner().finish();
+        for (idx, e) in &stream_failed {
+            eprintln!("Error: {}: {}.", inputs[*idx].display(), e);
+        }
+
+        failed.extend(stream_failed);
+    }
 
-            page_pixmap.draw_pixmap(
-                bbox.x() as i32,
-                bbox.y() as i32,
-                pixmap.as_ref(),
-                &tiny_skia::PixmapPaint::default(),
-                tiny_skia::Transform::default(),
-                None,
+    let mut stats = svgr::CacheStats::default();
+    for (worker_failed, worker_stats) in results {
+        failed.extend(worker_failed);
+        stats.node_hits += worker_stats.node_hits;
+        stats.node_misses += worker_stats.node_misses;
+        stats.filter_hits += worker_stats.filter_hits;
+        stats.filter_misses += worker_stats.filter_misses;
+    }
+
+    if args.perf {
+        let elapsed = now.elapsed().as_micros() as f64 / 1000.0;
+        eprintln!(
+            "Batch: {} files in {:.2}ms ({:.2}ms per file, {} jobs)",
+            inputs.len(),
+            elapsed,
+            elapsed / inputs.len() as f64,
+            jobs
+        );
+        print_cache_stats(stats);
+    }
+
+    // Report the first failed input, so the exit code doesn't depend on the threads timing.
+    failed.sort_by_key(|(idx, _)| *idx);
+    match failed.first() {
+        Some((_, e)) => {
+            let msg = format!(
+                "{} of {} files failed to render",
+                failed.len(),
+                inputs.len()
             );
-            page_pixmap
-        } else {
-            pixmap
+            Err(match e {
+                Error::Other(_) => Error::Other(msg),
+                Error::Parse(_) => Error::Parse(msg),
+                Error::Render(_) => Error::Render(msg),
+            })
         }
-    } else {
-        let size = args
-            .fit_to
-            .fit_to_size(tree.size().to_int_size())
-            .ok_or_else(|| "target size is zero".to_string())?;
+        None => Ok(()),
+    }
+}
 
-        // Unwrap is safe, because `size` is already valid.
-        let mut pixmap = tiny_skia::Pixmap::new(size.width(), size.height()).unwrap();
+fn render_batch_input(
+    args: &Args,
+    fonts: &Fonts,
+    path: &path::Path,
+    cache: &mut svgr::SvgrCache,
+    pixmap_pool: &svgr::PixmapPool,
+) -> Result<tiny_skia::Pixmap, Error> {
+    let tree = load_tree(args, &InputFrom::File(path.to_path_buf()), fonts, false)?;
+    render_svg(args, &tree, cache, pixmap_pool, false).map_err(Error::Render)
+}
 
-        if let Some(background) = args.background {
-            pixmap.fill(svg_to_skia_color(background));
+fn save_batch_output(
+    args: &Args,
+    batch: &Batch,
+    idx: usize,
+    path: &path::Path,
+    img: &tiny_skia::Pixmap,
+) -> Result<(), Error> {
+    let output = batch_output_path(&batch.out_pattern, idx, path);
+
+    if let Some(dir) = output.parent().filter(|d| !d.as_os_str().is_empty()) {
+        std::fs::create_dir_all(dir).map_err(|_| "failed to create the output directory")?;
+    }
+
+    save_output(args, img, &OutputTo::File(output), false)
+}
+
+fn batch_output_path(pattern: &str, idx: usize, path: &path::Path) -> path::PathBuf {
+    let name = path.file_stem().unwrap_or_default().to_string_lossy();
+    path::PathBuf::from(format_out_pattern(pattern, idx, &name).0)
+}
+
+/// Checks that each input is saved to its own file.
+///
+/// Otherwise, `%s` would let inputs with the same name from different
+/// directories silently overwrite each other.
+fn check_batch_outputs(inputs: &[path::PathBuf], pattern: &str) -> Result<(), Error> {
+    let mut outputs = std::collections::HashMap::new();
+    for (idx, path) in inputs.iter().enumerate() {
+        let output = batch_output_path(pattern, idx, path);
+        if let Some(prev) = outputs.insert(output, path) {
+            return Err(Error::Other(format!(
+                "'{}' and '{}' have the same output path",
+                prev.display(),
+                path.display()
+            )));
         }
+    }
+
+    Ok(())
+}
 
-        let ts = args.fit_to.fit_to_transform(tree.size().to_int_size());
+type StreamWriter = Box<dyn std::io::Write + Send>;
 
-        let mut cache = svgr::SvgrCache::new(10);
-        let ctx = svgr::Context::new_from_pixmap(&pixmap);
-        svgr::render(
-            tree,
-            ts,
-            &mut pixmap.as_mut(),
-            &mut cache,
-            &PixmapPool::new(),
-            &ctx,
-        );
+/// A [`FrameStream`] shared between batch workers.
+///
+/// Frames that are done before the previous ones are held in memory,
+/// so workers may run at most `window` frames ahead of the stream.
+struct SharedFrameStream {
+    stream: std::sync::Mutex<FrameStream>,
+    written: std::sync::Condvar,
+    window: usize,
+}
 
-        if args.export_area_drawing {
-            trim_pixmap(tree, ts, &pixmap).unwrap_or(pixmap)
-        } else {
-            pixmap
+impl SharedFrameStream {
+    fn new(stream: FrameStream, window: usize) -> Self {
+        SharedFrameStream {
+            stream: std::sync::Mutex::new(stream),
+            written: std::sync::Condvar::new(),
+            window,
         }
-    };
+    }
 
-    if args.perf {
-        let elapsed = now.elapsed().as_micros() as f64 / 1000.0;
-        println!("Rendering: {:.2}ms", elapsed);
+    /// Blocks until the frame with the specified index can be rendered.
+    ///
+    /// Indices are taken in order, so the frame the stream waits for
+    /// is always rendered by a worker that is not blocked.
+    fn wait_for_turn(&self, idx: usize) {
+        let stream = self.stream.lock().unwrap();
+        let _stream = self
+            .written
+            .wait_while(stream, |stream| idx >= stream.next + self.window)
+            .unwrap();
     }
 
-    Ok(img)
+    /// Adds a frame with the specified index. `None` marks a skipped frame.
+    fn push(&self, idx: usize, frame: Option<tiny_skia::Pixmap>) {
+        self.stream.lock().unwrap().push(idx, frame);
+        self.written.notify_all();
+    }
+
+    fn into_inner(self) -> FrameStream {
+        self.stream.into_inner().unwrap()
+    }
 }
 
-fn trim_pixmap(
-    tree: &usvgr::Tree,
-    transform: tiny_skia::Transform,
-    pixmap: &tiny_skia::Pixmap,
-) -> Option<tiny_skia::Pixmap> {
-    let content_area = tree.root().layer_bounding_box();
+/// Writes batch frames into a single raw or y4m stream.
+///
+/// Workers finish frames in any order, so frames are held back
+/// until all the previous ones are written.
+struct FrameStream {
+    format: OutputFormat,
+    fps: (u32, u32),
+    writer: Option<StreamWriter>,
+    /// Created on the first frame, which defines the stream size.
+    y4m: Option<svgr::Y4mWriter<StreamWriter>>,
+    next: usize,
+    pending: std::collections::BTreeMap<usize, Option<tiny_skia::Pixmap>>,
+    /// Frames that failed to be written.
+    failed: Vec<(usize, Error)>,
+    broken: bool,
+}
 
-    let limit = tiny_skia::IntRect::from_xywh(0, 0, pixmap.width(), pixmap.height()).unwrap();
+impl FrameStream {
+    fn new(args: &Args, output: &str) -> Result<Self, Error> {
+        let writer: StreamWriter = if output == "-c" {
+            Box::new(std::io::BufWriter::new(std::io::stdout()))
+        } else {
+            let file = std::fs::File::create(output)
+                .map_err(|_| "failed to create the output file".to_string())?;
+            Box::new(std::io::BufWriter::new(file))
+        };
 
-    let content_area = content_area.transform(transform)?.to_int_rect();
-    let content_area = fit_to_rect(content_area, limit);
-    let content_area = tiny_skia::IntRect::from_xywh(
-        content_area.x(),
-        content_area.y(),
-        content_area.width(),
-        content_area.height(),
-    )?;
+        Ok(FrameStream {
+            format: args.format,
+            fps: args.fps,
+            writer: Some(writer),
+            y4m: None,
+            next: 0,
+            pending: std::collections::BTreeMap::new(),
+            failed: Vec::new(),
+            broken: false,
+        })
+    }
 
-    pixmap.clone_rect(content_area)
-}
+    /// Adds a frame with the specified index. `None` marks a skipped frame.
+    fn push(&mut self, idx: usize, frame: Option<tiny_skia::Pixmap>) {
+        self.pending.insert(idx, frame);
+
+        while let Some(frame) = self.pending.remove(&self.next) {
+            if let Some(frame) = frame {
+                if !self.broken {
+                    if let Err(e) = self.write(&frame) {
+                        // Size mismatches affect only a single frame.
+                        self.broken = e.kind() != std::io::ErrorKind::InvalidInput;
+                        self.failed.push((self.next, Error::Other(e.to_string())));
+                    }
+                }
+            }
 
-/// Fits the current rect into the specified bounds.
-fn fit_to_rect(r: tiny_skia::IntRect, bounds: tiny_skia::IntRect) -> tiny_skia::IntRect {
-    let mut left = r.left();
-    if left < bounds.left() {
-        left = bounds.left();
+            self.next += 1;
+        }
     }
 
-    let mut top = r.top();
-    if top < bounds.top() {
-        top = bounds.top();
+    fn write(&mut self, frame: &tiny_skia::Pixmap) -> std::io::Result<()> {
+        match self.format {
+            OutputFormat::Y4m(chroma) => {
+                if let Some(writer) = self.writer.take() {
+                    let size = tiny_skia::IntSize::from_wh(frame.width(), frame.height()).unwrap();
+                    self.y4m = Some(svgr::Y4mWriter::new(writer, size, self.fps, chroma)?);
+                }
+
+                // Unwrap is safe, because the writer was created above.
+                self.y4m.as_mut().unwrap().write_frame(frame)
+            }
+            OutputFormat::Raw(format) => {
+                // Unwrap is safe, because raw streams never take the writer.
+                svgr::write_raw(frame, format, self.writer.as_mut().unwrap())
+            }
+            OutputFormat::Png => unreachable!(),
+        }
     }
 
-    let mut right = r.right();
-    if right > bounds.right() {
-        right = bounds.right();
+    /// Flushes the stream and returns all write errors.
+    fn finish(mut self) -> Vec<(usize, Error)> {
+        let res = match (self.y4m.take(), self.writer.as_mut()) {
+            (Some(y4m), _) => y4m.finish().map(|_| ()),
+            (None, Some(writer)) => writer.flush(),
+            (None, None) => Ok(()),
+        };
+
+        if let Err(e) = res {
+            if !self.broken {
+                let idx = self.next.saturating_sub(1);
+                self.failed.push((idx, Error::Other(e.to_string())));
+            }
+        }
+
+        self.failed
+    }
+}
+
+/// Expands directories and the manifest into a list of files.
+fn collect_batch_inputs(batch: &Batch) -> Result<Vec<path::PathBuf>, Error> {
+    let mut inputs = Vec::new();
+    for path in &batch.inputs {
+        if !path.is_dir() {
+            inputs.push(path.clone());
+            continue;
+        }
+
+        let entries = std::fs::read_dir(path)
+            .map_err(|_| format!("failed to read the '{}' directory", path.display()))?;
+
+        let mut files: Vec<_> = entries
+            .filter_map(|e| e.ok().map(|e| e.path()))
+            .filter(|p| {
+                let ext = p.extension().and_then(|e| e.to_str()).unwrap_or_default();
+                ext.eq_ignore_ascii_case("svg") || ext.eq_ignore_ascii_case("svgz")
+            })
+            .collect();
+        files.sort();
+        inputs.extend(files);
     }
 
-    let mut bottom = r.bottom();
-    if bottom > bounds.bottom() {
-        bottom = bounds.bottom();
+    if let Some(ref manifest) = batch.manifest {
+        let text = std::fs::read_to_string(manifest)
+            .map_err(|_| format!("failed to read the '{}' manifest", manifest.display()))?;
+        let base_dir = manifest.parent().unwrap_or(path::Path::new(""));
+
+        for line in text.lines().map(str::trim) {
+            if !line.is_empty() && !line.starts_with('#') {
+                inputs.push(base_dir.join(line));
+            }
+        }
+    }
+
+    Ok(inputs)
+}
+
+/// Substitutes `%d`, `%0Nd`, `%s` and `%%` in the output pattern.
+///
+/// Also returns whether the pattern has any `%d` or `%s` placeholders.
+fn format_out_pattern(pattern: &str, index: usize, name: &str) -> (String, bool) {
+    let mut out = String::with_capacity(pattern.len() + 8);
+    let mut has_placeholders = false;
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            out.push(c);
+            continue;
+        }
+
+        let mut width = String::new();
+        while let Some(d) = chars.next_if(|d| d.is_ascii_digit()) {
+            width.push(d);
+        }
+
+        match chars.peek() {
+            Some('d') => {
+                chars.next();
+                let width = width.parse().unwrap_or(0);
+                out.push_str(&format!("{:0width$}", index, width = width));
+                has_placeholders = true;
+            }
+            Some('s') if width.is_empty() => {
+                chars.next();
+                out.push_str(name);
+                has_placeholders = true;
+            }
+            Some('%') if width.is_empty() => {
+                chars.next();
+                out.push('%');
+            }
+            _ => {
+                out.push('%');
+                out.push_str(&width);
+            }
+        }
     }
 
-    tiny_skia::IntRect::from_ltrb(left, top, right, bottom).unwrap()
+    (out, has_placeholders)
 }
 
 fn svg_to_skia_color(color: svgrtypes::Color) -> tiny_skia::Color {
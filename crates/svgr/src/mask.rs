@@ -36,9 +36,10 @@ pub fn apply(
                     transform,
                 );
 
+                // Mask content is not a part of the background image.
                 crate::render::render_nodes(
                     mask.root(),
-                    ctx,
+                    &ctx.with_background(None),
                     transform,
                     &mut mask_pixmap.as_mut(),
                     cache,
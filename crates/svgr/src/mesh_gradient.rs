@@ -0,0 +1,363 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use tiny_skia::Point;
+
+/// A maximum mesh pixmap side, to prevent huge allocations for meshes
+/// which are mostly outside the canvas.
+const MAX_PIXMAP_SIZE: f32 = 4096.0;
+
+/// A maximum number of subdivisions per patch side.
+const MAX_SUBDIVISIONS: f32 = 64.0;
+
+/// Premultiplied RGBA in the 0..1 range.
+type Rgba = [f32; 4];
+
+/// Renders a mesh gradient into a pixmap that can be used as a pattern shader.
+///
+/// Patches are subdivided into triangles with interpolated colors.
+/// The pixmap has a one pixel transparent border, so it can be used with `SpreadMode::Pad`.
+pub fn render_mesh_pixmap<'a>(
+    gradient: &usvgr::MeshGradient,
+    transform: tiny_skia::Transform,
+    cache: &'a mut crate::cache::SvgrCache,
+    pixmap_pool: &'a crate::cache::PixmapPool,
+) -> Option<(&'a tiny_skia::Pixmap, tiny_skia::Transform)> {
+    let (img_size, raster_ts, ts) = mesh_layout(gradient, transform)?;
+
+    // The pixmap depends only on the scale and the skew. The translation is applied
+    // by the returned pattern transform, so moved meshes can share a cache entry.
+    let key_ts = tiny_skia::Transform {
+        tx: 0.0,
+        ty: 0.0,
+        ..transform
+    };
+
+    let pixmap =
+        cache.with_subpixmap_cache(gradient, key_ts, pixmap_pool, img_size, |mut pixmap, _| {
+            let width = pixmap.width() as i32;
+            let height = pixmap.height() as i32;
+            let data = pixmap.data_mut();
+            rasterize_mesh(gradient, raster_ts, width, height, &mut |idx, color| {
+                for c in 0..4 {
+                    data[idx * 4 + c] = (color[c] * 255.0 + 0.5) as u8;
+                }
+            });
+
+            Some(pixmap)
+        })?;
+
+    Some((pixmap, ts))
+}
+
+/// Same as [`render_mesh_pixmap`], but keeps colors unrounded and doesn't use the cache.
+pub fn render_mesh_pixmap_f32(
+    gradient: &usvgr::MeshGradient,
+    transform: tiny_skia::Transform,
+) -> Option<(crate::filter::PixmapF32, tiny_skia::Transform)> {
+    let (img_size, raster_ts, ts) = mesh_layout(gradient, transform)?;
+
+    let mut pixmap = crate::filter::PixmapF32::new(img_size.width(), img_size.height());
+    let (width, height) = (pixmap.width as i32, pixmap.height as i32);
+    rasterize_mesh(gradient, raster_ts, width, height, &mut |idx, color| {
+        pixmap.data[idx] = rgb::RGBA::new(color[0], color[1], color[2], color[3]);
+    });
+
+    Some((pixmap, ts))
+}
+
+/// Returns the mesh pixmap size, the mesh to pixmap transform
+/// and the pixmap to user space transform.
+fn mesh_layout(
+    gradient: &usvgr::MeshGradient,
+    transform: tiny_skia::Transform,
+) -> Option<(
+    tiny_skia::IntSize,
+    tiny_skia::Transform,
+    tiny_skia::Transform,
+)> {
+    let points = gradient.patches().iter().flat_map(|p| p.points().iter());
+    let rect = tiny_skia::Rect::from_points(&points.copied().collect::<Vec<_>>())?;
+
+    let (mut sx, mut sy) = {
+        let ts2 = transform.pre_concat(gradient.transform());
+        ts2.get_scale()
+    };
+    sx = sx.min(MAX_PIXMAP_SIZE / rect.width().max(1.0));
+    sy = sy.min(MAX_PIXMAP_SIZE / rect.height().max(1.0));
+
+    let img_size = tiny_skia::IntSize::from_wh(
+        (rect.width() * sx).ceil() as u32 + 2,
+        (rect.height() * sy).ceil() as u32 + 2,
+    )?;
+
+    let raster_ts = tiny_skia::Transform::from_translate(1.0, 1.0)
+        .pre_scale(sx, sy)
+        .pre_translate(-rect.x(), -rect.y());
+
+    let ts = gradient
+        .transform()
+        .pre_translate(rect.x(), rect.y())
+        .pre_scale(1.0 / sx, 1.0 / sy)
+        .pre_translate(-1.0, -1.0);
+
+    Some((img_size, raster_ts, ts))
+}
+
+/// Rasterizes patches into a `width` x `height` image.
+///
+/// `put` receives a pixel index and a clamped premultiplied color.
+fn rasterize_mesh(
+    gradient: &usvgr::MeshGradient,
+    ts: tiny_skia::Transform,
+    width: i32,
+    height: i32,
+    put: &mut dyn FnMut(usize, Rgba),
+) {
+    let columns = gradient.columns();
+    let rows = gradient.rows();
+
+    // Corner colors shared by adjacent patches, `(rows + 1) x (columns + 1)`.
+    let mut nodes = vec![[0.0; 4]; (rows + 1) * (columns + 1)];
+    let node_idx = |row: usize, col: usize| row * (columns + 1) + col;
+    for (i, patch) in gradient.patches().iter().enumerate() {
+        let (row, col) = (i / columns, i % columns);
+        let colors = patch_colors(patch);
+        nodes[node_idx(row, col)] = colors[0];
+        nodes[node_idx(row, col + 1)] = colors[1];
+        nodes[node_idx(row + 1, col + 1)] = colors[2];
+        nodes[node_idx(row + 1, col)] = colors[3];
+    }
+
+    // Color derivatives for the bicubic interpolation, estimated like in Catmull-Rom splines.
+    let tangent = |prev: Rgba, next: Rgba, span: f32| -> Rgba {
+        std::array::from_fn(|c| (next[c] - prev[c]) / span)
+    };
+    let mut u_tangents = vec![[0.0; 4]; nodes.len()];
+    let mut v_tangents = vec![[0.0; 4]; nodes.len()];
+    if gradient.kind() == usvgr::MeshGradientType::Bicubic {
+        for row in 0..=rows {
+            for col in 0..=columns {
+                let (prev, next) = (col.saturating_sub(1), (col + 1).min(columns));
+                u_tangents[node_idx(row, col)] = tangent(
+                    nodes[node_idx(row, prev)],
+                    nodes[node_idx(row, next)],
+                    (next - prev) as f32,
+                );
+
+                let (prev, next) = (row.saturating_sub(1), (row + 1).min(rows));
+                v_tangents[node_idx(row, col)] = tangent(
+                    nodes[node_idx(prev, col)],
+                    nodes[node_idx(next, col)],
+                    (next - prev) as f32,
+                );
+            }
+        }
+    }
+
+    for (i, patch) in gradient.patches().iter().enumerate() {
+        let (row, col) = (i / columns, i % columns);
+        let corners = [
+            node_idx(row, col),
+            node_idx(row, col + 1),
+            node_idx(row + 1, col + 1),
+            node_idx(row + 1, col),
+        ];
+
+        let mut points = *patch.points();
+        for p in &mut points {
+            ts.map_point(p);
+        }
+
+        let color_at = |u: f32, v: f32| -> Rgba {
+            let weights = [(1.0 - u, 1.0 - v), (u, 1.0 - v), (u, v), (1.0 - u, v)];
+            let hermite = [
+                (h00(u) * h00(v), h10(u) * h00(v), h00(u) * h10(v)),
+                (h01(u) * h00(v), h11(u) * h00(v), h01(u) * h10(v)),
+                (h01(u) * h01(v), h11(u) * h01(v), h01(u) * h11(v)),
+                (h00(u) * h01(v), h10(u) * h01(v), h00(u) * h11(v)),
+            ];
+
+            let mut color = [0.0; 4];
+            for (k, &idx) in corners.iter().enumerate() {
+                for c in 0..4 {
+                    color[c] += if gradient.kind() == usvgr::MeshGradientType::Bicubic {
+                        let (w, wu, wv) = hermite[k];
+                        w * nodes[idx][c] + wu * u_tangents[idx][c] + wv * v_tangents[idx][c]
+                    } else {
+                        weights[k].0 * weights[k].1 * nodes[idx][c]
+                    };
+                }
+            }
+
+            color
+        };
+
+        let n = subdivisions(&points);
+        let step = 1.0 / n as f32;
+        let mut prev_row: Vec<(Point, Rgba)> = (0..=n)
+            .map(|k| {
+                let u = k as f32 * step;
+                (coons_point(&points, u, 0.0), color_at(u, 0.0))
+            })
+            .collect();
+        for j in 1..=n {
+            let v = j as f32 * step;
+            let row: Vec<(Point, Rgba)> = (0..=n)
+                .map(|k| {
+                    let u = k as f32 * step;
+                    (coons_point(&points, u, v), color_at(u, v))
+                })
+                .collect();
+
+            for k in 0..n {
+                fill_triangle(width, height, put, prev_row[k], prev_row[k + 1], row[k + 1]);
+                fill_triangle(width, height, put, prev_row[k], row[k + 1], row[k]);
+            }
+
+            prev_row = row;
+        }
+    }
+}
+
+fn patch_colors(patch: &usvgr::MeshPatch) -> [Rgba; 4] {
+    std::array::from_fn(|i| {
+        let c = patch.colors()[i];
+        let a = patch.opacities()[i].get();
+        [
+            c.red as f32 / 255.0 * a,
+            c.green as f32 / 255.0 * a,
+            c.blue as f32 / 255.0 * a,
+            a,
+        ]
+    })
+}
+
+/// Picks a subdivision level that keeps triangles a few pixels wide.
+fn subdivisions(points: &[Point; 12]) -> usize {
+    let mut max_len: f32 = 0.0;
+    for edge in 0..4 {
+        let mut len = 0.0;
+        for i in 0..3 {
+            let p1 = points[edge * 3 + i];
+            let p2 = points[(edge * 3 + i + 1) % 12];
+            len += p1.distance(p2);
+        }
+        max_len = max_len.max(len);
+    }
+
+    (max_len / 4.0).ceil().clamp(1.0, MAX_SUBDIVISIONS) as usize
+}
+
+/// Evaluates a Coons patch at the specified parametric coordinates.
+fn coons_point(p: &[Point; 12], u: f32, v: f32) -> Point {
+    let top = cubic_point(p[0], p[1], p[2], p[3], u);
+    let bottom = cubic_point(p[9], p[8], p[7], p[6], u);
+    let left = cubic_point(p[0], p[11], p[10], p[9], v);
+    let right = cubic_point(p[3], p[4], p[5], p[6], v);
+
+    let lerp = |a: f32, b: f32, t: f32| a + (b - a) * t;
+    let bilinear = |c0: f32, c1: f32, c2: f32, c3: f32| lerp(lerp(c0, c1, u), lerp(c3, c2, u), v);
+
+    Point::from_xy(
+        lerp(top.x, bottom.x, v) + lerp(left.x, right.x, u)
+            - bilinear(p[0].x, p[3].x, p[6].x, p[9].x),
+        lerp(top.y, bottom.y, v) + lerp(left.y, right.y, u)
+            - bilinear(p[0].y, p[3].y, p[6].y, p[9].y),
+    )
+}
+
+fn cubic_point(p0: Point, p1: Point, p2: Point, p3: Point, t: f32) -> Point {
+    let mt = 1.0 - t;
+    let a = mt * mt * mt;
+    let b = 3.0 * mt * mt * t;
+    let c = 3.0 * mt * t * t;
+    let d = t * t * t;
+    Point::from_xy(
+        a * p0.x + b * p1.x + c * p2.x + d * p3.x,
+        a * p0.y + b * p1.y + c * p2.y + d * p3.y,
+    )
+}
+
+// Cubic Hermite basis functions.
+fn h00(t: f32) -> f32 {
+    2.0 * t * t * t - 3.0 * t * t + 1.0
+}
+
+fn h10(t: f32) -> f32 {
+    t * t * t - 2.0 * t * t + t
+}
+
+fn h01(t: f32) -> f32 {
+    -2.0 * t * t * t + 3.0 * t * t
+}
+
+fn h11(t: f32) -> f32 {
+    t * t * t - t * t
+}
+
+/// Fills a triangle with a linearly interpolated color.
+///
+/// Pixels are sampled at their centers using the top-left rule,
+/// so adjacent triangles do not overlap. Later patches replace earlier ones.
+fn fill_triangle(
+    width: i32,
+    height: i32,
+    put: &mut dyn FnMut(usize, Rgba),
+    (p0, c0): (Point, Rgba),
+    (p1, c1): (Point, Rgba),
+    (p2, c2): (Point, Rgba),
+) {
+    let area = edge_function(p0, p1, p2);
+    if !area.is_normal() {
+        return;
+    }
+
+    // Make the winding consistent.
+    let ((p1, c1), (p2, c2)) = if area < 0.0 {
+        ((p2, c2), (p1, c1))
+    } else {
+        ((p1, c1), (p2, c2))
+    };
+    let area = area.abs();
+
+    let min_x = (p0.x.min(p1.x).min(p2.x).floor() as i32).max(0);
+    let min_y = (p0.y.min(p1.y).min(p2.y).floor() as i32).max(0);
+    let max_x = (p0.x.max(p1.x).max(p2.x).ceil() as i32).min(width - 1);
+    let max_y = (p0.y.max(p1.y).max(p2.y).ceil() as i32).min(height - 1);
+
+    let is_top_left = |a: Point, b: Point| (a.y == b.y && b.x < a.x) || b.y < a.y;
+    let bias = |a: Point, b: Point| {
+        if is_top_left(a, b) {
+            0.0
+        } else {
+            -f32::EPSILON
+        }
+    };
+    let (bias0, bias1, bias2) = (bias(p1, p2), bias(p2, p0), bias(p0, p1));
+
+    for y in min_y..=max_y {
+        for x in min_x..=max_x {
+            let p = Point::from_xy(x as f32 + 0.5, y as f32 + 0.5);
+            let w0 = edge_function(p1, p2, p);
+            let w1 = edge_function(p2, p0, p);
+            let w2 = edge_function(p0, p1, p);
+            if w0 + bias0 < 0.0 || w1 + bias1 < 0.0 || w2 + bias2 < 0.0 {
+                continue;
+            }
+
+            let (w0, w1, w2) = (w0 / area, w1 / area, w2 / area);
+            let a = (c0[3] * w0 + c1[3] * w1 + c2[3] * w2).clamp(0.0, 1.0);
+            let mut color = [0.0, 0.0, 0.0, a];
+            for c in 0..3 {
+                color[c] = (c0[c] * w0 + c1[c] * w1 + c2[c] * w2).clamp(0.0, a);
+            }
+            put((y * width + x) as usize, color);
+        }
+    }
+}
+
+fn edge_function(a: Point, b: Point, c: Point) -> f32 {
+    (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)
+}
@@ -0,0 +1,270 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::sync::Arc;
+
+use crate::cache::{PixmapPool, SvgrCache};
+use crate::render::Context;
+use crate::{CustomFilters, FilterPrecision, GradientDithering};
+
+/// An output image size.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum FitTo {
+    /// Keep original size.
+    #[default]
+    Original,
+    /// Scale to width.
+    Width(u32),
+    /// Scale to height.
+    Height(u32),
+    /// Scale to size.
+    Size(u32, u32),
+    /// Zoom by factor.
+    Zoom(f32),
+}
+
+impl FitTo {
+    /// Returns the output size for an image of the provided size.
+    ///
+    /// Returns `None` when the output size would be zero.
+    pub fn fit_to_size(&self, size: tiny_skia::IntSize) -> Option<tiny_skia::IntSize> {
+        match *self {
+            FitTo::Original => Some(size),
+            FitTo::Width(w) => size.scale_to_width(w),
+            FitTo::Height(h) => size.scale_to_height(h),
+            FitTo::Size(w, h) => tiny_skia::IntSize::from_wh(w, h).map(|s| size.scale_to(s)),
+            FitTo::Zoom(z) => size.scale_by(z),
+        }
+    }
+
+    /// Returns a transform that scales an image of the provided size to the output size.
+    pub fn fit_to_transform(&self, size: tiny_skia::IntSize) -> tiny_skia::Transform {
+        let size1 = size.to_size();
+        let size2 = match self.fit_to_size(size) {
+            Some(v) => v.to_size(),
+            None => return tiny_skia::Transform::default(),
+        };
+        tiny_skia::Transform::from_scale(
+            size2.width() / size1.width(),
+            size2.height() / size1.height(),
+        )
+    }
+}
+
+/// A part of the image that should be rendered.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub enum ExportArea {
+    /// The whole image.
+    #[default]
+    Page,
+    /// The tight bounding box of the drawing.
+    ///
+    /// The image is rendered as a page and then trimmed.
+    Drawing,
+    /// An element with the specified ID, cropped to its bounding box.
+    Node(String),
+    /// An element with the specified ID, positioned on a page-sized image.
+    NodeOnPage(String),
+}
+
+/// A [`Context`] constructor used for rendering.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ContextStrategy {
+    /// Uses [`Context::new_from_pixmap`].
+    #[default]
+    Safe,
+    /// Uses [`Context::new_from_pixmap_unsafe`].
+    ///
+    /// Faster, but filters and masks that reach outside the image can be cut.
+    Unsafe,
+}
+
+/// Rendering options used by [`render_to_pixmap`].
+#[derive(Clone, Default)]
+pub struct RenderOptions {
+    /// Output image size.
+    ///
+    /// Default: [`FitTo::Original`]
+    pub fit_to: FitTo,
+
+    /// A color the output image is filled with before rendering.
+    ///
+    /// Default: `None`
+    pub background: Option<tiny_skia::Color>,
+
+    /// A part of the image that should be rendered.
+    ///
+    /// Default: [`ExportArea::Page`]
+    pub area: ExportArea,
+
+    /// A rendering context constructor.
+    ///
+    /// Default: [`ContextStrategy::Safe`]
+    pub context: ContextStrategy,
+
+    /// Gradients dithering mode.
+    ///
+    /// Default: [`GradientDithering::None`]
+    pub gradient_dithering: GradientDithering,
+
+    /// Pixel format of intermediate filter results.
+    ///
+    /// Default: [`FilterPrecision::U8`]
+    pub filter_precision: FilterPrecision,
+
+    /// User-defined filter primitives.
+    ///
+    /// Default: `None`
+    pub custom_filters: Option<Arc<CustomFilters>>,
+}
+
+impl RenderOptions {
+    /// Creates a rendering context for the provided pixmap.
+    pub fn to_context(&self, pixmap: &tiny_skia::Pixmap) -> Context {
+        let mut ctx = match self.context {
+            ContextStrategy::Safe => Context::new_from_pixmap(pixmap),
+            ContextStrategy::Unsafe => Context::new_from_pixmap_unsafe(pixmap),
+        }
+        .with_gradient_dithering(self.gradient_dithering)
+        .with_filter_precision(self.filter_precision);
+
+        if let Some(ref filters) = self.custom_filters {
+            ctx = ctx.with_custom_filters(filters.clone());
+        }
+
+        ctx
+    }
+}
+
+/// List of all rendering errors.
+#[derive(Clone, PartialEq, Debug)]
+pub enum RenderError {
+    /// An element with the requested ID doesn't exist.
+    NodeNotFound(String),
+
+    /// The requested element has a zero size.
+    ZeroSizedNode,
+
+    /// The output image size is zero.
+    ZeroSizedOutput,
+
+    /// The output image is too large to be allocated.
+    OutputTooLarge,
+}
+
+impl std::fmt::Display for RenderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            RenderError::NodeNotFound(ref id) => {
+                write!(f, "SVG doesn't have '{}' ID", id)
+            }
+            RenderError::ZeroSizedNode => {
+                write!(f, "node has zero size")
+            }
+            RenderError::ZeroSizedOutput => {
+                write!(f, "target size is zero")
+            }
+            RenderError::OutputTooLarge => {
+                write!(f, "target size is too large")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RenderError {}
+
+/// Renders a tree onto a newly allocated pixmap.
+///
+/// The pixmap size is defined by the tree size, `options.fit_to` and `options.area`.
+///
+/// The produced content is in the sRGB color space.
+pub fn render_to_pixmap(
+    tree: &usvgr::Tree,
+    options: &RenderOptions,
+    cache: &mut SvgrCache,
+    pixmap_pool: &PixmapPool,
+) -> Result<tiny_skia::Pixmap, RenderError> {
+    let page_size = tree.size().to_int_size();
+
+    let (id, on_page) = match options.area {
+        ExportArea::Page | ExportArea::Drawing => {
+            let size = fit_size(options.fit_to, page_size)?;
+            let mut pixmap = new_pixmap(size, options.background)?;
+            let ts = options.fit_to.fit_to_transform(page_size);
+            let ctx = options.to_context(&pixmap);
+            crate::render(tree, ts, &mut pixmap.as_mut(), cache, pixmap_pool, &ctx);
+
+            if options.area == ExportArea::Drawing {
+                return Ok(trim_pixmap(tree, ts, &pixmap).unwrap_or(pixmap));
+            }
+
+            return Ok(pixmap);
+        }
+        ExportArea::Node(ref id) => (id, false),
+        ExportArea::NodeOnPage(ref id) => (id, true),
+    };
+
+    let node = tree
+        .node_by_id(id)
+        .ok_or_else(|| RenderError::NodeNotFound(id.clone()))?;
+    let bbox = node
+        .abs_layer_bounding_box()
+        .ok_or(RenderError::ZeroSizedNode)?;
+
+    let (size, ts) = if on_page {
+        // Undo the bounding box offset applied by `render_node`.
+        let ts = options
+            .fit_to
+            .fit_to_transform(page_size)
+            .pre_translate(bbox.x(), bbox.y());
+        (fit_size(options.fit_to, page_size)?, ts)
+    } else {
+        let node_size = bbox.size().to_int_size();
+        let ts = options.fit_to.fit_to_transform(node_size);
+        (fit_size(options.fit_to, node_size)?, ts)
+    };
+
+    let mut pixmap = new_pixmap(size, options.background)?;
+    let ctx = options.to_context(&pixmap);
+    crate::render_node(node, ts, &mut pixmap.as_mut(), cache, pixmap_pool, &ctx);
+    Ok(pixmap)
+}
+
+fn fit_size(fit_to: FitTo, size: tiny_skia::IntSize) -> Result<tiny_skia::IntSize, RenderError> {
+    fit_to.fit_to_size(size).ok_or(RenderError::ZeroSizedOutput)
+}
+
+fn new_pixmap(
+    size: tiny_skia::IntSize,
+    background: Option<tiny_skia::Color>,
+) -> Result<tiny_skia::Pixmap, RenderError> {
+    // `IntSize` doesn't account for the max row stride.
+    let mut pixmap =
+        tiny_skia::Pixmap::new(size.width(), size.height()).ok_or(RenderError::OutputTooLarge)?;
+    if let Some(background) = background {
+        pixmap.fill(background);
+    }
+
+    Ok(pixmap)
+}
+
+/// Crops the pixmap to the drawing's bounding box.
+///
+/// `transform` should be the same one the tree was rendered with.
+///
+/// Returns `None` when the drawing is outside the pixmap.
+pub fn trim_pixmap(
+    tree: &usvgr::Tree,
+    transform: tiny_skia::Transform,
+    pixmap: &tiny_skia::Pixmap,
+) -> Option<tiny_skia::Pixmap> {
+    let content_area = tree.root().layer_bounding_box();
+
+    let limit = tiny_skia::IntRect::from_xywh(0, 0, pixmap.width(), pixmap.height()).unwrap();
+
+    let content_area = content_area.transform(transform)?.to_int_rect();
+    let content_area = content_area.intersect(&limit)?;
+
+    pixmap.clone_rect(content_area)
+}
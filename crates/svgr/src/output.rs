@@ -0,0 +1,394 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::io::{self, Write};
+
+use tiny_skia::{ColorU8, PremultipliedColorU8};
+
+use crate::cache::PixmapPool;
+
+/// A raw 8-bit pixel format.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum PixelFormat {
+    /// Premultiplied RGBA. The format `tiny_skia::Pixmap` stores.
+    #[default]
+    RgbaPremultiplied,
+    /// Premultiplied BGRA.
+    BgraPremultiplied,
+    /// RGBA with straight alpha.
+    ///
+    /// This is what ffmpeg expects from the `rgba` raw video.
+    Rgba,
+    /// BGRA with straight alpha.
+    Bgra,
+    /// Premultiplied native-endian `0xAARRGGBB` words.
+    ///
+    /// Matches Cairo's `ARGB32` and Qt's `ARGB32_Premultiplied`.
+    /// Same as `BgraPremultiplied` on little-endian machines.
+    Argb32Premultiplied,
+    /// Native-endian `0xAARRGGBB` words with straight alpha.
+    ///
+    /// Matches Qt's `ARGB32`.
+    Argb32,
+}
+
+impl PixelFormat {
+    /// Returns the number of bytes used by a single pixel.
+    pub fn bytes_per_pixel(&self) -> usize {
+        4
+    }
+
+    fn is_premultiplied(&self) -> bool {
+        matches!(
+            self,
+            PixelFormat::RgbaPremultiplied
+                | PixelFormat::BgraPremultiplied
+                | PixelFormat::Argb32Premultiplied
+        )
+    }
+
+    /// Returns `[r, g, b, a]` in the format's byte order.
+    #[inline]
+    fn to_bytes(self, [r, g, b, a]: [u8; 4]) -> [u8; 4] {
+        match self {
+            PixelFormat::RgbaPremultiplied | PixelFormat::Rgba => [r, g, b, a],
+            PixelFormat::BgraPremultiplied | PixelFormat::Bgra => [b, g, r, a],
+            PixelFormat::Argb32Premultiplied | PixelFormat::Argb32 => {
+                u32::from_be_bytes([a, r, g, b]).to_ne_bytes()
+            }
+        }
+    }
+
+    /// Returns `[r, g, b, a]` from bytes in the format's byte order.
+    #[inline]
+    fn from_bytes(self, bytes: [u8; 4]) -> [u8; 4] {
+        match self {
+            PixelFormat::RgbaPremultiplied | PixelFormat::Rgba => bytes,
+            PixelFormat::BgraPremultiplied | PixelFormat::Bgra => {
+                let [b, g, r, a] = bytes;
+                [r, g, b, a]
+            }
+            PixelFormat::Argb32Premultiplied | PixelFormat::Argb32 => {
+                let [a, r, g, b] = u32::from_ne_bytes(bytes).to_be_bytes();
+                [r, g, b, a]
+            }
+        }
+    }
+}
+
+/// A caller-owned 8-bit pixel buffer with an arbitrary row stride.
+pub struct PixelBuffer<'a> {
+    data: &'a mut [u8],
+    size: tiny_skia::IntSize,
+    stride: usize,
+    format: PixelFormat,
+}
+
+impl<'a> PixelBuffer<'a> {
+    /// Wraps caller-owned pixels.
+    ///
+    /// `stride` is the number of bytes between the starts of two consecutive rows.
+    ///
+    /// Returns `None` when the size is zero, `stride` is smaller than a row
+    /// or `data` is too short.
+    pub fn new(
+        data: &'a mut [u8],
+        width: u32,
+        height: u32,
+        stride: usize,
+        format: PixelFormat,
+    ) -> Option<Self> {
+        let size = tiny_skia::IntSize::from_wh(width, height)?;
+        let row_len = (width as usize).checked_mul(format.bytes_per_pixel())?;
+        let data_len = stride
+            .checked_mul(height as usize - 1)?
+            .checked_add(row_len)?;
+
+        if stride < row_len || data.len() < data_len {
+            return None;
+        }
+
+        Some(PixelBuffer {
+            data,
+            size,
+            stride,
+            format,
+        })
+    }
+
+    /// Returns the buffer size.
+    pub fn size(&self) -> tiny_skia::IntSize {
+        self.size
+    }
+
+    /// Returns the number of bytes between the starts of two consecutive rows.
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Returns the pixel format.
+    pub fn format(&self) -> PixelFormat {
+        self.format
+    }
+
+    /// Calls `f` with a pixmap that wraps the buffer content.
+    ///
+    /// Tightly packed premultiplied RGBA is used as is. Other buffers are converted
+    /// into a pooled pixmap and converted back after `f` returns.
+    /// Only pixels changed by `f` are written back, since straight alpha
+    /// doesn't survive a premultiplied round-trip.
+    ///
+    /// Returns `None` when a pixmap cannot be allocated.
+    pub(crate) fn with_pixmap<T>(
+        &mut self,
+        pixmap_pool: &PixmapPool,
+        f: impl FnOnce(&mut tiny_skia::PixmapMut) -> T,
+    ) -> Option<T> {
+        let width = self.size.width();
+        let height = self.size.height();
+        let row_len = width as usize * self.format.bytes_per_pixel();
+
+        if self.format == PixelFormat::RgbaPremultiplied && self.stride == row_len {
+            let len = row_len * height as usize;
+            let mut pixmap =
+                tiny_skia::PixmapMut::from_bytes(&mut self.data[..len], width, height)?;
+            return Some(f(&mut pixmap));
+        }
+
+        let mut pixmap = pixmap_pool.take_or_allocate(width, height)?;
+        for (src, dst) in self
+            .data
+            .chunks(self.stride)
+            .zip(pixmap.pixels_mut().chunks_exact_mut(width as usize))
+        {
+            load_row(&src[..row_len], self.format, dst);
+        }
+
+        let result = f(&mut pixmap.as_mut());
+
+        for (src, dst) in pixmap
+            .pixels()
+            .chunks_exact(width as usize)
+            .zip(self.data.chunks_mut(self.stride))
+        {
+            store_row(src, self.format, &mut dst[..row_len]);
+        }
+
+        pixmap_pool.release(pixmap);
+        Some(result)
+    }
+}
+
+/// Converts `src` bytes in the specified format into premultiplied RGBA pixels.
+fn load_row(src: &[u8], format: PixelFormat, dst: &mut [PremultipliedColorU8]) {
+    for (d, s) in dst
+        .iter_mut()
+        .zip(src.chunks_exact(format.bytes_per_pixel()))
+    {
+        *d = load_pixel(s, format);
+    }
+}
+
+#[inline]
+fn load_pixel(s: &[u8], format: PixelFormat) -> PremultipliedColorU8 {
+    let [r, g, b, a] = format.from_bytes([s[0], s[1], s[2], s[3]]);
+    if format.is_premultiplied() {
+        // Channels larger than alpha are invalid, so clamp them.
+        PremultipliedColorU8::from_rgba(r.min(a), g.min(a), b.min(a), a).unwrap()
+    } else {
+        ColorU8::from_rgba(r, g, b, a).premultiply()
+    }
+}
+
+/// Converts premultiplied RGBA pixels back into `dst` bytes previously loaded by [`load_row`].
+///
+/// Pixels that are the same as the loaded ones are left untouched.
+fn store_row(src: &[PremultipliedColorU8], format: PixelFormat, dst: &mut [u8]) {
+    for (d, p) in dst.chunks_exact_mut(format.bytes_per_pixel()).zip(src) {
+        if load_pixel(d, format) != *p {
+            convert_row(std::slice::from_ref(p), format, d);
+        }
+    }
+}
+
+/// Converts premultiplied RGBA pixels into `dst` bytes in the specified format.
+pub(crate) fn convert_row(src: &[PremultipliedColorU8], format: PixelFormat, dst: &mut [u8]) {
+    let dst = dst.chunks_exact_mut(format.bytes_per_pixel());
+    if format.is_premultiplied() {
+        for (d, p) in dst.zip(src) {
+            d.copy_from_slice(&format.to_bytes([p.red(), p.green(), p.blue(), p.alpha()]));
+        }
+    } else {
+        for (d, p) in dst.zip(src) {
+            let c = p.demultiply();
+            d.copy_from_slice(&format.to_bytes([c.red(), c.green(), c.blue(), c.alpha()]));
+        }
+    }
+}
+
+/// Writes pixmap pixels in the specified format, row by row, without any header.
+pub fn write_raw<W: Write>(
+    pixmap: &tiny_skia::Pixmap,
+    format: PixelFormat,
+    writer: &mut W,
+) -> io::Result<()> {
+    let width = pixmap.width() as usize;
+    let mut row = vec![0; width * format.bytes_per_pixel()];
+    for src in pixmap.pixels().chunks_exact(width) {
+        convert_row(src, format, &mut row);
+        writer.write_all(&row)?;
+    }
+
+    Ok(())
+}
+
+/// A chroma subsampling of a YUV4MPEG2 stream.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Y4mChroma {
+    /// 4:2:0, each chroma sample covers 2x2 pixels.
+    #[default]
+    I420,
+    /// 4:4:4, no subsampling.
+    I444,
+}
+
+/// A YUV4MPEG2 (y4m) stream writer.
+///
+/// Pixels are converted using BT.601 limited range coefficients.
+/// Alpha is ignored, so transparent areas become black.
+/// Use [`RenderOptions::background`](crate::RenderOptions::background)
+/// to render onto a different color.
+pub struct Y4mWriter<W: Write> {
+    writer: W,
+    width: u32,
+    height: u32,
+    chroma: Y4mChroma,
+    planes: [Vec<u8>; 3],
+}
+
+impl<W: Write> Y4mWriter<W> {
+    /// Creates a new stream and writes its header.
+    ///
+    /// `frame_rate` is a numerator and a denominator, like `(30000, 1001)`.
+    pub fn new(
+        mut writer: W,
+        size: tiny_skia::IntSize,
+        frame_rate: (u32, u32),
+        chroma: Y4mChroma,
+    ) -> io::Result<Self> {
+        let colorspace = match chroma {
+            Y4mChroma::I420 => "420jpeg",
+            Y4mChroma::I444 => "444",
+        };
+
+        writeln!(
+            writer,
+            "YUV4MPEG2 W{} H{} F{}:{} Ip A1:1 C{}",
+            size.width(),
+            size.height(),
+            frame_rate.0,
+            frame_rate.1,
+            colorspace
+        )?;
+
+        Ok(Y4mWriter {
+            writer,
+            width: size.width(),
+            height: size.height(),
+            chroma,
+            planes: Default::default(),
+        })
+    }
+
+    /// Converts and writes a single frame.
+    ///
+    /// Returns an `InvalidInput` error when the pixmap size doesn't match the stream size.
+    pub fn write_frame(&mut self, pixmap: &tiny_skia::Pixmap) -> io::Result<()> {
+        if pixmap.width() != self.width || pixmap.height() != self.height {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "frame size {}x{} doesn't match the stream size {}x{}",
+                    pixmap.width(),
+                    pixmap.height(),
+                    self.width,
+                    self.height
+                ),
+            ));
+        }
+
+        let width = self.width as usize;
+        let height = self.height as usize;
+        let pixels = pixmap.pixels();
+
+        let [y_plane, u_plane, v_plane] = &mut self.planes;
+        y_plane.clear();
+        y_plane.extend(pixels.iter().map(|p| luma(rgb(p))));
+
+        u_plane.clear();
+        v_plane.clear();
+        match self.chroma {
+            Y4mChroma::I444 => {
+                for p in pixels {
+                    let (u, v) = chroma(rgb(p));
+                    u_plane.push(u);
+                    v_plane.push(v);
+                }
+            }
+            Y4mChroma::I420 => {
+                for y in (0..height).step_by(2) {
+                    for x in (0..width).step_by(2) {
+                        // Average the 2x2 block, repeating the last row and column
+                        // for odd sizes.
+                        let mut sum = [0u32; 3];
+                        for (dx, dy) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+                            let px = (x + dx).min(width - 1);
+                            let py = (y + dy).min(height - 1);
+                            let c = rgb(&pixels[py * width + px]);
+                            for (s, c) in sum.iter_mut().zip(c) {
+                                *s += c as u32;
+                            }
+                        }
+
+                        let (u, v) = chroma(sum.map(|s| ((s + 2) / 4) as u8));
+                        u_plane.push(u);
+                        v_plane.push(v);
+                    }
+                }
+            }
+        }
+
+        self.writer.write_all(b"FRAME\n")?;
+        for plane in &self.planes {
+            self.writer.write_all(plane)?;
+        }
+
+        Ok(())
+    }
+
+    /// Flushes the underlying writer and returns it.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+#[inline]
+fn rgb(p: &PremultipliedColorU8) -> [u8; 3] {
+    [p.red(), p.green(), p.blue()]
+}
+
+#[inline]
+fn luma([r, g, b]: [u8; 3]) -> u8 {
+    let (r, g, b) = (r as i32, g as i32, b as i32);
+    (((66 * r + 129 * g + 25 * b + 128) >> 8) + 16) as u8
+}
+
+#[inline]
+fn chroma([r, g, b]: [u8; 3]) -> (u8, u8) {
+    let (r, g, b) = (r as i32, g as i32, b as i32);
+    let u = ((-38 * r - 74 * g + 112 * b + 128) >> 8) + 128;
+    let v = ((112 * r - 94 * g - 18 * b + 128) >> 8) + 128;
+    (u as u8, v as u8)
+}
@@ -49,17 +49,90 @@ pub fn fill_path(
         usvgr::FillRule::EvenOdd => tiny_skia::FillRule::EvenOdd,
     };
 
+    fill_with_paint(
+        path.data(),
+        fill.paint(),
+        fill.opacity(),
+        rule,
+        path.rendering_mode().use_shape_antialiasing(),
+        blend_mode,
+        path.bounding_box(),
+        ctx,
+        transform,
+        pixmap,
+        cache,
+        pixmap_pool,
+    )
+}
+
+/// Fills a path with an arbitrary paint.
+///
+/// `bbox` is the paint area in user space and is used only for manually rendered gradients.
+pub fn fill_with_paint(
+    data: &tiny_skia::Path,
+    paint_src: &usvgr::Paint,
+    opacity: usvgr::Opacity,
+    rule: tiny_skia::FillRule,
+    anti_alias: bool,
+    blend_mode: tiny_skia::BlendMode,
+    bbox: tiny_skia::Rect,
+    ctx: &Context,
+    transform: tiny_skia::Transform,
+    pixmap: &mut tiny_skia::PixmapMut,
+    cache: &mut crate::cache::SvgrCache,
+    pixmap_pool: &crate::cache::PixmapPool,
+) -> Option<()> {
     let pattern_pixmap;
     let mut paint = tiny_skia::Paint::default();
-    match fill.paint() {
+    match paint_src {
         usvgr::Paint::Color(c) => {
-            paint.set_color_rgba8(c.red, c.green, c.blue, fill.opacity().to_u8());
+            paint.set_color_rgba8(c.red, c.green, c.blue, opacity.to_u8());
         }
-        usvgr::Paint::LinearGradient(ref lg) => {
-            paint.shader = convert_linear_gradient(lg, fill.opacity())?;
+        usvgr::Paint::LinearGradient(ref lg)
+            if !crate::gradient::needs_manual_rendering(lg, ctx) =>
+        {
+            paint.shader = convert_linear_gradient(lg, opacity)?;
         }
-        usvgr::Paint::RadialGradient(ref rg) => {
-            paint.shader = convert_radial_gradient(rg, fill.opacity())?;
+        usvgr::Paint::RadialGradient(ref rg)
+            if !crate::gradient::needs_manual_rendering(rg, ctx) =>
+        {
+            paint.shader = convert_radial_gradient(rg, opacity)?;
+        }
+        usvgr::Paint::LinearGradient(_) | usvgr::Paint::RadialGradient(_) => {
+            let bbox = bbox.transform(transform)?;
+            let canvas = tiny_skia::IntSize::from_wh(pixmap.width(), pixmap.height())?;
+            let (grad_pix, grad_ts) = crate::gradient::render_gradient_pixmap(
+                paint_src,
+                opacity,
+                ctx,
+                transform,
+                bbox,
+                canvas,
+                cache,
+                pixmap_pool,
+            )?;
+
+            pattern_pixmap = grad_pix;
+            paint.shader = tiny_skia::Pattern::new(
+                pattern_pixmap.as_ref(),
+                tiny_skia::SpreadMode::Pad,
+                tiny_skia::FilterQuality::Nearest,
+                1.0,
+                grad_ts,
+            )
+        }
+        usvgr::Paint::MeshGradient(ref mg) => {
+            let (mesh_pix, mesh_ts) =
+                crate::mesh_gradient::render_mesh_pixmap(mg, transform, cache, pixmap_pool)?;
+
+            pattern_pixmap = mesh_pix;
+            paint.shader = tiny_skia::Pattern::new(
+                pattern_pixmap.as_ref(),
+                tiny_skia::SpreadMode::Pad,
+                tiny_skia::FilterQuality::Bilinear,
+                opacity.get(),
+                mesh_ts,
+            )
         }
         usvgr::Paint::Pattern(ref pattern) => {
             let (patt_pix, patt_ts) =
@@ -70,15 +143,15 @@ pub fn fill_path(
                 pattern_pixmap.as_ref(),
                 tiny_skia::SpreadMode::Repeat,
                 tiny_skia::FilterQuality::Bicubic,
-                fill.opacity().get(),
+                opacity.get(),
                 patt_ts,
             )
         }
     }
-    paint.anti_alias = path.rendering_mode().use_shape_antialiasing();
+    paint.anti_alias = anti_alias;
     paint.blend_mode = blend_mode;
 
-    pixmap.fill_path(path.data(), &paint, rule, transform, None);
+    pixmap.fill_path(data, &paint, rule, transform, None);
     Some(())
 }
 
@@ -98,12 +171,59 @@ fn stroke_path<'a>(
         usvgr::Paint::Color(c) => {
             paint.set_color_rgba8(c.red, c.green, c.blue, stroke.opacity().to_u8());
         }
-        usvgr::Paint::LinearGradient(ref lg) => {
+        usvgr::Paint::LinearGradient(ref lg)
+            if !crate::gradient::needs_manual_rendering(lg, ctx) =>
+        {
             paint.shader = convert_linear_gradient(lg, stroke.opacity())?;
         }
-        usvgr::Paint::RadialGradient(ref rg) => {
+        usvgr::Paint::RadialGradient(ref rg)
+            if !crate::gradient::needs_manual_rendering(rg, ctx) =>
+        {
             paint.shader = convert_radial_gradient(rg, stroke.opacity())?;
         }
+        usvgr::Paint::LinearGradient(_) | usvgr::Paint::RadialGradient(_) => {
+            let bbox = if path.vector_effect() == usvgr::VectorEffect::NonScalingStroke {
+                let outset = path.non_scaling_stroke_outset();
+                path.bounding_box()
+                    .transform(transform)?
+                    .outset(outset, outset)?
+            } else {
+                path.stroke_bounding_box().transform(transform)?
+            };
+            let canvas = tiny_skia::IntSize::from_wh(pixmap.width(), pixmap.height())?;
+            let (grad_pix, grad_ts) = crate::gradient::render_gradient_pixmap(
+                stroke.paint(),
+                stroke.opacity(),
+                ctx,
+                transform,
+                bbox,
+                canvas,
+                cache,
+                pixmap_pool,
+            )?;
+
+            pattern_pixmap = grad_pix;
+            paint.shader = tiny_skia::Pattern::new(
+                pattern_pixmap.as_ref(),
+                tiny_skia::SpreadMode::Pad,
+                tiny_skia::FilterQuality::Nearest,
+                1.0,
+                grad_ts,
+            )
+        }
+        usvgr::Paint::MeshGradient(ref mg) => {
+            let (mesh_pix, mesh_ts) =
+                crate::mesh_gradient::render_mesh_pixmap(mg, transform, cache, pixmap_pool)?;
+
+            pattern_pixmap = mesh_pix;
+            paint.shader = tiny_skia::Pattern::new(
+                pattern_pixmap.as_ref(),
+                tiny_skia::SpreadMode::Pad,
+                tiny_skia::FilterQuality::Bilinear,
+                stroke.opacity().get(),
+                mesh_ts,
+            )
+        }
         usvgr::Paint::Pattern(ref pattern) => {
             let (patt_pix, patt_ts) =
                 render_pattern_pixmap(pattern, ctx, transform, cache, pixmap_pool)?;
@@ -121,6 +241,22 @@ fn stroke_path<'a>(
     paint.anti_alias = path.rendering_mode().use_shape_antialiasing();
     paint.blend_mode = blend_mode;
 
+    if path.vector_effect() == usvgr::VectorEffect::NonScalingStroke {
+        // A non-scaling stroke is applied in the canvas coordinates.
+        // So we have to transform the path and the paint beforehand
+        // and then stroke it without any transform.
+        let data = path.data().clone().transform(transform)?;
+        paint.shader.transform(transform);
+        pixmap.stroke_path(
+            &data,
+            &paint,
+            &stroke.to_tiny_skia(),
+            tiny_skia::Transform::default(),
+            None,
+        );
+        return Some(());
+    }
+
     pixmap.stroke_path(path.data(), &paint, &stroke.to_tiny_skia(), transform, None);
     Some(())
 }
@@ -215,9 +351,10 @@ fn render_pattern_pixmap<'a>(
                 transform = transform.pre_concat(ts);
             }
 
+            // Pattern content is not a part of the background image.
             crate::render::render_nodes(
                 pattern.root(),
-                ctx,
+                &ctx.with_background(None),
                 transform,
                 &mut pixmap.as_mut(),
                 cache,
@@ -2,16 +2,36 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
+use std::sync::Arc;
+
 /// General context for the rendering.
+#[derive(Clone)]
 pub struct Context {
     /// The max bounding box for the whole SVG.
     pub max_bbox: tiny_skia::IntRect,
+    /// Gradients dithering mode.
+    pub(crate) gradient_dithering: crate::GradientDithering,
+    /// User-defined filter primitives.
+    pub(crate) custom_filters: Option<std::sync::Arc<crate::CustomFilters>>,
+    /// Pixel format of intermediate filter results.
+    pub(crate) filter_precision: crate::FilterPrecision,
+    /// Content of the `enable-background="new"` ancestor rendered so far,
+    /// excluding the current layer. In the current layer coordinates.
+    ///
+    /// Set only when the current layer content has background users.
+    pub(crate) background: Option<Background>,
+    /// Whether the floating point renderer is used.
+    pub(crate) hdr: bool,
 }
 
 impl Context {
     /// Default implementation of the max bounding box is 4 times the size of the pixmap.
     pub fn new_from_pixmap(pixmap: &tiny_skia::Pixmap) -> Self {
-        let target_size = tiny_skia::IntSize::from_wh(pixmap.width(), pixmap.height()).unwrap();
+        Self::new_from_size(tiny_skia::IntSize::from_wh(pixmap.width(), pixmap.height()).unwrap())
+    }
+
+    /// Same as [`Context::new_from_pixmap`], but for a render target of the specified size.
+    pub fn new_from_size(target_size: tiny_skia::IntSize) -> Self {
         let max_bbox = tiny_skia::IntRect::from_xywh(
             -(target_size.width() as i32) * 2,
             -(target_size.height() as i32) * 2,
@@ -20,7 +40,14 @@ impl Context {
         )
         .unwrap();
 
-        Self { max_bbox }
+        Self {
+            max_bbox,
+            gradient_dithering: crate::GradientDithering::None,
+            custom_filters: None,
+            filter_precision: crate::FilterPrecision::U8,
+            background: None,
+            hdr: false,
+        }
     }
 
     /// Unsafe but faster max bbox which might cut some filters and masks.
@@ -28,10 +55,54 @@ impl Context {
         let max_bbox =
             tiny_skia::IntRect::from_xywh(0, 0, pixmap.width(), pixmap.height()).unwrap();
 
-        Self { max_bbox }
+        Self {
+            max_bbox,
+            gradient_dithering: crate::GradientDithering::None,
+            custom_filters: None,
+            filter_precision: crate::FilterPrecision::U8,
+            background: None,
+            hdr: false,
+        }
+    }
+
+    /// Sets the gradients dithering mode.
+    pub fn with_gradient_dithering(mut self, dithering: crate::GradientDithering) -> Self {
+        self.gradient_dithering = dithering;
+        self
+    }
+
+    /// Sets the user-defined filter primitives registry.
+    pub fn with_custom_filters(mut self, filters: std::sync::Arc<crate::CustomFilters>) -> Self {
+        self.custom_filters = Some(filters);
+        self
+    }
+
+    /// Sets the pixel format of intermediate filter results.
+    pub fn with_filter_precision(mut self, precision: crate::FilterPrecision) -> Self {
+        self.filter_precision = precision;
+        self
+    }
+
+    /// Returns a context with a different background image.
+    pub(crate) fn with_background(&self, background: Option<Background>) -> Self {
+        Self {
+            background,
+            ..self.clone()
+        }
     }
 }
 
+/// A background image used by `BackgroundImage` and `BackgroundAlpha` filter inputs.
+///
+/// Layers between the current one and the `enable-background="new"` ancestor
+/// cannot change while the current layer is being rendered,
+/// therefore they are composited once, when a new layer is started.
+#[derive(Clone)]
+pub(crate) enum Background {
+    U8(Arc<tiny_skia::Pixmap>),
+    F32(Arc<crate::filter::PixmapF32>),
+}
+
 pub fn render_nodes(
     parent: &usvgr::Group,
     ctx: &Context,
@@ -69,7 +140,7 @@ pub fn render_node(
             );
         }
         usvgr::Node::Image(ref image) => {
-            crate::image::render(image, transform, pixmap, cache, pixmap_pool);
+            crate::image::render(image, ctx, transform, pixmap, cache, pixmap_pool);
         }
         usvgr::Node::Text(ref text) => {
             render_group(text.flattened(), ctx, transform, pixmap, cache, pixmap_pool);
@@ -92,7 +163,7 @@ fn render_group(
         render_nodes(group, ctx, final_transform, pixmap, cache, pixmap_pool);
     } else {
         // Calculate the bounding box of the group's content *after* the final transform.
-        let final_bbox = group.layer_bounding_box().transform(final_transform)?;
+        let final_bbox = crate::geom::layer_bbox(group, final_transform)?;
         let mut ibbox = if group.filters().is_empty() {
             tiny_skia::IntRect::from_xywh(
                 final_bbox.x().floor() as i32 - 2,
@@ -106,10 +177,13 @@ fn render_group(
         ibbox = crate::geom::fit_to_rect(ibbox, ctx.max_bbox)?;
 
         // Check if the isolated group has any effects (filters, clip path, mask)
+        // or a background image, which is rendered with the final transform as well.
         let require_transform_before_subsampling = !group.filters().is_empty()
             || group.clip_path().is_some()
             || group.mask().is_some()
-            || !group.abs_transform().is_identity();
+            || !group.abs_transform().is_identity()
+            || group.enable_background()
+            || (ctx.background.is_some() && needs_background(group));
 
         if require_transform_before_subsampling {
             // Tier 2: Isolated group which requires transform before filtering or clipping
@@ -160,15 +234,33 @@ fn render_isolated_group_with_effects(
         tiny_skia::Transform::from_translate(-(ibbox.x() as f32), -(ibbox.y() as f32))
             .pre_concat(final_transform); // Use final_transform here
 
+    // The background is the ancestors background combined with the current layer.
+    // Since the background isn't a part of the group, it must be a part of the cache key as well.
+    let background = match ctx.background {
+        Some(Background::U8(ref ancestors)) if needs_background(group) => {
+            Some(Arc::new(background_image(ancestors, pixmap, ibbox)?))
+        }
+        _ => None,
+    };
+
+    let children_background = if group.enable_background() {
+        let empty = tiny_skia::Pixmap::new(ibbox.width(), ibbox.height())?;
+        Some(Background::U8(Arc::new(empty)))
+    } else if group.has_background_users() {
+        background.clone().map(Background::U8)
+    } else {
+        None
+    };
+
     let sub_pixmap = cache.with_subpixmap_cache(
-        group,
+        &(group, background.as_ref().map(|p| p.data())),
         final_transform, // cache is invalidated every time the transform changed
         pixmap_pool,
         ibbox.size(),
         |mut sub_pixmap, cache| {
             render_nodes(
                 group,
-                ctx,
+                &ctx.with_background(children_background),
                 render_transform_in_subpixmap,
                 &mut sub_pixmap.as_mut(),
                 cache,
@@ -179,8 +271,11 @@ fn render_isolated_group_with_effects(
                 for filter in group.filters() {
                     crate::filter::apply(
                         filter,
+                        group,
+                        ctx,
                         render_transform_in_subpixmap,
                         &mut sub_pixmap,
+                        background.as_deref(),
                         cache,
                         pixmap_pool,
                     );
@@ -190,6 +285,7 @@ fn render_isolated_group_with_effects(
             if let Some(clip_path) = group.clip_path() {
                 crate::clip::apply(
                     clip_path,
+                    ctx,
                     render_transform_in_subpixmap,
                     &mut sub_pixmap,
                     cache,
@@ -254,7 +350,7 @@ fn render_isolated_group_without_effects(
 
             render_nodes(
                 group,
-                ctx,
+                &ctx.with_background(None),
                 render_transform_in_subpixmap,
                 &mut sub_pixmap.as_mut(),
                 cache,
@@ -283,6 +379,39 @@ fn render_isolated_group_without_effects(
     Some(())
 }
 
+pub fn has_background_input(group: &usvgr::Group) -> bool {
+    group.filters().iter().any(|f| {
+        f.has_input(&usvgr::filter::Input::BackgroundImage)
+            || f.has_input(&usvgr::filter::Input::BackgroundAlpha)
+    })
+}
+
+/// Checks that the group or its children are using the background image under the group.
+pub(crate) fn needs_background(group: &usvgr::Group) -> bool {
+    has_background_input(group) || (!group.enable_background() && group.has_background_users())
+}
+
+/// Composites the ancestors background and the current layer into a group layer sized image.
+fn background_image(
+    ancestors: &tiny_skia::Pixmap,
+    layer: &tiny_skia::PixmapMut,
+    ibbox: tiny_skia::IntRect,
+) -> Option<tiny_skia::Pixmap> {
+    let mut background = tiny_skia::Pixmap::new(ibbox.width(), ibbox.height())?;
+    for image in [ancestors.as_ref(), layer.as_ref()] {
+        background.draw_pixmap(
+            -ibbox.x(),
+            -ibbox.y(),
+            image,
+            &tiny_skia::PixmapPaint::default(),
+            tiny_skia::Transform::identity(),
+            None,
+        );
+    }
+
+    Some(background)
+}
+
 pub trait TinySkiaPixmapMutExt {
     fn create_rect_mask(
         &self,
@@ -0,0 +1,298 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+fn run(args: &[&str], stdin: &[u8]) -> std::process::Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_svgr"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(stdin).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn y4m_to_stdout_has_no_diagnostics() {
+    let svg = std::fs::read("tests/extra/render-options.svg").unwrap();
+    let output = run(&["-", "-c", "--format", "y4m"], &svg);
+
+    assert!(output.status.success());
+    assert!(output.stdout.starts_with(b"YUV4MPEG2 "));
+    // The stdin warning goes to stderr.
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--resources-dir"));
+}
+
+/// Creates an empty directory for a test in the cargo temporary directory.
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR"))
+        .join("cli")
+        .join(name);
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Writes a 2x2 SVG filled with a gray level.
+fn write_svg(path: &Path, level: u8) {
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    let svg = format!(
+        "<svg xmlns='http://www.w3.org/2000/svg' width='2' height='2'>\
+         <rect width='2' height='2' fill='rgb({0},{0},{0})'/></svg>",
+        level
+    );
+    std::fs::write(path, svg).unwrap();
+}
+
+fn list_dir(dir: &Path) -> Vec<String> {
+    let mut names: Vec<_> = std::fs::read_dir(dir)
+        .unwrap()
+        .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+        .collect();
+    names.sort();
+    names
+}
+
+fn path_str(path: &Path) -> &str {
+    path.to_str().unwrap()
+}
+
+#[test]
+fn batch_expands_directories_and_manifests() {
+    let dir = temp_dir("batch-inputs");
+    write_svg(&dir.join("frames/b.svg"), 0);
+    write_svg(&dir.join("frames/a.svg"), 0);
+    std::fs::write(dir.join("frames/notes.txt"), "").unwrap();
+    write_svg(&dir.join("extra/c.svg"), 0);
+    write_svg(&dir.join("lists/d.svg"), 0);
+    std::fs::write(
+        dir.join("lists/manifest.txt"),
+        "# relative to the manifest\n../extra/c.svg\n\n  d.svg  \n#e.svg\n",
+    )
+    .unwrap();
+
+    let out = dir.join("out");
+    let output = run(
+        &[
+            "-j",
+            "1",
+            "--out-pattern",
+            path_str(&out.join("%05d-%s.png")),
+            "--manifest",
+            path_str(&dir.join("lists/manifest.txt")),
+            path_str(&dir.join("frames")),
+        ],
+        b"",
+    );
+
+    assert!(output.status.success());
+    assert_eq!(
+        list_dir(&out),
+        ["00000-a.png", "00001-b.png", "00002-c.png", "00003-d.png"]
+    );
+}
+
+#[test]
+fn batch_out_pattern_placeholders() {
+    let dir = temp_dir("batch-out-pattern");
+    write_svg(&dir.join("in/first.svg"), 0);
+    write_svg(&dir.join("in/second.svg"), 0);
+
+    let out = dir.join("out");
+    let output = run(
+        &[
+            "--out-pattern",
+            path_str(&out.join("100%%-%d-%s-%03d%x.png")),
+            path_str(&dir.join("in/first.svg")),
+            path_str(&dir.join("in/second.svg")),
+        ],
+        b"",
+    );
+
+    assert!(output.status.success());
+    assert_eq!(
+        list_dir(&out),
+        ["100%-0-first-000%x.png", "100%-1-second-001%x.png"]
+    );
+}
+
+#[test]
+fn batch_rejects_shared_output_names() {
+    let dir = temp_dir("batch-shared-output");
+    write_svg(&dir.join("a/frame.svg"), 0);
+    write_svg(&dir.join("b/frame.svg"), 0);
+
+    let out = dir.join("out");
+    let output = run(
+        &[
+            "--out-pattern",
+            path_str(&out.join("%s.png")),
+            path_str(&dir.join("a")),
+            path_str(&dir.join("b")),
+        ],
+        b"",
+    );
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("the same output path"));
+    assert!(!out.exists());
+
+    // Without placeholders, only a stream format can take several inputs.
+    let output = run(
+        &[
+            "--out-pattern",
+            path_str(&out.join("frame.png")),
+            path_str(&dir.join("a")),
+            path_str(&dir.join("b")),
+        ],
+        b"",
+    );
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(!out.exists());
+}
+
+#[test]
+fn batch_exit_code_with_a_failed_file() {
+    let dir = temp_dir("batch-failed-file");
+    for i in 0..6 {
+        write_svg(&dir.join(format!("in/{}.svg", i)), 0);
+    }
+    std::fs::write(dir.join("in/3.svg"), "<svg").unwrap();
+
+    for jobs in ["1", "4"] {
+        let out = dir.join(format!("out-{}", jobs));
+        let output = run(
+            &[
+                "-j",
+                jobs,
+                "--out-pattern",
+                path_str(&out.join("%s.png")),
+                path_str(&dir.join("in")),
+            ],
+            b"",
+        );
+
+        // The rest of the batch is still rendered.
+        assert_eq!(output.status.code(), Some(2));
+        assert!(String::from_utf8_lossy(&output.stderr).contains("1 of 6 files failed"));
+        assert_eq!(
+            list_dir(&out),
+            ["0.png", "1.png", "2.png", "4.png", "5.png"]
+        );
+    }
+}
+
+#[test]
+fn batch_stream_keeps_frames_order() {
+    let dir = temp_dir("batch-stream");
+    let levels = [10, 20, 30, 40, 50, 60, 70, 80];
+    for (i, level) in levels.iter().enumerate() {
+        write_svg(&dir.join(format!("in/{}.svg", i)), *level);
+    }
+    std::fs::write(dir.join("in/5.svg"), "<svg").unwrap();
+
+    let out = dir.join("frames.rgba");
+    let output = run(
+        &[
+            "-j",
+            "4",
+            "--format",
+            "rgba",
+            "--out-pattern",
+            path_str(&out),
+            path_str(&dir.join("in")),
+        ],
+        b"",
+    );
+
+    // A failed frame is skipped.
+    assert_eq!(output.status.code(), Some(2));
+    let data = std::fs::read(&out).unwrap();
+    let frames: Vec<u8> = data.chunks(2 * 2 * 4).map(|frame| frame[0]).collect();
+    assert_eq!(frames, [10, 20, 30, 40, 50, 70, 80]);
+
+    // The same for y4m written to stdout.
+    std::fs::remove_file(dir.join("in/5.svg")).unwrap();
+    let output = run(
+        &[
+            "-j",
+            "4",
+            "--format",
+            "y4m",
+            "--chroma",
+            "444",
+            "--out-pattern",
+            "-c",
+            path_str(&dir.join("in")),
+        ],
+        b"",
+    );
+
+    assert!(output.status.success());
+    let header_end = output.stdout.iter().position(|b| *b == b'\n').unwrap();
+    assert!(output.stdout.starts_with(b"YUV4MPEG2 W2 H2 "));
+
+    let frames: Vec<_> = output.stdout[header_end + 1..]
+        .chunks(b"FRAME\n".len() + 2 * 2 * 3)
+        .map(|frame| {
+            assert!(frame.starts_with(b"FRAME\n"));
+            frame[b"FRAME\n".len()]
+        })
+        .collect();
+    assert_eq!(frames.len(), levels.len() - 1);
+    assert!(frames.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn batch_stream_with_a_slow_first_frame() {
+    let dir = temp_dir("batch-stream-slow");
+
+    // Thousands of same colored shapes make the first frame much slower than the others,
+    // so the workers have to wait for it instead of rendering all the following frames.
+    let shapes: String = (0..20000)
+        .map(|i| {
+            format!(
+                "<rect x='{}' width='0.5' height='2' fill='rgb(5,5,5)'/>",
+                i % 4 / 2
+            )
+        })
+        .collect();
+    std::fs::create_dir_all(dir.join("in")).unwrap();
+    std::fs::write(
+        dir.join("in/00.svg"),
+        format!(
+            "<svg xmlns='http://www.w3.org/2000/svg' width='2' height='2'>\
+             <rect width='2' height='2' fill='rgb(5,5,5)'/>{}</svg>",
+            shapes
+        ),
+    )
+    .unwrap();
+
+    let levels: Vec<u8> = (1..40).map(|i| 5 + i * 5).collect();
+    for (i, level) in levels.iter().enumerate() {
+        write_svg(&dir.join(format!("in/{:02}.svg", i + 1)), *level);
+    }
+
+    let out = dir.join("frames.rgba");
+    let output = run(
+        &[
+            "-j",
+            "4",
+            "--format",
+            "rgba",
+            "--out-pattern",
+            path_str(&out),
+            path_str(&dir.join("in")),
+        ],
+        b"",
+    );
+
+    assert!(output.status.success());
+    let data = std::fs::read(&out).unwrap();
+    let frames: Vec<u8> = data.chunks(2 * 2 * 4).map(|frame| frame[0]).collect();
+    assert_eq!(frames[0], 5);
+    assert_eq!(frames[1..], levels);
+}
@@ -1,4 +1,13 @@
-use crate::{render_extra, render_extra_with_scale, render_node};
+use crate::{
+    render_extra, render_extra_hdr, render_extra_with_cache, render_extra_with_custom_filters,
+    render_extra_with_dithering, render_extra_with_filter_precision, render_extra_with_options,
+    render_extra_with_scale, render_node, render_with_text_grouping, GLOBAL_FONTDB,
+};
+use svgr::{
+    ExportArea, FilterPrecision, FitTo, GradientDithering, HdrFormat, HdrOutput, HdrPixmap,
+    PixelFormat, PixmapPool, RenderOptions, SvgrCache, TransferFunction, Y4mChroma, Y4mWriter,
+};
+use usvgr::TextGrouping;
 
 #[test]
 fn group_with_only_transform() {
@@ -63,6 +72,14 @@ fn filter_region_precision() {
     );
 }
 
+#[test]
+fn non_scaling_stroke_in_isolated_group() {
+    assert_eq!(
+        render_extra_with_scale("extra/non-scaling-stroke-in-isolated-group", 0.25),
+        0
+    );
+}
+
 #[test]
 fn translate_outside_viewbox() {
     assert_eq!(render_extra("extra/translate-outside-viewbox"), 0);
@@ -77,3 +94,528 @@ fn render_node_filter_on_empty_group() {
 fn render_node_filter_with_transform_on_shape() {
     assert_eq!(render_node("extra/filter-with-transform-on-shape", "g1"), 0);
 }
+
+#[test]
+fn text_grouping_glyph() {
+    assert_eq!(
+        render_with_text_grouping("tests/text/tspan/tspan-bbox-1", TextGrouping::Glyph),
+        0
+    );
+}
+
+#[test]
+fn text_grouping_glyph_with_rotate() {
+    assert_eq!(
+        render_with_text_grouping(
+            "tests/text/text/rotate-with-multiple-values-and-complex-text",
+            TextGrouping::Glyph
+        ),
+        0
+    );
+}
+
+#[test]
+fn text_grouping_word_with_bidi() {
+    assert_eq!(
+        render_with_text_grouping("tests/text/tspan/bidi-reordering", TextGrouping::Word),
+        0
+    );
+}
+
+#[test]
+fn text_grouping_line_with_decorations() {
+    assert_eq!(
+        render_with_text_grouping(
+            "tests/text/text-decoration/indirect-with-multiple-colors",
+            TextGrouping::Line
+        ),
+        0
+    );
+}
+
+#[test]
+fn gradient_ordered_dithering() {
+    assert_eq!(
+        render_extra_with_dithering(
+            "extra/gradient-ordered-dithering",
+            GradientDithering::Ordered
+        ),
+        0
+    );
+}
+
+#[test]
+fn gradient_blue_noise_dithering() {
+    assert_eq!(
+        render_extra_with_dithering(
+            "extra/gradient-blue-noise-dithering",
+            GradientDithering::BlueNoise
+        ),
+        0
+    );
+}
+
+#[test]
+fn gradient_dithering_in_nested_image() {
+    assert_eq!(
+        render_extra_with_dithering(
+            "extra/gradient-dithering-in-nested-image",
+            GradientDithering::Ordered
+        ),
+        0
+    );
+}
+
+#[test]
+fn custom_filter() {
+    let mut filters = svgr::CustomFilters::new();
+    filters.register(
+        "mix",
+        |args: &[f32],
+         input1: &tiny_skia::Pixmap,
+         input2: Option<&tiny_skia::Pixmap>,
+         _: tiny_skia::Transform,
+         output: &mut tiny_skia::Pixmap| {
+            let t = args[0];
+            let input2 = input2.unwrap();
+            let pixels = input1.data().iter().zip(input2.data());
+            for (p, (a, b)) in output.data_mut().iter_mut().zip(pixels) {
+                *p = (*a as f32 * (1.0 - t) + *b as f32 * t).round() as u8;
+            }
+        },
+    );
+    filters.register(
+        "swap-rb",
+        |_: &[f32],
+         input1: &tiny_skia::Pixmap,
+         _: Option<&tiny_skia::Pixmap>,
+         _: tiny_skia::Transform,
+         output: &mut tiny_skia::Pixmap| {
+            output.data_mut().copy_from_slice(input1.data());
+            for p in output.data_mut().chunks_exact_mut(4) {
+                p.swap(0, 2);
+            }
+        },
+    );
+
+    assert_eq!(
+        render_extra_with_custom_filters("extra/custom-filter", filters),
+        0
+    );
+}
+
+#[test]
+fn filter_precision_f32() {
+    assert_eq!(
+        render_extra_with_filter_precision("extra/filter-precision-f32", FilterPrecision::F32),
+        0
+    );
+}
+
+#[test]
+fn filter_cache() {
+    assert_eq!(
+        render_extra_with_cache(&[
+            "extra/filter-cache-1",
+            "extra/filter-cache-2",
+            "extra/filter-cache-3",
+            "extra/filter-cache-1",
+        ]),
+        0
+    );
+}
+
+#[test]
+fn render_options_fit_to_width() {
+    let options = RenderOptions {
+        fit_to: FitTo::Width(100),
+        background: Some(svgr::tiny_skia::Color::WHITE),
+        ..RenderOptions::default()
+    };
+
+    assert_eq!(
+        render_extra_with_options(
+            "extra/render-options",
+            "extra/render-options-fit-to-width",
+            &options
+        ),
+        0
+    );
+}
+
+#[test]
+fn render_options_export_area_drawing() {
+    let options = RenderOptions {
+        area: ExportArea::Drawing,
+        ..RenderOptions::default()
+    };
+
+    assert_eq!(
+        render_extra_with_options(
+            "extra/render-options",
+            "extra/render-options-export-area-drawing",
+            &options
+        ),
+        0
+    );
+}
+
+#[test]
+fn render_options_node_on_page() {
+    let options = RenderOptions {
+        fit_to: FitTo::Zoom(2.0),
+        area: ExportArea::NodeOnPage("rect1".to_string()),
+        ..RenderOptions::default()
+    };
+
+    assert_eq!(
+        render_extra_with_options(
+            "extra/render-options",
+            "extra/render-options-node-on-page",
+            &options
+        ),
+        0
+    );
+}
+
+#[test]
+fn render_options_too_large() {
+    let svg_data = std::fs::read("tests/extra/render-options.svg").unwrap();
+    let tree = {
+        let db = GLOBAL_FONTDB.lock().unwrap();
+        usvgr::Tree::from_data(&svg_data, &usvgr::Options::default(), &db).unwrap()
+    };
+
+    for fit_to in [FitTo::Width(600_000_000), FitTo::Zoom(5_000_000.0)] {
+        let options = RenderOptions {
+            fit_to,
+            ..RenderOptions::default()
+        };
+
+        let result =
+            svgr::render_to_pixmap(&tree, &options, &mut SvgrCache::none(), &PixmapPool::new());
+        assert_eq!(result.err(), Some(svgr::RenderError::OutputTooLarge));
+    }
+}
+
+#[test]
+fn cache_stats() {
+    let svg_data = std::fs::read("tests/extra/filter-cache-1.svg").unwrap();
+    let tree = {
+        let db = GLOBAL_FONTDB.lock().unwrap();
+        usvgr::Tree::from_data(&svg_data, &usvgr::Options::default(), &db).unwrap()
+    };
+
+    let mut cache = SvgrCache::new(16);
+    let pixmap_pool = PixmapPool::new();
+    let options = RenderOptions::default();
+
+    svgr::render_to_pixmap(&tree, &options, &mut cache, &pixmap_pool).unwrap();
+    let stats = cache.stats();
+    assert_eq!(stats.node_hits, 0);
+    assert_ne!(stats.node_misses, 0);
+    assert_ne!(stats.filter_misses, 0);
+
+    svgr::render_to_pixmap(&tree, &options, &mut cache, &pixmap_pool).unwrap();
+    assert_ne!(cache.stats().node_hits, 0);
+
+    cache.reset_stats();
+    assert_eq!(cache.stats(), svgr::CacheStats::default());
+}
+
+#[test]
+fn mesh_gradient_cache_ignores_translation() {
+    let svg_data = std::fs::read("tests/tests/paint-servers/meshgradient/bilinear.svg").unwrap();
+    let tree = {
+        let db = GLOBAL_FONTDB.lock().unwrap();
+        usvgr::Tree::from_data(&svg_data, &usvgr::Options::default(), &db).unwrap()
+    };
+
+    let pixmap_pool = PixmapPool::new();
+    let render = |transform: tiny_skia::Transform, cache: &mut SvgrCache| {
+        let mut pixmap = tiny_skia::Pixmap::new(200, 200).unwrap();
+        let ctx = svgr::Context::new_from_pixmap(&pixmap);
+        svgr::render(
+            &tree,
+            transform,
+            &mut pixmap.as_mut(),
+            cache,
+            &pixmap_pool,
+            &ctx,
+        );
+        pixmap
+    };
+
+    let mut cache = SvgrCache::new(16);
+    render(tiny_skia::Transform::identity(), &mut cache);
+    cache.reset_stats();
+
+    let transform = tiny_skia::Transform::from_translate(10.5, -20.25);
+    let cached = render(transform, &mut cache);
+    assert_ne!(cache.stats().node_hits, 0);
+
+    let expected = render(transform, &mut SvgrCache::none());
+    assert!(cached.data() == expected.data());
+}
+
+#[test]
+fn raw_output() {
+    let mut pixmap = tiny_skia::Pixmap::new(2, 1).unwrap();
+    pixmap.fill(tiny_skia::Color::from_rgba8(255, 0, 0, 128));
+
+    let write = |format| {
+        let mut buf = Vec::new();
+        svgr::write_raw(&pixmap, format, &mut buf).unwrap();
+        buf
+    };
+
+    assert_eq!(
+        write(PixelFormat::RgbaPremultiplied),
+        [128, 0, 0, 128].repeat(2)
+    );
+    assert_eq!(
+        write(PixelFormat::BgraPremultiplied),
+        [0, 0, 128, 128].repeat(2)
+    );
+    assert_eq!(write(PixelFormat::Rgba), [255, 0, 0, 128].repeat(2));
+    assert_eq!(write(PixelFormat::Bgra), [0, 0, 255, 128].repeat(2));
+}
+
+#[test]
+fn render_to_buffer() {
+    let svg_data = std::fs::read("tests/extra/fill-and-stroke.svg").unwrap();
+    let tree = {
+        let db = GLOBAL_FONTDB.lock().unwrap();
+        usvgr::Tree::from_data(&svg_data, &usvgr::Options::default(), &db).unwrap()
+    };
+
+    let size = tree.size().to_int_size();
+    let (width, height) = (size.width(), size.height());
+    let ts = tiny_skia::Transform::default();
+    let pixmap_pool = PixmapPool::new();
+
+    let mut pixmap = tiny_skia::Pixmap::new(width, height).unwrap();
+    let ctx = svgr::Context::new_from_pixmap(&pixmap);
+    svgr::render(
+        &tree,
+        ts,
+        &mut pixmap.as_mut(),
+        &mut SvgrCache::none(),
+        &pixmap_pool,
+        &ctx,
+    );
+
+    let row_len = width as usize * 4;
+    let stride = row_len + 12;
+    for format in [
+        PixelFormat::RgbaPremultiplied,
+        PixelFormat::Bgra,
+        PixelFormat::Argb32Premultiplied,
+    ] {
+        let mut data = vec![0; stride * height as usize];
+        let mut buffer = svgr::PixelBuffer::new(&mut data, width, height, stride, format).unwrap();
+        svgr::render_to_buffer(
+            &tree,
+            ts,
+            &mut buffer,
+            &mut SvgrCache::none(),
+            &pixmap_pool,
+            &ctx,
+        )
+        .unwrap();
+
+        let mut expected = Vec::new();
+        svgr::write_raw(&pixmap, format, &mut expected).unwrap();
+
+        let actual: Vec<u8> = data
+            .chunks(stride)
+            .flat_map(|row| &row[..row_len])
+            .copied()
+            .collect();
+        assert_eq!(actual, expected, "{:?}", format);
+
+        // Padding must stay untouched.
+        assert!(data.chunks(stride).all(|row| row[row_len..] == [0; 12]));
+    }
+}
+
+#[test]
+fn render_to_buffer_keeps_untouched_pixels() {
+    let svg_data = std::fs::read("tests/extra/fill-and-stroke.svg").unwrap();
+    let tree = {
+        let db = GLOBAL_FONTDB.lock().unwrap();
+        usvgr::Tree::from_data(&svg_data, &usvgr::Options::default(), &db).unwrap()
+    };
+
+    let size = tree.size().to_int_size();
+    let (width, height) = (size.width(), size.height());
+    let ctx = svgr::Context::new_from_size(size);
+    let stride = width as usize * 4;
+
+    // A semi-transparent background which cannot survive a premultiplied round-trip.
+    let background = [200, 100, 50, 3];
+    for format in [PixelFormat::Rgba, PixelFormat::Bgra, PixelFormat::Argb32] {
+        let mut data = background.repeat((width * height) as usize);
+        let mut buffer = svgr::PixelBuffer::new(&mut data, width, height, stride, format).unwrap();
+        svgr::render_to_buffer(
+            &tree,
+            tiny_skia::Transform::default(),
+            &mut buffer,
+            &mut SvgrCache::none(),
+            &PixmapPool::new(),
+            &ctx,
+        )
+        .unwrap();
+
+        // Corners are outside of the drawing.
+        assert_eq!(data[..4], background, "{:?}", format);
+        assert_eq!(data[data.len() - 4..], background, "{:?}", format);
+        // And the rest was actually rendered.
+        assert!(data.chunks(4).any(|p| p != background), "{:?}", format);
+    }
+}
+
+#[test]
+fn pixel_buffer_size_validation() {
+    let mut data = vec![0; 40];
+    let format = PixelFormat::Rgba;
+    assert!(svgr::PixelBuffer::new(&mut data, 2, 2, 8, format).is_some());
+    // The last row doesn't need padding.
+    assert!(svgr::PixelBuffer::new(&mut data, 2, 2, 32, format).is_some());
+    assert!(svgr::PixelBuffer::new(&mut data, 2, 2, 36, format).is_none());
+    assert!(svgr::PixelBuffer::new(&mut data, 2, 2, 4, format).is_none());
+    assert!(svgr::PixelBuffer::new(&mut data, 0, 2, 8, format).is_none());
+}
+
+#[test]
+fn y4m_output() {
+    let mut pixmap = tiny_skia::Pixmap::new(3, 3).unwrap();
+    pixmap.fill(tiny_skia::Color::from_rgba8(255, 0, 0, 255));
+
+    let size = tiny_skia::IntSize::from_wh(3, 3).unwrap();
+    let mut writer = Y4mWriter::new(Vec::new(), size, (25, 1), Y4mChroma::I420).unwrap();
+    writer.write_frame(&pixmap).unwrap();
+    writer.write_frame(&pixmap).unwrap();
+
+    let small = tiny_skia::Pixmap::new(2, 2).unwrap();
+    assert!(writer.write_frame(&small).is_err());
+
+    let data = writer.finish().unwrap();
+    let header = b"YUV4MPEG2 W3 H3 F25:1 Ip A1:1 C420jpeg\n";
+    assert!(data.starts_with(header));
+
+    // 3x3 luma and 2x2 chroma planes.
+    let mut frame = b"FRAME\n".to_vec();
+    frame.extend([82; 9]);
+    frame.extend([90; 4]);
+    frame.extend([240; 4]);
+    assert_eq!(&data[header.len()..], frame.repeat(2).as_slice());
+}
+
+#[test]
+fn hdr_render() {
+    assert_eq!(render_extra_hdr("extra/hdr"), 0);
+}
+
+#[test]
+fn hdr_pixmap_too_large() {
+    assert!(HdrPixmap::new(0, 1).is_none());
+    assert!(HdrPixmap::new(u32::MAX, 1).is_none());
+    assert!(HdrPixmap::new(u32::MAX, u32::MAX).is_none());
+
+    let pixmap = HdrPixmap::new(3, 2).unwrap();
+    assert_eq!(pixmap.pixel(2, 1), Some([0.0; 4]));
+    assert_eq!(pixmap.pixel(3, 1), None);
+}
+
+#[test]
+fn hdr_output() {
+    let mut pixmap = HdrPixmap::new(1, 1).unwrap();
+    pixmap.fill(tiny_skia::Color::from_rgba(1.0, 0.5, 0.0, 0.5).unwrap());
+
+    let encode = |format, transfer, premultiplied| {
+        let output = HdrOutput {
+            format,
+            transfer,
+            premultiplied,
+            ..HdrOutput::default()
+        };
+        pixmap.encode(&output)
+    };
+
+    let u16s = |data: Vec<u8>| -> Vec<u16> {
+        data.chunks(2)
+            .map(|v| u16::from_le_bytes([v[0], v[1]]))
+            .collect()
+    };
+    let f32s = |data: Vec<u8>| -> Vec<f32> {
+        data.chunks(4)
+            .map(|v| f32::from_le_bytes([v[0], v[1], v[2], v[3]]))
+            .collect()
+    };
+
+    assert_eq!(
+        u16s(encode(HdrFormat::Rgba16, TransferFunction::Srgb, false)),
+        [65535, 32768, 0, 32768]
+    );
+    assert_eq!(
+        u16s(encode(HdrFormat::Rgba16, TransferFunction::Srgb, true)),
+        [32768, 16384, 0, 32768]
+    );
+    // 1.0 and 0.5 in half-precision.
+    assert_eq!(
+        u16s(encode(HdrFormat::RgbaF16, TransferFunction::Linear, false)),
+        [0x3C00, 0x32D9, 0, 0x3800]
+    );
+
+    let linear = f32s(encode(HdrFormat::RgbaF32, TransferFunction::Linear, false));
+    assert!((linear[1] - 0.214).abs() < 0.001);
+
+    // sRGB white is 203 nits.
+    let pq = f32s(encode(HdrFormat::RgbaF32, TransferFunction::Pq, false));
+    assert!((pq[0] - 0.58).abs() < 0.001);
+
+    // sRGB white is 75% of the HLG signal.
+    let hlg = f32s(encode(HdrFormat::RgbaF32, TransferFunction::Hlg, false));
+    assert!((hlg[0] - 0.75).abs() < 0.001);
+}
+
+#[test]
+fn hdr_gradient_precision() {
+    let svg = r##"
+    <svg viewBox="0 0 1000 1" xmlns="http://www.w3.org/2000/svg">
+        <linearGradient id="lg1">
+            <stop offset="0" stop-color="black"/>
+            <stop offset="1" stop-color="#080808"/>
+        </linearGradient>
+        <rect width="1000" height="1" fill="url(#lg1)"/>
+    </svg>
+    "##;
+
+    let tree = {
+        let db = GLOBAL_FONTDB.lock().unwrap();
+        usvgr::Tree::from_str(svg, &usvgr::Options::default(), &db).unwrap()
+    };
+
+    let mut pixmap = HdrPixmap::new(1000, 1).unwrap();
+    let ctx = svgr::Context::new_from_size(pixmap.size());
+    svgr::render_hdr(
+        &tree,
+        tiny_skia::Transform::default(),
+        &mut pixmap,
+        &mut SvgrCache::none(),
+        &PixmapPool::new(),
+        &ctx,
+    );
+
+    let data = pixmap.encode(&HdrOutput::default());
+    let mut reds: Vec<u16> = data
+        .chunks(8)
+        .map(|v| u16::from_le_bytes([v[0], v[1]]))
+        .collect();
+    reds.dedup();
+
+    // An 8-bit gradient has only 9 distinct values.
+    assert!(reds.len() > 500);
+    assert!(reds.windows(2).all(|v| v[0] < v[1]));
+}
@@ -7,6 +7,9 @@ use usvgr::fontdb;
 #[rustfmt::skip]
 mod render;
 
+#[rustfmt::skip]
+mod render_hdr;
+
 mod extra;
 
 const IMAGE_SIZE: u32 = 300;
@@ -59,6 +62,10 @@ static GLOBAL_SUB_SVGS: Lazy<std::sync::Arc<HashMap<String, Arc<usvgr::Tree>>>>
         "../../../resources/svg-with-text.svg".to_owned(),
         load_sub_svg("tests/resources/svg-with-text.svg"),
     );
+    hash_map.insert(
+        "gradient-ordered-dithering.svg".to_owned(),
+        load_sub_svg("tests/extra/gradient-ordered-dithering.svg"),
+    );
 
     std::sync::Arc::new(hash_map)
 });
@@ -83,10 +90,15 @@ fn load_image(path: &str) -> Arc<usvgr::PreloadedImageData> {
 }
 
 pub fn render(name: &str) -> usize {
+    render_with_text_grouping(name, usvgr::TextGrouping::Span)
+}
+
+/// Parses a reference suite test.
+fn load_test_tree(name: &str, text_grouping: usvgr::TextGrouping) -> usvgr::Tree {
     let svg_path = format!("tests/{}.svg", name);
-    let png_path = format!("tests/{}.png", name);
 
     let mut opt = usvgr::Options::default();
+    opt.text_grouping = text_grouping;
     opt.resources_dir = Some(
         std::path::PathBuf::from(&svg_path)
             .parent()
@@ -96,23 +108,32 @@ pub fn render(name: &str) -> usize {
     opt.image_data = Some(&GLOBAL_IMAGE_DATA);
     opt.sub_svg_data = Some(&GLOBAL_SUB_SVGS);
 
-    let tree = {
-        let svg_data = std::fs::read(&svg_path).unwrap();
-        let db = GLOBAL_FONTDB.lock().unwrap();
-        usvgr::Tree::from_data(&svg_data, &opt, &db).unwrap()
-    };
+    let svg_data = std::fs::read(&svg_path).unwrap();
+    let db = GLOBAL_FONTDB.lock().unwrap();
+    usvgr::Tree::from_data(&svg_data, &opt, &db).unwrap()
+}
 
+/// Returns a reference suite image size and a transform to render the tree with.
+fn test_render_size(tree: &usvgr::Tree) -> (tiny_skia::IntSize, tiny_skia::Transform) {
     let size = tree
         .size()
         .to_int_size()
         .scale_to_width(IMAGE_SIZE)
         .unwrap();
-    let mut pixmap = tiny_skia::Pixmap::new(size.width(), size.height()).unwrap();
-    let ctx = svgr::Context::new_from_pixmap(&pixmap);
     let render_ts = tiny_skia::Transform::from_scale(
-        size.width() as f32 / tree.size().width() as f32,
-        size.height() as f32 / tree.size().height() as f32,
+        size.width() as f32 / tree.size().width(),
+        size.height() as f32 / tree.size().height(),
     );
+    (size, render_ts)
+}
+
+pub fn render_with_text_grouping(name: &str, text_grouping: usvgr::TextGrouping) -> usize {
+    let png_path = format!("tests/{}.png", name);
+
+    let tree = load_test_tree(name, text_grouping);
+    let (size, render_ts) = test_render_size(&tree);
+    let mut pixmap = tiny_skia::Pixmap::new(size.width(), size.height()).unwrap();
+    let ctx = svgr::Context::new_from_pixmap(&pixmap);
     svgr::render(
         &tree,
         render_ts,
@@ -122,9 +143,11 @@ pub fn render(name: &str) -> usize {
         &ctx,
     );
 
-    pixmap
-        .save_png(&format!("tests/{}-actual.png", name))
-        .unwrap();
+    if text_grouping == usvgr::TextGrouping::Span {
+        pixmap
+            .save_png(&format!("tests/{}-actual.png", name))
+            .unwrap();
+    }
 
     let mut rgba = pixmap.take();
     demultiply_alpha(rgba.as_mut_slice().as_rgba_mut());
@@ -153,10 +176,71 @@ pub fn render(name: &str) -> usize {
 }
 
 pub fn render_extra_with_scale(name: &str, scale: f32) -> usize {
+    render_extra_impl(
+        name,
+        scale,
+        |ctx| ctx,
+        &mut SvgrCache::none(),
+        &PixmapPool::new(),
+    )
+}
+
+pub fn render_extra_with_dithering(name: &str, dithering: svgr::GradientDithering) -> usize {
+    render_extra_impl(
+        name,
+        1.0,
+        |ctx| ctx.with_gradient_dithering(dithering),
+        &mut SvgrCache::none(),
+        &PixmapPool::new(),
+    )
+}
+
+pub fn render_extra_with_custom_filters(name: &str, filters: svgr::CustomFilters) -> usize {
+    render_extra_impl(
+        name,
+        1.0,
+        |ctx| ctx.with_custom_filters(Arc::new(filters)),
+        &mut SvgrCache::none(),
+        &PixmapPool::new(),
+    )
+}
+
+pub fn render_extra_with_filter_precision(name: &str, precision: svgr::FilterPrecision) -> usize {
+    render_extra_impl(
+        name,
+        1.0,
+        |ctx| ctx.with_filter_precision(precision),
+        &mut SvgrCache::none(),
+        &PixmapPool::new(),
+    )
+}
+
+/// Renders files one after another, sharing a single cache and pixmap pool.
+pub fn render_extra_with_cache(names: &[&str]) -> usize {
+    let mut cache = SvgrCache::new(16);
+    let pixmap_pool = PixmapPool::new();
+
+    names
+        .iter()
+        .map(|name| render_extra_impl(name, 1.0, |ctx| ctx, &mut cache, &pixmap_pool))
+        .sum()
+}
+
+fn render_extra_impl(
+    name: &str,
+    scale: f32,
+    configure: impl FnOnce(svgr::Context) -> svgr::Context,
+    cache: &mut SvgrCache,
+    pixmap_pool: &PixmapPool,
+) -> usize {
     let svg_path = format!("tests/{}.svg", name);
     let png_path = format!("tests/{}.png", name);
 
-    let opt = usvgr::Options::default();
+    let opt = usvgr::Options {
+        image_data: Some(&GLOBAL_IMAGE_DATA),
+        sub_svg_data: Some(&GLOBAL_SUB_SVGS),
+        ..usvgr::Options::default()
+    };
 
     let tree = {
         let svg_data = std::fs::read(svg_path).unwrap();
@@ -168,13 +252,13 @@ pub fn render_extra_with_scale(name: &str, scale: f32) -> usize {
     let mut pixmap = tiny_skia::Pixmap::new(size.width(), size.height()).unwrap();
 
     let render_ts = tiny_skia::Transform::from_scale(scale, scale);
-    let ctx = svgr::Context::new_from_pixmap(&pixmap);
+    let ctx = configure(svgr::Context::new_from_pixmap(&pixmap));
     svgr::render(
         &tree,
         render_ts,
         &mut pixmap.as_mut(),
-        &mut SvgrCache::none(),
-        &PixmapPool::new(),
+        cache,
+        pixmap_pool,
         &ctx,
     );
 
@@ -210,6 +294,141 @@ pub fn render_extra(name: &str) -> usize {
     render_extra_with_scale(name, 1.0)
 }
 
+/// Renders a reference suite test using `svgr::render_hdr`
+/// and compares its 8-bit version with the reference image.
+///
+/// Returns the number of pixels with any channel difference larger than `tolerance`.
+pub fn render_hdr(name: &str, tolerance: u8) -> usize {
+    let png_path = format!("tests/{}.png", name);
+
+    let tree = load_test_tree(name, usvgr::TextGrouping::Span);
+    let (size, render_ts) = test_render_size(&tree);
+    let mut pixmap = svgr::HdrPixmap::new(size.width(), size.height()).unwrap();
+    let ctx = svgr::Context::new_from_size(size);
+    svgr::render_hdr(
+        &tree,
+        render_ts,
+        &mut pixmap,
+        &mut SvgrCache::none(),
+        &PixmapPool::new(),
+        &ctx,
+    );
+
+    // Compare premultiplied colors, since demultiplying amplifies
+    // rounding differences of nearly transparent pixels.
+    let rgba = pixmap.to_pixmap().take();
+
+    let mut expected_data = load_png(&png_path);
+    assert_eq!(expected_data.len(), rgba.len());
+    premultiply_alpha(expected_data.as_mut_slice().as_rgba_mut());
+
+    let tolerance = tolerance as i32;
+    expected_data
+        .as_rgba()
+        .iter()
+        .zip(rgba.as_rgba())
+        .filter(|(a, b)| {
+            (a.r as i32 - b.r as i32).abs() > tolerance
+                || (a.g as i32 - b.g as i32).abs() > tolerance
+                || (a.b as i32 - b.b as i32).abs() > tolerance
+                || (a.a as i32 - b.a as i32).abs() > tolerance
+        })
+        .count()
+}
+
+/// Renders `name` using `svgr::render_hdr` and compares its 8-bit version with the reference image.
+pub fn render_extra_hdr(name: &str) -> usize {
+    let svg_path = format!("tests/{}.svg", name);
+    let png_path = format!("tests/{}.png", name);
+
+    let opt = usvgr::Options::default();
+
+    let tree = {
+        let svg_data = std::fs::read(svg_path).unwrap();
+        let db = GLOBAL_FONTDB.lock().unwrap();
+        usvgr::Tree::from_data(&svg_data, &opt, &db).unwrap()
+    };
+
+    let size = tree.size().to_int_size();
+    let mut pixmap = svgr::HdrPixmap::new(size.width(), size.height()).unwrap();
+    let ctx = svgr::Context::new_from_size(size);
+    svgr::render_hdr(
+        &tree,
+        tiny_skia::Transform::default(),
+        &mut pixmap,
+        &mut SvgrCache::none(),
+        &PixmapPool::new(),
+        &ctx,
+    );
+    let pixmap = pixmap.to_pixmap();
+
+    // pixmap.save_png(&format!("tests/{}.png", name)).unwrap();
+
+    let mut rgba = pixmap.take();
+    demultiply_alpha(rgba.as_mut_slice().as_rgba_mut());
+
+    let expected_data = load_png(&png_path);
+    assert_eq!(expected_data.len(), rgba.len());
+
+    let mut pixels_d = 0;
+    for (a, b) in expected_data
+        .as_slice()
+        .as_rgba()
+        .iter()
+        .zip(rgba.as_rgba())
+    {
+        if is_pix_diff(*a, *b) {
+            pixels_d += 1;
+        }
+    }
+
+    pixels_d
+}
+
+/// Renders `name` using `svgr::render_to_pixmap` and compares it with the `reference` image.
+pub fn render_extra_with_options(
+    name: &str,
+    reference: &str,
+    options: &svgr::RenderOptions,
+) -> usize {
+    let svg_path = format!("tests/{}.svg", name);
+    let png_path = format!("tests/{}.png", reference);
+
+    let opt = usvgr::Options::default();
+
+    let tree = {
+        let svg_data = std::fs::read(svg_path).unwrap();
+        let db = GLOBAL_FONTDB.lock().unwrap();
+        usvgr::Tree::from_data(&svg_data, &opt, &db).unwrap()
+    };
+
+    let pixmap =
+        svgr::render_to_pixmap(&tree, options, &mut SvgrCache::none(), &PixmapPool::new()).unwrap();
+
+    let expected = load_png_size(&png_path);
+    assert_eq!(expected, (pixmap.width(), pixmap.height()));
+
+    let mut rgba = pixmap.take();
+    demultiply_alpha(rgba.as_mut_slice().as_rgba_mut());
+
+    let expected_data = load_png(&png_path);
+    assert_eq!(expected_data.len(), rgba.len());
+
+    let mut pixels_d = 0;
+    for (a, b) in expected_data
+        .as_slice()
+        .as_rgba()
+        .iter()
+        .zip(rgba.as_rgba())
+    {
+        if is_pix_diff(*a, *b) {
+            pixels_d += 1;
+        }
+    }
+
+    pixels_d
+}
+
 pub fn render_node(name: &str, id: &str) -> usize {
     let svg_path = format!("tests/{}.svg", name);
     let png_path = format!("tests/{}.png", name);
@@ -306,6 +525,12 @@ fn load_png(path: &str) -> Vec<u8> {
     }
 }
 
+fn load_png_size(path: &str) -> (u32, u32) {
+    let data = std::fs::read(path).unwrap();
+    let reader = png::Decoder::new(data.as_slice()).read_info().unwrap();
+    (reader.info().width, reader.info().height)
+}
+
 // TODO: remove
 fn is_pix_diff(c1: rgb::RGBA8, c2: rgb::RGBA8) -> bool {
     (c1.r as i32 - c2.r as i32).abs() > 1
@@ -352,6 +577,16 @@ fn demultiply_alpha(data: &mut [RGBA8]) {
     }
 }
 
+/// Premultiplies provided pixels alpha.
+fn premultiply_alpha(data: &mut [RGBA8]) {
+    for p in data {
+        let a = p.a as f64 / 255.0;
+        p.b = (p.b as f64 * a + 0.5) as u8;
+        p.g = (p.g as f64 * a + 0.5) as u8;
+        p.r = (p.r as f64 * a + 0.5) as u8;
+    }
+}
+
 /// A simple stderr logger.
 static LOGGER: SimpleLogger = SimpleLogger;
 struct SimpleLogger;
@@ -422,10 +422,12 @@ use crate::render;
 #[test] fn masking_clipPath_clipping_with_complex_text_2() { assert_eq!(render("tests/masking/clipPath/clipping-with-complex-text-2"), 0); }
 #[test] fn masking_clipPath_clipping_with_complex_text_and_clip_rule() { assert_eq!(render("tests/masking/clipPath/clipping-with-complex-text-and-clip-rule"), 0); }
 #[test] fn masking_clipPath_clipping_with_text() { assert_eq!(render("tests/masking/clipPath/clipping-with-text"), 0); }
+#[test] fn masking_clipPath_ellipse_shorthand() { assert_eq!(render("tests/masking/clipPath/ellipse-shorthand"), 0); }
 #[test] fn masking_clipPath_fill_has_no_effect() { assert_eq!(render("tests/masking/clipPath/fill-has-no-effect"), 0); }
 #[test] fn masking_clipPath_filter_has_no_effect() { assert_eq!(render("tests/masking/clipPath/filter-has-no-effect"), 0); }
 #[test] fn masking_clipPath_g_is_not_a_valid_child() { assert_eq!(render("tests/masking/clipPath/g-is-not-a-valid-child"), 0); }
 #[test] fn masking_clipPath_image_is_not_a_valid_child() { assert_eq!(render("tests/masking/clipPath/image-is-not-a-valid-child"), 0); }
+#[test] fn masking_clipPath_inset_shorthand() { assert_eq!(render("tests/masking/clipPath/inset-shorthand"), 0); }
 #[test] fn masking_clipPath_invalid_FuncIRI() { assert_eq!(render("tests/masking/clipPath/invalid-FuncIRI"), 0); }
 #[test] fn masking_clipPath_invalid_clip_path_on_child() { assert_eq!(render("tests/masking/clipPath/invalid-clip-path-on-child"), 0); }
 #[test] fn masking_clipPath_invalid_clip_path_on_self() { assert_eq!(render("tests/masking/clipPath/invalid-clip-path-on-self"), 0); }
@@ -445,10 +447,13 @@ use crate::render;
 #[test] fn masking_clipPath_on_the_root_svg_without_size() { assert_eq!(render("tests/masking/clipPath/on-the-root-svg-without-size"), 0); }
 #[test] fn masking_clipPath_opacity_has_no_effect() { assert_eq!(render("tests/masking/clipPath/opacity-has-no-effect"), 0); }
 #[test] fn masking_clipPath_overlapped_shapes_with_evenodd() { assert_eq!(render("tests/masking/clipPath/overlapped-shapes-with-evenodd"), 0); }
+#[test] fn masking_clipPath_path_shorthand() { assert_eq!(render("tests/masking/clipPath/path-shorthand"), 0); }
+#[test] fn masking_clipPath_polygon_shorthand() { assert_eq!(render("tests/masking/clipPath/polygon-shorthand"), 0); }
 #[test] fn masking_clipPath_recursive_on_child() { assert_eq!(render("tests/masking/clipPath/recursive-on-child"), 0); }
 #[test] fn masking_clipPath_recursive_on_self() { assert_eq!(render("tests/masking/clipPath/recursive-on-self"), 0); }
 #[test] fn masking_clipPath_recursive() { assert_eq!(render("tests/masking/clipPath/recursive"), 0); }
 #[test] fn masking_clipPath_self_recursive() { assert_eq!(render("tests/masking/clipPath/self-recursive"), 0); }
+#[test] fn masking_clipPath_shorthand_via_CSS() { assert_eq!(render("tests/masking/clipPath/shorthand-via-CSS"), 0); }
 #[test] fn masking_clipPath_simple_case() { assert_eq!(render("tests/masking/clipPath/simple-case"), 0); }
 #[test] fn masking_clipPath_stroke_has_no_effect() { assert_eq!(render("tests/masking/clipPath/stroke-has-no-effect"), 0); }
 #[test] fn masking_clipPath_switch_is_not_a_valid_child() { assert_eq!(render("tests/masking/clipPath/switch-is-not-a-valid-child"), 0); }
@@ -500,6 +505,7 @@ use crate::render;
 #[test] fn paint_servers_linearGradient_attributes_via_xlink_href_from_rect() { assert_eq!(render("tests/paint-servers/linearGradient/attributes-via-xlink-href-from-rect"), 0); }
 #[test] fn paint_servers_linearGradient_attributes_via_xlink_href_only_required() { assert_eq!(render("tests/paint-servers/linearGradient/attributes-via-xlink-href-only-required"), 0); }
 #[test] fn paint_servers_linearGradient_attributes_via_xlink_href() { assert_eq!(render("tests/paint-servers/linearGradient/attributes-via-xlink-href"), 0); }
+#[test] fn paint_servers_linearGradient_color_interpolation_eq_linearRGB() { assert_eq!(render("tests/paint-servers/linearGradient/color-interpolation=linearRGB"), 0); }
 #[test] fn paint_servers_linearGradient_default_attributes() { assert_eq!(render("tests/paint-servers/linearGradient/default-attributes"), 0); }
 #[test] fn paint_servers_linearGradient_gradientTransform_and_transform() { assert_eq!(render("tests/paint-servers/linearGradient/gradientTransform-and-transform"), 0); }
 #[test] fn paint_servers_linearGradient_gradientTransform() { assert_eq!(render("tests/paint-servers/linearGradient/gradientTransform"), 0); }
@@ -533,6 +539,10 @@ use crate::render;
 #[test] fn paint_servers_linearGradient_stops_via_xlink_href_from_rect() { assert_eq!(render("tests/paint-servers/linearGradient/stops-via-xlink-href-from-rect"), 0); }
 #[test] fn paint_servers_linearGradient_stops_via_xlink_href() { assert_eq!(render("tests/paint-servers/linearGradient/stops-via-xlink-href"), 0); }
 #[test] fn paint_servers_linearGradient_unresolved_xlink_href() { assert_eq!(render("tests/paint-servers/linearGradient/unresolved-xlink-href"), 0); }
+#[test] fn paint_servers_meshgradient_bicubic() { assert_eq!(render("tests/paint-servers/meshgradient/bicubic"), 0); }
+#[test] fn paint_servers_meshgradient_bilinear() { assert_eq!(render("tests/paint-servers/meshgradient/bilinear"), 0); }
+#[test] fn paint_servers_meshgradient_objectBoundingBox() { assert_eq!(render("tests/paint-servers/meshgradient/objectBoundingBox"), 0); }
+#[test] fn paint_servers_meshgradient_on_stroke() { assert_eq!(render("tests/paint-servers/meshgradient/on-stroke"), 0); }
 #[test] fn paint_servers_pattern_attributes_via_xlink_href() { assert_eq!(render("tests/paint-servers/pattern/attributes-via-xlink-href"), 0); }
 #[test] fn paint_servers_pattern_child_with_invalid_FuncIRI() { assert_eq!(render("tests/paint-servers/pattern/child-with-invalid-FuncIRI"), 0); }
 #[test] fn paint_servers_pattern_children_via_xlink_href() { assert_eq!(render("tests/paint-servers/pattern/children-via-xlink-href"), 0); }
@@ -569,6 +579,7 @@ use crate::render;
 #[test] fn paint_servers_radialGradient_attributes_via_xlink_href_from_rect() { assert_eq!(render("tests/paint-servers/radialGradient/attributes-via-xlink-href-from-rect"), 0); }
 #[test] fn paint_servers_radialGradient_attributes_via_xlink_href_only_required() { assert_eq!(render("tests/paint-servers/radialGradient/attributes-via-xlink-href-only-required"), 0); }
 #[test] fn paint_servers_radialGradient_attributes_via_xlink_href() { assert_eq!(render("tests/paint-servers/radialGradient/attributes-via-xlink-href"), 0); }
+#[test] fn paint_servers_radialGradient_color_interpolation_eq_linearRGB() { assert_eq!(render("tests/paint-servers/radialGradient/color-interpolation=linearRGB"), 0); }
 #[test] fn paint_servers_radialGradient_default_attributes() { assert_eq!(render("tests/paint-servers/radialGradient/default-attributes"), 0); }
 #[test] fn paint_servers_radialGradient_fr_eq__1() { assert_eq!(render("tests/paint-servers/radialGradient/fr=-1"), 0); }
 #[test] fn paint_servers_radialGradient_fr_eq_0_2() { assert_eq!(render("tests/paint-servers/radialGradient/fr=0.2"), 0); }
@@ -940,6 +951,10 @@ use crate::render;
 #[test] fn painting_stroke_width_negative() { assert_eq!(render("tests/painting/stroke-width/negative"), 0); }
 #[test] fn painting_stroke_width_percentage() { assert_eq!(render("tests/painting/stroke-width/percentage"), 0); }
 #[test] fn painting_stroke_width_zero() { assert_eq!(render("tests/painting/stroke-width/zero"), 0); }
+#[test] fn painting_vector_effect_non_scaling_stroke() { assert_eq!(render("tests/painting/vector-effect/non-scaling-stroke"), 0); }
+#[test] fn painting_vector_effect_on_group() { assert_eq!(render("tests/painting/vector-effect/on-group"), 0); }
+#[test] fn painting_vector_effect_with_gradient() { assert_eq!(render("tests/painting/vector-effect/with-gradient"), 0); }
+#[test] fn painting_vector_effect_with_stroke_dasharray() { assert_eq!(render("tests/painting/vector-effect/with-stroke-dasharray"), 0); }
 #[test] fn painting_visibility_bbox_impact_1() { assert_eq!(render("tests/painting/visibility/bbox-impact-1"), 0); }
 #[test] fn painting_visibility_bbox_impact_2() { assert_eq!(render("tests/painting/visibility/bbox-impact-2"), 0); }
 #[test] fn painting_visibility_bbox_impact_3() { assert_eq!(render("tests/painting/visibility/bbox-impact-3"), 0); }
@@ -1015,6 +1030,7 @@ use crate::render;
 #[test] fn shapes_path_M_rel_M_rel_implicit_M_rel_implicit() { assert_eq!(render("tests/shapes/path/M-rel-M-rel-implicit-M-rel-implicit"), 0); }
 #[test] fn shapes_path_M_rel_M() { assert_eq!(render("tests/shapes/path/M-rel-M"), 0); }
 #[test] fn shapes_path_M() { assert_eq!(render("tests/shapes/path/M"), 0); }
+#[test] fn shapes_path_d_property_via_CSS() { assert_eq!(render("tests/shapes/path/d-property-via-CSS"), 0); }
 #[test] fn shapes_path_empty() { assert_eq!(render("tests/shapes/path/empty"), 0); }
 #[test] fn shapes_path_extra_spaces() { assert_eq!(render("tests/shapes/path/extra-spaces"), 0); }
 #[test] fn shapes_path_invalid_data_in_L() { assert_eq!(render("tests/shapes/path/invalid-data-in-L"), 0); }
@@ -1538,6 +1554,8 @@ use crate::render;
 #[test] fn text_textPath_dy_with_tiny_coordinates() { assert_eq!(render("tests/text/textPath/dy-with-tiny-coordinates"), 0); }
 #[test] fn text_textPath_invalid_link() { assert_eq!(render("tests/text/textPath/invalid-link"), 0); }
 #[test] fn text_textPath_invalid_textPath_in_the_middle() { assert_eq!(render("tests/text/textPath/invalid-textPath-in-the-middle"), 0); }
+#[test] fn text_textPath_link_to_circle_and_side_eq_right() { assert_eq!(render("tests/text/textPath/link-to-circle-and-side=right"), 0); }
+#[test] fn text_textPath_link_to_circle() { assert_eq!(render("tests/text/textPath/link-to-circle"), 0); }
 #[test] fn text_textPath_link_to_rect() { assert_eq!(render("tests/text/textPath/link-to-rect"), 0); }
 #[test] fn text_textPath_m_A_path() { assert_eq!(render("tests/text/textPath/m-A-path"), 0); }
 #[test] fn text_textPath_m_L_Z_path() { assert_eq!(render("tests/text/textPath/m-L-Z-path"), 0); }
@@ -0,0 +1,574 @@
+use crate::directional_position::DirectionalPosition;
+use crate::{Error, Length, LengthUnit, Stream};
+
+/// Representation of the [`<geometry-box>`] type.
+///
+/// [`<geometry-box>`]: https://www.w3.org/TR/css-masking-1/#typedef-geometry-box
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[allow(missing_docs)]
+pub enum GeometryBox {
+    FillBox,
+    StrokeBox,
+    ViewBox,
+    ContentBox,
+    PaddingBox,
+    BorderBox,
+    MarginBox,
+}
+
+/// Representation of the [`<shape-radius>`] type.
+///
+/// [`<shape-radius>`]: https://www.w3.org/TR/css-shapes-1/#typedef-shape-radius
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[allow(missing_docs)]
+pub enum ShapeRadius {
+    Length(Length),
+    ClosestSide,
+    FarthestSide,
+}
+
+/// A [`<fill-rule>`] of a basic shape.
+///
+/// [`<fill-rule>`]: https://www.w3.org/TR/css-shapes-1/#typedef-fill-rule
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[allow(missing_docs)]
+pub enum ShapeFillRule {
+    NonZero,
+    EvenOdd,
+}
+
+/// Representation of the [`<basic-shape>`] type.
+///
+/// [`<basic-shape>`]: https://www.w3.org/TR/css-shapes-1/#typedef-basic-shape
+#[derive(Clone, PartialEq, Debug)]
+pub enum BasicShape<'a> {
+    /// An `inset()` function.
+    Inset {
+        /// Top, right, bottom and left insets.
+        insets: [Length; 4],
+        /// Top-left, top-right, bottom-right and bottom-left corners radii.
+        ///
+        /// Elliptical corners are not supported.
+        radii: [Length; 4],
+    },
+    /// A `circle()` function.
+    Circle {
+        /// Circle radius.
+        radius: ShapeRadius,
+        /// Circle center. `50% 50%` by default.
+        position: (Length, Length),
+    },
+    /// An `ellipse()` function.
+    Ellipse {
+        /// Horizontal radius.
+        rx: ShapeRadius,
+        /// Vertical radius.
+        ry: ShapeRadius,
+        /// Ellipse center. `50% 50%` by default.
+        position: (Length, Length),
+    },
+    /// A `polygon()` function.
+    Polygon {
+        /// Polygon fill rule.
+        fill_rule: ShapeFillRule,
+        /// Polygon vertices.
+        points: Vec<(Length, Length)>,
+    },
+    /// A `path()` function.
+    Path {
+        /// Path fill rule.
+        fill_rule: ShapeFillRule,
+        /// Unparsed path data.
+        data: &'a str,
+    },
+}
+
+impl<'a> BasicShape<'a> {
+    /// Parsers a `BasicShape` from a string.
+    ///
+    /// We can't use the `FromStr` trait because it requires
+    /// an owned value as a return type.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(text: &'a str) -> Result<Self, Error> {
+        let mut s = Stream::from(text);
+        let shape = s.parse_basic_shape()?;
+        s.skip_spaces();
+        if !s.at_end() {
+            return Err(Error::UnexpectedData(s.calc_char_pos()));
+        }
+
+        Ok(shape)
+    }
+}
+
+/// Representation of the [`clip-path`] property value.
+///
+/// [`clip-path`]: https://www.w3.org/TR/css-masking-1/#the-clip-path
+#[derive(Clone, PartialEq, Debug)]
+pub enum ClipPathValue<'a> {
+    /// A `none` value.
+    None,
+    /// A link to a `clipPath` element.
+    Url(&'a str),
+    /// A basic shape with an optional reference box.
+    Shape {
+        /// A shape.
+        shape: BasicShape<'a>,
+        /// A reference box. `border-box` should be used when not set.
+        reference_box: Option<GeometryBox>,
+    },
+    /// Just a reference box.
+    ReferenceBox(GeometryBox),
+}
+
+impl<'a> ClipPathValue<'a> {
+    /// Parsers a `ClipPathValue` from a string.
+    ///
+    /// We can't use the `FromStr` trait because it requires
+    /// an owned value as a return type.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(text: &'a str) -> Result<Self, Error> {
+        let mut s = Stream::from(text);
+        s.skip_spaces();
+
+        let value = if s.starts_with(b"none") {
+            s.advance(4);
+            ClipPathValue::None
+        } else if s.starts_with(b"url(") {
+            ClipPathValue::Url(s.parse_func_iri()?)
+        } else {
+            // A shape and a box can be set in any order.
+            let mut shape = None;
+            let mut reference_box = None;
+            loop {
+                s.skip_spaces();
+                if s.at_end() {
+                    break;
+                }
+
+                if reference_box.is_none() {
+                    if let Some(b) = s.try_parse_geometry_box() {
+                        reference_box = Some(b);
+                        continue;
+                    }
+                }
+
+                if shape.is_none() {
+                    shape = Some(s.parse_basic_shape()?);
+                    continue;
+                }
+
+                return Err(Error::UnexpectedData(s.calc_char_pos()));
+            }
+
+            match (shape, reference_box) {
+                (Some(shape), reference_box) => ClipPathValue::Shape {
+                    shape,
+                    reference_box,
+                },
+                (None, Some(b)) => ClipPathValue::ReferenceBox(b),
+                (None, None) => return Err(Error::InvalidValue),
+            }
+        };
+
+        s.skip_spaces();
+        if !s.at_end() {
+            return Err(Error::UnexpectedData(s.calc_char_pos()));
+        }
+
+        Ok(value)
+    }
+}
+
+impl<'a> Stream<'a> {
+    fn try_parse_geometry_box(&mut self) -> Option<GeometryBox> {
+        let mut s = *self;
+        let b = match s.consume_ascii_ident() {
+            "fill-box" => GeometryBox::FillBox,
+            "stroke-box" => GeometryBox::StrokeBox,
+            "view-box" => GeometryBox::ViewBox,
+            "content-box" => GeometryBox::ContentBox,
+            "padding-box" => GeometryBox::PaddingBox,
+            "border-box" => GeometryBox::BorderBox,
+            "margin-box" => GeometryBox::MarginBox,
+            _ => return None,
+        };
+
+        *self = s;
+        Some(b)
+    }
+
+    /// Parses a [`<basic-shape>`] from the stream.
+    ///
+    /// [`<basic-shape>`]: https://www.w3.org/TR/css-shapes-1/#typedef-basic-shape
+    pub fn parse_basic_shape(&mut self) -> Result<BasicShape<'a>, Error> {
+        self.skip_spaces();
+
+        let start = self.pos();
+        let name = self.consume_ascii_ident();
+        self.skip_spaces();
+        self.consume_byte(b'(')?;
+        self.skip_spaces();
+
+        let shape = match name.as_bytes() {
+            b"inset" => self.parse_inset_func()?,
+            b"circle" => {
+                let radius = self
+                    .try_parse_shape_radius()
+                    .unwrap_or(ShapeRadius::ClosestSide);
+                let position = self.parse_shape_position()?;
+                BasicShape::Circle { radius, position }
+            }
+            b"ellipse" => {
+                let (rx, ry) = match self.try_parse_shape_radius() {
+                    Some(rx) => {
+                        let ry = self.try_parse_shape_radius().ok_or(Error::InvalidValue)?;
+                        (rx, ry)
+                    }
+                    None => (ShapeRadius::ClosestSide, ShapeRadius::ClosestSide),
+                };
+                let position = self.parse_shape_position()?;
+                BasicShape::Ellipse { rx, ry, position }
+            }
+            b"polygon" => {
+                let fill_rule = self.parse_shape_fill_rule()?;
+                let mut points = Vec::new();
+                loop {
+                    let x = self.parse_length()?;
+                    let y = self.parse_length()?;
+                    points.push((x, y));
+
+                    self.skip_spaces();
+                    if self.is_curr_byte_eq(b',') {
+                        self.advance(1);
+                    } else {
+                        break;
+                    }
+                }
+
+                BasicShape::Polygon { fill_rule, points }
+            }
+            b"path" => {
+                let fill_rule = self.parse_shape_fill_rule()?;
+                let data = self.parse_quoted_string()?;
+                BasicShape::Path { fill_rule, data }
+            }
+            _ => {
+                return Err(Error::UnexpectedData(self.calc_char_pos_at(start)));
+            }
+        };
+
+        self.skip_spaces();
+        self.consume_byte(b')')?;
+
+        Ok(shape)
+    }
+
+    fn parse_inset_func(&mut self) -> Result<BasicShape<'a>, Error> {
+        let mut values = Vec::with_capacity(4);
+        while values.len() < 4 {
+            match self.try_parse_length() {
+                Some(v) => values.push(v),
+                None => break,
+            }
+        }
+
+        let insets = expand_box_values(&values).ok_or(Error::InvalidValue)?;
+
+        self.skip_spaces();
+        let mut radii = [Length::zero(); 4];
+        if self.starts_with(b"round") {
+            self.advance(5);
+
+            let mut values = Vec::with_capacity(4);
+            while values.len() < 4 {
+                match self.try_parse_length() {
+                    Some(v) => values.push(v),
+                    None => break,
+                }
+            }
+
+            radii = expand_box_values(&values).ok_or(Error::InvalidValue)?;
+        }
+
+        Ok(BasicShape::Inset { insets, radii })
+    }
+
+    fn try_parse_length(&mut self) -> Option<Length> {
+        let mut s = *self;
+        let length = s.parse_length().ok()?;
+        *self = s;
+        Some(length)
+    }
+
+    fn try_parse_shape_radius(&mut self) -> Option<ShapeRadius> {
+        self.skip_spaces();
+        if self.starts_with(b"closest-side") {
+            self.advance(12);
+            Some(ShapeRadius::ClosestSide)
+        } else if self.starts_with(b"farthest-side") {
+            self.advance(13);
+            Some(ShapeRadius::FarthestSide)
+        } else {
+            self.try_parse_length().map(ShapeRadius::Length)
+        }
+    }
+
+    fn parse_shape_position(&mut self) -> Result<(Length, Length), Error> {
+        let center = Length::new(50.0, LengthUnit::Percent);
+
+        self.skip_spaces();
+        if !self.starts_with(b"at") {
+            return Ok((center, center));
+        }
+        self.advance(2);
+
+        let first = self.parse_position_component()?;
+        let second = self.try_parse_position_component();
+
+        // Keywords allow any order, like `top left`.
+        let is_vertical = |p: &PositionComponent| match p {
+            PositionComponent::Keyword(k) => {
+                *k == DirectionalPosition::Top || *k == DirectionalPosition::Bottom
+            }
+            PositionComponent::Length(_) => false,
+        };
+        let is_horizontal = |p: &PositionComponent| match p {
+            PositionComponent::Keyword(k) => {
+                *k == DirectionalPosition::Left || *k == DirectionalPosition::Right
+            }
+            PositionComponent::Length(_) => false,
+        };
+
+        match second {
+            Some(second) => {
+                if is_vertical(&first) || is_horizontal(&second) {
+                    if is_horizontal(&first) || is_vertical(&second) {
+                        return Err(Error::InvalidValue);
+                    }
+
+                    Ok((second.into(), first.into()))
+                } else {
+                    Ok((first.into(), second.into()))
+                }
+            }
+            None => {
+                if is_vertical(&first) {
+                    Ok((center, first.into()))
+                } else {
+                    Ok((first.into(), center))
+                }
+            }
+        }
+    }
+
+    fn parse_position_component(&mut self) -> Result<PositionComponent, Error> {
+        self.try_parse_position_component()
+            .ok_or(Error::InvalidValue)
+    }
+
+    fn try_parse_position_component(&mut self) -> Option<PositionComponent> {
+        let mut s = *self;
+        if let Ok(k) = s.parse_directional_position() {
+            *self = s;
+            return Some(PositionComponent::Keyword(k));
+        }
+
+        self.try_parse_length().map(PositionComponent::Length)
+    }
+
+    fn parse_shape_fill_rule(&mut self) -> Result<ShapeFillRule, Error> {
+        self.skip_spaces();
+
+        let fill_rule = if self.starts_with(b"nonzero") {
+            self.advance(7);
+            ShapeFillRule::NonZero
+        } else if self.starts_with(b"evenodd") {
+            self.advance(7);
+            ShapeFillRule::EvenOdd
+        } else {
+            return Ok(ShapeFillRule::NonZero);
+        };
+
+        self.skip_spaces();
+        self.consume_byte(b',')?;
+        self.skip_spaces();
+
+        Ok(fill_rule)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum PositionComponent {
+    Keyword(DirectionalPosition),
+    Length(Length),
+}
+
+impl From<PositionComponent> for Length {
+    fn from(value: PositionComponent) -> Self {
+        match value {
+            PositionComponent::Keyword(k) => k.into(),
+            PositionComponent::Length(l) => l,
+        }
+    }
+}
+
+// Expands 1-4 values using the CSS `margin` rules.
+fn expand_box_values(values: &[Length]) -> Option<[Length; 4]> {
+    match *values {
+        [a] => Some([a, a, a, a]),
+        [a, b] => Some([a, b, a, b]),
+        [a, b, c] => Some([a, b, c, b]),
+        [a, b, c, d] => Some([a, b, c, d]),
+        _ => None,
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn percent(n: f64) -> Length {
+        Length::new(n, LengthUnit::Percent)
+    }
+
+    #[test]
+    fn none() {
+        assert_eq!(ClipPathValue::from_str("none").unwrap(), ClipPathValue::None);
+    }
+
+    #[test]
+    fn url() {
+        assert_eq!(ClipPathValue::from_str("url(#clip1)").unwrap(), ClipPathValue::Url("clip1"));
+    }
+
+    #[test]
+    fn reference_box() {
+        assert_eq!(ClipPathValue::from_str("fill-box").unwrap(),
+                   ClipPathValue::ReferenceBox(GeometryBox::FillBox));
+    }
+
+    #[test]
+    fn circle_default() {
+        assert_eq!(BasicShape::from_str("circle()").unwrap(), BasicShape::Circle {
+            radius: ShapeRadius::ClosestSide,
+            position: (percent(50.0), percent(50.0)),
+        });
+    }
+
+    #[test]
+    fn circle_with_box() {
+        assert_eq!(ClipPathValue::from_str("circle(40% at 50% 30px) stroke-box").unwrap(),
+                   ClipPathValue::Shape {
+                       shape: BasicShape::Circle {
+                           radius: ShapeRadius::Length(percent(40.0)),
+                           position: (percent(50.0), Length::new(30.0, LengthUnit::Px)),
+                       },
+                       reference_box: Some(GeometryBox::StrokeBox),
+                   });
+    }
+
+    #[test]
+    fn circle_with_box_first() {
+        assert_eq!(ClipPathValue::from_str("view-box circle(farthest-side)").unwrap(),
+                   ClipPathValue::Shape {
+                       shape: BasicShape::Circle {
+                           radius: ShapeRadius::FarthestSide,
+                           position: (percent(50.0), percent(50.0)),
+                       },
+                       reference_box: Some(GeometryBox::ViewBox),
+                   });
+    }
+
+    #[test]
+    fn circle_keyword_position() {
+        assert_eq!(BasicShape::from_str("circle(10 at top right)").unwrap(), BasicShape::Circle {
+            radius: ShapeRadius::Length(Length::new_number(10.0)),
+            position: (percent(100.0), percent(0.0)),
+        });
+    }
+
+    #[test]
+    fn circle_single_keyword_position() {
+        assert_eq!(BasicShape::from_str("circle(10 at bottom)").unwrap(), BasicShape::Circle {
+            radius: ShapeRadius::Length(Length::new_number(10.0)),
+            position: (percent(50.0), percent(100.0)),
+        });
+    }
+
+    #[test]
+    fn ellipse() {
+        assert_eq!(BasicShape::from_str("ellipse(10px 20% at left 5px)").unwrap(), BasicShape::Ellipse {
+            rx: ShapeRadius::Length(Length::new(10.0, LengthUnit::Px)),
+            ry: ShapeRadius::Length(percent(20.0)),
+            position: (percent(0.0), Length::new(5.0, LengthUnit::Px)),
+        });
+    }
+
+    #[test]
+    fn ellipse_single_radius() {
+        assert!(BasicShape::from_str("ellipse(10px)").is_err());
+    }
+
+    #[test]
+    fn inset() {
+        assert_eq!(BasicShape::from_str("inset(10px 20px round 5px 0)").unwrap(), BasicShape::Inset {
+            insets: [
+                Length::new(10.0, LengthUnit::Px),
+                Length::new(20.0, LengthUnit::Px),
+                Length::new(10.0, LengthUnit::Px),
+                Length::new(20.0, LengthUnit::Px),
+            ],
+            radii: [
+                Length::new(5.0, LengthUnit::Px),
+                Length::zero(),
+                Length::new(5.0, LengthUnit::Px),
+                Length::zero(),
+            ],
+        });
+    }
+
+    #[test]
+    fn inset_empty() {
+        assert!(BasicShape::from_str("inset()").is_err());
+    }
+
+    #[test]
+    fn polygon() {
+        assert_eq!(BasicShape::from_str("polygon(evenodd, 0 0, 100% 0, 50% 100%)").unwrap(), BasicShape::Polygon {
+            fill_rule: ShapeFillRule::EvenOdd,
+            points: vec![
+                (Length::zero(), Length::zero()),
+                (percent(100.0), Length::zero()),
+                (percent(50.0), percent(100.0)),
+            ],
+        });
+    }
+
+    #[test]
+    fn path() {
+        assert_eq!(BasicShape::from_str("path('M 0 0 L 10 10')").unwrap(), BasicShape::Path {
+            fill_rule: ShapeFillRule::NonZero,
+            data: "M 0 0 L 10 10",
+        });
+    }
+
+    #[test]
+    fn path_with_fill_rule() {
+        assert_eq!(BasicShape::from_str("path(evenodd, \"M 0 0 L 10 10\")").unwrap(), BasicShape::Path {
+            fill_rule: ShapeFillRule::EvenOdd,
+            data: "M 0 0 L 10 10",
+        });
+    }
+
+    #[test]
+    fn unknown_shape() {
+        assert!(ClipPathValue::from_str("square(10)").is_err());
+    }
+
+    #[test]
+    fn two_shapes() {
+        assert!(ClipPathValue::from_str("circle() circle()").is_err());
+    }
+}
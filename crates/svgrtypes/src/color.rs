@@ -1,4 +1,4 @@
-use crate::{colors, ByteExt, Error, Stream};
+use crate::{colors, Angle, AngleUnit, ByteExt, Error, Stream};
 
 /// Representation of the [`<color>`] type.
 ///
@@ -98,6 +98,11 @@ impl std::str::FromStr for Color {
 
     /// Parses [CSS3](https://www.w3.org/TR/css-color-3/) `Color` from a string.
     ///
+    /// Also supports `hwb()`, `lab()`, `lch()`, `oklab()`, `oklch()`, `color()`
+    /// and the space-separated `rgb()`/`hsl()` syntax from the
+    /// [CSS Color Module Level 4][css-color-4].
+    /// Colors outside of the sRGB gamut are mapped into it by reducing their chroma.
+    ///
     /// # Errors
     ///
     ///  - Returns error if a color has an invalid format.
@@ -114,6 +119,7 @@ impl std::str::FromStr for Color {
     /// [SVG 1.1 spec]: https://www.w3.org/TR/SVG11/types.html#DataTypeColor
     /// [details]: https://lists.w3.org/Archives/Public/www-svg/2014Jan/0109.html
     /// [css-color-4-hex]: https://www.w3.org/TR/css-color-4/#hex-notation
+    /// [css-color-4]: https://www.w3.org/TR/css-color-4/
     fn from_str(text: &str) -> Result<Self, Error> {
         let mut s = Stream::from(text);
         let color = s.parse_color()?;
@@ -189,55 +195,102 @@ impl<'a> Stream<'a> {
             if name == "rgb" || name == "rgba" {
                 self.consume_byte(b'(')?;
 
-                let mut is_percent = false;
-                let value = self.parse_number()?;
-                if self.starts_with(b"%") {
-                    self.advance(1);
-                    is_percent = true;
-                }
-                self.skip_spaces();
-                self.parse_list_separator();
-
-                if is_percent {
-                    fn from_percent(v: f64) -> u8 {
-                        let n = (v * 255.0).round() as i32;
-                        bound(0, n, 255) as u8
-                    }
-
-                    color.red = from_percent(value / 100.0);
-                    color.green = from_percent(self.parse_list_number_or_percent()?);
-                    color.blue = from_percent(self.parse_list_number_or_percent()?);
+                if self.is_legacy_color_syntax() {
+                    color = self.parse_legacy_rgb()?;
                 } else {
-                    color.red = f64_bound(0.0, (value.round() as i32).into(), 255.0) as u8;
-                    color.green = f64_bound(0.0, self.parse_list_number()?.round(), 255.0) as u8;
-                    color.blue = f64_bound(0.0, self.parse_list_number()?.round(), 255.0) as u8;
-                }
-
-                self.skip_spaces();
-                if !self.starts_with(b")") {
-                    color.alpha = (f64_bound(0.0, self.parse_list_number()?, 1.0) * 255.0) as u8;
+                    let ([r, g, b], alpha) = self.parse_color_channels()?;
+                    color = srgb_to_color([
+                        r.number(255.0)? / 255.0,
+                        g.number(255.0)? / 255.0,
+                        b.number(255.0)? / 255.0,
+                    ]);
+                    color.alpha = alpha;
                 }
-
-                self.skip_spaces();
-                self.consume_byte(b')')?;
             } else if name == "hsl" || name == "hsla" {
                 self.consume_byte(b'(')?;
 
-                let mut hue = self.parse_list_integer()?;
-                hue = ((hue % 360) + 360) % 360;
+                if self.is_legacy_color_syntax() {
+                    color = self.parse_legacy_hsl()?;
+                } else {
+                    let ([h, s, l], alpha) = self.parse_color_channels()?;
+                    let saturation = f64_bound(0.0, s.number(100.0)? / 100.0, 1.0);
+                    let lightness = f64_bound(0.0, l.number(100.0)? / 100.0, 1.0);
+                    color = hsl_to_rgb(h.hue()? as f32 / 60.0, saturation as f32, lightness as f32);
+                    color.alpha = alpha;
+                }
+            } else if name == "hwb" {
+                self.consume_byte(b'(')?;
 
-                let saturation = f64_bound(0.0, self.parse_list_number_or_percent()?, 1.0);
-                let lightness = f64_bound(0.0, self.parse_list_number_or_percent()?, 1.0);
+                let ([h, w, b], alpha) = self.parse_color_channels()?;
+                let whiteness = f64_bound(0.0, w.number(100.0)? / 100.0, 1.0);
+                let blackness = f64_bound(0.0, b.number(100.0)? / 100.0, 1.0);
+                color = srgb_to_color(hwb_to_srgb(h.hue()?, whiteness, blackness));
+                color.alpha = alpha;
+            } else if name == "lab" || name == "lch" {
+                self.consume_byte(b'(')?;
 
-                color = hsl_to_rgb(hue as f32 / 60.0, saturation as f32, lightness as f32);
+                let ([l, c1, c2], alpha) = self.parse_color_channels()?;
+                let lightness = f64_bound(0.0, l.number(100.0)?, 100.0);
+                let lab = if name == "lab" {
+                    [lightness, c1.number(125.0)?, c2.number(125.0)?]
+                } else {
+                    let chroma = c1.number(150.0)?.max(0.0);
+                    lch_to_lab(lightness, chroma, c2.hue()?)
+                };
+                let lab = limit_chroma(lab, 230.0);
+
+                let xyz = mul(&D50_TO_D65, lab_to_xyz_d50(lab));
+                color = linear_srgb_to_color(mul(&XYZ_TO_LINEAR_SRGB, xyz));
+                color.alpha = alpha;
+            } else if name == "oklab" || name == "oklch" {
+                self.consume_byte(b'(')?;
 
+                let ([l, c1, c2], alpha) = self.parse_color_channels()?;
+                let lightness = f64_bound(0.0, l.number(1.0)?, 1.0);
+                let oklab = if name == "oklab" {
+                    [lightness, c1.number(0.4)?, c2.number(0.4)?]
+                } else {
+                    let chroma = c1.number(0.4)?.max(0.0);
+                    lch_to_lab(lightness, chroma, c2.hue()?)
+                };
+                let oklab = limit_chroma(oklab, 0.5);
+
+                color = linear_srgb_to_color(oklab_to_linear_srgb(oklab));
+                color.alpha = alpha;
+            } else if name == "color" {
+                self.consume_byte(b'(')?;
                 self.skip_spaces();
-                if !self.starts_with(b")") {
-                    color.alpha = (f64_bound(0.0, self.parse_list_number()?, 1.0) * 255.0) as u8;
-                }
 
-                self.skip_spaces();
-                self.consume_byte(b')')?;
+                let space = self.consume_ascii_ident().to_ascii_lowercase();
+                let ([c1, c2, c3], alpha) = self.parse_color_channels()?;
+                let values = [c1.number(1.0)?, c2.number(1.0)?, c3.number(1.0)?];
+                let linear = match space.as_str() {
+                    "srgb" => values.map(srgb_to_linear),
+                    "srgb-linear" => values,
+                    "display-p3" => {
+                        let xyz = mul(&LINEAR_DISPLAY_P3_TO_XYZ, values.map(srgb_to_linear));
+                        mul(&XYZ_TO_LINEAR_SRGB, xyz)
+                    }
+                    "a98-rgb" => {
+                        let linear = values.map(|v| v.signum() * v.abs().powf(563.0 / 256.0));
+                        mul(&XYZ_TO_LINEAR_SRGB, mul(&LINEAR_A98_RGB_TO_XYZ, linear))
+                    }
+                    "prophoto-rgb" => {
+                        let linear = values.map(prophoto_to_linear);
+                        let xyz = mul(&D50_TO_D65, mul(&LINEAR_PROPHOTO_RGB_TO_XYZ_D50, linear));
+                        mul(&XYZ_TO_LINEAR_SRGB, xyz)
+                    }
+                    "rec2020" => {
+                        let linear = values.map(rec2020_to_linear);
+                        mul(&XYZ_TO_LINEAR_SRGB, mul(&LINEAR_REC2020_TO_XYZ, linear))
+                    }
+                    "xyz" | "xyz-d65" => mul(&XYZ_TO_LINEAR_SRGB, values),
+                    "xyz-d50" => mul(&XYZ_TO_LINEAR_SRGB, mul(&D50_TO_D65, values)),
+                    _ => return Err(Error::InvalidValue),
+                };
+
+                color = linear_srgb_to_color(linear);
+                color.alpha = alpha;
             } else {
                 match colors::from_str(&name) {
                     Some(c) => {
@@ -252,6 +305,164 @@ impl<'a> Stream<'a> {
 
         Ok(color)
     }
+
+    /// Checks that color function arguments are separated by commas.
+    ///
+    /// Legacy syntax: `rgb(255, 0, 0)`. Modern syntax: `rgb(255 0 0 / 50%)`.
+    fn is_legacy_color_syntax(&self) -> bool {
+        let mut s = *self;
+        s.skip_spaces();
+        s.skip_bytes(|_, c| !c.is_space() && !matches!(c, b',' | b'/' | b')'));
+        s.skip_spaces();
+        s.is_curr_byte_eq(b',')
+    }
+
+    fn parse_legacy_rgb(&mut self) -> Result<Color, Error> {
+        let mut color = Color::black();
+
+        let mut is_percent = false;
+        let value = self.parse_number()?;
+        if self.starts_with(b"%") {
+            self.advance(1);
+            is_percent = true;
+        }
+        self.skip_spaces();
+        self.parse_list_separator();
+
+        if is_percent {
+            fn from_percent(v: f64) -> u8 {
+                let n = (v * 255.0).round() as i32;
+                bound(0, n, 255) as u8
+            }
+
+            color.red = from_percent(value / 100.0);
+            color.green = from_percent(self.parse_list_number_or_percent()?);
+            color.blue = from_percent(self.parse_list_number_or_percent()?);
+        } else {
+            color.red = f64_bound(0.0, (value.round() as i32).into(), 255.0) as u8;
+            color.green = f64_bound(0.0, self.parse_list_number()?.round(), 255.0) as u8;
+            color.blue = f64_bound(0.0, self.parse_list_number()?.round(), 255.0) as u8;
+        }
+
+        self.skip_spaces();
+        if !self.starts_with(b")") {
+            color.alpha = (f64_bound(0.0, self.parse_list_number()?, 1.0) * 255.0) as u8;
+        }
+
+        self.skip_spaces();
+        self.consume_byte(b')')?;
+
+        Ok(color)
+    }
+
+    fn parse_legacy_hsl(&mut self) -> Result<Color, Error> {
+        let mut hue = self.parse_list_integer()?;
+        hue = ((hue % 360) + 360) % 360;
+
+        let saturation = f64_bound(0.0, self.parse_list_number_or_percent()?, 1.0);
+        let lightness = f64_bound(0.0, self.parse_list_number_or_percent()?, 1.0);
+
+        let mut color = hsl_to_rgb(hue as f32 / 60.0, saturation as f32, lightness as f32);
+
+        self.skip_spaces();
+        if !self.starts_with(b")") {
+            color.alpha = (f64_bound(0.0, self.parse_list_number()?, 1.0) * 255.0) as u8;
+        }
+
+        self.skip_spaces();
+        self.consume_byte(b')')?;
+
+        Ok(color)
+    }
+
+    /// Parses three space-separated channels, an optional `/ alpha` and a closing `)`.
+    fn parse_color_channels(&mut self) -> Result<([Channel; 3], u8), Error> {
+        let channels = [
+            self.parse_color_channel()?,
+            self.parse_color_channel()?,
+            self.parse_color_channel()?,
+        ];
+
+        self.skip_spaces();
+        let mut alpha = 1.0;
+        if self.is_curr_byte_eq(b'/') {
+            self.advance(1);
+            alpha = f64_bound(0.0, self.parse_color_channel()?.number(1.0)?, 1.0);
+            self.skip_spaces();
+        }
+
+        self.consume_byte(b')')?;
+
+        Ok((channels, (alpha * 255.0) as u8))
+    }
+
+    fn parse_color_channel(&mut self) -> Result<Channel, Error> {
+        self.skip_spaces();
+        if self.at_end() {
+            return Err(Error::UnexpectedEndOfStream);
+        }
+
+        if self.starts_with(b"none") {
+            self.advance(4);
+            return Ok(Channel::None);
+        }
+
+        let n = self.parse_number()?;
+        let channel = if self.starts_with(b"%") {
+            self.advance(1);
+            Channel::Percent(n / 100.0)
+        } else if self.starts_with(b"deg") {
+            self.advance(3);
+            Channel::Angle(Angle::new(n, AngleUnit::Degrees))
+        } else if self.starts_with(b"grad") {
+            self.advance(4);
+            Channel::Angle(Angle::new(n, AngleUnit::Gradians))
+        } else if self.starts_with(b"rad") {
+            self.advance(3);
+            Channel::Angle(Angle::new(n, AngleUnit::Radians))
+        } else if self.starts_with(b"turn") {
+            self.advance(4);
+            Channel::Angle(Angle::new(n, AngleUnit::Turns))
+        } else {
+            Channel::Number(n)
+        };
+
+        Ok(channel)
+    }
+}
+
+/// A CSS Color Level 4 function channel.
+#[derive(Clone, Copy, Debug)]
+enum Channel {
+    Number(f64),
+    /// Normalized to a 0..1 range.
+    Percent(f64),
+    Angle(Angle),
+    None,
+}
+
+impl Channel {
+    /// Returns a channel value, where `percent_ref` corresponds to `100%`.
+    ///
+    /// `none` is treated as zero.
+    fn number(self, percent_ref: f64) -> Result<f64, Error> {
+        match self {
+            Channel::Number(n) => Ok(n),
+            Channel::Percent(n) => Ok(n * percent_ref),
+            Channel::None => Ok(0.0),
+            Channel::Angle(_) => Err(Error::InvalidValue),
+        }
+    }
+
+    /// Returns a hue in degrees.
+    fn hue(self) -> Result<f64, Error> {
+        match self {
+            Channel::Number(n) => Ok(n.rem_euclid(360.0)),
+            Channel::Angle(a) => Ok(a.to_degrees().rem_euclid(360.0)),
+            Channel::None => Ok(0.0),
+            Channel::Percent(_) => Err(Error::InvalidValue),
+        }
+    }
 }
 
 #[inline]
@@ -329,6 +540,326 @@ fn f64_bound(min: f64, val: f64, max: f64) -> f64 {
     val.max(min).min(max)
 }
 
+// Color spaces conversions.
+// Based on https://www.w3.org/TR/css-color-4/#color-conversion-code
+
+type Vec3 = [f64; 3];
+type Mat3 = [[f64; 3]; 3];
+
+const XYZ_TO_LINEAR_SRGB: Mat3 = [
+    [12831.0 / 3959.0, -329.0 / 214.0, -1974.0 / 3959.0],
+    [
+        -851781.0 / 878810.0,
+        1648619.0 / 878810.0,
+        36519.0 / 878810.0,
+    ],
+    [705.0 / 12673.0, -2585.0 / 12673.0, 705.0 / 667.0],
+];
+
+const LINEAR_DISPLAY_P3_TO_XYZ: Mat3 = [
+    [
+        608311.0 / 1250200.0,
+        189793.0 / 714400.0,
+        198249.0 / 1000160.0,
+    ],
+    [
+        35783.0 / 156275.0,
+        247089.0 / 357200.0,
+        198249.0 / 2500400.0,
+    ],
+    [0.0, 32229.0 / 714400.0, 5220557.0 / 5000800.0],
+];
+
+const LINEAR_A98_RGB_TO_XYZ: Mat3 = [
+    [
+        573536.0 / 994567.0,
+        263643.0 / 1420810.0,
+        187206.0 / 994567.0,
+    ],
+    [
+        591459.0 / 1989134.0,
+        6239551.0 / 9945670.0,
+        374412.0 / 4972835.0,
+    ],
+    [
+        53769.0 / 1989134.0,
+        351524.0 / 4972835.0,
+        4929758.0 / 4972835.0,
+    ],
+];
+
+const LINEAR_PROPHOTO_RGB_TO_XYZ_D50: Mat3 = [
+    [
+        0.7977666449006423,
+        0.13518129740053308,
+        0.031347734128392286,
+    ],
+    [0.2880748288194013, 0.711835234241873, 0.00008993693872564],
+    [0.0, 0.0, 0.8251046025104602],
+];
+
+const LINEAR_REC2020_TO_XYZ: Mat3 = [
+    [
+        63426534.0 / 99577255.0,
+        20160776.0 / 139408157.0,
+        47086771.0 / 278816314.0,
+    ],
+    [
+        26158966.0 / 99577255.0,
+        472592308.0 / 697040785.0,
+        8267143.0 / 139408157.0,
+    ],
+    [0.0, 19567812.0 / 697040785.0, 295819943.0 / 278816314.0],
+];
+
+// Bradford chromatic adaptation.
+const D50_TO_D65: Mat3 = [
+    [0.955473421488075, -0.02309845494876471, 0.06325924320057072],
+    [
+        -0.0283697093338637,
+        1.0099953980813041,
+        0.021041441191917323,
+    ],
+    [
+        0.012314014864481998,
+        -0.020507649298898964,
+        1.330365926242124,
+    ],
+];
+
+const D50_WHITE: Vec3 = [0.3457 / 0.3585, 1.0, (1.0 - 0.3457 - 0.3585) / 0.3585];
+
+#[inline]
+fn mul(m: &Mat3, v: Vec3) -> Vec3 {
+    [
+        m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+        m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+        m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+    ]
+}
+
+fn srgb_to_linear(v: f64) -> f64 {
+    let abs = v.abs();
+    if abs <= 0.04045 {
+        v / 12.92
+    } else {
+        v.signum() * ((abs + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(v: f64) -> f64 {
+    let abs = v.abs();
+    if abs <= 0.0031308 {
+        v * 12.92
+    } else {
+        v.signum() * (1.055 * abs.powf(1.0 / 2.4) - 0.055)
+    }
+}
+
+fn prophoto_to_linear(v: f64) -> f64 {
+    let abs = v.abs();
+    if abs <= 16.0 / 512.0 {
+        v / 16.0
+    } else {
+        v.signum() * abs.powf(1.8)
+    }
+}
+
+fn rec2020_to_linear(v: f64) -> f64 {
+    const ALPHA: f64 = 1.09929682680944;
+    const BETA: f64 = 0.018053968510807;
+
+    let abs = v.abs();
+    if abs < BETA * 4.5 {
+        v / 4.5
+    } else {
+        v.signum() * ((abs + ALPHA - 1.0) / ALPHA).powf(1.0 / 0.45)
+    }
+}
+
+// `hue` is in degrees, while `whiteness` and `blackness` are in a 0..=1 range.
+fn hwb_to_srgb(hue: f64, whiteness: f64, blackness: f64) -> Vec3 {
+    if whiteness + blackness >= 1.0 {
+        let gray = whiteness / (whiteness + blackness);
+        return [gray; 3];
+    }
+
+    let hue = hue as f32 / 60.0;
+    let rgb = [
+        hue_to_rgb(0.0, 1.0, hue + 2.0),
+        hue_to_rgb(0.0, 1.0, hue),
+        hue_to_rgb(0.0, 1.0, hue - 2.0),
+    ];
+    rgb.map(|v| v as f64 * (1.0 - whiteness - blackness) + whiteness)
+}
+
+fn lch_to_lab(lightness: f64, chroma: f64, hue: f64) -> Vec3 {
+    let hue = hue.to_radians();
+    [lightness, chroma * hue.cos(), chroma * hue.sin()]
+}
+
+/// Limits the chroma of a Lab or OKLab color, preserving its hue.
+///
+/// Chroma is unbounded in CSS, but huge values overflow the conversion to sRGB.
+/// `max` is the practical upper bound from the CSS Color 4 spec. Colors outside of it
+/// are not visible anyway and end up gamut mapped into sRGB.
+fn limit_chroma(lab: Vec3, max: f64) -> Vec3 {
+    let [lightness, a, b] = lab;
+    if a.hypot(b) <= max {
+        return lab;
+    }
+
+    let (sin, cos) = b.atan2(a).sin_cos();
+    [lightness, max * cos, max * sin]
+}
+
+fn lab_to_xyz_d50(lab: Vec3) -> Vec3 {
+    const KAPPA: f64 = 24389.0 / 27.0;
+    const EPSILON: f64 = 216.0 / 24389.0;
+
+    let [l, a, b] = lab;
+    let f1 = (l + 16.0) / 116.0;
+    let f0 = a / 500.0 + f1;
+    let f2 = f1 - b / 200.0;
+
+    let x = if f0.powi(3) > EPSILON {
+        f0.powi(3)
+    } else {
+        (116.0 * f0 - 16.0) / KAPPA
+    };
+
+    let y = if l > KAPPA * EPSILON {
+        f1.powi(3)
+    } else {
+        l / KAPPA
+    };
+
+    let z = if f2.powi(3) > EPSILON {
+        f2.powi(3)
+    } else {
+        (116.0 * f2 - 16.0) / KAPPA
+    };
+
+    [x * D50_WHITE[0], y * D50_WHITE[1], z * D50_WHITE[2]]
+}
+
+// Based on https://bottosson.github.io/posts/oklab/
+fn oklab_to_linear_srgb(lab: Vec3) -> Vec3 {
+    let [l, a, b] = lab;
+    let l_ = l + 0.3963377774 * a + 0.2158037573 * b;
+    let m_ = l - 0.1055613458 * a - 0.0638541728 * b;
+    let s_ = l - 0.0894841775 * a - 1.2914855480 * b;
+
+    let l = l_ * l_ * l_;
+    let m = m_ * m_ * m_;
+    let s = s_ * s_ * s_;
+
+    [
+        4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+        -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+        -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+    ]
+}
+
+fn linear_srgb_to_oklab(rgb: Vec3) -> Vec3 {
+    let [r, g, b] = rgb;
+    let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+    let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+    let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+
+    [
+        0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+        1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+        0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+    ]
+}
+
+/// Maps a linear sRGB color into the sRGB gamut.
+///
+/// Reduces OKLCh chroma until the color is inside the gamut
+/// or close enough to be clipped.
+///
+/// Based on https://www.w3.org/TR/css-color-4/#binsearch
+fn gamut_map_linear_srgb(rgb: Vec3) -> Vec3 {
+    // Just noticeable difference.
+    const JND: f64 = 0.02;
+    const EPSILON: f64 = 0.0001;
+
+    fn in_gamut(rgb: Vec3) -> bool {
+        rgb.iter().all(|v| (-EPSILON..=1.0 + EPSILON).contains(v))
+    }
+
+    fn clip(rgb: Vec3) -> Vec3 {
+        rgb.map(|v| v.clamp(0.0, 1.0))
+    }
+
+    fn delta_eok(a: Vec3, b: Vec3) -> f64 {
+        ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)).sqrt()
+    }
+
+    if in_gamut(rgb) {
+        return clip(rgb);
+    }
+
+    let origin = linear_srgb_to_oklab(rgb);
+    let [lightness, a, b] = origin;
+    let chroma = a.hypot(b);
+    if !chroma.is_finite() || lightness.is_nan() {
+        return clip(rgb);
+    }
+
+    // Conversion matrices are not exact, therefore a small error is allowed.
+    if lightness >= 1.0 - EPSILON {
+        return [1.0; 3];
+    } else if lightness <= EPSILON {
+        return [0.0; 3];
+    }
+
+    let (sin, cos) = b.atan2(a).sin_cos();
+    let mut clipped = clip(rgb);
+    if delta_eok(linear_srgb_to_oklab(clipped), origin) < JND {
+        return clipped;
+    }
+
+    let mut min = 0.0;
+    let mut max = chroma;
+    let mut min_in_gamut = true;
+    while max - min > EPSILON {
+        let chroma = (min + max) / 2.0;
+        let current_lab = [lightness, chroma * cos, chroma * sin];
+        let current = oklab_to_linear_srgb(current_lab);
+        clipped = clip(current);
+        if min_in_gamut && in_gamut(current) {
+            min = chroma;
+            continue;
+        }
+
+        let e = delta_eok(linear_srgb_to_oklab(clipped), current_lab);
+        if e < JND {
+            if JND - e < EPSILON {
+                break;
+            }
+
+            min_in_gamut = false;
+            min = chroma;
+        } else {
+            max = chroma;
+        }
+    }
+
+    clipped
+}
+
+fn linear_srgb_to_color(rgb: Vec3) -> Color {
+    srgb_to_color(gamut_map_linear_srgb(rgb).map(linear_to_srgb))
+}
+
+// Channels are expected to be in a 0..=1 range. Out of range values will be clipped.
+fn srgb_to_color(rgb: Vec3) -> Color {
+    let [r, g, b] = rgb.map(|v| (v.clamp(0.0, 1.0) * 255.0).round() as u8);
+    Color::new_rgb(r, g, b)
+}
+
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
@@ -572,6 +1103,180 @@ mod tests {
         Color::new_rgba(127, 255, 127, 127)
     );
 
+    test!(
+        rgb_modern,
+        "rgb(254 203 231)",
+        Color::new_rgb(254, 203, 231)
+    );
+
+    test!(
+        rgb_modern_with_alpha,
+        "rgb(255 0 0 / 50%)",
+        Color::new_rgba(255, 0, 0, 127)
+    );
+
+    test!(
+        rgb_modern_mixed_units,
+        "rgb(100% 128 0 / 0.5)",
+        Color::new_rgba(255, 128, 0, 127)
+    );
+
+    test!(
+        rgb_modern_none,
+        "rgb(none 255 none)",
+        Color::new_rgb(0, 255, 0)
+    );
+
+    test!(
+        hsl_modern,
+        "hsl(120deg 100% 50%)",
+        Color::new_rgb(0, 255, 0)
+    );
+
+    test!(
+        hsl_modern_turn,
+        "hsl(0.5turn 100% 25% / 0.5)",
+        Color::new_rgba(0, 127, 127, 127)
+    );
+
+    test!(
+        hwb_green,
+        "hwb(120 0% 0%)",
+        Color::new_rgb(0, 255, 0)
+    );
+
+    test!(
+        hwb_gray,
+        "hwb(0 100% 100%)",
+        Color::new_rgb(128, 128, 128)
+    );
+
+    test!(
+        hwb_mixed,
+        "hwb(200 20% 30%)",
+        Color::new_rgb(51, 136, 179)
+    );
+
+    test!(
+        lab_white,
+        "lab(100 0 0)",
+        Color::new_rgb(255, 255, 255)
+    );
+
+    test!(
+        lab_gray,
+        "lab(50% 0 0)",
+        Color::new_rgb(119, 119, 119)
+    );
+
+    test!(
+        lab_red,
+        "lab(54.29 80.81 69.89)",
+        Color::new_rgb(255, 0, 0)
+    );
+
+    test!(
+        lch_red,
+        "lch(54.29 106.84 40.85)",
+        Color::new_rgb(255, 0, 0)
+    );
+
+    test!(
+        lch_out_of_gamut,
+        "lch(70% 100% 120)",
+        Color::new_rgb(48, 196, 0)
+    );
+
+    test!(
+        oklab_red,
+        "oklab(0.628 0.2249 0.1258)",
+        Color::new_rgb(255, 0, 0)
+    );
+
+    test!(
+        oklch_red,
+        "oklch(62.8% 0.2577 29.23deg)",
+        Color::new_rgb(255, 0, 0)
+    );
+
+    test!(
+        oklch_teal,
+        "oklch(0.7 0.1 200 / 0.5)",
+        Color::new_rgba(64, 177, 183, 127)
+    );
+
+    test!(
+        oklch_white,
+        "oklch(1 0.4 0)",
+        Color::new_rgb(255, 255, 255)
+    );
+
+    test!(
+        color_srgb,
+        "color(srgb 0.5 0.5 0.5)",
+        Color::new_rgb(128, 128, 128)
+    );
+
+    test!(
+        color_srgb_linear,
+        "color(srgb-linear 50% 50% 50%)",
+        Color::new_rgb(188, 188, 188)
+    );
+
+    test!(
+        color_display_p3_in_gamut,
+        "color(display-p3 1 1 1)",
+        Color::new_rgb(255, 255, 255)
+    );
+
+    test!(
+        color_display_p3_red,
+        "color(display-p3 1 0 0)",
+        Color::new_rgb(255, 11, 12)
+    );
+
+    test!(
+        color_rec2020_blue,
+        "color(rec2020 0 0 1)",
+        Color::new_rgb(0, 81, 147)
+    );
+
+    test!(
+        color_xyz_d50_white,
+        "color(xyz-d50 0.9642 1 0.8251)",
+        Color::new_rgb(255, 255, 255)
+    );
+
+    test!(
+        color_prophoto_white,
+        "color(prophoto-rgb 1 1 1)",
+        Color::new_rgb(255, 255, 255)
+    );
+
+    test!(
+        lab_huge_values,
+        "lab(50 1e300 0)",
+        Color::new_rgb(255, 0, 141)
+    );
+
+    test!(
+        lch_huge_values,
+        "lch(50 1e300% 120)",
+        Color::new_rgb(71, 128, 0)
+    );
+
+    test!(
+        oklab_huge_values,
+        "oklab(0.5 1e300 0)",
+        Color::new_rgb(187, 0, 94)
+    );
+
+    test!(
+        oklch_huge_values,
+        "oklch(0.5 1e300% 260)",
+        Color::new_rgb(0, 69, 254)
+    );
+
     macro_rules! test_err {
         ($name:ident, $text:expr, $err:expr) => {
             #[test]
@@ -622,4 +1327,22 @@ mod tests {
         "rgb(140%, -10mm, 130pt)",
         "invalid number at position 14"
     );
+
+    test_err!(
+        rgb_modern_alpha_without_slash,
+        "rgb(255 0 0 0.5)",
+        "expected ')' not '0' at position 13"
+    );
+
+    test_err!(
+        lab_angle_channel,
+        "lab(50 0 0deg)",
+        "invalid value"
+    );
+
+    test_err!(
+        color_unknown_space,
+        "color(unknown 1 0 0)",
+        "invalid value"
+    );
 }
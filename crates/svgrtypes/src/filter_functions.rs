@@ -1,4 +1,4 @@
-use crate::{Angle, AngleUnit, Color, Error, Length, LengthUnit, Stream};
+use crate::{Angle, AngleUnit, Color, Error, Length, LengthUnit, NumberListParser, Stream};
 
 /// Representation of the [`<filter-function>`] | [`<url>`] type.
 ///
@@ -9,6 +9,7 @@ use crate::{Angle, AngleUnit, Color, Error, Length, LengthUnit, Stream};
 /// [`<url>`]: https://www.w3.org/TR/filter-effects-1/#typedef-filter-url
 #[derive(Clone, Copy, PartialEq, Debug)]
 #[allow(missing_docs)]
+#[non_exhaustive]
 pub enum FilterValue<'a> {
     /// Cannot be negative and/or have a percentage units.
     Blur(Length),
@@ -40,6 +41,13 @@ pub enum FilterValue<'a> {
     Saturate(f64),
     /// Cannot be empty.
     Url(&'a str),
+    /// A user-defined filter function, like `custom(film-grain, 0.5, 2)`.
+    Custom {
+        /// Cannot be empty.
+        name: &'a str,
+        /// A valid, possibly empty, number list.
+        args: &'a str,
+    },
 }
 
 /// A list of possible [`FilterValueListParser`] errors.
@@ -191,6 +199,7 @@ impl<'a> FilterValueListParser<'a> {
             b"opacity" => FilterValue::Opacity(parse_generic_color_func(s)?),
             b"saturate" => FilterValue::Saturate(parse_generic_color_func(s)?),
             b"sepia" => FilterValue::Sepia(parse_generic_color_func(s)?),
+            b"custom" => parse_custom_func(s)?,
             b"url" => {
                 s.consume_byte(b'#')?;
                 let link = s.consume_bytes(|_, c| c != b' ' && c != b')');
@@ -267,6 +276,28 @@ fn parse_drop_shadow_func<'a>(
     })
 }
 
+#[inline(never)]
+fn parse_custom_func<'a>(
+    s: &mut Stream<'a>,
+) -> Result<FilterValue<'a>, FilterValueListParserError> {
+    let start = s.pos();
+    let name = s.consume_ascii_ident();
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        return Err(Error::UnexpectedData(s.calc_char_pos_at(start)).into());
+    }
+
+    s.skip_spaces();
+    s.parse_list_separator();
+    s.skip_spaces();
+
+    let args = s.consume_bytes(|_, c| c != b')').trim_end();
+    for n in NumberListParser::from(args) {
+        n?;
+    }
+
+    Ok(FilterValue::Custom { name, args })
+}
+
 #[inline(never)]
 fn parse_generic_color_func(s: &mut Stream) -> Result<f64, FilterValueListParserError> {
     if s.is_curr_byte_eq(b')') {
@@ -576,6 +607,29 @@ mod tests {
         assert!(parser.next().is_none());
     }
 
+    #[test]
+    fn custom() {
+        let mut parser = FilterValueListParser::from("custom(film-grain, 0.5, 2)");
+        assert_eq!(parser.next().unwrap().unwrap(), FilterValue::Custom { name: "film-grain", args: "0.5, 2" });
+        assert!(parser.next().is_none());
+    }
+
+    #[test]
+    fn custom_without_args() {
+        let mut parser = FilterValueListParser::from("custom(glitch) blur()");
+        assert_eq!(parser.next().unwrap().unwrap(), FilterValue::Custom { name: "glitch", args: "" });
+        assert_eq!(parser.next().unwrap().unwrap(), FilterValue::Blur(Length::zero()));
+        assert!(parser.next().is_none());
+    }
+
+    #[test]
+    fn custom_without_name() {
+        let mut parser = FilterValueListParser::from("custom(1 2)");
+        assert_eq!(parser.next().unwrap().unwrap_err().to_string(),
+                   "unexpected data at position 8");
+        assert!(parser.next().is_none());
+    }
+
     #[test]
     fn multiple_1() {
         let mut parser = FilterValueListParser::from("blur() blur()");
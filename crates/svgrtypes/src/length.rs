@@ -3,6 +3,7 @@ use crate::{tokens_helper::TokenizableNumber, Error, Stream};
 /// List of all SVG length units.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 #[allow(missing_docs)]
+#[non_exhaustive]
 pub enum LengthUnit {
     None,
     Em,
@@ -14,6 +15,13 @@ pub enum LengthUnit {
     Pt,
     Pc,
     Percent,
+    Rem,
+    Ch,
+    Vw,
+    Vh,
+    Vmin,
+    Vmax,
+    Q,
 }
 
 /// Representation of the [`<length>`] type.
@@ -39,6 +47,13 @@ impl quote::ToTokens for LengthUnit {
             LengthUnit::Pt => quote::quote! {svgrtypes::LengthUnit::Pt},
             LengthUnit::Pc => quote::quote! {svgrtypes::LengthUnit::Pc},
             LengthUnit::Percent => quote::quote! {svgrtypes::LengthUnit::Percent},
+            LengthUnit::Rem => quote::quote! {svgrtypes::LengthUnit::Rem},
+            LengthUnit::Ch => quote::quote! {svgrtypes::LengthUnit::Ch},
+            LengthUnit::Vw => quote::quote! {svgrtypes::LengthUnit::Vw},
+            LengthUnit::Vh => quote::quote! {svgrtypes::LengthUnit::Vh},
+            LengthUnit::Vmin => quote::quote! {svgrtypes::LengthUnit::Vmin},
+            LengthUnit::Vmax => quote::quote! {svgrtypes::LengthUnit::Vmax},
+            LengthUnit::Q => quote::quote! {svgrtypes::LengthUnit::Q},
         }
         .to_tokens(tokens)
     }
@@ -118,6 +133,7 @@ impl<'a> Stream<'a> {
     /// # Notes
     ///
     /// - Suffix must be lowercase, otherwise it will be an error.
+    ///   The only exception is `Q`, which must be uppercase.
     pub fn parse_length(&mut self) -> Result<Length, Error> {
         self.skip_spaces();
 
@@ -127,34 +143,34 @@ impl<'a> Stream<'a> {
             return Ok(Length::new(n, LengthUnit::None));
         }
 
-        let u = if self.starts_with(b"%") {
-            LengthUnit::Percent
-        } else if self.starts_with(b"em") {
-            LengthUnit::Em
-        } else if self.starts_with(b"ex") {
-            LengthUnit::Ex
-        } else if self.starts_with(b"px") {
-            LengthUnit::Px
-        } else if self.starts_with(b"in") {
-            LengthUnit::In
-        } else if self.starts_with(b"cm") {
-            LengthUnit::Cm
-        } else if self.starts_with(b"mm") {
-            LengthUnit::Mm
-        } else if self.starts_with(b"pt") {
-            LengthUnit::Pt
-        } else if self.starts_with(b"pc") {
-            LengthUnit::Pc
-        } else {
-            LengthUnit::None
+        // Longer suffixes must be checked first.
+        const UNITS: &[(&[u8], LengthUnit)] = &[
+            (b"%", LengthUnit::Percent),
+            (b"rem", LengthUnit::Rem),
+            (b"em", LengthUnit::Em),
+            (b"ex", LengthUnit::Ex),
+            (b"px", LengthUnit::Px),
+            (b"in", LengthUnit::In),
+            (b"cm", LengthUnit::Cm),
+            (b"mm", LengthUnit::Mm),
+            (b"pt", LengthUnit::Pt),
+            (b"pc", LengthUnit::Pc),
+            (b"ch", LengthUnit::Ch),
+            (b"vw", LengthUnit::Vw),
+            (b"vh", LengthUnit::Vh),
+            (b"vmin", LengthUnit::Vmin),
+            (b"vmax", LengthUnit::Vmax),
+            (b"Q", LengthUnit::Q),
+        ];
+
+        let u = match UNITS.iter().find(|(suffix, _)| self.starts_with(suffix)) {
+            Some(&(suffix, unit)) => {
+                self.advance(suffix.len());
+                unit
+            }
+            None => LengthUnit::None,
         };
 
-        match u {
-            LengthUnit::Percent => self.advance(1),
-            LengthUnit::None => {}
-            _ => self.advance(2),
-        }
-
         Ok(Length::new(n, u))
     }
 
@@ -241,6 +257,13 @@ mod tests {
     test_p!(parse_11, "1e0", Length::new(1.0, LengthUnit::None));
     test_p!(parse_12, "1.0e0", Length::new(1.0, LengthUnit::None));
     test_p!(parse_13, "1.0e0em", Length::new(1.0, LengthUnit::Em));
+    test_p!(parse_17, "1rem", Length::new(1.0, LengthUnit::Rem));
+    test_p!(parse_18, "1ch", Length::new(1.0, LengthUnit::Ch));
+    test_p!(parse_19, "1vw", Length::new(1.0, LengthUnit::Vw));
+    test_p!(parse_20, "1vh", Length::new(1.0, LengthUnit::Vh));
+    test_p!(parse_21, "1vmin", Length::new(1.0, LengthUnit::Vmin));
+    test_p!(parse_22, "1vmax", Length::new(1.0, LengthUnit::Vmax));
+    test_p!(parse_23, "1Q", Length::new(1.0, LengthUnit::Q));
 
     #[test]
     fn parse_14() {
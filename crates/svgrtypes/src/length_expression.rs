@@ -0,0 +1,313 @@
+use crate::{Error, Length, LengthUnit, Stream};
+
+/// A maximum nesting level of parentheses inside `calc()`.
+const MAX_NESTING: u8 = 32;
+
+/// Representation of a `<length>` that can be a [`calc()`] expression.
+///
+/// Unitless numbers are stored as lengths with `LengthUnit::None`.
+///
+/// [`calc()`]: https://www.w3.org/TR/css-values-4/#calc-func
+#[derive(Clone, PartialEq, Debug)]
+#[allow(missing_docs)]
+pub enum LengthExpression {
+    Length(Length),
+    Add(Box<LengthExpression>, Box<LengthExpression>),
+    Sub(Box<LengthExpression>, Box<LengthExpression>),
+    Mul(Box<LengthExpression>, Box<LengthExpression>),
+    Div(Box<LengthExpression>, Box<LengthExpression>),
+}
+
+impl LengthExpression {
+    /// Returns a length when the expression is a plain length.
+    #[inline]
+    pub fn as_length(&self) -> Option<Length> {
+        match self {
+            LengthExpression::Length(length) => Some(*length),
+            _ => None,
+        }
+    }
+
+    /// Checks that the expression contains only unitless numbers.
+    pub fn is_number(&self) -> bool {
+        match self {
+            LengthExpression::Length(length) => length.unit == LengthUnit::None,
+            LengthExpression::Add(a, b)
+            | LengthExpression::Sub(a, b)
+            | LengthExpression::Mul(a, b)
+            | LengthExpression::Div(a, b) => a.is_number() && b.is_number(),
+        }
+    }
+
+    /// Evaluates the expression.
+    ///
+    /// `resolve` converts each length into an absolute value.
+    /// Unitless numbers are passed to it as well.
+    ///
+    /// Division by zero produces an infinite value, which has to be handled by the caller.
+    pub fn evaluate<F: FnMut(Length) -> f64>(&self, mut resolve: F) -> f64 {
+        self.evaluate_impl(&mut resolve)
+    }
+
+    fn evaluate_impl<F: FnMut(Length) -> f64>(&self, resolve: &mut F) -> f64 {
+        match self {
+            LengthExpression::Length(length) => resolve(*length),
+            LengthExpression::Add(a, b) => a.evaluate_impl(resolve) + b.evaluate_impl(resolve),
+            LengthExpression::Sub(a, b) => a.evaluate_impl(resolve) - b.evaluate_impl(resolve),
+            LengthExpression::Mul(a, b) => a.evaluate_impl(resolve) * b.evaluate_impl(resolve),
+            LengthExpression::Div(a, b) => a.evaluate_impl(resolve) / b.evaluate_impl(resolve),
+        }
+    }
+}
+
+impl From<Length> for LengthExpression {
+    #[inline]
+    fn from(length: Length) -> Self {
+        LengthExpression::Length(length)
+    }
+}
+
+impl std::str::FromStr for LengthExpression {
+    type Err = Error;
+
+    #[inline]
+    fn from_str(text: &str) -> Result<Self, Error> {
+        let mut s = Stream::from(text);
+        let expr = s.parse_length_expression()?;
+
+        s.skip_spaces();
+        if !s.at_end() {
+            return Err(Error::UnexpectedData(s.calc_char_pos()));
+        }
+
+        Ok(expr)
+    }
+}
+
+impl<'a> Stream<'a> {
+    /// Parses a length or a `calc()` expression from the stream.
+    ///
+    /// Only the `+`, `-`, `*` and `/` operators and parentheses are supported.
+    /// At least one side of `*` and the right side of `/` must be a unitless number.
+    pub fn parse_length_expression(&mut self) -> Result<LengthExpression, Error> {
+        self.skip_spaces();
+
+        if self.starts_with(b"calc(") {
+            self.parse_calc_function(0)
+        } else {
+            self.parse_length().map(LengthExpression::Length)
+        }
+    }
+
+    /// Parses a length expression from a list of length expressions.
+    pub fn parse_list_length_expression(&mut self) -> Result<LengthExpression, Error> {
+        if self.at_end() {
+            return Err(Error::UnexpectedEndOfStream);
+        }
+
+        let l = self.parse_length_expression()?;
+        self.skip_spaces();
+        self.parse_list_separator();
+        Ok(l)
+    }
+
+    fn parse_calc_function(&mut self, depth: u8) -> Result<LengthExpression, Error> {
+        self.advance(4); // calc
+        self.parse_calc_group(depth)
+    }
+
+    fn parse_calc_group(&mut self, depth: u8) -> Result<LengthExpression, Error> {
+        if depth == MAX_NESTING {
+            return Err(Error::InvalidValue);
+        }
+
+        self.consume_byte(b'(')?;
+        let expr = self.parse_calc_sum(depth + 1)?;
+        self.skip_spaces();
+        self.consume_byte(b')')?;
+        Ok(expr)
+    }
+
+    fn parse_calc_sum(&mut self, depth: u8) -> Result<LengthExpression, Error> {
+        let mut expr = self.parse_calc_product(depth)?;
+        loop {
+            self.skip_spaces();
+            let is_add = match self.curr_byte() {
+                Ok(b'+') => true,
+                Ok(b'-') => false,
+                _ => return Ok(expr),
+            };
+
+            self.advance(1);
+            let rhs = Box::new(self.parse_calc_product(depth)?);
+            expr = if is_add {
+                LengthExpression::Add(Box::new(expr), rhs)
+            } else {
+                LengthExpression::Sub(Box::new(expr), rhs)
+            };
+        }
+    }
+
+    fn parse_calc_product(&mut self, depth: u8) -> Result<LengthExpression, Error> {
+        let mut expr = self.parse_calc_value(depth)?;
+        loop {
+            self.skip_spaces();
+            let is_mul = match self.curr_byte() {
+                Ok(b'*') => true,
+                Ok(b'/') => false,
+                _ => return Ok(expr),
+            };
+
+            self.advance(1);
+            let rhs = self.parse_calc_value(depth)?;
+            let is_valid = if is_mul {
+                expr.is_number() || rhs.is_number()
+            } else {
+                rhs.is_number()
+            };
+
+            if !is_valid {
+                return Err(Error::InvalidValue);
+            }
+
+            expr = if is_mul {
+                LengthExpression::Mul(Box::new(expr), Box::new(rhs))
+            } else {
+                LengthExpression::Div(Box::new(expr), Box::new(rhs))
+            };
+        }
+    }
+
+    fn parse_calc_value(&mut self, depth: u8) -> Result<LengthExpression, Error> {
+        self.skip_spaces();
+
+        if self.starts_with(b"(") {
+            self.parse_calc_group(depth)
+        } else if self.starts_with(b"calc(") {
+            self.parse_calc_function(depth)
+        } else {
+            self.parse_length().map(LengthExpression::Length)
+        }
+    }
+}
+
+/// A pull-based list of [`LengthExpression`] parser.
+///
+/// Same as [`LengthListParser`](crate::LengthListParser), but also supports `calc()`.
+///
+/// # Examples
+///
+/// ```
+/// use svgrtypes::{Length, LengthUnit, LengthExpression, LengthExpressionListParser};
+///
+/// let mut p = LengthExpressionListParser::from("10px calc(20% - 1em)");
+/// assert_eq!(p.next().unwrap().unwrap(), Length::new(10.0, LengthUnit::Px).into());
+/// assert_eq!(p.next().unwrap().unwrap(), LengthExpression::Sub(
+///     Box::new(Length::new(20.0, LengthUnit::Percent).into()),
+///     Box::new(Length::new(1.0, LengthUnit::Em).into()),
+/// ));
+/// assert_eq!(p.next().is_none(), true);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct LengthExpressionListParser<'a>(Stream<'a>);
+
+impl<'a> From<&'a str> for LengthExpressionListParser<'a> {
+    #[inline]
+    fn from(v: &'a str) -> Self {
+        LengthExpressionListParser(Stream::from(v))
+    }
+}
+
+impl<'a> Iterator for LengthExpressionListParser<'a> {
+    type Item = Result<LengthExpression, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0.at_end() {
+            None
+        } else {
+            let v = self.0.parse_list_length_expression();
+            if v.is_err() {
+                self.0.jump_to_end();
+            }
+
+            Some(v)
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn len(number: f64, unit: LengthUnit) -> Box<LengthExpression> {
+        Box::new(LengthExpression::Length(Length::new(number, unit)))
+    }
+
+    macro_rules! test_p {
+        ($name:ident, $text:expr, $result:expr) => (
+            #[test]
+            fn $name() {
+                assert_eq!(LengthExpression::from_str($text).unwrap(), $result);
+            }
+        )
+    }
+
+    test_p!(parse_length, "10mm", *len(10.0, LengthUnit::Mm));
+
+    test_p!(parse_sub, "calc(100% - 2em)",
+        LengthExpression::Sub(len(100.0, LengthUnit::Percent), len(2.0, LengthUnit::Em)));
+
+    test_p!(parse_precedence, "calc(1vw + 2 * 3px)",
+        LengthExpression::Add(
+            len(1.0, LengthUnit::Vw),
+            Box::new(LengthExpression::Mul(len(2.0, LengthUnit::None), len(3.0, LengthUnit::Px))),
+        ));
+
+    test_p!(parse_parentheses, "calc((1rem + 2ch) / 2)",
+        LengthExpression::Div(
+            Box::new(LengthExpression::Add(len(1.0, LengthUnit::Rem), len(2.0, LengthUnit::Ch))),
+            len(2.0, LengthUnit::None),
+        ));
+
+    test_p!(parse_nested_calc, " calc( 50vmin*calc(1 + 1) ) ",
+        LengthExpression::Mul(
+            len(50.0, LengthUnit::Vmin),
+            Box::new(LengthExpression::Add(len(1.0, LengthUnit::None), len(1.0, LengthUnit::None))),
+        ));
+
+    #[test]
+    fn evaluate() {
+        let expr = LengthExpression::from_str("calc((100% - 2em) / 4 + 1Q)").unwrap();
+        let n = expr.evaluate(|length| match length.unit {
+            LengthUnit::Percent => length.number * 2.0,
+            LengthUnit::Em => length.number * 10.0,
+            LengthUnit::Q => length.number * 3.0,
+            _ => length.number,
+        });
+        assert_eq!(n, 48.0);
+    }
+
+    #[test]
+    fn parse_list() {
+        let list: Vec<_> = LengthExpressionListParser::from("1, calc(1px + 1px) 3").flatten().collect();
+        assert_eq!(list.len(), 3);
+        assert_eq!(list[1], LengthExpression::Add(len(1.0, LengthUnit::Px), len(1.0, LengthUnit::Px)));
+    }
+
+    macro_rules! test_err {
+        ($name:ident, $text:expr, $err:expr) => (
+            #[test]
+            fn $name() {
+                assert_eq!(LengthExpression::from_str($text).unwrap_err().to_string(), $err);
+            }
+        )
+    }
+
+    test_err!(err_mul_lengths, "calc(1px * 1px)", "invalid value");
+    test_err!(err_div_by_length, "calc(1 / 1px)", "invalid value");
+    test_err!(err_unclosed, "calc(1px + 1px", "unexpected end of stream");
+    test_err!(err_trailing, "calc(1px) 1", "unexpected data at position 11");
+    test_err!(err_nesting, &format!("calc{}1{}", "(".repeat(40), ")".repeat(40)), "invalid value");
+}
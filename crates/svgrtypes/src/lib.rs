@@ -6,6 +6,7 @@
 - [`<color>`](https://www.w3.org/TR/css-color-3/)
 - [`<number>`](https://www.w3.org/TR/SVG2/types.html#InterfaceSVGNumber)
 - [`<length>`](https://www.w3.org/TR/SVG2/types.html#InterfaceSVGLength)
+- [`calc()`](https://www.w3.org/TR/css-values-4/#calc-func)
 - [`<angle>`](https://www.w3.org/TR/SVG2/types.html#InterfaceSVGAngle)
 - [`<viewBox>`](https://www.w3.org/TR/SVG2/coords.html#ViewBoxAttribute)
 - [`<path>`](https://www.w3.org/TR/SVG2/paths.html#PathData)
@@ -20,6 +21,8 @@
 - [`<IRI>`](https://www.w3.org/TR/SVG11/types.html#DataTypeIRI)
 - [`<FuncIRI>`](https://www.w3.org/TR/SVG11/types.html#DataTypeFuncIRI)
 - [`paint-order`](https://www.w3.org/TR/SVG2/painting.html#PaintOrder)
+- [`<basic-shape>`](https://www.w3.org/TR/css-shapes-1/#typedef-basic-shape)
+- [`clip-path`](https://www.w3.org/TR/css-masking-1/#the-clip-path)
 
 ## Features
 
@@ -64,6 +67,7 @@ macro_rules! matches {
 
 mod angle;
 mod aspect_ratio;
+mod clip_path;
 mod color;
 #[rustfmt::skip] mod colors;
 mod directional_position;
@@ -73,6 +77,7 @@ mod filter_functions;
 mod font;
 mod funciri;
 mod length;
+mod length_expression;
 mod number;
 mod paint;
 mod paint_order;
@@ -88,6 +93,7 @@ use crate::stream::{ByteExt, Stream};
 
 pub use crate::angle::*;
 pub use crate::aspect_ratio::*;
+pub use crate::clip_path::*;
 pub use crate::color::*;
 pub use crate::enable_background::*;
 pub use crate::error::*;
@@ -95,6 +101,7 @@ pub use crate::filter_functions::*;
 pub use crate::font::*;
 pub use crate::funciri::*;
 pub use crate::length::*;
+pub use crate::length_expression::*;
 pub use crate::number::*;
 pub use crate::paint::*;
 pub use crate::paint_order::*;
@@ -70,6 +70,9 @@ pub use fontdb;
 pub use ahash;
 pub use lru;
 #[cfg(feature = "text")]
-pub use text_to_paths::UsvgrTextOutlineCache;
+pub use text_to_paths::{
+    layout_str, TextLayoutChunk, TextLayoutCluster, UsvgrTextOutlineCache,
+    DEFAULT_GLYPH_CACHE_CAPACITY,
+};
 pub use writer::WriteOptions;
 pub use xmlwriter::Indent;
@@ -414,6 +414,8 @@ fn process(args: Args) -> Result<(), String> {
         image_rendering: args.image_rendering,
         default_size: usvgr::Size::from_wh(args.default_width as f32, args.default_height as f32)
             .unwrap(),
+        text_grouping: usvgr::TextGrouping::default(),
+        font_fallback: Vec::new(),
         image_data: None,
         sub_svg_data: None,
     };
@@ -5,10 +5,15 @@
 use std::str::FromStr;
 use std::sync::Arc;
 
-use super::converter;
+use svgrtypes::{BasicShape, ClipPathValue, GeometryBox, Length, LengthUnit, ShapeRadius};
+use tiny_skia_path::PathBuilder;
+
 use super::svgtree::{AId, EId, SvgNode};
+use super::{converter, units};
 use crate::svgtree::SvgAttributeValueRef;
-use crate::{ClipPath, Group, NonEmptyString, NonZeroRect, Transform, Units};
+use crate::{
+    ClipPath, Fill, FillRule, Group, Node, NonEmptyString, NonZeroRect, Path, Transform, Units,
+};
 
 pub(crate) fn convert(
     node: SvgNode,
@@ -93,6 +98,220 @@ pub(crate) fn convert(
     }
 }
 
+/// Converts a CSS `clip-path` value, like `circle(40%)` or `inset(10px) fill-box`,
+/// into a clip path.
+///
+/// Links must be resolved by the caller.
+pub(crate) fn convert_css(
+    value: ClipPathValue,
+    node: SvgNode,
+    state: &converter::State,
+    object_bbox: Option<NonZeroRect>,
+    object_stroke_bbox: Option<NonZeroRect>,
+    cache: &mut converter::Cache,
+) -> Option<Arc<ClipPath>> {
+    let (shape, reference_box) = match value {
+        ClipPathValue::Shape {
+            shape,
+            reference_box,
+        } => (Some(shape), reference_box),
+        ClipPathValue::ReferenceBox(reference_box) => (None, Some(reference_box)),
+        ClipPathValue::None | ClipPathValue::Url(_) => return None,
+    };
+
+    // SVG elements do not have a CSS layout box. In which case,
+    // `content-box` and `padding-box` are resolved as `fill-box`
+    // and `border-box` and `margin-box` as `stroke-box`.
+    let rect = match reference_box.unwrap_or(GeometryBox::BorderBox) {
+        GeometryBox::FillBox | GeometryBox::ContentBox | GeometryBox::PaddingBox => object_bbox,
+        GeometryBox::StrokeBox | GeometryBox::BorderBox | GeometryBox::MarginBox => {
+            object_stroke_bbox
+        }
+        GeometryBox::ViewBox => Some(state.view_box),
+    };
+
+    let rect = match rect {
+        Some(v) => v,
+        None => {
+            log::warn!("Clipping of zero-sized shapes is not allowed.");
+            return None;
+        }
+    };
+
+    let (data, rule) = match shape {
+        Some(shape) => convert_basic_shape(shape, rect, node, state)?,
+        None => (PathBuilder::from_rect(rect.to_rect()), FillRule::NonZero),
+    };
+
+    let mut path = Path::new_simple(Arc::new(data))?;
+    path.fill = Some(Fill {
+        rule,
+        ..Fill::default()
+    });
+
+    let mut clip = ClipPath::empty(cache.gen_clip_path_id());
+    clip.root.children.push(Node::Path(Box::new(path)));
+    clip.root.calculate_bounding_boxes();
+    Some(Arc::new(clip))
+}
+
+fn convert_basic_shape(
+    shape: BasicShape,
+    rect: NonZeroRect,
+    node: SvgNode,
+    state: &converter::State,
+) -> Option<(tiny_skia_path::Path, FillRule)> {
+    // Percentages are resolved against the reference box and not the viewport.
+    let convert = |length: Length, base: f32| {
+        if length.unit == LengthUnit::Percent {
+            base * length.number as f32 / 100.0
+        } else {
+            units::convert_user_length(length, node, AId::ClipPath, state)
+        }
+    };
+    let convert_x = |length: Length| convert(length, rect.width());
+    let convert_y = |length: Length| convert(length, rect.height());
+
+    match shape {
+        BasicShape::Inset { insets, radii } => {
+            let left = rect.x() + convert_x(insets[3]);
+            let top = rect.y() + convert_y(insets[0]);
+            let right = rect.right() - convert_x(insets[1]);
+            let bottom = rect.bottom() - convert_y(insets[2]);
+            let inset_rect = NonZeroRect::from_ltrb(left, top, right, bottom)?;
+
+            let radii = radii.map(|r| convert_x(r).max(0.0));
+            let path = rounded_rect(inset_rect, radii)?;
+            Some((path, FillRule::NonZero))
+        }
+        BasicShape::Circle { radius, position } => {
+            let cx = rect.x() + convert_x(position.0);
+            let cy = rect.y() + convert_y(position.1);
+            let r = match radius {
+                ShapeRadius::Length(length) => {
+                    let diagonal = (rect.width().powi(2) + rect.height().powi(2)) / 2.0;
+                    convert(length, diagonal.sqrt())
+                }
+                ShapeRadius::ClosestSide => {
+                    let (x_side, y_side) = closest_sides(rect, cx, cy);
+                    x_side.min(y_side)
+                }
+                ShapeRadius::FarthestSide => {
+                    let (x_side, y_side) = farthest_sides(rect, cx, cy);
+                    x_side.max(y_side)
+                }
+            };
+
+            let oval = tiny_skia_path::Rect::from_xywh(cx - r, cy - r, r * 2.0, r * 2.0)?;
+            Some((PathBuilder::from_oval(oval)?, FillRule::NonZero))
+        }
+        BasicShape::Ellipse { rx, ry, position } => {
+            let cx = rect.x() + convert_x(position.0);
+            let cy = rect.y() + convert_y(position.1);
+            let rx = match rx {
+                ShapeRadius::Length(length) => convert_x(length),
+                ShapeRadius::ClosestSide => closest_sides(rect, cx, cy).0,
+                ShapeRadius::FarthestSide => farthest_sides(rect, cx, cy).0,
+            };
+            let ry = match ry {
+                ShapeRadius::Length(length) => convert_y(length),
+                ShapeRadius::ClosestSide => closest_sides(rect, cx, cy).1,
+                ShapeRadius::FarthestSide => farthest_sides(rect, cx, cy).1,
+            };
+
+            let oval = tiny_skia_path::Rect::from_xywh(cx - rx, cy - ry, rx * 2.0, ry * 2.0)?;
+            Some((PathBuilder::from_oval(oval)?, FillRule::NonZero))
+        }
+        BasicShape::Polygon { fill_rule, points } => {
+            let mut builder = PathBuilder::new();
+            for (x, y) in points {
+                let x = rect.x() + convert_x(x);
+                let y = rect.y() + convert_y(y);
+                if builder.is_empty() {
+                    builder.move_to(x, y);
+                } else {
+                    builder.line_to(x, y);
+                }
+            }
+            builder.close();
+
+            Some((builder.finish()?, convert_fill_rule(fill_rule)))
+        }
+        BasicShape::Path { fill_rule, data } => {
+            // Path coordinates are relative to the reference box origin.
+            let path = super::shapes::parse_path_data(data)?;
+            let path = path
+                .as_ref()
+                .clone()
+                .transform(Transform::from_translate(rect.x(), rect.y()))?;
+            Some((path, convert_fill_rule(fill_rule)))
+        }
+    }
+}
+
+fn closest_sides(rect: NonZeroRect, cx: f32, cy: f32) -> (f32, f32) {
+    (
+        (cx - rect.left()).abs().min((rect.right() - cx).abs()),
+        (cy - rect.top()).abs().min((rect.bottom() - cy).abs()),
+    )
+}
+
+fn farthest_sides(rect: NonZeroRect, cx: f32, cy: f32) -> (f32, f32) {
+    (
+        (cx - rect.left()).abs().max((rect.right() - cx).abs()),
+        (cy - rect.top()).abs().max((rect.bottom() - cy).abs()),
+    )
+}
+
+fn convert_fill_rule(rule: svgrtypes::ShapeFillRule) -> FillRule {
+    match rule {
+        svgrtypes::ShapeFillRule::NonZero => FillRule::NonZero,
+        svgrtypes::ShapeFillRule::EvenOdd => FillRule::EvenOdd,
+    }
+}
+
+/// Builds a rectangle with top-left, top-right, bottom-right and bottom-left corner radii.
+fn rounded_rect(rect: NonZeroRect, mut radii: [f32; 4]) -> Option<tiny_skia_path::Path> {
+    // Like in CSS, radii are scaled down proportionally when they do not fit.
+    let mut scale = 1.0f32;
+    for (r1, r2, side) in [
+        (radii[0], radii[1], rect.width()),
+        (radii[3], radii[2], rect.width()),
+        (radii[0], radii[3], rect.height()),
+        (radii[1], radii[2], rect.height()),
+    ] {
+        if r1 + r2 > side {
+            scale = scale.min(side / (r1 + r2));
+        }
+    }
+    for r in &mut radii {
+        *r *= scale;
+    }
+
+    if radii.iter().all(|r| *r == 0.0) {
+        return Some(PathBuilder::from_rect(rect.to_rect()));
+    }
+
+    // A cubic approximation of a quarter of a circle.
+    const K: f32 = 0.552_284_8;
+
+    let (l, t, r, b) = (rect.left(), rect.top(), rect.right(), rect.bottom());
+    let [tl, tr, br, bl] = radii;
+
+    let mut builder = PathBuilder::new();
+    builder.move_to(l + tl, t);
+    builder.line_to(r - tr, t);
+    builder.cubic_to(r - tr * (1.0 - K), t, r, t + tr * (1.0 - K), r, t + tr);
+    builder.line_to(r, b - br);
+    builder.cubic_to(r, b - br * (1.0 - K), r - br * (1.0 - K), b, r - br, b);
+    builder.line_to(l + bl, b);
+    builder.cubic_to(l + bl * (1.0 - K), b, l, b - bl * (1.0 - K), l, b - bl);
+    builder.line_to(l, t + tl);
+    builder.cubic_to(l, t + tl * (1.0 - K), l + tl * (1.0 - K), t, l + tl, t);
+    builder.close();
+    builder.finish()
+}
+
 fn resolve_clip_path_transform(node: SvgNode, state: &converter::State) -> Option<Transform> {
     // Do not use Node::attribute::<Transform>, because it will always
     // return a valid transform.
@@ -6,7 +6,7 @@ use std::collections::{HashMap, HashSet};
 use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
-use svgrtypes::{Length, LengthUnit as Unit, PaintOrderKind, TransformOrigin};
+use svgrtypes::{Length, LengthExpression, LengthUnit as Unit, PaintOrderKind, TransformOrigin};
 use tiny_skia_path::Transform;
 
 #[cfg(feature = "text")]
@@ -34,9 +34,17 @@ pub struct State<'a> {
     /// Used only during nested `svg` size resolving.
     /// Width and height can be set independently.
     pub(crate) use_size: (Option<f32>, Option<f32>),
+    /// A size of the root SVG element.
+    /// Used by viewport-relative units.
+    pub(crate) viewport: Size,
     pub(crate) opt: &'a Options<'a>,
     #[cfg(feature = "text")]
     pub(crate) fontdb: &'a fontdb::Database,
+    /// A hash of the fonts loaded from `@font-face` rules.
+    ///
+    /// Zero when `fontdb` is the shared database.
+    #[cfg(feature = "text")]
+    pub(crate) font_faces_hash: u64,
 }
 
 #[derive(Debug, Default)]
@@ -44,6 +52,8 @@ pub struct State<'a> {
 pub struct Cache {
     #[cfg(feature = "text")]
     pub usvgr_text_cache: Option<UsvgrTextOutlineCache>,
+    #[cfg(feature = "text")]
+    pub(crate) document_fonts: super::fonts::DocumentFontsCache,
     pub clip_paths: HashMap<String, Arc<ClipPath>>,
     pub masks: HashMap<String, Arc<Mask>>,
     pub filters: HashMap<String, Arc<filter::Filter>>,
@@ -53,10 +63,12 @@ pub struct Cache {
     all_ids: HashSet<u64>,
     linear_gradient_index: usize,
     radial_gradient_index: usize,
+    mesh_gradient_index: usize,
     pattern_index: usize,
     clip_path_index: usize,
     mask_index: usize,
     filter_index: usize,
+    text_path_index: usize,
 }
 
 impl Cache {
@@ -73,10 +85,12 @@ impl Cache {
 
         self.linear_gradient_index = 0;
         self.radial_gradient_index = 0;
+        self.mesh_gradient_index = 0;
         self.pattern_index = 0;
         self.clip_path_index = 0;
         self.mask_index = 0;
         self.filter_index = 0;
+        self.text_path_index = 0;
     }
 
     /// Creates a new cache with an external text outline cache
@@ -88,6 +102,19 @@ impl Cache {
         }
     }
 
+    /// Creates a new cache with an external text outline cache
+    /// and a glyph outlines cache of the given capacity
+    #[cfg(feature = "text")]
+    pub fn new_with_text_caches(text_cache_capacity: usize, glyph_cache_capacity: usize) -> Self {
+        Self {
+            usvgr_text_cache: UsvgrTextOutlineCache::with_capacities(
+                text_cache_capacity,
+                glyph_cache_capacity,
+            ),
+            ..Default::default()
+        }
+    }
+
     // TODO: macros?
     pub(crate) fn gen_linear_gradient_id(&mut self) -> NonEmptyString {
         loop {
@@ -111,6 +138,17 @@ impl Cache {
         }
     }
 
+    pub(crate) fn gen_mesh_gradient_id(&mut self) -> NonEmptyString {
+        loop {
+            self.mesh_gradient_index += 1;
+            let new_id = format!("meshgradient{}", self.mesh_gradient_index);
+            let new_hash = string_hash(&new_id);
+            if !self.all_ids.contains(&new_hash) {
+                return NonEmptyString::new(new_id).unwrap();
+            }
+        }
+    }
+
     pub(crate) fn gen_pattern_id(&mut self) -> NonEmptyString {
         loop {
             self.pattern_index += 1;
@@ -154,6 +192,17 @@ impl Cache {
             }
         }
     }
+
+    pub(crate) fn gen_text_path_id(&mut self) -> NonEmptyString {
+        loop {
+            self.text_path_index += 1;
+            let new_id = format!("textPath{}", self.text_path_index);
+            let new_hash = string_hash(&new_id);
+            if !self.all_ids.contains(&new_hash) {
+                return NonEmptyString::new(new_id).unwrap();
+            }
+        }
+    }
 }
 
 // TODO: is there a simpler way?
@@ -172,13 +221,10 @@ impl<'a, 'input: 'a> SvgNode<'a, 'input> {
         state: &State,
         def: Length,
     ) -> f32 {
-        units::convert_length(
-            self.attribute(aid).unwrap_or(def),
-            *self,
-            aid,
-            object_units,
-            state,
-        )
+        match self.attribute::<LengthExpression>(aid) {
+            Some(expr) => units::convert_length_expression(&expr, *self, aid, object_units, state),
+            None => units::convert_length(def, *self, aid, object_units, state),
+        }
     }
 
     pub fn convert_user_length(&self, aid: AId, state: &State, def: Length) -> f32 {
@@ -198,8 +244,14 @@ impl<'a, 'input: 'a> SvgNode<'a, 'input> {
         );
 
         if let Some(n) = self.ancestors().find(|n| n.has_attribute(aid)) {
-            if let Some(length) = n.attribute(aid) {
-                return units::convert_user_length(length, n, aid, state);
+            if let Some(expr) = n.attribute::<LengthExpression>(aid) {
+                return units::convert_length_expression(
+                    &expr,
+                    n,
+                    aid,
+                    Units::UserSpaceOnUse,
+                    state,
+                );
             }
         }
 
@@ -222,8 +274,8 @@ impl<'a, 'input: 'a> SvgNode<'a, 'input> {
         object_units: Units,
         state: &State,
     ) -> Option<f32> {
-        Some(units::convert_length(
-            self.attribute(aid)?,
+        Some(units::convert_length_expression(
+            &self.attribute(aid)?,
             *self,
             aid,
             object_units,
@@ -274,6 +326,15 @@ pub(crate) fn convert_doc(
     cache: &mut Cache,
     #[cfg(feature = "text")] fontdb: &fontdb::Database,
 ) -> Result<Tree, Error> {
+    #[cfg(feature = "text")]
+    let document_fonts =
+        super::fonts::load_document_fonts(svg_doc, opt, fontdb, &mut cache.document_fonts);
+    #[cfg(feature = "text")]
+    let (fontdb, font_faces_hash) = match document_fonts {
+        Some(ref fonts) => (&fonts.fontdb, fonts.hash),
+        None => (fontdb, 0),
+    };
+
     let svg = svg_doc.root_element();
     let (size, restore_viewbox) = resolve_svg_size(
         &svg,
@@ -295,6 +356,7 @@ pub(crate) fn convert_doc(
         root: Group::empty(),
         linear_gradients: Vec::new(),
         radial_gradients: Vec::new(),
+        mesh_gradients: Vec::new(),
         patterns: Vec::new(),
         clip_paths: Vec::new(),
         masks: Vec::new(),
@@ -312,9 +374,12 @@ pub(crate) fn convert_doc(
         fe_image_link: false,
         view_box: view_box.rect,
         use_size: (None, None),
+        viewport: size,
         opt,
         #[cfg(feature = "text")]
         fontdb,
+        #[cfg(feature = "text")]
+        font_faces_hash,
     };
 
     for node in svg_doc.descendants() {
@@ -325,6 +390,7 @@ pub(crate) fn convert_doc(
                     | EId::Filter
                     | EId::LinearGradient
                     | EId::Mask
+                    | EId::Meshgradient
                     | EId::Pattern
                     | EId::RadialGradient
             ) && !node.element_id().is_empty()
@@ -369,9 +435,12 @@ fn resolve_svg_size(
         fe_image_link: false,
         view_box: NonZeroRect::from_xywh(0.0, 0.0, 100.0, 100.0).unwrap(),
         use_size: (None, None),
+        viewport: opt.default_size,
         opt,
         #[cfg(feature = "text")]
         fontdb,
+        #[cfg(feature = "text")]
+        font_faces_hash: 0,
     };
 
     let def = Length::new(100.0, Unit::Percent);
@@ -659,6 +728,9 @@ pub(crate) fn convert_group(
         clip_path: None,
         mask: None,
         filters: Vec::new(),
+        enable_background: false,
+        filter_fill: None,
+        filter_stroke: None,
         is_context_element: false,
         bounding_box: dummy,
         abs_bounding_box: dummy,
@@ -666,10 +738,23 @@ pub(crate) fn convert_group(
         abs_stroke_bounding_box: dummy,
         layer_bounding_box: NonZeroRect::from_xywh(0.0, 0.0, 1.0, 1.0).unwrap(),
         abs_layer_bounding_box: NonZeroRect::from_xywh(0.0, 0.0, 1.0, 1.0).unwrap(),
+        non_scaling_stroke_outset: 0.0,
         children: Vec::new(),
     };
     collect_children(cache, &mut g);
 
+    // `accumulate` simply continues the parent background.
+    // A background region is ignored, the whole layer is used instead.
+    //
+    // A new background image requires a separate layer, therefore we start one
+    // only when it will actually be used by a filter.
+    // Many editors are setting `enable-background="new"` on most of the groups.
+    g.enable_background = matches!(
+        node.attribute(AId::EnableBackground),
+        Some(svgrtypes::EnableBackground::New)
+            | Some(svgrtypes::EnableBackground::NewWithRegion { .. })
+    ) && g.has_background_users();
+
     // We need to know group's bounding box before converting
     // clipPaths, masks and filters.
     let object_bbox = g.calculate_object_bbox();
@@ -683,6 +768,26 @@ pub(crate) fn convert_group(
         if clip_path.is_none() {
             return None;
         }
+    } else if let Some(value) = node.attribute::<&str>(AId::ClipPath) {
+        match svgrtypes::ClipPathValue::from_str(value) {
+            Ok(value @ svgrtypes::ClipPathValue::Shape { .. })
+            | Ok(value @ svgrtypes::ClipPathValue::ReferenceBox(_)) => {
+                let object_stroke_bbox = g.calculate_object_stroke_bbox();
+                clip_path = Some(super::clippath::convert_css(
+                    value,
+                    node,
+                    state,
+                    object_bbox,
+                    object_stroke_bbox,
+                    cache,
+                )?);
+            }
+            // `none` or a link to a non-existing element.
+            Ok(_) => {}
+            Err(_) => {
+                log::warn!("Failed to parse {} value: '{}'.", AId::ClipPath, value);
+            }
+        }
     }
 
     let mut mask = None;
@@ -731,6 +836,7 @@ pub(crate) fn convert_group(
         || !transform.is_identity()
         || blend_mode != BlendMode::Normal
         || isolate
+        || g.enable_background
         || is_g_or_use
         || force;
 
@@ -739,6 +845,16 @@ pub(crate) fn convert_group(
         return None;
     }
 
+    // `FillPaint` and `StrokePaint` filter inputs are using the paint of the filtered element.
+    // Paint servers are converted into user space later, alongside the paths ones.
+    let has_input = |input| filters.iter().any(|f| f.has_input(&input));
+    if has_input(filter::Input::FillPaint) {
+        g.filter_fill = super::style::resolve_fill(node, true, state, cache).map(|f| f.paint);
+    }
+    if has_input(filter::Input::StrokePaint) {
+        g.filter_stroke = super::style::resolve_stroke(node, true, state, cache).map(|s| s.paint);
+    }
+
     g.clip_path = clip_path;
     g.mask = mask;
     g.filters = filters;
@@ -768,6 +884,7 @@ fn convert_path(
     let rendering_mode: ShapeRendering = node
         .find_attribute(AId::ShapeRendering)
         .unwrap_or(state.opt.shape_rendering);
+    let vector_effect: VectorEffect = node.attribute(AId::VectorEffect).unwrap_or_default();
 
     // TODO: handle `markers` before `stroke`
     let raw_paint_order: svgrtypes::PaintOrder =
@@ -866,7 +983,8 @@ fn convert_path(
         rendering_mode,
         tiny_skia_path,
         path_transform,
-    );
+    )
+    .and_then(|path| path.with_vector_effect(vector_effect));
 
     let path = match path {
         Some(v) => v,
@@ -136,6 +136,9 @@ pub(crate) fn convert(
             svgrtypes::FilterValue::Saturate(amount) => {
                 create_base_filter_func(convert_saturate_function(amount), &mut filters, cache)
             }
+            svgrtypes::FilterValue::Custom { name, args } => {
+                create_base_filter_func(convert_custom_function(name, args), &mut filters, cache)
+            }
             svgrtypes::FilterValue::Url(url) => {
                 if let Some(link) = node.document().element_by_id(url) {
                     if let Ok(res) = convert_url(link, state, object_bbox, cache) {
@@ -149,6 +152,7 @@ pub(crate) fn convert(
                     has_invalid_urls = true;
                 }
             }
+            _ => {}
         }
     }
 
@@ -338,6 +342,9 @@ fn collect_children(
                 EId::FeColorMatrix => convert_color_matrix(child, &primitives),
                 EId::FeConvolveMatrix => convert_convolve_matrix(child, &primitives)
                     .unwrap_or_else(create_dummy_primitive),
+                EId::FeCustom => {
+                    convert_custom(child, &primitives).unwrap_or_else(create_dummy_primitive)
+                }
                 EId::FeMorphology => convert_morphology(child, scale, &primitives),
                 EId::FeDisplacementMap => convert_displacement_map(child, scale, &primitives),
                 EId::FeTurbulence => convert_turbulence(child),
@@ -499,10 +506,10 @@ fn parse_in(s: &str) -> Input {
     match s {
         "SourceGraphic" => Input::SourceGraphic,
         "SourceAlpha" => Input::SourceAlpha,
-        "BackgroundImage" | "BackgroundAlpha" | "FillPaint" | "StrokePaint" => {
-            log::warn!("{} filter input isn't supported and not planed.", s);
-            Input::SourceGraphic
-        }
+        "BackgroundImage" => Input::BackgroundImage,
+        "BackgroundAlpha" => Input::BackgroundAlpha,
+        "FillPaint" => Input::FillPaint,
+        "StrokePaint" => Input::StrokePaint,
         _ => Input::Reference(s.to_string()),
     }
 }
@@ -726,6 +733,29 @@ fn convert_convolve_matrix(fe: SvgNode, primitives: &[Primitive]) -> Option<Kind
     }))
 }
 
+fn convert_custom(fe: SvgNode, primitives: &[Primitive]) -> Option<Kind> {
+    let name = match fe.attribute::<&str>(AId::Name) {
+        Some(name) if !name.is_empty() => name,
+        _ => {
+            log::warn!("'feCustom' without a name is not allowed.");
+            return None;
+        }
+    };
+
+    let input2 = if fe.has_attribute(AId::In2) {
+        Some(resolve_input(fe, AId::In2, primitives))
+    } else {
+        None
+    };
+
+    Some(Kind::Custom(Custom {
+        name: name.to_string(),
+        input1: resolve_input(fe, AId::In, primitives),
+        input2,
+        args: fe.attribute(AId::Values).unwrap_or_default(),
+    }))
+}
+
 fn convert_displacement_map(fe: SvgNode, scale: Size, primitives: &[Primitive]) -> Kind {
     let parse_channel = |aid| match fe.attribute(aid).unwrap_or("A") {
         "R" => ColorChannel::R,
@@ -1197,6 +1227,20 @@ fn convert_opacity_function(amount: f64) -> Kind {
     })
 }
 
+#[inline(never)]
+fn convert_custom_function(name: &str, args: &str) -> Kind {
+    Kind::Custom(Custom {
+        name: name.to_string(),
+        input1: Input::SourceGraphic,
+        input2: None,
+        // Already validated by the parser.
+        args: svgrtypes::NumberListParser::from(args)
+            .flatten()
+            .map(|n| n as f32)
+            .collect(),
+    })
+}
+
 #[inline(never)]
 fn convert_brightness_function(amount: f64) -> Kind {
     let amount = amount as f32;
@@ -0,0 +1,275 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::hash::{Hash, Hasher};
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+
+use super::svgtree::{self, FontFace, FontFaceSource};
+use super::Options;
+
+/// How many sets of document fonts [`DocumentFontsCache`] keeps.
+const DOCUMENT_FONTS_CACHE_CAPACITY: usize = 8;
+
+/// Fonts loaded from the document `@font-face` rules.
+#[derive(Debug)]
+pub(crate) struct DocumentFonts {
+    /// A copy of the shared database with the document fonts.
+    ///
+    /// Faces of the shared database that have the same family as a loaded `@font-face` rule
+    /// are removed, so the document fonts always take precedence.
+    pub(crate) fontdb: fontdb::Database,
+    /// A hash of all loaded fonts data and descriptors.
+    ///
+    /// Face IDs are unique only inside a single database,
+    /// therefore text caches must take this hash into account.
+    pub(crate) hash: u64,
+}
+
+/// Document fonts shared between documents with the same `@font-face` rules.
+///
+/// Keyed by the rules themselves, which include embedded fonts data,
+/// so neither the shared database is copied nor fonts are decoded again.
+/// Like the text outlines cache, must be used with a single `fontdb::Database`.
+#[derive(Debug)]
+pub(crate) struct DocumentFontsCache {
+    fonts: lru::LruCache<u64, Option<Arc<DocumentFonts>>>,
+}
+
+impl Default for DocumentFontsCache {
+    fn default() -> Self {
+        DocumentFontsCache {
+            fonts: lru::LruCache::new(NonZeroUsize::new(DOCUMENT_FONTS_CACHE_CAPACITY).unwrap()),
+        }
+    }
+}
+
+/// Loads fonts referenced by the document `@font-face` rules.
+///
+/// Returns `None` when the document has no loadable `@font-face` rules.
+pub(crate) fn load_document_fonts(
+    doc: &svgtree::Document,
+    opt: &Options,
+    fontdb: &fontdb::Database,
+    cache: &mut DocumentFontsCache,
+) -> Option<Arc<DocumentFonts>> {
+    if doc.font_faces().is_empty() {
+        return None;
+    }
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    doc.font_faces().hash(&mut hasher);
+    opt.resources_dir.hash(&mut hasher);
+    let key = hasher.finish();
+
+    cache
+        .fonts
+        .get_or_insert(key, || {
+            load_fonts(doc.font_faces(), opt, fontdb).map(Arc::new)
+        })
+        .clone()
+}
+
+fn load_fonts(
+    faces: &[FontFace],
+    opt: &Options,
+    fontdb: &fontdb::Database,
+) -> Option<DocumentFonts> {
+    let mut db = fontdb.clone();
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    let mut loaded_families = Vec::new();
+    for face in faces {
+        let source = face
+            .sources
+            .iter()
+            .find_map(|source| load_source(source, opt, fontdb));
+
+        let (source, index) = match source {
+            Some(v) => v,
+            None => {
+                log::warn!(
+                    "Failed to load any source of the '{}' font face.",
+                    face.family
+                );
+                continue;
+            }
+        };
+
+        if let fontdb::Source::Binary(ref data) = source {
+            data.as_ref().as_ref().hash(&mut hasher);
+        }
+        index.hash(&mut hasher);
+        face.hash(&mut hasher);
+
+        let ids = db.load_font_source(source);
+        for id in ids {
+            // Only faces from the requested collection index.
+            let mut info = match db.face(id) {
+                Some(info) if index.is_none_or(|index| info.index == index) => info.clone(),
+                _ => {
+                    db.remove_face(id);
+                    continue;
+                }
+            };
+
+            db.remove_face(id);
+            apply_descriptors(face, &mut info);
+            db.push_face_info(info);
+            loaded_families.push(face.family.as_str());
+        }
+    }
+
+    if loaded_families.is_empty() {
+        return None;
+    }
+
+    // Families which failed to load are still resolved using the shared database.
+    // IDs are preserved by the database copy.
+    let shadowed = fontdb.faces().filter(|face| {
+        face.families.iter().any(|(name, _)| {
+            loaded_families
+                .iter()
+                .any(|family| family.eq_ignore_ascii_case(name))
+        })
+    });
+    for face in shadowed {
+        db.remove_face(face.id);
+    }
+
+    Some(DocumentFonts {
+        fontdb: db,
+        hash: hasher.finish(),
+    })
+}
+
+/// Loads a font face source.
+///
+/// Returns a source with an optional face index inside a collection.
+fn load_source(
+    source: &FontFaceSource,
+    opt: &Options,
+    fontdb: &fontdb::Database,
+) -> Option<(fontdb::Source, Option<u32>)> {
+    match source {
+        FontFaceSource::Url { url, .. } => {
+            let data = if url.starts_with("data:") {
+                let url = data_url::DataUrl::process(url).ok()?;
+                url.decode_to_vec().ok()?.0
+            } else {
+                read_resource(url, opt)?
+            };
+
+            let data = decompress(data)?;
+            Some((fontdb::Source::Binary(Arc::new(data)), None))
+        }
+        FontFaceSource::Local(name) => {
+            let face = fontdb.faces().find(|face| {
+                face.post_script_name == *name || face.families.iter().any(|f| f.0 == *name)
+            })?;
+            Some((face.source.clone(), Some(face.index)))
+        }
+    }
+}
+
+/// Reads a font file relative to `Options::resources_dir`.
+///
+/// Files outside of the resources directory are not allowed.
+fn read_resource(url: &str, opt: &Options) -> Option<Vec<u8>> {
+    let dir = match opt.resources_dir {
+        Some(ref dir) => dir.canonicalize().ok()?,
+        None => {
+            log::warn!("Font files cannot be loaded without Options::resources_dir.");
+            return None;
+        }
+    };
+
+    let url = url.strip_prefix("file://").unwrap_or(url);
+    let path = dir.join(url).canonicalize().ok()?;
+    if !path.starts_with(&dir) {
+        log::warn!("Font file '{}' is outside of the resources directory.", url);
+        return None;
+    }
+
+    std::fs::read(path).ok()
+}
+
+/// Decompresses WOFF and WOFF2 fonts.
+fn decompress(data: Vec<u8>) -> Option<Vec<u8>> {
+    let res = match data.get(0..4) {
+        Some(b"wOFF") => wuff::decompress_woff1(&data),
+        Some(b"wOF2") => wuff::decompress_woff2(&data),
+        _ => return Some(data),
+    };
+
+    match res {
+        Ok(data) => Some(data),
+        Err(e) => {
+            log::warn!("Failed to decompress a WOFF font cause {:?}.", e);
+            None
+        }
+    }
+}
+
+fn apply_descriptors(face: &FontFace, info: &mut fontdb::FaceInfo) {
+    info.families = vec![(face.family.clone(), fontdb::Language::English_UnitedStates)];
+
+    if let Some(weight) = face.weight.as_deref().and_then(parse_weight) {
+        info.weight = fontdb::Weight(weight);
+    }
+
+    match face
+        .style
+        .as_deref()
+        .and_then(|s| s.split_whitespace().next())
+    {
+        Some("normal") => info.style = fontdb::Style::Normal,
+        Some("italic") => info.style = fontdb::Style::Italic,
+        Some("oblique") => info.style = fontdb::Style::Oblique,
+        _ => {}
+    }
+
+    if let Some(stretch) = face.stretch.as_deref().and_then(parse_stretch) {
+        info.stretch = stretch;
+    }
+}
+
+fn parse_weight(s: &str) -> Option<u16> {
+    // Variable fonts can have a range, like `100 900`. Use the lower bound.
+    match s.split_whitespace().next()? {
+        "normal" => Some(400),
+        "bold" => Some(700),
+        n => n.parse::<f32>().ok().map(|n| n.clamp(1.0, 1000.0) as u16),
+    }
+}
+
+fn parse_stretch(s: &str) -> Option<fontdb::Stretch> {
+    let s = s.split_whitespace().next()?;
+    let stretch = match s {
+        "ultra-condensed" => fontdb::Stretch::UltraCondensed,
+        "extra-condensed" => fontdb::Stretch::ExtraCondensed,
+        "condensed" => fontdb::Stretch::Condensed,
+        "semi-condensed" => fontdb::Stretch::SemiCondensed,
+        "normal" => fontdb::Stretch::Normal,
+        "semi-expanded" => fontdb::Stretch::SemiExpanded,
+        "expanded" => fontdb::Stretch::Expanded,
+        "extra-expanded" => fontdb::Stretch::ExtraExpanded,
+        "ultra-expanded" => fontdb::Stretch::UltraExpanded,
+        _ => {
+            let n: f32 = s.strip_suffix('%')?.parse().ok()?;
+            match n {
+                n if n <= 56.25 => fontdb::Stretch::UltraCondensed,
+                n if n <= 68.75 => fontdb::Stretch::ExtraCondensed,
+                n if n <= 81.25 => fontdb::Stretch::Condensed,
+                n if n <= 93.75 => fontdb::Stretch::SemiCondensed,
+                n if n <= 106.25 => fontdb::Stretch::Normal,
+                n if n <= 118.75 => fontdb::Stretch::SemiExpanded,
+                n if n <= 137.5 => fontdb::Stretch::Expanded,
+                n if n <= 175.0 => fontdb::Stretch::ExtraExpanded,
+                _ => fontdb::Stretch::UltraExpanded,
+            }
+        }
+    };
+
+    Some(stretch)
+}
@@ -4,7 +4,7 @@
 
 use std::borrow::Cow;
 use std::sync::Arc;
-use svgrtypes::Length;
+use svgrtypes::{Length, LengthExpression};
 
 use super::svgtree::{AId, SvgNode};
 use super::{converter, OptionLog};
@@ -119,8 +119,8 @@ pub(crate) fn convert(node: SvgNode, state: &converter::State, parent: &mut Grou
     );
 
     match (
-        node.attribute::<Length>(AId::Width),
-        node.attribute::<Length>(AId::Height),
+        node.attribute::<LengthExpression>(AId::Width),
+        node.attribute::<LengthExpression>(AId::Height),
     ) {
         (Some(_), None) => {
             // Only width was defined, so we need to scale height accordingly.
@@ -5,6 +5,8 @@
 mod clippath;
 mod converter;
 mod filter;
+#[cfg(feature = "text")]
+mod fonts;
 mod image;
 mod marker;
 mod mask;
@@ -22,7 +24,7 @@ pub mod svgtree;
 mod text;
 
 pub use image::PreloadedImageData;
-pub use options::Options;
+pub use options::{FontFallback, Options, TextGrouping};
 pub(crate) use svgtree::{AId, EId};
 
 pub use self::converter::Cache;
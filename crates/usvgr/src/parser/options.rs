@@ -8,6 +8,81 @@ use crate::{ImageRendering, ShapeRendering, Size, TextRendering, Tree};
 
 use super::image::PreloadedImageData;
 
+/// Specifies how glyphs are grouped when text is converted into paths.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum TextGrouping {
+    /// A path per text span.
+    #[default]
+    Span,
+    /// A group per grapheme cluster.
+    Glyph,
+    /// A group per word.
+    ///
+    /// Word separators, like spaces, do not belong to any group.
+    Word,
+    /// A group per text chunk.
+    ///
+    /// A chunk is a part of the text with an absolute position, like a `tspan` with `x`/`y`.
+    Line,
+}
+
+/// An ordered font fallback chain for a Unicode script.
+///
+/// Used when a span font doesn't have a glyph for a character.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct FontFallback {
+    /// An ISO 15924 script code, like `Hani`, `Arab` or `Deva`.
+    ///
+    /// Case-insensitive.
+    pub script: String,
+
+    /// An optional language tag to match against the text `xml:lang`.
+    ///
+    /// Matches the tag itself and all of its subtags, so `zh` matches `zh-Hant`.
+    /// Case-insensitive.
+    pub language: Option<String>,
+
+    /// Font families in order of preference.
+    pub families: Vec<String>,
+}
+
+impl FontFallback {
+    /// Creates a new fallback chain for all languages of the script.
+    pub fn new(script: &str, families: &[&str]) -> Self {
+        FontFallback {
+            script: script.to_string(),
+            language: None,
+            families: families.iter().map(|f| f.to_string()).collect(),
+        }
+    }
+
+    /// Restricts the fallback chain to a language.
+    pub fn with_language(mut self, language: &str) -> Self {
+        self.language = Some(language.to_string());
+        self
+    }
+
+    pub(crate) fn matches(&self, script: &str, language: Option<&str>) -> bool {
+        if !self.script.eq_ignore_ascii_case(script) {
+            return false;
+        }
+
+        match (self.language.as_deref(), language) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(tag), Some(language)) => {
+                let prefix = match language.get(..tag.len()) {
+                    Some(v) => v,
+                    None => return false,
+                };
+
+                prefix.eq_ignore_ascii_case(tag)
+                    && matches!(language.as_bytes().get(tag.len()), None | Some(b'-'))
+            }
+        }
+    }
+}
+
 /// Processing options.
 #[derive(Debug)]
 pub struct Options<'a> {
@@ -36,6 +111,7 @@ pub struct Options<'a> {
     /// A default font size.
     ///
     /// Will be used when no `font-size` attribute is set in the SVG.
+    /// Also used by the `rem` unit when the root element has no `font-size`.
     ///
     /// Default: 12
     pub font_size: f32,
@@ -76,6 +152,29 @@ pub struct Options<'a> {
     /// Default: `(100, 100)`
     pub default_size: Size,
 
+    /// Specifies how text glyphs are grouped in `Text::flattened`.
+    ///
+    /// Anything other than `TextGrouping::Span` will put each unit into its own `Group`
+    /// with an ID like `{text-id}-glyph-3`, `{text-id}-word-1` or `{text-id}-line-0`.
+    /// The group transform is set to the unit origin, so it can be animated in place.
+    /// Units are numbered in logical order.
+    ///
+    /// Default: `TextGrouping::Span`
+    pub text_grouping: TextGrouping,
+
+    /// Font fallback chains.
+    ///
+    /// When a span font doesn't have a character, chains matching the character script
+    /// and the text `xml:lang` are tried first, followed by chains without a language.
+    /// Chains are tried in the order they are listed.
+    /// If none of them have the character, all fonts are checked
+    /// in the family and PostScript name order.
+    ///
+    /// The chosen fonts are reported by `Text::fallback_faces`.
+    ///
+    /// Default: empty
+    pub font_fallback: Vec<FontFallback>,
+
     /// FFRAMES requirement
     /// Preloded decoded image data
     pub image_data: Option<&'a HashMap<String, Arc<PreloadedImageData>>>,
@@ -98,6 +197,8 @@ impl Default for Options<'_> {
             text_rendering: TextRendering::default(),
             image_rendering: ImageRendering::default(),
             default_size: Size::from_wh(100.0, 100.0).unwrap(),
+            text_grouping: TextGrouping::default(),
+            font_fallback: Vec::new(),
             image_data: None,
             sub_svg_data: None,
         }
@@ -32,6 +32,7 @@ pub(crate) fn convert(
     let paint = match node.tag_name().unwrap() {
         EId::LinearGradient => convert_linear(node, state),
         EId::RadialGradient => convert_radial(node, state),
+        EId::Meshgradient => convert_mesh(node, state),
         EId::Pattern => convert_pattern(node, state, cache),
         _ => unreachable!(),
     };
@@ -73,6 +74,7 @@ fn convert_linear(node: SvgNode, state: &converter::State) -> Option<ServerOrCol
             units,
             transform,
             spread_method: convert_spread_method(node),
+            color_interpolation: convert_color_interpolation(node),
             stops,
         },
     };
@@ -136,6 +138,7 @@ fn convert_radial(node: SvgNode, state: &converter::State) -> Option<ServerOrCol
             units,
             transform,
             spread_method,
+            color_interpolation: convert_color_interpolation(node),
             stops,
         },
     };
@@ -145,6 +148,229 @@ fn convert_radial(node: SvgNode, state: &converter::State) -> Option<ServerOrCol
     ))))
 }
 
+#[inline(never)]
+fn convert_mesh(node: SvgNode, state: &converter::State) -> Option<ServerOrColor> {
+    let id = NonEmptyString::new(node.element_id().to_string())?;
+
+    let mesh = find_mesh_with_rows(node)?;
+
+    let units = convert_units(node, AId::GradientUnits, Units::ObjectBoundingBox);
+    let transform = node.resolve_transform(AId::GradientTransform, state);
+    let origin = tiny_skia_path::Point::from_xy(
+        resolve_number(node, AId::X, units, state, Length::zero()),
+        resolve_number(node, AId::Y, units, state, Length::zero()),
+    );
+
+    let kind = match resolve_attr(node, AId::Type).attribute(AId::Type) {
+        Some("bicubic") => MeshGradientType::Bicubic,
+        _ => MeshGradientType::Bilinear,
+    };
+
+    let mut columns = 0;
+    let mut patches: Vec<MeshPatch> = Vec::new();
+    for (row_idx, row) in mesh
+        .children()
+        .filter(|n| n.tag_name() == Some(EId::Meshrow))
+        .enumerate()
+    {
+        let row_start = patches.len();
+        for (col_idx, patch) in row
+            .children()
+            .filter(|n| n.tag_name() == Some(EId::Meshpatch))
+            .enumerate()
+        {
+            if row_idx != 0 && col_idx >= columns {
+                break;
+            }
+
+            let left = if col_idx != 0 {
+                patches.last().copied()
+            } else {
+                None
+            };
+            let top = if row_idx != 0 {
+                Some(patches[row_start - columns + col_idx])
+            } else {
+                None
+            };
+
+            let patch = convert_mesh_patch(patch, origin, left, top).log_none(|| {
+                log::warn!(
+                    "Mesh gradient '{}' has an invalid patch.",
+                    node.element_id()
+                )
+            })?;
+            patches.push(patch);
+        }
+
+        let row_len = patches.len() - row_start;
+        if row_idx == 0 {
+            columns = row_len;
+        } else if row_len != columns {
+            log::warn!(
+                "Mesh gradient '{}' has rows of different length.",
+                node.element_id()
+            );
+            return None;
+        }
+    }
+
+    if patches.is_empty() {
+        return None;
+    }
+
+    let gradient = MeshGradient {
+        id,
+        units,
+        transform,
+        kind,
+        columns,
+        patches,
+    };
+
+    Some(ServerOrColor::Server(Paint::MeshGradient(Arc::new(
+        gradient,
+    ))))
+}
+
+/// Converts a `meshpatch` element.
+///
+/// Edges shared with the `left` and `top` patches are taken from them,
+/// so only the remaining edges are defined by the `stop` children.
+fn convert_mesh_patch(
+    node: SvgNode,
+    origin: tiny_skia_path::Point,
+    left: Option<MeshPatch>,
+    top: Option<MeshPatch>,
+) -> Option<MeshPatch> {
+    let mut points = [tiny_skia_path::Point::zero(); 12];
+    let mut colors = [Color::black(); 4];
+    let mut opacities = [Opacity::ONE; 4];
+    // Corners are known either from the neighbors or from the previous edge.
+    // The first corner is always known.
+    let mut known_corners = [true, false, false, false];
+    // Only corners shared with the neighbors have their colors set already.
+    let mut known_colors = [false; 4];
+
+    points[0] = origin;
+    if let Some(ref top) = top {
+        for (i, p) in points.iter_mut().take(4).enumerate() {
+            *p = top.points[9 - i];
+        }
+        colors[0] = top.colors[3];
+        colors[1] = top.colors[2];
+        opacities[0] = top.opacities[3];
+        opacities[1] = top.opacities[2];
+        known_corners[1] = true;
+        known_colors[0] = true;
+        known_colors[1] = true;
+    }
+
+    if let Some(ref left) = left {
+        points[9] = left.points[6];
+        points[10] = left.points[5];
+        points[11] = left.points[4];
+        points[0] = left.points[3];
+        colors[0] = left.colors[1];
+        colors[3] = left.colors[2];
+        opacities[0] = left.opacities[1];
+        opacities[3] = left.opacities[2];
+        known_corners[3] = true;
+        known_colors[0] = true;
+        known_colors[3] = true;
+    }
+
+    let mut stops = node.children().filter(|n| n.tag_name() == Some(EId::Stop));
+    for edge in 0..4 {
+        let is_shared = (edge == 0 && top.is_some()) || (edge == 3 && left.is_some());
+        if is_shared {
+            continue;
+        }
+
+        let stop = stops.next()?;
+        let start = edge * 3;
+        let end = (start + 3) % 12;
+
+        if !known_colors[edge] {
+            let (color, opacity) = convert_stop_color(stop);
+            colors[edge] = color;
+            opacities[edge] = opacity;
+        }
+
+        let implied_end = if known_corners[end / 3] {
+            Some(points[end])
+        } else {
+            None
+        };
+
+        let path = stop.attribute::<&str>(AId::Path)?;
+        let [p1, p2, p3] = parse_mesh_edge(path, points[start], implied_end)?;
+        points[start + 1] = p1;
+        points[start + 2] = p2;
+        points[end] = p3;
+        known_corners[end / 3] = true;
+    }
+
+    Some(MeshPatch {
+        points,
+        colors,
+        opacities,
+    })
+}
+
+/// Parses a mesh `stop` path.
+///
+/// The path is a single `l`, `L`, `c` or `C` segment starting at `start`.
+/// The end point can be omitted when the edge ends at an already known corner,
+/// in which case `implied_end` is used. `implied_end` also takes precedence
+/// over an explicit end point, so adjacent edges are always connected.
+///
+/// Returns the curve control points and the end point.
+fn parse_mesh_edge(
+    text: &str,
+    start: tiny_skia_path::Point,
+    implied_end: Option<tiny_skia_path::Point>,
+) -> Option<[tiny_skia_path::Point; 3]> {
+    let text = text.trim_start();
+    let cmd = *text.as_bytes().first()?;
+    let is_relative = cmd.is_ascii_lowercase();
+
+    let mut numbers = Vec::new();
+    for n in svgrtypes::NumberListParser::from(&text[1..]) {
+        numbers.push(n.ok()? as f32);
+    }
+
+    let mut points = Vec::new();
+    for pair in numbers.chunks_exact(2) {
+        let mut p = tiny_skia_path::Point::from_xy(pair[0], pair[1]);
+        if is_relative {
+            p += start;
+        }
+        points.push(p);
+    }
+
+    let (p1, p2, p3) = match (cmd.to_ascii_lowercase(), numbers.len()) {
+        (b'l', 0 | 2) => {
+            let end = implied_end.or_else(|| points.first().copied())?;
+            let lerp = |t: f32| {
+                tiny_skia_path::Point::from_xy(
+                    start.x + (end.x - start.x) * t,
+                    start.y + (end.y - start.y) * t,
+                )
+            };
+            let (p1, p2) = (lerp(1.0 / 3.0), lerp(2.0 / 3.0));
+            (p1, p2, end)
+        }
+        (b'c', 4 | 6) => {
+            let end = implied_end.or_else(|| points.get(2).copied())?;
+            (points[0], points[1], end)
+        }
+        _ => return None,
+    };
+
+    Some([p1, p2, p3])
+}
+
 #[inline(never)]
 fn convert_pattern(
     node: SvgNode,
@@ -208,6 +434,11 @@ fn convert_spread_method(node: SvgNode) -> SpreadMethod {
     node.attribute(AId::SpreadMethod).unwrap_or_default()
 }
 
+fn convert_color_interpolation(node: SvgNode) -> filter::ColorInterpolation {
+    node.find_attribute(AId::ColorInterpolation)
+        .unwrap_or(filter::ColorInterpolation::SRGB)
+}
+
 pub(crate) fn convert_units(node: SvgNode, name: AId, def: Units) -> Units {
     let node = resolve_attr(node, name);
     node.attribute(name).unwrap_or(def)
@@ -255,6 +486,25 @@ fn find_pattern_with_children<'a, 'input: 'a>(
     None
 }
 
+fn find_mesh_with_rows<'a, 'input: 'a>(node: SvgNode<'a, 'input>) -> Option<SvgNode<'a, 'input>> {
+    for link in node.href_iter() {
+        if link.tag_name() != Some(EId::Meshgradient) {
+            log::warn!(
+                "Mesh gradient '{}' cannot reference '{}' via 'xlink:href'.",
+                node.element_id(),
+                link.tag_name().unwrap()
+            );
+            return None;
+        }
+
+        if link.children().any(|n| n.tag_name() == Some(EId::Meshrow)) {
+            return Some(link);
+        }
+    }
+
+    None
+}
+
 fn convert_stops(grad: SvgNode) -> Vec<Stop> {
     let mut stops = Vec::new();
 
@@ -276,30 +526,11 @@ fn convert_stops(grad: SvgNode) -> Vec<Stop> {
             prev_offset = Length::new_number(offset);
             let offset = crate::f32_bound(0.0, offset as f32, 1.0);
 
-            let (color, opacity) = match stop.attribute_value(AId::StopColor) {
-                Some(svgtree::SvgAttributeValueRef::Str("currentColor")) => stop
-                    .find_attribute(AId::Color)
-                    .unwrap_or_else(svgrtypes::Color::black),
-                Some(svgtree::SvgAttributeValueRef::Str(value)) => {
-                    if let Ok(c) = svgrtypes::Color::from_str(value) {
-                        c
-                    } else {
-                        log::warn!("Failed to parse stop-color value: '{}'.", value);
-                        svgrtypes::Color::black()
-                    }
-                }
-                Some(svgtree::SvgAttributeValueRef::Color(color)) => color,
-                _ => svgrtypes::Color::black(),
-            }
-            .split_alpha();
-
-            let stop_opacity = stop
-                .attribute::<Opacity>(AId::StopOpacity)
-                .unwrap_or(Opacity::ONE);
+            let (color, opacity) = convert_stop_color(stop);
             stops.push(Stop {
                 offset: StopOffset::new_clamped(offset),
                 color,
-                opacity: opacity * stop_opacity,
+                opacity,
             });
         }
     }
@@ -385,6 +616,30 @@ fn convert_stops(grad: SvgNode) -> Vec<Stop> {
     stops
 }
 
+fn convert_stop_color(stop: SvgNode) -> (Color, Opacity) {
+    let (color, opacity) = match stop.attribute_value(AId::StopColor) {
+        Some(svgtree::SvgAttributeValueRef::Str("currentColor")) => stop
+            .find_attribute(AId::Color)
+            .unwrap_or_else(svgrtypes::Color::black),
+        Some(svgtree::SvgAttributeValueRef::Str(value)) => {
+            if let Ok(c) = svgrtypes::Color::from_str(value) {
+                c
+            } else {
+                log::warn!("Failed to parse stop-color value: '{}'.", value);
+                svgrtypes::Color::black()
+            }
+        }
+        Some(svgtree::SvgAttributeValueRef::Color(color)) => color,
+        _ => svgrtypes::Color::black(),
+    }
+    .split_alpha();
+
+    let stop_opacity = stop
+        .attribute::<Opacity>(AId::StopOpacity)
+        .unwrap_or(Opacity::ONE);
+    (color, opacity * stop_opacity)
+}
+
 #[inline(never)]
 pub(crate) fn resolve_number(
     node: SvgNode,
@@ -404,6 +659,7 @@ fn resolve_attr<'a, 'input: 'a>(node: SvgNode<'a, 'input>, name: AId) -> SvgNode
     match node.tag_name().unwrap() {
         EId::LinearGradient => resolve_lg_attr(node, name),
         EId::RadialGradient => resolve_rg_attr(node, name),
+        EId::Meshgradient => resolve_mesh_attr(node, name),
         EId::Pattern => resolve_pattern_attr(node, name),
         EId::Filter => resolve_filter_attr(node, name),
         _ => node,
@@ -477,6 +733,25 @@ fn resolve_rg_attr<'a, 'input>(node: SvgNode<'a, 'input>, name: AId) -> SvgNode<
     node
 }
 
+fn resolve_mesh_attr<'a, 'input: 'a>(node: SvgNode<'a, 'input>, name: AId) -> SvgNode<'a, 'input> {
+    for link in node.href_iter() {
+        let tag_name = match link.tag_name() {
+            Some(v) => v,
+            None => return node,
+        };
+
+        if tag_name != EId::Meshgradient {
+            break;
+        }
+
+        if link.has_attribute(name) {
+            return link;
+        }
+    }
+
+    node
+}
+
 fn resolve_pattern_attr<'a, 'input: 'a>(
     node: SvgNode<'a, 'input>,
     name: AId,
@@ -569,6 +844,23 @@ fn node_to_user_coordinates(
 ) {
     match node {
         Node::Group(ref mut g) => {
+            for paint in [&mut g.filter_fill, &mut g.filter_stroke] {
+                if let Some(ref mut p) = paint {
+                    let ok = process_paint(
+                        p,
+                        false,
+                        context_transform,
+                        context_bbox,
+                        g.abs_transform,
+                        g.bounding_box,
+                        cache,
+                    );
+                    if !ok {
+                        *paint = None;
+                    }
+                }
+            }
+
             // No need to check clip paths, because they cannot have paint servers.
             if let Some(ref mut mask) = g.mask {
                 if let Some(ref mut mask) = Arc::get_mut(mask) {
@@ -635,6 +927,21 @@ fn node_to_user_coordinates(
                 bbox,
                 cache,
             );
+
+            // Paths inside `Text::flattened` groups (see `TextGrouping`) are positioned
+            // relative to the group origin, while paint servers are still in the text
+            // coordinate system. Therefore we have to move paint servers into the path one.
+            if text_bbox.is_some() && !path.abs_transform.is_identity() {
+                for paint in [
+                    path.fill.as_mut().map(|f| &mut f.paint),
+                    path.stroke.as_mut().map(|s| &mut s.paint),
+                ]
+                .into_iter()
+                .flatten()
+                {
+                    process_context_paint(paint, Transform::default(), path.abs_transform, cache);
+                }
+            }
         }
         Node::Image(ref mut _image) => {
             // FFRAMES CHANGE
@@ -682,17 +989,48 @@ fn node_to_user_coordinates(
                 }
             }
 
-            update_paint_servers(
-                &mut text.flattened,
-                context_transform,
-                context_bbox,
-                Some(bbox),
-                cache,
-            );
+            // Flattened groups are shared through the text cache,
+            // so copy them only when there is something to resolve.
+            if has_unresolved_paints(&text.flattened) {
+                update_paint_servers(
+                    Arc::make_mut(&mut text.flattened),
+                    context_transform,
+                    context_bbox,
+                    Some(bbox),
+                    cache,
+                );
+            }
         }
     }
 }
 
+/// Checks that a group has paints that `update_paint_servers` would change.
+fn has_unresolved_paints(group: &Group) -> bool {
+    group.children.iter().any(|node| match node {
+        Node::Group(ref g) => {
+            g.filter_fill.is_some()
+                || g.filter_stroke.is_some()
+                || g.mask.is_some()
+                || !g.filters.is_empty()
+                || has_unresolved_paints(g)
+        }
+        Node::Path(ref path) => {
+            let fill = path
+                .fill
+                .as_ref()
+                .map(|f| (&f.paint, f.context_element.is_some()));
+            let stroke = path
+                .stroke
+                .as_ref()
+                .map(|s| (&s.paint, s.context_element.is_some()));
+            fill.into_iter()
+                .chain(stroke)
+                .any(|(paint, has_context)| has_context || !matches!(paint, Paint::Color(_)))
+        }
+        _ => true,
+    })
+}
+
 fn process_fill(
     fill: &mut Option<Fill>,
     path_transform: Transform,
@@ -781,6 +1119,7 @@ fn process_context_paint(
                     units: lg.units,
                     transform,
                     spread_method: lg.spread_method,
+                    color_interpolation: lg.color_interpolation,
                     stops: lg.stops.clone(),
                 },
             }));
@@ -798,10 +1137,22 @@ fn process_context_paint(
                     units: rg.units,
                     transform,
                     spread_method: rg.spread_method,
+                    color_interpolation: rg.color_interpolation,
                     stops: rg.stops.clone(),
                 },
             }))
         }
+        Paint::MeshGradient(ref mg) => {
+            let transform = mg.transform.post_concat(rev_transform);
+            *paint = Paint::MeshGradient(Arc::new(MeshGradient {
+                id: cache.gen_mesh_gradient_id(),
+                units: mg.units,
+                transform,
+                kind: mg.kind,
+                columns: mg.columns,
+                patches: mg.patches.clone(),
+            }))
+        }
         Paint::Pattern(ref pat) => {
             let transform = pat.transform.post_concat(rev_transform);
             *paint = Paint::Pattern(Arc::new(Pattern {
@@ -910,6 +1261,7 @@ impl Paint {
                             units: Units::UserSpaceOnUse,
                             transform,
                             spread_method: lg.spread_method,
+                            color_interpolation: lg.color_interpolation,
                             stops: lg.stops.clone(),
                         },
                     });
@@ -932,11 +1284,28 @@ impl Paint {
                             units: Units::UserSpaceOnUse,
                             transform,
                             spread_method: rg.spread_method,
+                            color_interpolation: rg.color_interpolation,
                             stops: rg.stops.clone(),
                         },
                     });
                 }
             }
+            Paint::MeshGradient(ref mut mg) => {
+                let transform = mg.transform.post_concat(Transform::from_bbox(bbox));
+                if let Some(ref mut mg) = Arc::get_mut(mg) {
+                    mg.transform = transform;
+                    mg.units = Units::UserSpaceOnUse;
+                } else {
+                    *mg = Arc::new(MeshGradient {
+                        id: cache.gen_mesh_gradient_id(),
+                        units: Units::UserSpaceOnUse,
+                        transform,
+                        kind: mg.kind,
+                        columns: mg.columns,
+                        patches: mg.patches.clone(),
+                    });
+                }
+            }
             Paint::Pattern(ref mut patt) => {
                 let rect = if patt.units == Units::ObjectBoundingBox {
                     patt.rect.bbox_transform(bbox)
@@ -1004,6 +1373,7 @@ impl Paint {
             Self::Color(_) => Units::UserSpaceOnUse,
             Self::LinearGradient(ref lg) => lg.units,
             Self::RadialGradient(ref rg) => rg.units,
+            Self::MeshGradient(ref mg) => mg.units,
             Self::Pattern(ref patt) => patt.units,
         }
     }
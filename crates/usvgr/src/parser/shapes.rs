@@ -4,12 +4,12 @@
 
 use std::sync::Arc;
 
-use svgrtypes::Length;
+use svgrtypes::{Length, LengthExpression};
 use tiny_skia_path::Path;
 
 use super::svgtree::{AId, EId, SvgNode};
 use super::{converter, units};
-use crate::{ApproxEqUlps, IsValidLength, Rect};
+use crate::{ApproxEqUlps, IsValidLength, Rect, Units};
 
 pub(crate) fn convert(node: SvgNode, state: &converter::State) -> Option<Arc<Path>> {
     match node.tag_name()? {
@@ -26,6 +26,13 @@ pub(crate) fn convert(node: SvgNode, state: &converter::State) -> Option<Arc<Pat
 
 pub(crate) fn convert_path(node: SvgNode) -> Option<Arc<Path>> {
     let value: &str = node.attribute(AId::D)?;
+    parse_path_data(value)
+}
+
+/// Parses path data.
+///
+/// Like in `d`, the path is rendered up to the first error.
+pub(crate) fn parse_path_data(value: &str) -> Option<Arc<Path>> {
     let mut builder = tiny_skia_path::PathBuilder::new();
     for segment in svgrtypes::SimplifyingPathParser::from(value) {
         let segment = match segment {
@@ -126,37 +133,18 @@ fn convert_rect(node: SvgNode, state: &converter::State) -> Option<Arc<Path>> {
 }
 
 fn resolve_rx_ry(node: SvgNode, state: &converter::State) -> (f32, f32) {
-    let mut rx_opt = node.attribute::<Length>(AId::Rx);
-    let mut ry_opt = node.attribute::<Length>(AId::Ry);
-
-    // Remove negative values first.
-    if let Some(v) = rx_opt {
-        if v.number.is_sign_negative() {
-            rx_opt = None;
-        }
-    }
-    if let Some(v) = ry_opt {
-        if v.number.is_sign_negative() {
-            ry_opt = None;
-        }
-    }
+    let resolve = |aid| {
+        let expr = node.attribute::<LengthExpression>(aid)?;
+        let n = units::convert_length_expression(&expr, node, aid, Units::UserSpaceOnUse, state);
+        // Remove negative values.
+        Some(n).filter(|n| !n.is_sign_negative())
+    };
 
-    // Resolve.
-    match (rx_opt, ry_opt) {
+    match (resolve(AId::Rx), resolve(AId::Ry)) {
         (None, None) => (0.0, 0.0),
-        (Some(rx), None) => {
-            let rx = units::convert_user_length(rx, node, AId::Rx, state);
-            (rx, rx)
-        }
-        (None, Some(ry)) => {
-            let ry = units::convert_user_length(ry, node, AId::Ry, state);
-            (ry, ry)
-        }
-        (Some(rx), Some(ry)) => {
-            let rx = units::convert_user_length(rx, node, AId::Rx, state);
-            let ry = units::convert_user_length(ry, node, AId::Ry, state);
-            (rx, ry)
-        }
+        (Some(rx), None) => (rx, rx),
+        (None, Some(ry)) => (ry, ry),
+        (Some(rx), Some(ry)) => (rx, ry),
     }
 }
 
@@ -0,0 +1,205 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! A minimal `@font-face` rules parser.
+//!
+//! `simplecss` skips at-rules, so we have to extract them ourselves.
+
+/// An `@font-face` rule.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct FontFace {
+    /// A `font-family` descriptor.
+    pub family: String,
+    /// A `src` descriptor in order of preference.
+    pub sources: Vec<FontFaceSource>,
+    /// A raw `font-weight` descriptor.
+    pub weight: Option<String>,
+    /// A raw `font-style` descriptor.
+    pub style: Option<String>,
+    /// A raw `font-stretch` descriptor.
+    pub stretch: Option<String>,
+}
+
+/// An `@font-face` `src` descriptor item.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum FontFaceSource {
+    /// `url()` with an optional `format()` hint.
+    Url { url: String, format: Option<String> },
+    /// `local()`
+    Local(String),
+}
+
+/// Extracts all valid `@font-face` rules from a CSS string.
+///
+/// Rules without `font-family` or `src` are ignored.
+pub(crate) fn parse_font_faces(css: &str, faces: &mut Vec<FontFace>) {
+    let css = strip_comments(css);
+    let mut rest = css.as_str();
+    while let Some(idx) = find_ascii_case_insensitive(rest, "@font-face") {
+        rest = &rest[idx + "@font-face".len()..];
+
+        let start = match rest.find('{') {
+            Some(v) => v + 1,
+            None => break,
+        };
+
+        let len = match take_until_top_level(&rest[start..], '}') {
+            Some(block) => block.len(),
+            None => break,
+        };
+
+        if let Some(face) = parse_font_face(&rest[start..start + len]) {
+            faces.push(face);
+        }
+
+        rest = &rest[(start + len + 1).min(rest.len())..];
+    }
+}
+
+fn parse_font_face(block: &str) -> Option<FontFace> {
+    let mut family = None;
+    let mut sources = Vec::new();
+    let mut weight = None;
+    let mut style = None;
+    let mut stretch = None;
+
+    for declaration in split_top_level(block, ';') {
+        let (name, value) = match declaration.split_once(':') {
+            Some(v) => v,
+            None => continue,
+        };
+
+        let value = value.trim();
+        match name.trim().to_ascii_lowercase().as_str() {
+            "font-family" => family = Some(unquote(value).to_string()),
+            "src" => {
+                sources = split_top_level(value, ',')
+                    .filter_map(parse_source)
+                    .collect()
+            }
+            "font-weight" => weight = Some(value.to_string()),
+            "font-style" => style = Some(value.to_string()),
+            "font-stretch" => stretch = Some(value.to_string()),
+            _ => {}
+        }
+    }
+
+    let family = family.filter(|f| !f.is_empty())?;
+    if sources.is_empty() {
+        return None;
+    }
+
+    Some(FontFace {
+        family,
+        sources,
+        weight,
+        style,
+        stretch,
+    })
+}
+
+fn parse_source(s: &str) -> Option<FontFaceSource> {
+    let s = s.trim();
+    if let Some((name, args, rest)) = parse_function(s) {
+        match name.to_ascii_lowercase().as_str() {
+            "url" => {
+                let format = parse_function(rest.trim())
+                    .filter(|(name, _, _)| name.eq_ignore_ascii_case("format"))
+                    .map(|(_, args, _)| unquote(args).to_ascii_lowercase());
+
+                return Some(FontFaceSource::Url {
+                    url: unquote(args).to_string(),
+                    format,
+                });
+            }
+            "local" => return Some(FontFaceSource::Local(unquote(args).to_string())),
+            _ => {}
+        }
+    }
+
+    log::warn!("Unsupported @font-face source: '{}'.", s);
+    None
+}
+
+/// Splits `name(args) rest` into parts.
+fn parse_function(s: &str) -> Option<(&str, &str, &str)> {
+    let open = s.find('(')?;
+    let name = s[..open].trim();
+    let args = take_until_top_level(&s[open + 1..], ')')?;
+    let rest = s.get(open + 1 + args.len() + 1..).unwrap_or("");
+    Some((name, args.trim(), rest))
+}
+
+fn unquote(s: &str) -> &str {
+    let s = s.trim();
+    for quote in ['"', '\''] {
+        if s.len() >= 2 && s.starts_with(quote) && s.ends_with(quote) {
+            return &s[1..s.len() - 1];
+        }
+    }
+
+    s
+}
+
+/// Returns byte indices of a character that is not inside quotes or parentheses.
+///
+/// `url(data:font/woff2;base64,...)` must not be split by `;` or `,`.
+fn top_level_indices(s: &str, separator: char) -> impl Iterator<Item = usize> + '_ {
+    let mut depth = 0u32;
+    let mut quote = None;
+    s.char_indices().filter_map(move |(idx, c)| {
+        match (quote, c) {
+            (Some(q), _) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, _) if c == separator && depth == 0 => return Some(idx),
+            (None, '(') => depth += 1,
+            (None, ')') => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+
+        None
+    })
+}
+
+/// Splits a string by a top-level separator, skipping empty items.
+fn split_top_level(s: &str, separator: char) -> impl Iterator<Item = &str> {
+    let mut start = 0;
+    let mut items = Vec::new();
+    for idx in top_level_indices(s, separator) {
+        items.push(&s[start..idx]);
+        start = idx + separator.len_utf8();
+    }
+    items.push(&s[start..]);
+
+    items.into_iter().filter(|item| !item.trim().is_empty())
+}
+
+/// Returns a string before the first top-level `close` character.
+fn take_until_top_level(s: &str, close: char) -> Option<&str> {
+    let idx = top_level_indices(s, close).next()?;
+    Some(&s[..idx])
+}
+
+fn strip_comments(css: &str) -> String {
+    let mut s = String::with_capacity(css.len());
+    let mut rest = css;
+    while let Some(start) = rest.find("/*") {
+        s.push_str(&rest[..start]);
+        rest = match rest[start + 2..].find("*/") {
+            Some(end) => &rest[start + 2 + end + 2..],
+            None => "",
+        };
+    }
+
+    s.push_str(rest);
+    s
+}
+
+fn find_ascii_case_insensitive(haystack: &str, needle: &str) -> Option<usize> {
+    haystack
+        .as_bytes()
+        .windows(needle.len())
+        .position(|w| w.eq_ignore_ascii_case(needle.as_bytes()))
+}
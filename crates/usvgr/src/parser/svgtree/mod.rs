@@ -6,6 +6,7 @@ use std::num::NonZeroU32;
 use std::str::FromStr;
 use std::{collections::HashMap, sync::Arc};
 
+mod font_face;
 #[rustfmt::skip] mod names;
 /// FFrames: parser should be available publicly for the svg macro
 pub mod parse;
@@ -18,8 +19,9 @@ use tiny_skia_path::Transform;
 
 use crate::{
     BlendMode, ImageRendering, Opacity, PreloadedImageData, ShapeRendering, SpreadMethod,
-    TextRendering, Units, Visibility,
+    TextRendering, Units, VectorEffect, Visibility,
 };
+pub use font_face::{FontFace, FontFaceSource};
 pub use names::{AId, EId, ATTRIBUTES};
 pub use roxmltree;
 
@@ -88,6 +90,7 @@ pub struct Document<'input> {
     nodes: Vec<NodeData>,
     attrs: Vec<Attribute<'input>>,
     links: HashMap<String, NodeId>,
+    font_faces: Vec<FontFace>,
 }
 
 impl<'input> Document<'input> {
@@ -116,6 +119,14 @@ impl<'input> Document<'input> {
         self.root().descendants()
     }
 
+    /// Returns `@font-face` rules from all `style` elements.
+    ///
+    /// Always empty for documents created from a `NestedSvgDocument`.
+    #[inline]
+    pub fn font_faces(&self) -> &[FontFace] {
+        &self.font_faces
+    }
+
     /// Returns an element by ID.
     ///
     /// Unlike the [`Descendants`] iterator, this is just a HashMap lookup.
@@ -907,7 +918,7 @@ impl EId {
     pub fn is_paint_server(&self) -> bool {
         matches!(
             self,
-            EId::LinearGradient | EId::RadialGradient | EId::Pattern
+            EId::LinearGradient | EId::RadialGradient | EId::Meshgradient | EId::Pattern
         )
     }
 }
@@ -1066,6 +1077,7 @@ fn is_non_inheritable(id: AId) -> bool {
             | AId::TextDecoration
             | AId::Transform
             | AId::TransformOrigin
+            | AId::VectorEffect
     )
 }
 
@@ -1132,6 +1144,15 @@ impl<'a, 'input: 'a> FromValue<'a, 'input> for svgrtypes::Length {
     }
 }
 
+impl<'a, 'input: 'a> FromValue<'a, 'input> for svgrtypes::LengthExpression {
+    fn parse(node: SvgNode, aid: AId, value: SvgAttributeValueRef<'a>) -> Option<Self> {
+        match value {
+            SvgAttributeValueRef::Str(s) => svgrtypes::LengthExpression::from_str(s).ok(),
+            _ => svgrtypes::Length::parse(node, aid, value).map(Into::into),
+        }
+    }
+}
+
 impl<'a, 'input: 'a> FromValue<'a, 'input> for Opacity {
     fn parse(node: SvgNode, aid: AId, value: SvgAttributeValueRef<'a>) -> Option<Self> {
         let length = svgrtypes::Length::parse(node, aid, value)?;
@@ -1326,6 +1347,17 @@ impl<'a, 'input: 'a> FromValue<'a, 'input> for ShapeRendering {
     }
 }
 
+impl<'a, 'input: 'a> FromValue<'a, 'input> for VectorEffect {
+    fn parse(_: SvgNode, _: AId, value: SvgAttributeValueRef<'a>) -> Option<Self> {
+        let s = value.as_str()?;
+        match s {
+            "none" => Some(VectorEffect::None),
+            "non-scaling-stroke" => Some(VectorEffect::NonScalingStroke),
+            _ => None,
+        }
+    }
+}
+
 impl<'a, 'input: 'a> FromValue<'a, 'input> for TextRendering {
     fn parse(_: SvgNode, _: AId, value: SvgAttributeValueRef<'a>) -> Option<Self> {
         let s = value.as_str()?;
@@ -19,6 +19,7 @@ pub enum EId {
     FeComponentTransfer,
     FeComposite,
     FeConvolveMatrix,
+    FeCustom,
     FeDiffuseLighting,
     FeDisplacementMap,
     FeDistantLight,
@@ -46,6 +47,9 @@ pub enum EId {
     LinearGradient,
     Marker,
     Mask,
+    Meshgradient,
+    Meshpatch,
+    Meshrow,
     Path,
     Pattern,
     Polygon,
@@ -67,72 +71,77 @@ pub enum EId {
 pub static ELEMENTS: Map<EId> = Map {
     key: 732231254413039614,
     disps: &[
-        (0, 12),
-        (1, 11),
-        (10, 26),
-        (2, 42),
-        (1, 19),
-        (0, 5),
-        (1, 13),
-        (8, 50),
+        (20, 18),
+        (5, 22),
+        (1, 9),
         (0, 0),
-        (1, 0),
-        (7, 45),
+        (0, 4),
+        (0, 17),
+        (0, 38),
+        (0, 13),
+        (3, 28),
+        (1, 4),
+        (5, 6),
+        (0, 43),
     ],
     entries: &[
         ("feFlood", EId::FeFlood),
-        ("radialGradient", EId::RadialGradient),
-        ("feImage", EId::FeImage),
-        ("stop", EId::Stop),
-        ("fePointLight", EId::FePointLight),
-        ("feConvolveMatrix", EId::FeConvolveMatrix),
+        ("feColorMatrix", EId::FeColorMatrix),
+        ("circle", EId::Circle),
+        ("rect", EId::Rect),
+        ("path", EId::Path),
         ("feComposite", EId::FeComposite),
-        ("clipPath", EId::ClipPath),
-        ("feMerge", EId::FeMerge),
-        ("defs", EId::Defs),
-        ("mask", EId::Mask),
-        ("svg", EId::Svg),
+        ("line", EId::Line),
+        ("feDisplacementMap", EId::FeDisplacementMap),
+        ("meshrow", EId::Meshrow),
+        ("tspan", EId::Tspan),
+        ("feBlend", EId::FeBlend),
+        ("polyline", EId::Polyline),
+        ("feFuncG", EId::FeFuncG),
         ("symbol", EId::Symbol),
-        ("linearGradient", EId::LinearGradient),
+        ("marker", EId::Marker),
+        ("feOffset", EId::FeOffset),
+        ("style", EId::Style),
+        ("feCustom", EId::FeCustom),
+        ("clipPath", EId::ClipPath),
+        ("g", EId::G),
+        ("textPath", EId::TextPath),
         ("feSpecularLighting", EId::FeSpecularLighting),
         ("feFuncB", EId::FeFuncB),
-        ("filter", EId::Filter),
-        ("feFuncG", EId::FeFuncG),
-        ("circle", EId::Circle),
-        ("g", EId::G),
+        ("feTurbulence", EId::FeTurbulence),
+        ("feMergeNode", EId::FeMergeNode),
+        ("feDistantLight", EId::FeDistantLight),
+        ("svg", EId::Svg),
+        ("feMerge", EId::FeMerge),
+        ("feFuncR", EId::FeFuncR),
+        ("a", EId::A),
+        ("fePointLight", EId::FePointLight),
+        ("switch", EId::Switch),
+        ("feGaussianBlur", EId::FeGaussianBlur),
         ("tref", EId::Tref),
-        ("feFuncA", EId::FeFuncA),
+        ("linearGradient", EId::LinearGradient),
+        ("meshpatch", EId::Meshpatch),
+        ("feTile", EId::FeTile),
+        ("mask", EId::Mask),
         ("image", EId::Image),
-        ("text", EId::Text),
-        ("line", EId::Line),
+        ("feDiffuseLighting", EId::FeDiffuseLighting),
+        ("stop", EId::Stop),
         ("pattern", EId::Pattern),
+        ("radialGradient", EId::RadialGradient),
+        ("meshgradient", EId::Meshgradient),
         ("use", EId::Use),
+        ("feComponentTransfer", EId::FeComponentTransfer),
+        ("text", EId::Text),
         ("feDropShadow", EId::FeDropShadow),
-        ("feSpotLight", EId::FeSpotLight),
-        ("marker", EId::Marker),
-        ("style", EId::Style),
-        ("switch", EId::Switch),
-        ("tspan", EId::Tspan),
-        ("feColorMatrix", EId::FeColorMatrix),
-        ("feOffset", EId::FeOffset),
-        ("path", EId::Path),
-        ("feGaussianBlur", EId::FeGaussianBlur),
-        ("feTile", EId::FeTile),
-        ("feTurbulence", EId::FeTurbulence),
-        ("feMergeNode", EId::FeMergeNode),
+        ("feFuncA", EId::FeFuncA),
+        ("feImage", EId::FeImage),
+        ("polygon", EId::Polygon),
+        ("defs", EId::Defs),
         ("feMorphology", EId::FeMorphology),
-        ("a", EId::A),
-        ("textPath", EId::TextPath),
+        ("feConvolveMatrix", EId::FeConvolveMatrix),
+        ("filter", EId::Filter),
         ("ellipse", EId::Ellipse),
-        ("feComponentTransfer", EId::FeComponentTransfer),
-        ("feDistantLight", EId::FeDistantLight),
-        ("polyline", EId::Polyline),
-        ("polygon", EId::Polygon),
-        ("feBlend", EId::FeBlend),
-        ("feDisplacementMap", EId::FeDisplacementMap),
-        ("feDiffuseLighting", EId::FeDiffuseLighting),
-        ("rect", EId::Rect),
-        ("feFuncR", EId::FeFuncR),
+        ("feSpotLight", EId::FeSpotLight),
     ],
 };
 
@@ -246,6 +255,7 @@ pub enum AId {
     KernelMatrix,
     KernelUnitLength,
     Kerning,
+    Lang,
     LengthAdjust,
     LetterSpacing,
     LightingColor,
@@ -277,6 +287,7 @@ pub enum AId {
     MaskUnits,
     MixBlendMode,
     Mode,
+    Name,
     NumOctaves,
     Offset,
     Opacity,
@@ -382,266 +393,268 @@ pub enum AId {
 
 /// A list of all attributes supported
 pub static ATTRIBUTES: Map<AId> = Map {
-    key: 732231254413039614,
+    key: 3347381344252206323,
     disps: &[
-        (63, 17),
-        (0, 1),
-        (0, 113),
-        (0, 21),
-        (0, 8),
-        (0, 23),
-        (0, 15),
-        (0, 3),
-        (40, 203),
-        (0, 3),
-        (0, 6),
-        (1, 31),
-        (0, 68),
-        (11, 161),
-        (0, 41),
-        (2, 58),
-        (7, 10),
+        (0, 195),
         (0, 0),
-        (0, 39),
-        (7, 73),
-        (0, 5),
-        (65, 30),
-        (0, 71),
+        (1, 71),
         (0, 28),
-        (0, 7),
-        (2, 99),
+        (0, 24),
+        (2, 112),
+        (0, 19),
+        (1, 127),
+        (0, 23),
+        (0, 183),
+        (0, 108),
+        (4, 70),
+        (0, 2),
+        (4, 118),
+        (0, 191),
+        (5, 61),
+        (6, 81),
+        (0, 4),
+        (1, 87),
+        (0, 1),
+        (1, 0),
+        (1, 3),
         (0, 168),
+        (6, 69),
+        (0, 204),
+        (0, 5),
         (1, 79),
-        (0, 0),
-        (0, 3),
-        (0, 21),
-        (1, 9),
-        (0, 0),
-        (5, 171),
-        (0, 45),
+        (11, 50),
+        (0, 146),
+        (0, 38),
+        (0, 37),
+        (0, 44),
         (0, 18),
-        (99, 110),
-        (31, 206),
-        (5, 125),
-        (0, 14),
-        (0, 4),
-        (0, 83),
+        (0, 119),
+        (1, 62),
+        (1, 205),
+        (11, 113),
+        (3, 8),
+        (0, 12),
+        (0, 81),
+        (1, 144),
+        (0, 0),
     ],
     entries: &[
-        ("mask-type", AId::MaskType),
-        ("markerWidth", AId::MarkerWidth),
-        ("textLength", AId::TextLength),
-        ("alignment-baseline", AId::AlignmentBaseline),
+        ("shape-subtract", AId::ShapeSubtract),
         ("unicode-bidi", AId::UnicodeBidi),
-        ("dominant-baseline", AId::DominantBaseline),
-        ("orient", AId::Orient),
-        ("x", AId::X),
-        ("font-style", AId::FontStyle),
-        ("color-interpolation", AId::ColorInterpolation),
-        ("style", AId::Style),
-        ("clip-rule", AId::ClipRule),
-        ("bias", AId::Bias),
-        ("font-weight", AId::FontWeight),
-        ("fill", AId::Fill),
-        ("mask-border-outset", AId::MaskBorderOutset),
-        ("x1", AId::X1),
+        ("image-rendering", AId::ImageRendering),
         ("mask", AId::Mask),
-        ("height", AId::Height),
-        ("stroke", AId::Stroke),
-        ("transform", AId::Transform),
-        ("isolation", AId::Isolation),
-        ("fy", AId::Fy),
-        ("text-orientation", AId::TextOrientation),
-        ("stop-opacity", AId::StopOpacity),
-        ("fx", AId::Fx),
-        ("font-kerning", AId::FontKerning),
+        ("requiredFeatures", AId::RequiredFeatures),
+        ("shape-padding", AId::ShapePadding),
+        ("specularConstant", AId::SpecularConstant),
+        ("clip", AId::Clip),
+        ("mask-border-mode", AId::MaskBorderMode),
+        ("dy", AId::Dy),
+        ("mask-size", AId::MaskSize),
+        ("y2", AId::Y2),
+        ("kernelMatrix", AId::KernelMatrix),
+        ("tableValues", AId::TableValues),
+        ("font-feature-settings", AId::FontFeatureSettings),
+        ("mask-border-source", AId::MaskBorderSource),
+        ("font-style", AId::FontStyle),
+        ("pointsAtY", AId::PointsAtY),
+        ("clipPathUnits", AId::ClipPathUnits),
+        ("preserveAspectRatio", AId::PreserveAspectRatio),
+        ("limitingConeAngle", AId::LimitingConeAngle),
+        ("refX", AId::RefX),
+        ("font-variant", AId::FontVariant),
+        ("display", AId::Display),
+        ("in2", AId::In2),
+        ("stroke-linecap", AId::StrokeLinecap),
+        ("mask-type", AId::MaskType),
+        ("text-decoration-stroke", AId::TextDecorationStroke),
+        ("stroke-dashoffset", AId::StrokeDashoffset),
+        ("shape-image-threshold", AId::ShapeImageThreshold),
+        ("font-synthesis", AId::FontSynthesis),
+        ("text-decoration-color", AId::TextDecorationColor),
+        ("shape-rendering", AId::ShapeRendering),
+        ("systemLanguage", AId::SystemLanguage),
+        ("id", AId::Id),
+        ("font-variant-position", AId::FontVariantPosition),
+        ("kernelUnitLength", AId::KernelUnitLength),
+        ("word-spacing", AId::WordSpacing),
+        ("surfaceScale", AId::SurfaceScale),
+        ("color", AId::Color),
         ("cx", AId::Cx),
-        ("maskContentUnits", AId::MaskContentUnits),
-        ("opacity", AId::Opacity),
-        ("filterUnits", AId::FilterUnits),
-        ("lengthAdjust", AId::LengthAdjust),
-        ("text-anchor", AId::TextAnchor),
-        ("stroke-miterlimit", AId::StrokeMiterlimit),
-        ("viewBox", AId::ViewBox),
-        ("visibility", AId::Visibility),
-        ("ry", AId::Ry),
+        ("font-variant-numeric", AId::FontVariantNumeric),
         (
             "glyph-orientation-horizontal",
             AId::GlyphOrientationHorizontal,
         ),
-        ("gradientTransform", AId::GradientTransform),
-        ("markerUnits", AId::MarkerUnits),
-        ("shape-inside", AId::ShapeInside),
-        ("font-size", AId::FontSize),
-        ("yChannelSelector", AId::YChannelSelector),
-        ("clip-path", AId::ClipPath),
-        ("flood-color", AId::FloodColor),
-        ("marker-mid", AId::MarkerMid),
-        ("surfaceScale", AId::SurfaceScale),
-        ("color-profile", AId::ColorProfile),
-        ("requiredFeatures", AId::RequiredFeatures),
-        ("class", AId::Class),
-        ("startOffset", AId::StartOffset),
+        ("pathLength", AId::PathLength),
+        ("mask-composite", AId::MaskComposite),
+        ("text-orientation", AId::TextOrientation),
         ("mask-origin", AId::MaskOrigin),
-        ("stdDeviation", AId::StdDeviation),
+        ("mask-border", AId::MaskBorder),
+        ("href", AId::Href),
         ("mode", AId::Mode),
-        ("overflow", AId::Overflow),
+        ("markerWidth", AId::MarkerWidth),
+        ("stdDeviation", AId::StdDeviation),
+        ("line-height", AId::LineHeight),
+        ("height", AId::Height),
+        ("fill-opacity", AId::FillOpacity),
+        ("font-stretch", AId::FontStretch),
+        ("k2", AId::K2),
+        ("fr", AId::Fr),
+        ("color-rendering", AId::ColorRendering),
+        ("transform-origin", AId::TransformOrigin),
+        ("r", AId::R),
+        ("mask-clip", AId::MaskClip),
+        ("y", AId::Y),
+        ("isolation", AId::Isolation),
+        ("text-underline-position", AId::TextUnderlinePosition),
+        ("elevation", AId::Elevation),
+        ("points", AId::Points),
+        ("edgeMode", AId::EdgeMode),
+        ("font-variant-caps", AId::FontVariantCaps),
+        ("maskUnits", AId::MaskUnits),
+        ("stroke-dasharray", AId::StrokeDasharray),
         ("text-decoration-fill", AId::TextDecorationFill),
-        ("mask-border-mode", AId::MaskBorderMode),
-        ("divisor", AId::Divisor),
-        ("marker-start", AId::MarkerStart),
         ("text-decoration-line", AId::TextDecorationLine),
-        ("preserveAlpha", AId::PreserveAlpha),
+        ("bias", AId::Bias),
         ("primitiveUnits", AId::PrimitiveUnits),
+        ("fy", AId::Fy),
+        ("viewBox", AId::ViewBox),
+        ("font", AId::Font),
+        ("marker-mid", AId::MarkerMid),
+        ("font-size", AId::FontSize),
+        ("intercept", AId::Intercept),
+        ("mask-image", AId::MaskImage),
+        ("yChannelSelector", AId::YChannelSelector),
+        ("z", AId::Z),
+        ("font-variant-east-asian", AId::FontVariantEastAsian),
+        ("dx", AId::Dx),
+        ("baseFrequency", AId::BaseFrequency),
+        ("text-align-last", AId::TextAlignLast),
+        ("diffuseConstant", AId::DiffuseConstant),
+        ("cy", AId::Cy),
+        ("x", AId::X),
+        ("divisor", AId::Divisor),
+        ("y1", AId::Y1),
+        ("numOctaves", AId::NumOctaves),
+        ("values", AId::Values),
+        ("baseline-shift", AId::BaselineShift),
+        ("requiredExtensions", AId::RequiredExtensions),
+        ("mix-blend-mode", AId::MixBlendMode),
+        ("order", AId::Order),
+        ("stroke-linejoin", AId::StrokeLinejoin),
+        ("marker-end", AId::MarkerEnd),
+        ("targetY", AId::TargetY),
+        ("space", AId::Space),
+        ("kerning", AId::Kerning),
+        ("k4", AId::K4),
+        ("glyph-orientation-vertical", AId::GlyphOrientationVertical),
+        ("color-profile", AId::ColorProfile),
+        ("clip-rule", AId::ClipRule),
+        ("paint-order", AId::PaintOrder),
+        ("name", AId::Name),
+        ("font-family", AId::FontFamily),
+        ("dominant-baseline", AId::DominantBaseline),
+        ("operator", AId::Operator),
         ("in", AId::In),
-        ("points", AId::Points),
-        ("pointsAtY", AId::PointsAtY),
+        ("overflow", AId::Overflow),
+        ("azimuth", AId::Azimuth),
+        ("side", AId::Side),
+        ("patternUnits", AId::PatternUnits),
         ("specularExponent", AId::SpecularExponent),
-        ("shape-rendering", AId::ShapeRendering),
-        ("refY", AId::RefY),
-        ("clip", AId::Clip),
-        ("white-space", AId::WhiteSpace),
-        ("vector-effect", AId::VectorEffect),
-        ("image-rendering", AId::ImageRendering),
-        ("stitchTiles", AId::StitchTiles),
+        ("opacity", AId::Opacity),
+        ("stroke-miterlimit", AId::StrokeMiterlimit),
+        ("result", AId::Result),
+        ("mask-border-outset", AId::MaskBorderOutset),
+        ("mask-position", AId::MaskPosition),
+        ("shape-inside", AId::ShapeInside),
+        ("unicode-range", AId::UnicodeRange),
+        ("mask-border-slice", AId::MaskBorderSlice),
+        ("offset", AId::Offset),
         ("stroke-opacity", AId::StrokeOpacity),
-        ("shape-margin", AId::ShapeMargin),
-        ("y2", AId::Y2),
-        ("operator", AId::Operator),
-        ("pathLength", AId::PathLength),
-        ("order", AId::Order),
+        ("lang", AId::Lang),
+        ("pointsAtX", AId::PointsAtX),
+        ("clip-path", AId::ClipPath),
+        ("x1", AId::X1),
+        ("scale", AId::Scale),
+        ("gradientTransform", AId::GradientTransform),
+        ("stop-color", AId::StopColor),
+        ("text-decoration-style", AId::TextDecorationStyle),
         ("text-rendering", AId::TextRendering),
-        ("mask-border", AId::MaskBorder),
+        ("orient", AId::Orient),
+        ("amplitude", AId::Amplitude),
+        ("d", AId::D),
+        ("class", AId::Class),
+        ("letter-spacing", AId::LetterSpacing),
+        ("startOffset", AId::StartOffset),
+        ("radius", AId::Radius),
+        ("ry", AId::Ry),
         ("exponent", AId::Exponent),
+        ("color-interpolation", AId::ColorInterpolation),
+        ("mask-mode", AId::MaskMode),
+        ("font-kerning", AId::FontKerning),
+        ("fill", AId::Fill),
+        ("flood-color", AId::FloodColor),
+        ("filter", AId::Filter),
+        ("lighting-color", AId::LightingColor),
+        ("inline-size", AId::InlineSize),
+        ("enable-background", AId::EnableBackground),
+        ("stroke-width", AId::StrokeWidth),
+        ("patternTransform", AId::PatternTransform),
+        ("width", AId::Width),
+        ("targetX", AId::TargetX),
+        ("transform", AId::Transform),
+        ("stitchTiles", AId::StitchTiles),
+        ("pointsAtZ", AId::PointsAtZ),
+        ("path", AId::Path),
+        ("shape-margin", AId::ShapeMargin),
+        ("markerHeight", AId::MarkerHeight),
+        ("alignment-baseline", AId::AlignmentBaseline),
+        ("textLength", AId::TextLength),
+        ("mask-border-repeat", AId::MaskBorderRepeat),
+        ("font-variant-ligatures", AId::FontVariantLigatures),
+        ("visibility", AId::Visibility),
         (
             "color-interpolation-filters",
             AId::ColorInterpolationFilters,
         ),
-        ("diffuseConstant", AId::DiffuseConstant),
-        ("space", AId::Space),
-        ("font-synthesis", AId::FontSynthesis),
+        ("white-space", AId::WhiteSpace),
+        ("rotate", AId::Rotate),
         ("direction", AId::Direction),
         ("font-size-adjust", AId::FontSizeAdjust),
-        ("kerning", AId::Kerning),
-        ("flood-opacity", AId::FloodOpacity),
-        ("mask-border-source", AId::MaskBorderSource),
-        ("line-height", AId::LineHeight),
-        ("mix-blend-mode", AId::MixBlendMode),
-        ("scale", AId::Scale),
-        ("mask-image", AId::MaskImage),
-        ("dy", AId::Dy),
-        ("xChannelSelector", AId::XChannelSelector),
-        ("unicode-range", AId::UnicodeRange),
-        ("y", AId::Y),
-        ("fill-opacity", AId::FillOpacity),
-        ("mask-composite", AId::MaskComposite),
-        ("targetY", AId::TargetY),
         ("writing-mode", AId::WritingMode),
-        ("transform-origin", AId::TransformOrigin),
-        ("font-variant-caps", AId::FontVariantCaps),
-        ("k2", AId::K2),
-        ("transform-box", AId::TransformBox),
+        ("text-anchor", AId::TextAnchor),
+        ("stroke", AId::Stroke),
+        ("vector-effect", AId::VectorEffect),
+        ("fx", AId::Fx),
+        ("marker-start", AId::MarkerStart),
+        ("style", AId::Style),
+        ("preserveAlpha", AId::PreserveAlpha),
+        ("markerUnits", AId::MarkerUnits),
         ("text-decoration", AId::TextDecoration),
-        ("filter", AId::Filter),
-        ("enable-background", AId::EnableBackground),
-        ("word-spacing", AId::WordSpacing),
-        ("gradientUnits", AId::GradientUnits),
-        ("font-feature-settings", AId::FontFeatureSettings),
-        ("stroke-linecap", AId::StrokeLinecap),
-        ("rx", AId::Rx),
-        ("kernelUnitLength", AId::KernelUnitLength),
-        ("shape-image-threshold", AId::ShapeImageThreshold),
-        ("inline-size", AId::InlineSize),
-        ("edgeMode", AId::EdgeMode),
-        ("text-decoration-style", AId::TextDecorationStyle),
-        ("pointsAtZ", AId::PointsAtZ),
-        ("elevation", AId::Elevation),
-        ("tableValues", AId::TableValues),
-        ("kernelMatrix", AId::KernelMatrix),
-        ("patternTransform", AId::PatternTransform),
+        ("transform-box", AId::TransformBox),
+        ("stop-opacity", AId::StopOpacity),
+        ("k3", AId::K3),
+        ("patternContentUnits", AId::PatternContentUnits),
+        ("xChannelSelector", AId::XChannelSelector),
         ("fill-rule", AId::FillRule),
-        ("color", AId::Color),
-        ("lighting-color", AId::LightingColor),
-        ("cy", AId::Cy),
-        ("fr", AId::Fr),
-        ("systemLanguage", AId::SystemLanguage),
-        ("text-indent", AId::TextIndent),
         ("slope", AId::Slope),
-        ("font", AId::Font),
-        ("requiredExtensions", AId::RequiredExtensions),
-        ("href", AId::Href),
-        ("baseFrequency", AId::BaseFrequency),
-        ("stroke-dashoffset", AId::StrokeDashoffset),
-        ("text-decoration-stroke", AId::TextDecorationStroke),
-        ("display", AId::Display),
-        ("amplitude", AId::Amplitude),
-        ("mask-size", AId::MaskSize),
-        ("font-variant-ligatures", AId::FontVariantLigatures),
-        ("in2", AId::In2),
-        ("maskUnits", AId::MaskUnits),
-        ("k4", AId::K4),
-        ("shape-padding", AId::ShapePadding),
-        ("r", AId::R),
-        ("text-underline-position", AId::TextUnderlinePosition),
-        ("font-variant-east-asian", AId::FontVariantEastAsian),
-        ("z", AId::Z),
-        ("text-decoration-color", AId::TextDecorationColor),
-        ("intercept", AId::Intercept),
-        ("side", AId::Side),
-        ("x2", AId::X2),
-        ("mask-position", AId::MaskPosition),
-        ("pointsAtX", AId::PointsAtX),
-        ("patternUnits", AId::PatternUnits),
-        ("result", AId::Result),
-        ("baseline-shift", AId::BaselineShift),
-        ("stop-color", AId::StopColor),
-        ("k3", AId::K3),
-        ("stroke-dasharray", AId::StrokeDasharray),
-        ("y1", AId::Y1),
-        ("targetX", AId::TargetX),
-        ("stroke-width", AId::StrokeWidth),
-        ("limitingConeAngle", AId::LimitingConeAngle),
-        ("id", AId::Id),
-        ("mask-border-width", AId::MaskBorderWidth),
-        ("shape-subtract", AId::ShapeSubtract),
-        ("font-variant", AId::FontVariant),
-        ("glyph-orientation-vertical", AId::GlyphOrientationVertical),
-        ("offset", AId::Offset),
-        ("type", AId::Type),
-        ("numOctaves", AId::NumOctaves),
-        ("font-stretch", AId::FontStretch),
-        ("mask-clip", AId::MaskClip),
-        ("clipPathUnits", AId::ClipPathUnits),
-        ("path", AId::Path),
+        ("refY", AId::RefY),
         ("seed", AId::Seed),
-        ("font-variant-position", AId::FontVariantPosition),
-        ("rotate", AId::Rotate),
+        ("flood-opacity", AId::FloodOpacity),
+        ("filterUnits", AId::FilterUnits),
+        ("k1", AId::K1),
         ("text-overflow", AId::TextOverflow),
-        ("mask-border-repeat", AId::MaskBorderRepeat),
-        ("stroke-linejoin", AId::StrokeLinejoin),
-        ("mask-mode", AId::MaskMode),
-        ("specularConstant", AId::SpecularConstant),
+        ("maskContentUnits", AId::MaskContentUnits),
+        ("mask-border-width", AId::MaskBorderWidth),
+        ("gradientUnits", AId::GradientUnits),
         ("spreadMethod", AId::SpreadMethod),
-        ("letter-spacing", AId::LetterSpacing),
-        ("font-family", AId::FontFamily),
-        ("azimuth", AId::Azimuth),
-        ("refX", AId::RefX),
-        ("color-rendering", AId::ColorRendering),
-        ("values", AId::Values),
-        ("marker-end", AId::MarkerEnd),
-        ("font-variant-numeric", AId::FontVariantNumeric),
-        ("markerHeight", AId::MarkerHeight),
-        ("radius", AId::Radius),
-        ("width", AId::Width),
-        ("text-align-last", AId::TextAlignLast),
-        ("preserveAspectRatio", AId::PreserveAspectRatio),
-        ("patternContentUnits", AId::PatternContentUnits),
-        ("k1", AId::K1),
-        ("paint-order", AId::PaintOrder),
-        ("d", AId::D),
-        ("mask-border-slice", AId::MaskBorderSlice),
-        ("dx", AId::Dx),
+        ("x2", AId::X2),
+        ("lengthAdjust", AId::LengthAdjust),
+        ("text-indent", AId::TextIndent),
         ("text-align", AId::TextAlign),
+        ("font-weight", AId::FontWeight),
+        ("rx", AId::Rx),
+        ("type", AId::Type),
     ],
 };
 
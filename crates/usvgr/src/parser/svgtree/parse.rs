@@ -65,6 +65,7 @@ fn parse<'input>(xml: &roxmltree::Document<'input>) -> Result<Document<'input>,
         nodes: Vec::new(),
         attrs: Vec::new(),
         links: HashMap::new(),
+        font_faces: Vec::new(),
     };
 
     // build a map of id -> node for resolve_href
@@ -85,7 +86,7 @@ fn parse<'input>(xml: &roxmltree::Document<'input>) -> Result<Document<'input>,
         kind: NodeKind::Root,
     });
 
-    let style_sheet = resolve_css(xml);
+    let style_sheet = resolve_css(xml, &mut doc.font_faces);
 
     parse_xml_node_children(
         xml.root(),
@@ -321,6 +322,19 @@ pub(crate) fn parse_svg_element<'input>(
                     declaration.value
                 );
             }
+        } else if declaration.name == "d" {
+            // The SVG2 `d` property. Only the `path()` function is allowed.
+            if tag_name == EId::Path {
+                if declaration.value == "none" {
+                    insert_attribute(AId::D, "");
+                } else if let Ok(svgrtypes::BasicShape::Path { data, .. }) =
+                    svgrtypes::BasicShape::from_str(declaration.value)
+                {
+                    insert_attribute(AId::D, data);
+                } else {
+                    log::warn!("Failed to parse {} value: '{}'", AId::D, declaration.value);
+                }
+            }
         } else if let Some(aid) = AId::from_str(declaration.name) {
             // Parse only the presentation attributes.
             if aid.is_presentation() {
@@ -574,7 +588,10 @@ fn parse_svg_use_element<'input>(
     )
 }
 
-fn resolve_css<'a>(xml: &'a roxmltree::Document<'a>) -> simplecss::StyleSheet<'a> {
+fn resolve_css<'a>(
+    xml: &'a roxmltree::Document<'a>,
+    font_faces: &mut Vec<super::FontFace>,
+) -> simplecss::StyleSheet<'a> {
     let mut sheet = simplecss::StyleSheet::new();
 
     for node in xml.descendants().filter(|n| n.has_tag_name("style")) {
@@ -590,6 +607,7 @@ fn resolve_css<'a>(xml: &'a roxmltree::Document<'a>) -> simplecss::StyleSheet<'a
         };
 
         sheet.parse_more(text);
+        super::font_face::parse_font_faces(text, font_faces);
     }
 
     sheet
@@ -768,6 +786,7 @@ impl<'a> TryFrom<&'a NestedSvgDocument<'a>> for Document<'a> {
             nodes: Vec::new(),
             attrs: Vec::new(),
             links: HashMap::new(),
+            font_faces: Vec::new(),
         };
 
         // Add a root node.
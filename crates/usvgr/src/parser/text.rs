@@ -5,7 +5,7 @@
 use std::sync::Arc;
 
 use kurbo::{ParamCurve, ParamCurveArclen};
-use svgrtypes::{parse_font_families, FontFamily, Length, LengthUnit};
+use svgrtypes::{parse_font_families, FontFamily, Length, LengthExpression, LengthUnit};
 use svgtree::SvgAttributeValueRef;
 
 use super::svgtree::{AId, EId, FromValue, SvgNode};
@@ -132,18 +132,22 @@ pub(crate) fn convert(
         rotate: rotate_list,
         writing_mode,
         chunks,
+        font_fallback: state.opt.font_fallback.clone(),
+        fallback_faces: Vec::new(),
         abs_transform: parent.abs_transform,
         // All fields below will be reset by `text_to_paths`.
         bounding_box: dummy,
         abs_bounding_box: dummy,
         stroke_bounding_box: dummy,
         abs_stroke_bounding_box: dummy,
-        flattened: Box::new(Group::empty()),
+        flattened: Arc::new(Group::empty()),
     };
 
     if let Some(text) = crate::text_to_paths::convert_with_cache(
         text,
         state.fontdb,
+        state.font_faces_hash,
+        state.opt.text_grouping,
         cache.usvgr_text_cache.as_ref(),
     ) {
         parent.children.push(Node::Text(Box::new(text)));
@@ -193,7 +197,7 @@ fn collect_text_chunks_impl(
                     continue;
                 }
 
-                match resolve_text_flow(child, state) {
+                match resolve_text_flow(child, state, cache) {
                     Some(v) => {
                         iter_state.text_flow = v;
                     }
@@ -296,6 +300,11 @@ fn collect_text_chunks_impl(
             word_spacing: parent.resolve_length(AId::WordSpacing, state, 0.0),
             text_length,
             length_adjust: parent.find_attribute(AId::LengthAdjust).unwrap_or_default(),
+            language: parent
+                .ancestors()
+                .find_map(|n| n.attribute::<&str>(AId::Lang))
+                .filter(|lang| !lang.is_empty())
+                .map(|lang| lang.to_string()),
         };
 
         let mut is_new_span = true;
@@ -357,16 +366,42 @@ fn collect_text_chunks_impl(
     }
 }
 
-fn resolve_text_flow(node: SvgNode, state: &converter::State) -> Option<TextFlow> {
-    let linked_node = node.attribute::<SvgNode>(AId::Href)?;
-    let path = super::shapes::convert(linked_node, state)?;
+fn resolve_text_flow(
+    node: SvgNode,
+    state: &converter::State,
+    cache: &mut converter::Cache,
+) -> Option<TextFlow> {
+    // SVG 2 `path` takes precedence over `href`, unless it's invalid.
+    let inline_path = node
+        .attribute::<&str>(AId::Path)
+        .and_then(super::shapes::parse_path_data);
+
+    let (id, path) = match inline_path {
+        Some(path) => (cache.gen_text_path_id(), path),
+        None => {
+            // Any basic shape can be referenced as well.
+            let linked_node = node.attribute::<SvgNode>(AId::Href)?;
+            let path = super::shapes::convert(linked_node, state)?;
+
+            // The reference path's transform needs to be applied
+            let transform = linked_node.resolve_transform(AId::Transform, state);
+            let path = if !transform.is_identity() {
+                let mut path_copy = path.as_ref().clone();
+                path_copy = path_copy.transform(transform)?;
+                Arc::new(path_copy)
+            } else {
+                path
+            };
+
+            let id = NonEmptyString::new(linked_node.element_id().to_string())?;
+            (id, path)
+        }
+    };
 
-    // The reference path's transform needs to be applied
-    let transform = linked_node.resolve_transform(AId::Transform, state);
-    let path = if !transform.is_identity() {
-        let mut path_copy = path.as_ref().clone();
-        path_copy = path_copy.transform(transform)?;
-        Arc::new(path_copy)
+    // `side=right` places text on the other side of the path,
+    // which is the same as reversing the path direction.
+    let path = if node.attribute(AId::Side) == Some("right") {
+        Arc::new(reverse_path(&path)?)
     } else {
         path
     };
@@ -381,7 +416,6 @@ fn resolve_text_flow(node: SvgNode, state: &converter::State) -> Option<TextFlow
         node.resolve_length(AId::StartOffset, state, 0.0)
     };
 
-    let id = NonEmptyString::new(linked_node.element_id().to_string())?;
     Some(TextFlow::Path(Arc::new(TextPath {
         id,
         start_offset,
@@ -389,7 +423,73 @@ fn resolve_text_flow(node: SvgNode, state: &converter::State) -> Option<TextFlow
     })))
 }
 
-fn convert_font(node: SvgNode, state: &converter::State) -> Font {
+/// Reverses the direction of all subpaths and their order.
+fn reverse_path(path: &tiny_skia_path::Path) -> Option<tiny_skia_path::Path> {
+    use tiny_skia_path::{PathSegment, Point};
+
+    struct SubPath {
+        start: Point,
+        segments: Vec<PathSegment>,
+        closed: bool,
+    }
+
+    let mut subpaths: Vec<SubPath> = Vec::new();
+    for segment in path.segments() {
+        match segment {
+            PathSegment::MoveTo(p) => subpaths.push(SubPath {
+                start: p,
+                segments: Vec::new(),
+                closed: false,
+            }),
+            PathSegment::Close => {
+                if let Some(subpath) = subpaths.last_mut() {
+                    subpath.closed = true;
+                }
+            }
+            _ => subpaths.last_mut()?.segments.push(segment),
+        }
+    }
+
+    let mut builder = tiny_skia_path::PathBuilder::new();
+    for subpath in subpaths.iter().rev() {
+        let end_point = |segment: &PathSegment| match *segment {
+            PathSegment::LineTo(p) | PathSegment::QuadTo(_, p) | PathSegment::CubicTo(_, _, p) => p,
+            _ => subpath.start,
+        };
+
+        let last = subpath.segments.last().map_or(subpath.start, end_point);
+        if subpath.closed && last != subpath.start {
+            // Keep the start point and walk the closing line backwards first.
+            builder.move_to(subpath.start.x, subpath.start.y);
+            builder.line_to(last.x, last.y);
+        } else {
+            builder.move_to(last.x, last.y);
+        }
+        for (idx, segment) in subpath.segments.iter().enumerate().rev() {
+            // The reversed segment ends at the start of the current one.
+            let p = match idx {
+                0 => subpath.start,
+                _ => end_point(&subpath.segments[idx - 1]),
+            };
+
+            match *segment {
+                PathSegment::QuadTo(p1, _) => builder.quad_to(p1.x, p1.y, p.x, p.y),
+                PathSegment::CubicTo(p1, p2, _) => {
+                    builder.cubic_to(p2.x, p2.y, p1.x, p1.y, p.x, p.y)
+                }
+                _ => builder.line_to(p.x, p.y),
+            }
+        }
+
+        if subpath.closed {
+            builder.close();
+        }
+    }
+
+    builder.finish()
+}
+
+pub(crate) fn convert_font(node: SvgNode, state: &converter::State) -> Font {
     let style: FontStyle = node.find_attribute(AId::FontStyle).unwrap_or_default();
     let stretch = conv_font_stretch(node);
     let weight = resolve_font_weight(node);
@@ -695,20 +795,25 @@ fn convert_baseline_shift(node: SvgNode, state: &converter::State) -> Vec<Baseli
         .take_while(|n| n.tag_name() != Some(EId::Text))
         .collect();
     for n in nodes {
-        if let Some(len) = n.try_attribute::<Length>(AId::BaselineShift) {
-            if len.unit == LengthUnit::Percent {
-                let n = super::units::resolve_font_size(n, state) * (len.number as f32 / 100.0);
-                shift.push(BaselineShift::Number(n));
-            } else {
-                let n = super::units::convert_length(
-                    len,
-                    n,
-                    AId::BaselineShift,
-                    Units::ObjectBoundingBox,
-                    state,
-                );
-                shift.push(BaselineShift::Number(n));
-            }
+        if let Some(expr) = n.try_attribute::<LengthExpression>(AId::BaselineShift) {
+            let value = expr.evaluate(|len| {
+                // Percentages are relative to the `font-size`.
+                let value = if len.unit == LengthUnit::Percent {
+                    super::units::resolve_font_size(n, state) * (len.number as f32 / 100.0)
+                } else {
+                    super::units::convert_length(
+                        len,
+                        n,
+                        AId::BaselineShift,
+                        Units::ObjectBoundingBox,
+                        state,
+                    )
+                };
+
+                value as f64
+            });
+
+            shift.push(BaselineShift::Number(super::units::finite_or_zero(value)));
         } else if let Some(s) = n.attribute(AId::BaselineShift) {
             match s {
                 "sub" => shift.push(BaselineShift::Subscript),
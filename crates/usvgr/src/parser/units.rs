@@ -2,7 +2,7 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use svgrtypes::{Length, LengthUnit as Unit};
+use svgrtypes::{Length, LengthExpression, LengthUnit as Unit};
 
 use super::converter;
 use super::svgtree::{AId, SvgNode};
@@ -28,6 +28,10 @@ pub(crate) fn convert_length(
         Unit::Mm => n * dpi / 25.4,
         Unit::Pt => n * dpi / 72.0,
         Unit::Pc => n * dpi / 6.0,
+        Unit::Q => n * dpi / 101.6,
+        Unit::Rem => n * resolve_font_size(node.document().root_element(), state),
+        Unit::Ch => n * resolve_font_size(node, state) * zero_advance(node, state),
+        Unit::Vw | Unit::Vh | Unit::Vmin | Unit::Vmax => convert_viewport_length(length, state),
         Unit::Percent => {
             if object_units == Units::ObjectBoundingBox {
                 n / 100.0
@@ -63,9 +67,25 @@ pub(crate) fn convert_length(
                 }
             }
         }
+        // `LengthUnit` is non-exhaustive. Unknown units are treated as user units.
+        _ => n,
     }
 }
 
+/// Converts a length or a `calc()` expression.
+///
+/// Each length inside the expression is resolved by [`convert_length`].
+pub(crate) fn convert_length_expression(
+    expr: &LengthExpression,
+    node: SvgNode,
+    aid: AId,
+    object_units: Units,
+    state: &converter::State,
+) -> f32 {
+    let n = expr.evaluate(|length| convert_length(length, node, aid, object_units, state) as f64);
+    finite_or_zero(n)
+}
+
 pub(crate) fn convert_user_length(
     length: Length,
     node: SvgNode,
@@ -89,8 +109,14 @@ pub(crate) fn convert_list(node: SvgNode, aid: AId, state: &converter::State) ->
         }
         Some(SvgAttributeValueRef::Str(text)) => {
             let mut num_list = Vec::new();
-            for length in svgrtypes::LengthListParser::from(text).flatten() {
-                num_list.push(convert_user_length(length, node, aid, state));
+            for expr in svgrtypes::LengthExpressionListParser::from(text).flatten() {
+                num_list.push(convert_length_expression(
+                    &expr,
+                    node,
+                    aid,
+                    Units::UserSpaceOnUse,
+                    state,
+                ));
             }
 
             Some(num_list)
@@ -103,30 +129,53 @@ fn convert_percent(length: Length, base: f32) -> f32 {
     base * (length.number as f32) / 100.0
 }
 
+fn convert_viewport_length(length: Length, state: &converter::State) -> f32 {
+    let viewport = state.viewport;
+    let base = match length.unit {
+        Unit::Vw => viewport.width(),
+        Unit::Vh => viewport.height(),
+        Unit::Vmin => viewport.width().min(viewport.height()),
+        _ => viewport.width().max(viewport.height()),
+    };
+
+    convert_percent(length, base)
+}
+
+/// Returns the `0` glyph advance relative to the font size.
+///
+/// Falls back to `0.5` when the font cannot be resolved, like browsers do.
+#[cfg(feature = "text")]
+fn zero_advance(node: SvgNode, state: &converter::State) -> f32 {
+    let font = super::text::convert_font(node, state);
+    crate::text_to_paths::zero_advance(&font, state.fontdb).unwrap_or(0.5)
+}
+
+#[cfg(not(feature = "text"))]
+fn zero_advance(_: SvgNode, _: &converter::State) -> f32 {
+    0.5
+}
+
+/// Converts a calculated value, replacing infinities from division by zero.
+pub(crate) fn finite_or_zero(n: f64) -> f32 {
+    let n = n as f32;
+    if n.is_finite() {
+        n
+    } else {
+        0.0
+    }
+}
+
 #[inline(never)]
 pub(crate) fn resolve_font_size(node: SvgNode, state: &converter::State) -> f32 {
     let nodes: Vec<_> = node.ancestors().collect();
     let mut font_size = state.opt.font_size;
     for n in nodes.iter().rev().skip(1) {
         // skip Root
-        if let Some(length) = n.try_attribute::<Length>(AId::FontSize) {
-            let dpi = state.opt.dpi;
-            let n = length.number as f32;
-            font_size = match length.unit {
-                Unit::None | Unit::Px => n,
-                Unit::Em => n * font_size,
-                Unit::Ex => n * font_size / 2.0,
-                Unit::In => n * dpi,
-                Unit::Cm => n * dpi / 2.54,
-                Unit::Mm => n * dpi / 25.4,
-                Unit::Pt => n * dpi / 72.0,
-                Unit::Pc => n * dpi / 6.0,
-                Unit::Percent => {
-                    // If `font-size` has percent units that it's value
-                    // is relative to the parent node `font-size`.
-                    length.number as f32 * font_size * 0.01
-                }
-            }
+        if let Some(expr) = n.try_attribute::<LengthExpression>(AId::FontSize) {
+            let parent_font_size = font_size;
+            let size = expr
+                .evaluate(|length| convert_font_size(length, *n, parent_font_size, state) as f64);
+            font_size = finite_or_zero(size);
         } else if let Some(name) = n.attribute(AId::FontSize) {
             font_size = convert_named_font_size(name, font_size);
         }
@@ -135,6 +184,44 @@ pub(crate) fn resolve_font_size(node: SvgNode, state: &converter::State) -> f32
     font_size
 }
 
+fn convert_font_size(
+    length: Length,
+    node: SvgNode,
+    parent_font_size: f32,
+    state: &converter::State,
+) -> f32 {
+    let dpi = state.opt.dpi;
+    let n = length.number as f32;
+    match length.unit {
+        Unit::None | Unit::Px => n,
+        Unit::Em => n * parent_font_size,
+        Unit::Ex => n * parent_font_size / 2.0,
+        Unit::In => n * dpi,
+        Unit::Cm => n * dpi / 2.54,
+        Unit::Mm => n * dpi / 25.4,
+        Unit::Pt => n * dpi / 72.0,
+        Unit::Pc => n * dpi / 6.0,
+        Unit::Q => n * dpi / 101.6,
+        Unit::Rem => {
+            // `rem` inside the root element `font-size` is relative to the default font size.
+            let root = node.document().root_element();
+            if node == root {
+                n * state.opt.font_size
+            } else {
+                n * resolve_font_size(root, state)
+            }
+        }
+        Unit::Ch => n * parent_font_size * zero_advance(node, state),
+        Unit::Vw | Unit::Vh | Unit::Vmin | Unit::Vmax => convert_viewport_length(length, state),
+        Unit::Percent => {
+            // If `font-size` has percent units that it's value
+            // is relative to the parent node `font-size`.
+            n * parent_font_size * 0.01
+        }
+        _ => n,
+    }
+}
+
 fn convert_named_font_size(name: &str, parent_font_size: f32) -> f32 {
     let factor = match name {
         "xx-small" => -3,
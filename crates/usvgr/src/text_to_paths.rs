@@ -3,7 +3,7 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::hash::{BuildHasher, Hash, Hasher};
 use std::num::{NonZeroU16, NonZeroUsize};
 use std::sync::Arc;
@@ -17,84 +17,230 @@ use unicode_script::UnicodeScript;
 
 use crate::*;
 
+/// A default capacity of the glyph outlines cache.
+pub const DEFAULT_GLYPH_CACHE_CAPACITY: usize = 4096;
+
 /// Text outline and layoute cache
+///
+/// Has two levels: whole `Text` nodes keyed by their hash
+/// and individual glyph outlines shared between all text nodes.
+/// Glyphs are keyed by a font ID and variation coordinates,
+/// so the cache must be used with a single `fontdb::Database`.
+/// Documents with `@font-face` rules use their own database copy,
+/// which is distinguished by a hash of the loaded fonts.
 #[derive(Debug)]
 pub struct UsvgrTextOutlineCache {
-    cache: RefCell<lru::LruCache<u64, Option<Text>>>,
+    cache: Option<RefCell<lru::LruCache<u64, Option<TextOutline>>>>,
+    glyphs: Option<GlyphOutlineCache>,
     hash_builder: ahash::RandomState,
 }
 
 impl UsvgrTextOutlineCache {
     /// Creates a new cache with the given size.
     /// If the size is 0 none returned.
+    ///
+    /// The glyph outlines cache uses `DEFAULT_GLYPH_CACHE_CAPACITY`.
     pub fn new(size: usize) -> Option<Self> {
         if size > 0 {
-            Some(UsvgrTextOutlineCache {
-                cache: RefCell::new(lru::LruCache::new(NonZeroUsize::new(size).unwrap())),
-                hash_builder: ahash::RandomState::new(),
-            })
+            Self::with_capacities(size, DEFAULT_GLYPH_CACHE_CAPACITY)
         } else {
             None
         }
     }
+
+    /// Creates a new cache with the given text nodes and glyph outlines capacities.
+    ///
+    /// A level with a 0 capacity is disabled.
+    /// If both capacities are 0 none returned.
+    pub fn with_capacities(text_capacity: usize, glyph_capacity: usize) -> Option<Self> {
+        if text_capacity == 0 && glyph_capacity == 0 {
+            return None;
+        }
+
+        Some(UsvgrTextOutlineCache {
+            cache: NonZeroUsize::new(text_capacity)
+                .map(|size| RefCell::new(lru::LruCache::new(size))),
+            glyphs: NonZeroUsize::new(glyph_capacity).map(GlyphOutlineCache::new),
+            hash_builder: ahash::RandomState::new(),
+        })
+    }
+}
+
+/// A glyph outlines cache key.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+struct GlyphCacheKey {
+    /// A hash of the document fonts. Zero for the shared database.
+    fonts: u64,
+    font: ID,
+    glyph: GlyphId,
+    /// Normalized variation coordinates the glyph was outlined with.
+    ///
+    /// Empty for non-variable fonts.
+    coords: Arc<[i16]>,
+}
+
+/// A cache of glyph outlines in font units.
+#[derive(Debug)]
+struct GlyphOutlineCache {
+    outlines: RefCell<lru::LruCache<GlyphCacheKey, Option<Arc<tiny_skia_path::Path>>>>,
+}
+
+impl GlyphOutlineCache {
+    fn new(capacity: NonZeroUsize) -> Self {
+        GlyphOutlineCache {
+            outlines: RefCell::new(lru::LruCache::new(capacity)),
+        }
+    }
+
+    fn outline(
+        &self,
+        db: &fontdb::Database,
+        fonts: u64,
+        font: &ResolvedFont,
+        glyph_id: GlyphId,
+    ) -> Option<Arc<tiny_skia_path::Path>> {
+        let key = GlyphCacheKey {
+            fonts,
+            font: font.id,
+            glyph: glyph_id,
+            coords: font.coords.clone(),
+        };
+
+        self.outlines
+            .borrow_mut()
+            .get_or_insert(key, || db.outline(font, glyph_id).map(Arc::new))
+            .clone()
+    }
+}
+
+/// A glyph outlines cache bound to a specific fonts database.
+#[derive(Clone, Copy)]
+struct GlyphCache<'a> {
+    outlines: &'a GlyphOutlineCache,
+    fonts: u64,
+}
+
+impl GlyphCache<'_> {
+    fn outline(
+        &self,
+        db: &fontdb::Database,
+        font: &ResolvedFont,
+        glyph_id: GlyphId,
+    ) -> Option<Arc<tiny_skia_path::Path>> {
+        self.outlines.outline(db, self.fonts, font, glyph_id)
+    }
+}
+
+/// A text converted into paths.
+///
+/// The flattened group is shared between all text nodes with the same hash.
+#[derive(Debug)]
+struct TextOutline {
+    flattened: Arc<Group>,
+    fallback_faces: Vec<FallbackFace>,
+    bounding_box: Rect,
+    abs_bounding_box: Rect,
+    stroke_bounding_box: Rect,
+    abs_stroke_bounding_box: Rect,
+}
+
+impl TextOutline {
+    fn apply(&self, text: &mut Text) {
+        text.flattened = self.flattened.clone();
+        text.fallback_faces = self.fallback_faces.clone();
+        text.bounding_box = self.bounding_box;
+        text.abs_bounding_box = self.abs_bounding_box;
+        text.stroke_bounding_box = self.stroke_bounding_box;
+        text.abs_stroke_bounding_box = self.abs_stroke_bounding_box;
+    }
 }
 
 pub(crate) fn convert_with_cache(
-    text: Text,
+    mut text: Text,
     fontdb: &fontdb::Database,
+    fonts_hash: u64,
+    grouping: TextGrouping,
     cache: Option<&UsvgrTextOutlineCache>,
 ) -> Option<Text> {
+    let glyph_cache = cache
+        .and_then(|c| c.glyphs.as_ref())
+        .map(|outlines| GlyphCache {
+            outlines,
+            fonts: fonts_hash,
+        });
     match cache {
         Some(UsvgrTextOutlineCache {
-            cache,
+            cache: Some(cache),
             hash_builder,
+            ..
         }) => {
             let mut hasher = hash_builder.build_hasher();
             text.hash(&mut hasher);
+            grouping.hash(&mut hasher);
+            fonts_hash.hash(&mut hasher);
             let hash = hasher.finish();
 
-            cache
-                .borrow_mut()
-                .get_or_insert(hash, || convert(text, fontdb))
-                // TODO figure out if we can avoid cloning here
-                // it is pretty expensive but in order to convert his to Rc
-                // it needs to remove all the mutabalities around flattened
-                .clone()
+            let mut cache = cache.borrow_mut();
+            let outline =
+                cache.get_or_insert(hash, || convert(&text, fontdb, grouping, glyph_cache));
+            outline.as_ref()?.apply(&mut text);
         }
-        None => convert(text, fontdb),
+        _ => convert(&text, fontdb, grouping, glyph_cache)?.apply(&mut text),
     }
+
+    Some(text)
 }
 
-pub(crate) fn convert(mut text: Text, fontdb: &fontdb::Database) -> Option<Text> {
-    let (new_paths, bbox, stroke_bbox) = text_to_paths(&text, fontdb)?;
+fn convert(
+    text: &Text,
+    fontdb: &fontdb::Database,
+    grouping: TextGrouping,
+    glyph_cache: Option<GlyphCache>,
+) -> Option<TextOutline> {
+    let (new_nodes, bbox, stroke_bbox, fallback_faces) =
+        text_to_paths(text, fontdb, grouping, glyph_cache)?;
 
     let mut group = Group {
         id: text.id.clone(),
         ..Group::empty()
     };
 
-    let rendering_mode = resolve_rendering_mode(&text);
-    for mut path in new_paths {
-        path.rendering_mode = rendering_mode;
-        group.children.push(Node::Path(Box::new(path)));
+    let rendering_mode = resolve_rendering_mode(text);
+    for mut node in new_nodes {
+        set_rendering_mode(&mut node, rendering_mode);
+        group.children.push(node);
     }
 
     group.calculate_bounding_boxes();
-    text.flattened = Box::new(group);
 
-    text.bounding_box = bbox.to_rect();
-    text.abs_bounding_box = bbox.transform(text.abs_transform)?.to_rect();
-    // TODO: test
-    // TODO: should we stroke transformed paths?
-    text.stroke_bounding_box = stroke_bbox.to_rect();
-    text.abs_stroke_bounding_box = stroke_bbox.transform(text.abs_transform)?.to_rect();
+    Some(TextOutline {
+        flattened: Arc::new(group),
+        fallback_faces,
+        bounding_box: bbox.to_rect(),
+        abs_bounding_box: bbox.transform(text.abs_transform)?.to_rect(),
+        // TODO: test
+        // TODO: should we stroke transformed paths?
+        stroke_bounding_box: stroke_bbox.to_rect(),
+        abs_stroke_bounding_box: stroke_bbox.transform(text.abs_transform)?.to_rect(),
+    })
+}
 
-    Some(text)
+fn set_rendering_mode(node: &mut Node, rendering_mode: ShapeRendering) {
+    match node {
+        Node::Path(ref mut path) => path.rendering_mode = rendering_mode,
+        Node::Group(ref mut group) => {
+            for child in &mut group.children {
+                set_rendering_mode(child, rendering_mode);
+            }
+        }
+        _ => {}
+    }
 }
 
 trait DatabaseExt {
     fn load_font(&self, id: ID) -> Option<ResolvedFont>;
-    fn outline(&self, id: ID, glyph_id: GlyphId) -> Option<tiny_skia_path::Path>;
+    fn outline(&self, font: &ResolvedFont, glyph_id: GlyphId) -> Option<tiny_skia_path::Path>;
+    fn glyph_bbox(&self, font: &ResolvedFont, glyph_id: GlyphId) -> Option<Rect>;
     fn has_char(&self, id: ID, c: char) -> bool;
 }
 
@@ -104,6 +250,12 @@ impl DatabaseExt for Database {
         self.with_face_data(id, |data, face_index| -> Option<ResolvedFont> {
             let font = ttf_parser::Face::parse(data, face_index).ok()?;
 
+            let coords = font
+                .variation_coordinates()
+                .iter()
+                .map(|c| c.get())
+                .collect();
+
             let units_per_em = NonZeroU16::new(font.units_per_em())?;
 
             let ascent = font.ascender();
@@ -168,20 +320,38 @@ impl DatabaseExt for Database {
                 line_through_position,
                 subscript_offset,
                 superscript_offset,
+                coords,
             })
         })?
     }
 
     #[inline(never)]
-    fn outline(&self, id: ID, glyph_id: GlyphId) -> Option<tiny_skia_path::Path> {
-        self.with_face_data(id, |data, face_index| -> Option<tiny_skia_path::Path> {
-            let font = ttf_parser::Face::parse(data, face_index).ok()?;
+    fn outline(&self, font: &ResolvedFont, glyph_id: GlyphId) -> Option<tiny_skia_path::Path> {
+        self.with_face_data(
+            font.id,
+            |data, face_index| -> Option<tiny_skia_path::Path> {
+                let font = ttf_parser::Face::parse(data, face_index).ok()?;
+
+                let mut builder = PathBuilder {
+                    builder: tiny_skia_path::PathBuilder::new(),
+                };
+                font.outline_glyph(glyph_id, &mut builder)?;
+                builder.builder.finish()
+            },
+        )?
+    }
 
-            let mut builder = PathBuilder {
-                builder: tiny_skia_path::PathBuilder::new(),
-            };
-            font.outline_glyph(glyph_id, &mut builder)?;
-            builder.builder.finish()
+    #[inline(never)]
+    fn glyph_bbox(&self, font: &ResolvedFont, glyph_id: GlyphId) -> Option<Rect> {
+        self.with_face_data(font.id, |data, face_index| -> Option<Rect> {
+            let font = ttf_parser::Face::parse(data, face_index).ok()?;
+            let bbox = font.glyph_bounding_box(glyph_id)?;
+            Rect::from_ltrb(
+                bbox.x_min as f32,
+                bbox.y_min as f32,
+                bbox.x_max as f32,
+                bbox.y_max as f32,
+            )
         })?
     }
 
@@ -197,7 +367,7 @@ impl DatabaseExt for Database {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 struct ResolvedFont {
     id: ID,
 
@@ -218,6 +388,11 @@ struct ResolvedFont {
 
     subscript_offset: i16,
     superscript_offset: i16,
+
+    /// Normalized variation coordinates the font is outlined with.
+    ///
+    /// Empty for non-variable fonts.
+    coords: Arc<[i16]>,
 }
 
 impl ResolvedFont {
@@ -391,6 +566,30 @@ impl ByteIndex {
     fn char_from(&self, text: &str) -> char {
         text[self.0..].chars().next().unwrap()
     }
+
+    /// Returns a script of the character at this position.
+    ///
+    /// `Common` and `Inherited` characters, like punctuation and combining marks,
+    /// take the script of the preceding character, or of the following one
+    /// when there is none.
+    fn script_from(&self, text: &str) -> unicode_script::Script {
+        use unicode_script::Script;
+
+        let is_resolved = |script: &Script| !matches!(script, Script::Common | Script::Inherited);
+
+        let script = self.char_from(text).script();
+        if is_resolved(&script) {
+            return script;
+        }
+
+        text[..self.0]
+            .chars()
+            .rev()
+            .map(|c| c.script())
+            .find(is_resolved)
+            .or_else(|| text[self.0..].chars().map(|c| c.script()).find(is_resolved))
+            .unwrap_or(script)
+    }
 }
 
 fn resolve_rendering_mode(text: &Text) -> ShapeRendering {
@@ -439,10 +638,7 @@ fn resolve_baseline(span: &TextSpan, font: &ResolvedFont, writing_mode: WritingM
 
 type FontsCacheInner = HashMap<Font, Arc<ResolvedFont>>;
 
-fn text_to_paths(
-    text_node: &Text,
-    fontdb: &fontdb::Database,
-) -> Option<(Vec<Path>, NonZeroRect, NonZeroRect)> {
+fn resolve_fonts(text_node: &Text, fontdb: &fontdb::Database) -> FontsCacheInner {
     let mut fonts_cache: FontsCacheInner = HashMap::new();
     for chunk in &text_node.chunks {
         for span in &chunk.spans {
@@ -454,19 +650,71 @@ fn text_to_paths(
         }
     }
 
-    let mut bbox = BBox::default();
-    let mut stroke_bbox = BBox::default();
+    fonts_cache
+}
+
+/// A text chunk after shaping and layouting.
+struct LaidOutChunk<'a> {
+    chunk: &'a TextChunk,
+    clusters: Vec<OutlinedCluster>,
+    x: f32,
+    y: f32,
+    text_ts: Transform,
+}
+
+impl LaidOutChunk<'_> {
+    /// Returns a transform that should be applied to all clusters of the span.
+    fn span_transform(
+        &self,
+        span: &TextSpan,
+        font: &ResolvedFont,
+        writing_mode: WritingMode,
+    ) -> Transform {
+        let mut span_ts = self.text_ts;
+        span_ts = span_ts.pre_translate(self.x, self.y);
+        if let TextFlow::Linear = self.chunk.text_flow {
+            let shift = resolve_baseline(span, font, writing_mode);
+
+            // In case of a horizontal flow, shift transform and not clusters,
+            // because clusters can be rotated and an additional shift will lead
+            // to invalid results.
+            span_ts = span_ts.pre_translate(0.0, shift);
+        }
+
+        span_ts
+    }
+}
+
+/// Shapes and positions all text chunks.
+///
+/// When `outline` is not set, glyph outlines will not be built
+/// and only glyph bounding boxes will be resolved.
+/// Otherwise, outlines are taken from `glyph_cache` when available.
+fn layout_chunks<'a>(
+    text_node: &'a Text,
+    fonts_cache: &FontsCacheInner,
+    fontdb: &fontdb::Database,
+    outline: bool,
+    glyph_cache: Option<GlyphCache>,
+) -> Vec<LaidOutChunk<'a>> {
     let mut char_offset = 0;
     let mut last_x = 0.0;
     let mut last_y = 0.0;
-    let mut new_paths = Vec::new();
+    let mut chunks = Vec::new();
     for chunk in &text_node.chunks {
         let (x, y) = match chunk.text_flow {
             TextFlow::Linear => (chunk.x.unwrap_or(last_x), chunk.y.unwrap_or(last_y)),
             TextFlow::Path(_) => (0.0, 0.0),
         };
 
-        let mut clusters = outline_chunk(chunk, &fonts_cache, fontdb);
+        let mut clusters = outline_chunk(
+            chunk,
+            fonts_cache,
+            &text_node.font_fallback,
+            fontdb,
+            outline,
+            glyph_cache,
+        );
         if clusters.is_empty() {
             char_offset += chunk.text.chars().count();
             continue;
@@ -481,7 +729,7 @@ fn text_to_paths(
             chunk,
             char_offset,
             text_node.writing_mode,
-            &fonts_cache,
+            fonts_cache,
             &mut clusters,
         );
 
@@ -492,24 +740,294 @@ fn text_to_paths(
             }
         }
 
+        char_offset += chunk.text.chars().count();
+
+        if text_node.writing_mode == WritingMode::TopToBottom {
+            if let TextFlow::Linear = chunk.text_flow {
+                std::mem::swap(&mut curr_pos.0, &mut curr_pos.1);
+            }
+        }
+
+        last_x = x + curr_pos.0;
+        last_y = y + curr_pos.1;
+
+        chunks.push(LaidOutChunk {
+            chunk,
+            clusters,
+            x,
+            y,
+            text_ts,
+        });
+    }
+
+    chunks
+}
+
+/// A laid out text chunk.
+///
+/// All coordinates are in the text element coordinate system.
+#[derive(Clone, Debug)]
+pub struct TextLayoutChunk {
+    x: f32,
+    y: f32,
+    width: f32,
+    ascent: f32,
+    descent: f32,
+    clusters: Vec<TextLayoutCluster>,
+}
+
+impl TextLayoutChunk {
+    /// Chunk's start position along the X axis.
+    pub fn x(&self) -> f32 {
+        self.x
+    }
+
+    /// Chunk's start position along the Y axis.
+    pub fn y(&self) -> f32 {
+        self.y
+    }
+
+    /// Chunk's width.
+    ///
+    /// A sum of all cluster advances, including letter and word spacing.
+    pub fn width(&self) -> f32 {
+        self.width
+    }
+
+    /// The largest cluster ascent.
+    pub fn ascent(&self) -> f32 {
+        self.ascent
+    }
+
+    /// The smallest cluster descent.
+    ///
+    /// Usually negative.
+    pub fn descent(&self) -> f32 {
+        self.descent
+    }
+
+    /// A list of glyph clusters in visual order.
+    pub fn clusters(&self) -> &[TextLayoutCluster] {
+        &self.clusters
+    }
+}
+
+/// A laid out glyph cluster.
+#[derive(Clone, Debug)]
+pub struct TextLayoutCluster {
+    byte_range: std::ops::Range<usize>,
+    advance: f32,
+    transform: Transform,
+    bbox: Option<Rect>,
+    visible: bool,
+}
+
+impl TextLayoutCluster {
+    /// A byte range of the cluster in `TextChunk::text`.
+    pub fn byte_range(&self) -> std::ops::Range<usize> {
+        self.byte_range.clone()
+    }
+
+    /// An advance along the X axis.
+    ///
+    /// Can be negative.
+    pub fn advance(&self) -> f32 {
+        self.advance
+    }
+
+    /// Cluster's origin.
+    pub fn position(&self) -> (f32, f32) {
+        (self.transform.tx, self.transform.ty)
+    }
+
+    /// Cluster's rotation in degrees.
+    pub fn rotation(&self) -> f32 {
+        self.transform.ky.atan2(self.transform.sx).to_degrees()
+    }
+
+    /// Cluster's transform.
+    ///
+    /// Contains position, rotation and baseline shift.
+    pub fn transform(&self) -> Transform {
+        self.transform
+    }
+
+    /// A tight bounding box of the cluster glyphs with `transform` applied.
+    ///
+    /// `None` for clusters without a visible shape, like spaces.
+    pub fn bounding_box(&self) -> Option<Rect> {
+        self.bbox
+    }
+
+    /// Whether the cluster should be rendered.
+    ///
+    /// Clusters outside of a text path are not visible.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+}
+
+impl LaidOutChunk<'_> {
+    fn to_layout(
+        &self,
+        fonts_cache: &FontsCacheInner,
+        writing_mode: WritingMode,
+    ) -> TextLayoutChunk {
+        let text = &self.chunk.text;
+
+        let mut starts: Vec<usize> = self.clusters.iter().map(|c| c.byte_idx.value()).collect();
+        starts.sort_unstable();
+        starts.dedup();
+
+        let mut clusters = Vec::with_capacity(self.clusters.len());
+        for cluster in &self.clusters {
+            let start = cluster.byte_idx.value();
+            let end = starts
+                .iter()
+                .find(|&&idx| idx > start)
+                .cloned()
+                .unwrap_or(text.len());
+
+            let span_ts = chunk_span_at(self.chunk, cluster.byte_idx)
+                .and_then(|span| Some((span, fonts_cache.get(&span.font)?)))
+                .map(|(span, font)| self.span_transform(span, font, writing_mode))
+                .unwrap_or(self.text_ts);
+            let ts = span_ts.pre_concat(cluster.transform);
+
+            clusters.push(TextLayoutCluster {
+                byte_range: start..end,
+                advance: cluster.advance,
+                transform: ts,
+                bbox: cluster.bbox.and_then(|r| r.transform(ts)),
+                visible: cluster.visible,
+            });
+        }
+
+        TextLayoutChunk {
+            x: self.x,
+            y: self.y,
+            width: clusters_length(&self.clusters),
+            ascent: self.clusters.iter().map(|c| c.ascent).fold(0.0, f32::max),
+            descent: self.clusters.iter().map(|c| c.descent).fold(0.0, f32::min),
+            clusters,
+        }
+    }
+}
+
+impl Text {
+    /// Lays out the text without converting it into paths.
+    ///
+    /// Returns a layout for each text chunk that has at least one glyph.
+    /// Glyph outlines are not built, which makes this method cheaper than a full conversion.
+    pub fn layout(&self, fontdb: &fontdb::Database) -> Vec<TextLayoutChunk> {
+        let fonts_cache = resolve_fonts(self, fontdb);
+        layout_chunks(self, &fonts_cache, fontdb, false, None)
+            .iter()
+            .map(|chunk| chunk.to_layout(&fonts_cache, self.writing_mode))
+            .collect()
+    }
+}
+
+/// Lays out a single line of text using the provided font.
+///
+/// The line starts at 0x0 and uses the alphabetic baseline.
+/// Can be used to measure a string without parsing an SVG.
+///
+/// Returns `None` when the font cannot be resolved or the text has no glyphs.
+pub fn layout_str(
+    text: &str,
+    font: &Font,
+    font_size: NonZeroPositiveF32,
+    fontdb: &fontdb::Database,
+) -> Option<TextLayoutChunk> {
+    let span = TextSpan {
+        start: 0,
+        end: text.len(),
+        fill: None,
+        stroke: None,
+        paint_order: PaintOrder::default(),
+        font: font.clone(),
+        font_size,
+        small_caps: false,
+        apply_kerning: true,
+        decoration: TextDecoration {
+            underline: None,
+            overline: None,
+            line_through: None,
+        },
+        dominant_baseline: DominantBaseline::default(),
+        alignment_baseline: AlignmentBaseline::default(),
+        baseline_shift: Vec::new(),
+        visibility: Visibility::default(),
+        letter_spacing: 0.0,
+        word_spacing: 0.0,
+        text_length: None,
+        length_adjust: LengthAdjust::default(),
+        language: None,
+    };
+
+    let dummy = Rect::from_xywh(0.0, 0.0, 0.0, 0.0).unwrap();
+    let text_node = Text {
+        id: String::new(),
+        rendering_mode: TextRendering::default(),
+        dx: Vec::new(),
+        dy: Vec::new(),
+        rotate: Vec::new(),
+        writing_mode: WritingMode::LeftToRight,
+        chunks: vec![TextChunk {
+            x: Some(0.0),
+            y: Some(0.0),
+            anchor: TextAnchor::Start,
+            spans: vec![span],
+            text_flow: TextFlow::Linear,
+            text: text.to_string(),
+        }],
+        font_fallback: Vec::new(),
+        fallback_faces: Vec::new(),
+        abs_transform: Transform::default(),
+        bounding_box: dummy,
+        abs_bounding_box: dummy,
+        stroke_bounding_box: dummy,
+        abs_stroke_bounding_box: dummy,
+        flattened: Arc::new(Group::empty()),
+    };
+
+    text_node.layout(fontdb).into_iter().next()
+}
+
+fn text_to_paths(
+    text_node: &Text,
+    fontdb: &fontdb::Database,
+    grouping: TextGrouping,
+    glyph_cache: Option<GlyphCache>,
+) -> Option<(Vec<Node>, NonZeroRect, NonZeroRect, Vec<FallbackFace>)> {
+    let fonts_cache = resolve_fonts(text_node, fontdb);
+
+    let mut bbox = BBox::default();
+    let mut stroke_bbox = BBox::default();
+    let mut new_paths = Vec::new();
+    let mut fallback_faces = Vec::new();
+    let mut next_unit = 0;
+    for mut laid_out in layout_chunks(text_node, &fonts_cache, fontdb, true, glyph_cache) {
+        let chunk = laid_out.chunk;
+        collect_fallback_faces(&laid_out, &fonts_cache, fontdb, &mut fallback_faces);
+
+        // Glyph groups and decorations that must be rendered on top of them.
+        let mut groups: BTreeMap<usize, Group> = BTreeMap::new();
+        let mut line_throughs = Vec::new();
+        let units = match grouping {
+            TextGrouping::Span => Vec::new(),
+            _ => resolve_cluster_units(grouping, &laid_out.clusters, &mut next_unit),
+        };
+
         for span in &chunk.spans {
             let font = match fonts_cache.get(&span.font) {
                 Some(v) => v,
                 None => continue,
             };
 
-            let decoration_spans = collect_decoration_spans(span, &clusters);
-
-            let mut span_ts = text_ts;
-            span_ts = span_ts.pre_translate(x, y);
-            if let TextFlow::Linear = chunk.text_flow {
-                let shift = resolve_baseline(span, font, text_node.writing_mode);
-
-                // In case of a horizontal flow, shift transform and not clusters,
-                // because clusters can be rotated and an additional shift will lead
-                // to invalid results.
-                span_ts = span_ts.pre_translate(0.0, shift);
-            }
+            let decoration_spans = collect_decoration_spans(span, &laid_out.clusters);
+            let span_ts = laid_out.span_transform(span, font, text_node.writing_mode);
 
             if let Some(decoration) = span.decoration.underline.clone() {
                 // TODO: No idea what offset should be used for top-to-bottom layout.
@@ -526,7 +1044,7 @@ fn text_to_paths(
                 {
                     bbox = bbox.expand(path.data.bounds());
                     stroke_bbox = stroke_bbox.expand(path.data.bounds());
-                    new_paths.push(path);
+                    new_paths.push(Node::Path(Box::new(path)));
                 }
             }
 
@@ -541,14 +1059,51 @@ fn text_to_paths(
                 {
                     bbox = bbox.expand(path.data.bounds());
                     stroke_bbox = stroke_bbox.expand(path.data.bounds());
-                    new_paths.push(path);
+                    new_paths.push(Node::Path(Box::new(path)));
                 }
             }
 
-            if let Some((path, span_bbox)) = convert_span(span, &mut clusters, span_ts) {
-                bbox = bbox.expand(span_bbox);
-                stroke_bbox = stroke_bbox.expand(path.stroke_bounding_box());
-                new_paths.push(path);
+            if grouping == TextGrouping::Span {
+                let all = |_| true;
+                if let Some((path, span_bbox)) = convert_span(
+                    span,
+                    &mut laid_out.clusters,
+                    span_ts,
+                    Transform::default(),
+                    all,
+                ) {
+                    bbox = bbox.expand(span_bbox);
+                    stroke_bbox = stroke_bbox.expand(path.stroke_bounding_box());
+                    new_paths.push(Node::Path(Box::new(path)));
+                }
+            } else {
+                for (unit, origin) in span_units(span, &laid_out.clusters, &units, span_ts) {
+                    let origin = match grouping {
+                        TextGrouping::Glyph => origin,
+                        _ => Transform::from_translate(origin.tx, origin.ty),
+                    };
+
+                    let group = groups.entry(unit).or_insert_with(|| Group {
+                        id: unit_id(&text_node.id, grouping, unit),
+                        transform: origin,
+                        abs_transform: origin,
+                        ..Group::empty()
+                    });
+
+                    // Clusters of a single unit can belong to multiple spans,
+                    // therefore we have to use the group transform and not the span one.
+                    let origin = group.transform;
+                    let in_unit = |idx: usize| units[idx] == Some(unit);
+                    if let Some((path, span_bbox)) =
+                        convert_span(span, &mut laid_out.clusters, span_ts, origin, in_unit)
+                    {
+                        bbox = bbox.expand(span_bbox);
+                        if let Some(r) = path.stroke_bounding_box().transform(origin) {
+                            stroke_bbox = stroke_bbox.expand(r);
+                        }
+                        group.children.push(Node::Path(Box::new(path)));
+                    }
+                }
             }
 
             if let Some(decoration) = span.decoration.line_through.clone() {
@@ -562,26 +1117,60 @@ fn text_to_paths(
                 {
                     bbox = bbox.expand(path.data.bounds());
                     stroke_bbox = stroke_bbox.expand(path.data.bounds());
-                    new_paths.push(path);
+                    line_throughs.push(Node::Path(Box::new(path)));
                 }
             }
-        }
 
-        char_offset += chunk.text.chars().count();
-
-        if text_node.writing_mode == WritingMode::TopToBottom {
-            if let TextFlow::Linear = chunk.text_flow {
-                std::mem::swap(&mut curr_pos.0, &mut curr_pos.1);
+            if grouping == TextGrouping::Span {
+                new_paths.append(&mut line_throughs);
             }
         }
 
-        last_x = x + curr_pos.0;
-        last_y = y + curr_pos.1;
+        for (_, mut group) in groups {
+            if group.calculate_bounding_boxes().is_some() {
+                new_paths.push(Node::Group(Box::new(group)));
+            }
+        }
+        new_paths.append(&mut line_throughs);
     }
 
     let bbox = bbox.to_non_zero_rect()?;
     let stroke_bbox = stroke_bbox.to_non_zero_rect().unwrap_or(bbox);
-    Some((new_paths, bbox, stroke_bbox))
+    Some((new_paths, bbox, stroke_bbox, fallback_faces))
+}
+
+/// Collects faces of clusters that were not shaped with their span font.
+fn collect_fallback_faces(
+    laid_out: &LaidOutChunk,
+    fonts_cache: &FontsCacheInner,
+    fontdb: &fontdb::Database,
+    faces: &mut Vec<FallbackFace>,
+) {
+    for cluster in &laid_out.clusters {
+        let span_font = chunk_span_at(laid_out.chunk, cluster.byte_idx)
+            .and_then(|span| fonts_cache.get(&span.font));
+        if span_font.is_none_or(|font| font.id == cluster.font) {
+            continue;
+        }
+
+        let face = match fontdb.face(cluster.font) {
+            Some(v) => v,
+            None => continue,
+        };
+
+        let face = FallbackFace {
+            script: cluster
+                .byte_idx
+                .script_from(&laid_out.chunk.text)
+                .short_name(),
+            family: face_family(face).to_string(),
+            post_script_name: face.post_script_name.clone(),
+        };
+
+        if !faces.contains(&face) {
+            faces.push(face);
+        }
+    }
 }
 
 fn resolve_font(font: &Font, fontdb: &fontdb::Database) -> Option<ResolvedFont> {
@@ -640,16 +1229,36 @@ fn resolve_font(font: &Font, fontdb: &fontdb::Database) -> Option<ResolvedFont>
     fontdb.load_font(id?)
 }
 
+/// Returns the `0` glyph advance relative to the font size.
+///
+/// Used to resolve the `ch` unit.
+pub(crate) fn zero_advance(font: &Font, fontdb: &fontdb::Database) -> Option<f32> {
+    let resolved = resolve_font(font, fontdb)?;
+    fontdb.with_face_data(resolved.id, |data, face_index| -> Option<f32> {
+        let face = ttf_parser::Face::parse(data, face_index).ok()?;
+        let glyph_id = face.glyph_index('0')?;
+        let advance = face.glyph_hor_advance(glyph_id)?;
+        Some(advance as f32 / resolved.units_per_em.get() as f32)
+    })?
+}
+
+/// Converts span clusters into a path.
+///
+/// The resulting path is in the `origin` coordinate system,
+/// while the returned bbox is always in the text coordinate system.
+/// Only clusters that pass the `filter` (by their index) are converted.
 fn convert_span(
     span: &TextSpan,
     clusters: &mut [OutlinedCluster],
     text_ts: Transform,
+    origin: Transform,
+    filter: impl Fn(usize) -> bool,
 ) -> Option<(Path, NonZeroRect)> {
     let mut path_builder = tiny_skia_path::PathBuilder::new();
     let mut bboxes_builder = tiny_skia_path::PathBuilder::new();
 
-    for cluster in clusters {
-        if !cluster.visible {
+    for (idx, cluster) in clusters.iter_mut().enumerate() {
+        if !cluster.visible || !filter(idx) {
             continue;
         }
 
@@ -680,7 +1289,7 @@ fn convert_span(
     }
 
     let mut path = path_builder.finish()?;
-    path = path.transform(text_ts)?;
+    path = path.transform(origin.invert()?.pre_concat(text_ts))?;
 
     let mut bboxes = bboxes_builder.finish()?;
     bboxes = bboxes.transform(text_ts)?;
@@ -705,12 +1314,102 @@ fn convert_span(
         span.paint_order,
         ShapeRendering::default(),
         Arc::new(path),
-        Transform::default(),
+        origin,
     )?;
 
     Some((path, bbox))
 }
 
+/// Assigns clusters to `TextGrouping` units.
+///
+/// Units are numbered in logical order, continuing from `next_unit`.
+/// Returns a unit index for each cluster.
+fn resolve_cluster_units(
+    grouping: TextGrouping,
+    clusters: &[OutlinedCluster],
+    next_unit: &mut usize,
+) -> Vec<Option<usize>> {
+    let mut logical: Vec<usize> = (0..clusters.len()).collect();
+    logical.sort_by_key(|&idx| clusters[idx].byte_idx.value());
+
+    let mut units = vec![None; clusters.len()];
+    let mut in_word = false;
+    for idx in logical {
+        units[idx] = match grouping {
+            TextGrouping::Span => None,
+            TextGrouping::Glyph => {
+                *next_unit += 1;
+                Some(*next_unit - 1)
+            }
+            TextGrouping::Word => {
+                if is_word_separator_characters(clusters[idx].codepoint) {
+                    in_word = false;
+                    None
+                } else {
+                    if !in_word {
+                        in_word = true;
+                        *next_unit += 1;
+                    }
+
+                    Some(*next_unit - 1)
+                }
+            }
+            TextGrouping::Line => Some(*next_unit),
+        };
+    }
+
+    if grouping == TextGrouping::Line {
+        *next_unit += 1;
+    }
+
+    units
+}
+
+/// Collects units that have clusters in the span, together with their origin.
+///
+/// The origin is a transform of the first cluster of the unit in logical order.
+fn span_units(
+    span: &TextSpan,
+    clusters: &[OutlinedCluster],
+    units: &[Option<usize>],
+    span_ts: Transform,
+) -> Vec<(usize, Transform)> {
+    let mut list: Vec<(usize, ByteIndex, Transform)> = Vec::new();
+    for (cluster, unit) in clusters.iter().zip(units) {
+        let unit = match unit {
+            Some(unit) if span_contains(span, cluster.byte_idx) => *unit,
+            _ => continue,
+        };
+
+        let ts = span_ts.pre_concat(cluster.transform);
+        match list.iter_mut().find(|(u, _, _)| *u == unit) {
+            Some(item) if cluster.byte_idx.value() < item.1.value() => {
+                *item = (unit, cluster.byte_idx, ts)
+            }
+            Some(_) => {}
+            None => list.push((unit, cluster.byte_idx, ts)),
+        }
+    }
+
+    list.sort_by_key(|(unit, _, _)| *unit);
+    list.into_iter().map(|(unit, _, ts)| (unit, ts)).collect()
+}
+
+fn unit_id(text_id: &str, grouping: TextGrouping, unit: usize) -> String {
+    if text_id.is_empty() {
+        return String::new();
+    }
+
+    let kind = match grouping {
+        TextGrouping::Span => "span",
+        TextGrouping::Glyph => "glyph",
+        TextGrouping::Word => "word",
+        TextGrouping::Line => "line",
+    };
+
+    format!("{}-{}-{}", text_id, kind, unit)
+}
+
 fn collect_decoration_spans(span: &TextSpan, clusters: &[OutlinedCluster]) -> Vec<DecorationSpan> {
     let mut spans = Vec::new();
 
@@ -876,6 +1575,9 @@ struct OutlinedCluster {
     /// A x-height in SVG coordinates.
     x_height: f32,
 
+    /// A font of the first cluster glyph.
+    font: ID,
+
     /// Indicates that this cluster was affected by the relative shift (via dx/dy attributes)
     /// during the text layouting. Which breaks the `text-decoration` line.
     ///
@@ -885,6 +1587,11 @@ struct OutlinedCluster {
     /// An actual outline.
     path: Option<tiny_skia_path::Path>,
 
+    /// A tight bounding box of the cluster glyphs.
+    ///
+    /// Unlike `path`, is resolved even when outlines were not requested.
+    bbox: Option<Rect>,
+
     /// A cluster's transform that contains it's position, rotation, etc.
     transform: Transform,
 
@@ -944,7 +1651,10 @@ impl<'a> Iterator for GlyphClusters<'a> {
 fn outline_chunk(
     chunk: &TextChunk,
     fonts_cache: &FontsCacheInner,
+    fallback: &[FontFallback],
     fontdb: &fontdb::Database,
+    outline: bool,
+    glyph_cache: Option<GlyphCache>,
 ) -> Vec<OutlinedCluster> {
     let mut glyphs = Vec::new();
     for span in &chunk.spans {
@@ -958,6 +1668,8 @@ fn outline_chunk(
             font,
             span.small_caps,
             span.apply_kerning,
+            span.language.as_deref(),
+            fallback,
             fontdb,
         );
 
@@ -991,6 +1703,8 @@ fn outline_chunk(
                 &chunk.text,
                 span.font_size.get(),
                 fontdb,
+                outline,
+                glyph_cache,
             ));
         }
     }
@@ -1004,6 +1718,8 @@ fn shape_text(
     font: Arc<ResolvedFont>,
     small_caps: bool,
     apply_kerning: bool,
+    language: Option<&str>,
+    fallback: &[FontFallback],
     fontdb: &fontdb::Database,
 ) -> Vec<Glyph> {
     let mut glyphs = shape_text_with_font(text, font.clone(), small_caps, apply_kerning, fontdb)
@@ -1017,13 +1733,20 @@ fn shape_text(
         let mut missing = None;
         for glyph in &glyphs {
             if glyph.is_missing() {
-                missing = Some(glyph.byte_idx.char_from(text));
+                missing = Some(glyph.byte_idx);
                 break;
             }
         }
 
-        if let Some(c) = missing {
-            let fallback_font = match find_font_for_char(c, &used_fonts, fontdb) {
+        if let Some(byte_idx) = missing {
+            let fallback_font = match find_font_for_char(
+                byte_idx.char_from(text),
+                byte_idx.script_from(text),
+                language,
+                &used_fonts,
+                fallback,
+                fontdb,
+            ) {
                 Some(v) => Arc::new(v),
                 None => break 'outer,
             };
@@ -1163,38 +1886,54 @@ fn shape_text_with_font(
 /// Outlines a glyph cluster.
 ///
 /// Uses one or more `Glyph`s to construct an `OutlinedCluster`.
+///
+/// When `outline` is not set, only the cluster bounding box will be resolved.
 fn outline_cluster(
     glyphs: &[Glyph],
     text: &str,
     font_size: f32,
     db: &fontdb::Database,
+    outline: bool,
+    glyph_cache: Option<GlyphCache>,
 ) -> OutlinedCluster {
     debug_assert!(!glyphs.is_empty());
 
     let mut builder = tiny_skia_path::PathBuilder::new();
+    let mut bbox = BBox::default();
     let mut width = 0.0;
     let mut x: f32 = 0.0;
 
     for glyph in glyphs {
         let sx = glyph.font.scale(font_size);
 
-        if let Some(outline) = db.outline(glyph.font.id, glyph.id) {
-            // By default, glyphs are upside-down, so we have to mirror them.
-            let mut ts = Transform::from_scale(1.0, -1.0);
+        // By default, glyphs are upside-down, so we have to mirror them.
+        let mut ts = Transform::from_scale(1.0, -1.0);
 
-            // Scale to font-size.
-            ts = ts.pre_scale(sx, sx);
+        // Scale to font-size.
+        ts = ts.pre_scale(sx, sx);
 
-            // Apply offset.
-            //
-            // The first glyph in the cluster will have an offset from 0x0,
-            // but the later one will have an offset from the "current position".
-            // So we have to keep an advance.
-            // TODO: should be done only inside a single text span
-            ts = ts.pre_translate(x + glyph.dx as f32, glyph.dy as f32);
+        // Apply offset.
+        //
+        // The first glyph in the cluster will have an offset from 0x0,
+        // but the later one will have an offset from the "current position".
+        // So we have to keep an advance.
+        // TODO: should be done only inside a single text span
+        ts = ts.pre_translate(x + glyph.dx as f32, glyph.dy as f32);
+
+        if outline {
+            let outline = match glyph_cache {
+                Some(cache) => cache.outline(db, &glyph.font, glyph.id),
+                None => db.outline(&glyph.font, glyph.id).map(Arc::new),
+            };
 
-            if let Some(outline) = outline.transform(ts) {
-                builder.push_path(&outline);
+            if let Some(outline) = outline {
+                if let Some(outline_bbox) = push_transformed(&mut builder, &outline, ts) {
+                    bbox = bbox.expand(outline_bbox);
+                }
+            }
+        } else if let Some(glyph_bbox) = db.glyph_bbox(&glyph.font, glyph.id) {
+            if let Some(glyph_bbox) = glyph_bbox.transform(ts) {
+                bbox = bbox.expand(glyph_bbox);
             }
         }
 
@@ -1216,60 +1955,132 @@ fn outline_cluster(
         ascent: font.ascent(font_size),
         descent: font.descent(font_size),
         x_height: font.x_height(font_size),
+        font: font.id,
         has_relative_shift: false,
         path: builder.finish(),
+        bbox: bbox.to_rect(),
         transform: Transform::default(),
         visible: true,
     }
 }
 
+/// Appends a transformed path to the builder without copying the source path.
+///
+/// Returns the bounds of the transformed path points.
+fn push_transformed(
+    builder: &mut tiny_skia_path::PathBuilder,
+    path: &tiny_skia_path::Path,
+    ts: Transform,
+) -> Option<Rect> {
+    let mut min = tiny_skia_path::Point::from_xy(f32::MAX, f32::MAX);
+    let mut max = tiny_skia_path::Point::from_xy(f32::MIN, f32::MIN);
+    let mut map = |mut p: tiny_skia_path::Point| {
+        ts.map_point(&mut p);
+        min = tiny_skia_path::Point::from_xy(min.x.min(p.x), min.y.min(p.y));
+        max = tiny_skia_path::Point::from_xy(max.x.max(p.x), max.y.max(p.y));
+        p
+    };
+
+    for segment in path.segments() {
+        match segment {
+            tiny_skia_path::PathSegment::MoveTo(p) => {
+                let p = map(p);
+                builder.move_to(p.x, p.y);
+            }
+            tiny_skia_path::PathSegment::LineTo(p) => {
+                let p = map(p);
+                builder.line_to(p.x, p.y);
+            }
+            tiny_skia_path::PathSegment::QuadTo(p1, p) => {
+                let (p1, p) = (map(p1), map(p));
+                builder.quad_to(p1.x, p1.y, p.x, p.y);
+            }
+            tiny_skia_path::PathSegment::CubicTo(p1, p2, p) => {
+                let (p1, p2, p) = (map(p1), map(p2), map(p));
+                builder.cubic_to(p1.x, p1.y, p2.x, p2.y, p.x, p.y);
+            }
+            tiny_skia_path::PathSegment::Close => builder.close(),
+        }
+    }
+
+    Rect::from_ltrb(min.x, min.y, max.x, max.y)
+}
+
 /// Finds a font with a specified char.
 ///
-/// This is a rudimentary font fallback algorithm.
+/// Families from the `fallback` chains matching the `script` are checked first.
+/// Otherwise, all faces with the same style are checked in a stable order,
+/// so the result doesn't depend on the fonts loading order.
 fn find_font_for_char(
     c: char,
+    script: unicode_script::Script,
+    language: Option<&str>,
     exclude_fonts: &[fontdb::ID],
+    fallback: &[FontFallback],
     fontdb: &fontdb::Database,
 ) -> Option<ResolvedFont> {
     let base_font_id = exclude_fonts[0];
+    let base_face = fontdb.face(base_font_id)?;
+    let is_candidate = |id: ID| !exclude_fonts.contains(&id) && fontdb.has_char(id, c);
 
-    // Iterate over fonts and check if any of them support the specified char.
-    for face in fontdb.faces() {
-        // Ignore fonts, that were used for shaping already.
-        if exclude_fonts.contains(&face.id) {
-            continue;
-        }
+    let script = script.short_name();
+    let chains = fallback
+        .iter()
+        .filter(|chain| chain.language.is_some() && chain.matches(script, language))
+        .chain(
+            fallback
+                .iter()
+                .filter(|chain| chain.language.is_none() && chain.matches(script, language)),
+        );
 
-        // Check that the new face has the same style.
-        let base_face = fontdb.face(base_font_id)?;
-        if base_face.style != face.style
-            && base_face.weight != face.weight
-            && base_face.stretch != face.stretch
-        {
-            continue;
-        }
+    for family in chains.flat_map(|chain| chain.families.iter()) {
+        let query = fontdb::Query {
+            families: &[fontdb::Family::Name(family)],
+            weight: base_face.weight,
+            stretch: base_face.stretch,
+            style: base_face.style,
+        };
 
-        if !fontdb.has_char(face.id, c) {
-            continue;
+        if let Some(id) = fontdb.query(&query).filter(|id| is_candidate(*id)) {
+            log::warn!("Fallback from {} to {}.", face_family(base_face), family);
+            return fontdb.load_font(id);
         }
-
-        let base_family = base_face
-            .families
-            .iter()
-            .find(|f| f.1 == fontdb::Language::English_UnitedStates)
-            .unwrap_or(&base_face.families[0]);
-
-        let new_family = face
-            .families
-            .iter()
-            .find(|f| f.1 == fontdb::Language::English_UnitedStates)
-            .unwrap_or(&base_face.families[0]);
-
-        log::warn!("Fallback from {} to {}.", base_family.0, new_family.0);
-        return fontdb.load_font(face.id);
     }
 
-    None
+    let mut faces: Vec<_> = fontdb
+        .faces()
+        .filter(|face| {
+            // Check that the new face has the same style.
+            base_face.style == face.style
+                || base_face.weight == face.weight
+                || base_face.stretch == face.stretch
+        })
+        .collect();
+    faces.sort_by(|a, b| {
+        (face_family(a), &a.post_script_name, a.index).cmp(&(
+            face_family(b),
+            &b.post_script_name,
+            b.index,
+        ))
+    });
+
+    let face = faces.into_iter().find(|face| is_candidate(face.id))?;
+    log::warn!(
+        "Fallback from {} to {}.",
+        face_family(base_face),
+        face_family(face)
+    );
+    fontdb.load_font(face.id)
+}
+
+/// Returns an English face family name, if any.
+fn face_family(face: &fontdb::FaceInfo) -> &str {
+    face.families
+        .iter()
+        .find(|f| f.1 == fontdb::Language::English_UnitedStates)
+        .or(face.families.first())
+        .map(|f| f.0.as_str())
+        .unwrap_or_default()
 }
 
 /// Resolves clusters positions.
@@ -1606,6 +2417,7 @@ fn apply_letter_spacing(chunk: &TextChunk, clusters: &mut [OutlinedCluster]) {
                     cluster.width = 0.0;
                     cluster.advance = 0.0;
                     cluster.path = None;
+                    cluster.bbox = None;
                 }
             }
         }
@@ -1761,6 +2573,10 @@ fn apply_writing_mode(writing_mode: WritingMode, clusters: &mut [OutlinedCluster
                 cluster.path = path.transform(ts);
             }
 
+            if let Some(bbox) = cluster.bbox.take() {
+                cluster.bbox = bbox.transform(ts);
+            }
+
             // Move "baseline" to the middle and make height equal to width.
             cluster.ascent = cluster.width / 2.0;
             cluster.descent = -cluster.width / 2.0;
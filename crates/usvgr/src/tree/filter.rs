@@ -51,6 +51,11 @@ impl Filter {
     pub fn primitives(&self) -> &[Primitive] {
         &self.primitives
     }
+
+    /// Checks that any of the filter primitives has a specific input.
+    pub fn has_input(&self, input: &Input) -> bool {
+        self.primitives.iter().any(|p| p.kind.has_input(input))
+    }
 }
 
 /// A filter primitive element.
@@ -110,6 +115,7 @@ pub enum Kind {
     ComponentTransfer(ComponentTransfer),
     Composite(Composite),
     ConvolveMatrix(ConvolveMatrix),
+    Custom(Custom),
     DiffuseLighting(DiffuseLighting),
     DisplacementMap(DisplacementMap),
     DropShadow(DropShadow),
@@ -133,6 +139,7 @@ impl Kind {
             Kind::ComponentTransfer(ref fe) => fe.input == *input,
             Kind::Composite(ref fe) => fe.input1 == *input || fe.input2 == *input,
             Kind::ConvolveMatrix(ref fe) => fe.input == *input,
+            Kind::Custom(ref fe) => fe.input1 == *input || fe.input2.as_ref() == Some(input),
             Kind::DiffuseLighting(ref fe) => fe.input == *input,
             Kind::DisplacementMap(ref fe) => fe.input1 == *input || fe.input2 == *input,
             Kind::DropShadow(ref fe) => fe.input == *input,
@@ -155,6 +162,15 @@ impl Kind {
 pub enum Input {
     SourceGraphic,
     SourceAlpha,
+    /// Content of the nearest `enable-background="new"` ancestor rendered
+    /// before the filtered element.
+    BackgroundImage,
+    /// Alpha channel of `BackgroundImage`.
+    BackgroundAlpha,
+    /// The filtered element fill, spanning the whole filter region.
+    FillPaint,
+    /// The filtered element stroke, spanning the whole filter region.
+    StrokePaint,
     Reference(String),
 }
 
@@ -880,6 +896,59 @@ pub enum ImageKind {
     Use(Box<Group>),
 }
 
+/// A user-defined filter primitive.
+///
+/// `feCustom` element or `custom()` filter function in the SVG.
+/// `svgr` resolves it by name using its custom filters registry.
+#[derive(Clone, Debug)]
+pub struct Custom {
+    pub(crate) name: String,
+    pub(crate) input1: Input,
+    pub(crate) input2: Option<Input>,
+    pub(crate) args: Vec<f32>,
+}
+
+impl std::hash::Hash for Custom {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.input1.hash(state);
+        self.input2.hash(state);
+        for v in &self.args {
+            v.to_bits().hash(state);
+        }
+    }
+}
+
+impl Custom {
+    /// A name the primitive was registered with.
+    ///
+    /// `name` in the SVG.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Identifies input for the given filter primitive.
+    ///
+    /// `in` in the SVG.
+    pub fn input1(&self) -> &Input {
+        &self.input1
+    }
+
+    /// Identifies an optional second input for the given filter primitive.
+    ///
+    /// `in2` in the SVG.
+    pub fn input2(&self) -> Option<&Input> {
+        self.input2.as_ref()
+    }
+
+    /// A list of numeric arguments.
+    ///
+    /// `values` in the SVG.
+    pub fn args(&self) -> &[f32] {
+        &self.args
+    }
+}
+
 /// A diffuse lighting filter primitive.
 ///
 /// `feDiffuseLighting` element in the SVG.
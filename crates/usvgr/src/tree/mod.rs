@@ -5,6 +5,7 @@
 pub mod filter;
 mod geom;
 mod text;
+mod text_content;
 
 use std::sync::Arc;
 pub use strict_num::{self, ApproxEqUlps, NonZeroPositiveF32, NormalizedF32, PositiveF32};
@@ -17,6 +18,7 @@ use crate::PreloadedImageData;
 
 pub use self::geom::*;
 pub use self::text::*;
+pub use self::text_content::*;
 
 /// An alias to `NormalizedF32`.
 pub type Opacity = NormalizedF32;
@@ -247,6 +249,7 @@ pub struct BaseGradient {
     pub(crate) units: Units, // used only during parsing
     pub(crate) transform: Transform,
     pub(crate) spread_method: SpreadMethod,
+    pub(crate) color_interpolation: filter::ColorInterpolation,
     pub(crate) stops: Vec<Stop>,
 }
 
@@ -255,6 +258,7 @@ impl std::hash::Hash for BaseGradient {
         self.id.hash(state);
         self.transform.custom_hash(state);
         self.spread_method.hash(state);
+        self.color_interpolation.hash(state);
         self.stops.hash(state);
     }
 }
@@ -282,6 +286,15 @@ impl BaseGradient {
         self.spread_method
     }
 
+    /// Color space used for stops interpolation.
+    ///
+    /// Unlike in filters, `sRGB` by default.
+    ///
+    /// `color-interpolation` in SVG.
+    pub fn color_interpolation(&self) -> filter::ColorInterpolation {
+        self.color_interpolation
+    }
+
     /// A list of `stop` elements.
     pub fn stops(&self) -> &[Stop] {
         &self.stops
@@ -401,6 +414,142 @@ impl std::ops::Deref for RadialGradient {
     }
 }
 
+/// A mesh gradient type.
+///
+/// `type` attribute in the SVG.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[allow(missing_docs)]
+pub enum MeshGradientType {
+    Bilinear,
+    Bicubic,
+}
+
+impl Default for MeshGradientType {
+    fn default() -> Self {
+        Self::Bilinear
+    }
+}
+
+/// A mesh gradient patch.
+///
+/// A Coons patch bounded by four cubic Bézier curves with a color at each corner.
+///
+/// `meshpatch` element in SVG.
+#[derive(Clone, Copy, Debug)]
+pub struct MeshPatch {
+    pub(crate) points: [tiny_skia_path::Point; 12],
+    pub(crate) colors: [Color; 4],
+    pub(crate) opacities: [Opacity; 4],
+}
+
+impl std::hash::Hash for MeshPatch {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        for p in &self.points {
+            p.custom_hash(state);
+        }
+        self.colors.hash(state);
+        self.opacities.hash(state);
+    }
+}
+
+impl MeshPatch {
+    /// Patch boundary control points.
+    ///
+    /// Points go clockwise starting from the top-left corner:
+    /// top edge is `0..=3`, right edge is `3..=6`, bottom edge is `6..=9`
+    /// and left edge is `9..=11` followed by `0`.
+    ///
+    /// Corners are at indices 0, 3, 6 and 9.
+    pub fn points(&self) -> &[tiny_skia_path::Point; 12] {
+        &self.points
+    }
+
+    /// Corner colors.
+    ///
+    /// In the top-left, top-right, bottom-right and bottom-left order.
+    ///
+    /// `stop-color` in SVG.
+    pub fn colors(&self) -> &[Color; 4] {
+        &self.colors
+    }
+
+    /// Corner opacities.
+    ///
+    /// In the same order as [`MeshPatch::colors`].
+    ///
+    /// `stop-opacity` in SVG.
+    pub fn opacities(&self) -> &[Opacity; 4] {
+        &self.opacities
+    }
+}
+
+/// A mesh gradient.
+///
+/// `meshgradient` element in SVG.
+#[derive(Debug)]
+pub struct MeshGradient {
+    pub(crate) id: NonEmptyString,
+    pub(crate) units: Units, // used only during parsing
+    pub(crate) transform: Transform,
+    pub(crate) kind: MeshGradientType,
+    pub(crate) columns: usize,
+    pub(crate) patches: Vec<MeshPatch>,
+}
+
+impl std::hash::Hash for MeshGradient {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+        self.transform.custom_hash(state);
+        self.kind.hash(state);
+        self.columns.hash(state);
+        self.patches.hash(state);
+    }
+}
+
+impl MeshGradient {
+    /// Element's ID.
+    ///
+    /// Taken from the SVG itself.
+    /// Used only during SVG writing. `svgr` doesn't rely on this property.
+    pub fn id(&self) -> &str {
+        self.id.get()
+    }
+
+    /// Gradient transform.
+    ///
+    /// `gradientTransform` in SVG.
+    pub fn transform(&self) -> Transform {
+        self.transform
+    }
+
+    /// Color interpolation type.
+    ///
+    /// `type` in SVG.
+    pub fn kind(&self) -> MeshGradientType {
+        self.kind
+    }
+
+    /// Number of patches in each row.
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Number of rows.
+    pub fn rows(&self) -> usize {
+        self.patches.len() / self.columns
+    }
+
+    /// A list of patches in the row-major order.
+    ///
+    /// Coordinates are already resolved, so shared edges are duplicated
+    /// between adjacent patches.
+    ///
+    /// Guarantee to be non-empty and a multiple of [`MeshGradient::columns`].
+    pub fn patches(&self) -> &[MeshPatch] {
+        &self.patches
+    }
+}
+
 /// An alias to `NormalizedF32`.
 pub type StopOffset = NormalizedF32;
 
@@ -653,6 +802,22 @@ impl Stroke {
         self.linejoin
     }
 
+    /// Returns the maximum distance the stroke can reach outside of the path geometry.
+    ///
+    /// Accounts for miter joins and square caps, therefore can be larger than a half of width.
+    pub fn outset(&self) -> f32 {
+        let mut scale: f32 = 1.0;
+        if matches!(self.linejoin, LineJoin::Miter | LineJoin::MiterClip) {
+            scale = scale.max(self.miterlimit.get());
+        }
+
+        if self.linecap == LineCap::Square {
+            scale = scale.max(std::f32::consts::SQRT_2);
+        }
+
+        self.width.get() / 2.0 * scale
+    }
+
     /// Converts into a `tiny_skia_path::Stroke` type.
     pub fn to_tiny_skia(&self) -> tiny_skia_path::Stroke {
         let mut stroke = tiny_skia_path::Stroke {
@@ -805,6 +970,7 @@ pub enum Paint {
     Color(Color),
     LinearGradient(Arc<LinearGradient>),
     RadialGradient(Arc<RadialGradient>),
+    MeshGradient(Arc<MeshGradient>),
     Pattern(Arc<Pattern>),
 }
 
@@ -815,6 +981,7 @@ impl PartialEq for Paint {
             (Self::Color(lc), Self::Color(rc)) => lc == rc,
             (Self::LinearGradient(ref lg1), Self::LinearGradient(ref lg2)) => Arc::ptr_eq(lg1, lg2),
             (Self::RadialGradient(ref rg1), Self::RadialGradient(ref rg2)) => Arc::ptr_eq(rg1, rg2),
+            (Self::MeshGradient(ref mg1), Self::MeshGradient(ref mg2)) => Arc::ptr_eq(mg1, mg2),
             (Self::Pattern(ref p1), Self::Pattern(ref p2)) => Arc::ptr_eq(p1, p2),
             _ => false,
         }
@@ -827,6 +994,7 @@ impl std::hash::Hash for Paint {
             Paint::Color(color) => color.hash(state),
             Paint::LinearGradient(gradient) => gradient.id.hash(state),
             Paint::RadialGradient(gradient) => gradient.id.hash(state),
+            Paint::MeshGradient(gradient) => gradient.id.hash(state),
             Paint::Pattern(pattern) => pattern.id.hash(state),
         }
     }
@@ -1112,12 +1280,16 @@ pub struct Group {
     pub(crate) is_context_element: bool,
     pub(crate) mask: Option<Arc<Mask>>,
     pub(crate) filters: Vec<Arc<filter::Filter>>,
+    pub(crate) enable_background: bool,
+    pub(crate) filter_fill: Option<Paint>,
+    pub(crate) filter_stroke: Option<Paint>,
     pub(crate) bounding_box: Rect,
     pub(crate) abs_bounding_box: Rect,
     pub(crate) stroke_bounding_box: Rect,
     pub(crate) abs_stroke_bounding_box: Rect,
     pub(crate) layer_bounding_box: NonZeroRect,
     pub(crate) abs_layer_bounding_box: NonZeroRect,
+    pub(crate) non_scaling_stroke_outset: f32,
     pub(crate) children: Vec<Node>,
 }
 
@@ -1133,12 +1305,16 @@ impl std::hash::Hash for Group {
         self.clip_path.hash(state);
         self.mask.hash(state);
         self.filters.hash(state);
+        self.enable_background.hash(state);
+        self.filter_fill.hash(state);
+        self.filter_stroke.hash(state);
         self.bounding_box.custom_hash(state);
         self.abs_bounding_box.custom_hash(state);
         self.stroke_bounding_box.custom_hash(state);
         self.abs_stroke_bounding_box.custom_hash(state);
         self.layer_bounding_box.custom_hash(state);
         self.abs_layer_bounding_box.custom_hash(state);
+        self.non_scaling_stroke_outset.to_bits().hash(state);
         self.children.hash(state);
     }
 }
@@ -1156,6 +1332,9 @@ impl Group {
             clip_path: None,
             mask: None,
             filters: Vec::new(),
+            enable_background: false,
+            filter_fill: None,
+            filter_stroke: None,
             is_context_element: false,
             bounding_box: dummy,
             abs_bounding_box: dummy,
@@ -1163,6 +1342,7 @@ impl Group {
             abs_stroke_bounding_box: dummy,
             layer_bounding_box: NonZeroRect::from_xywh(0.0, 0.0, 1.0, 1.0).unwrap(),
             abs_layer_bounding_box: NonZeroRect::from_xywh(0.0, 0.0, 1.0, 1.0).unwrap(),
+            non_scaling_stroke_outset: 0.0,
             children: Vec::new(),
         }
     }
@@ -1230,6 +1410,45 @@ impl Group {
         &self.filters
     }
 
+    /// Whether the group starts a new background image for its children.
+    ///
+    /// `enable-background="new"` in SVG.
+    /// Set only when the background image is used by a descendant filter.
+    pub fn enable_background(&self) -> bool {
+        self.enable_background
+    }
+
+    /// Checks that a descendant filter uses the background image started by this group.
+    ///
+    /// `BackgroundImage` and `BackgroundAlpha` filter inputs are referencing the nearest
+    /// `enable-background="new"` ancestor, so descendants of groups which start
+    /// their own background image are not checked.
+    pub fn has_background_users(&self) -> bool {
+        self.children.iter().any(|node| match node {
+            Node::Group(ref group) => {
+                group.filters.iter().any(|f| {
+                    f.has_input(&filter::Input::BackgroundImage)
+                        || f.has_input(&filter::Input::BackgroundAlpha)
+                }) || (!group.enable_background && group.has_background_users())
+            }
+            _ => false,
+        })
+    }
+
+    /// A fill paint used by the `FillPaint` filter input.
+    ///
+    /// Set only when one of the group's filters references `FillPaint`.
+    pub fn filter_fill(&self) -> Option<&Paint> {
+        self.filter_fill.as_ref()
+    }
+
+    /// A stroke paint used by the `StrokePaint` filter input.
+    ///
+    /// Set only when one of the group's filters references `StrokePaint`.
+    pub fn filter_stroke(&self) -> Option<&Paint> {
+        self.filter_stroke.as_ref()
+    }
+
     /// Element's object bounding box.
     ///
     /// `objectBoundingBox` in SVG terms. Meaning it doesn't affected by parent transforms.
@@ -1276,10 +1495,25 @@ impl Group {
     }
 
     /// Element's "layer" bounding box in canvas units.
+    ///
+    /// Includes `non_scaling_stroke_outset`.
     pub fn abs_layer_bounding_box(&self) -> NonZeroRect {
         self.abs_layer_bounding_box
     }
 
+    /// The maximum distance non-scaling strokes of the group content
+    /// can reach outside of `layer_bounding_box`. In canvas units.
+    ///
+    /// Non-scaling strokes are not affected by transforms, so their size in object units
+    /// depends on the final canvas transform, which is known only during rendering.
+    /// Therefore `layer_bounding_box` covers them only at 1:1 canvas scale
+    /// and this outset has to be applied after the transform.
+    ///
+    /// Zero when there are no non-scaling strokes or when the layer is defined by filters.
+    pub fn non_scaling_stroke_outset(&self) -> f32 {
+        self.non_scaling_stroke_outset
+    }
+
     /// Group's children.
     pub fn children(&self) -> &[Node] {
         &self.children
@@ -1292,6 +1526,7 @@ impl Group {
             || self.clip_path.is_some()
             || self.mask.is_some()
             || !self.filters.is_empty()
+            || self.enable_background
             || self.blend_mode != BlendMode::Normal // TODO: probably not needed?
     }
 
@@ -1367,6 +1602,24 @@ impl Default for PaintOrder {
     }
 }
 
+/// Representation of the [`vector-effect`] property.
+///
+/// Only `non-scaling-stroke` is supported and only on shapes.
+/// Text ignores it, so text strokes are always scaled.
+///
+/// [`vector-effect`]: https://www.w3.org/TR/SVG2/coords.html#VectorEffects
+#[derive(Clone, Copy, PartialEq, Debug, Hash, Eq, Default)]
+pub enum VectorEffect {
+    /// No vector effect.
+    #[default]
+    None,
+    /// A stroke is applied in the screen coordinate system.
+    ///
+    /// Meaning that stroke width, dashing and stroke paint are not affected
+    /// by transforms and will stay the same at any zoom.
+    NonScalingStroke,
+}
+
 /// A path element.
 #[derive(Clone, Debug)]
 pub struct Path {
@@ -1376,6 +1629,7 @@ pub struct Path {
     pub(crate) stroke: Option<Stroke>,
     pub(crate) paint_order: PaintOrder,
     pub(crate) rendering_mode: ShapeRendering,
+    pub(crate) vector_effect: VectorEffect,
     pub(crate) data: Arc<tiny_skia_path::Path>,
     pub(crate) abs_transform: Transform,
     pub(crate) bounding_box: Rect,
@@ -1392,6 +1646,7 @@ impl std::hash::Hash for Path {
         self.stroke.hash(state);
         self.paint_order.hash(state);
         self.rendering_mode.hash(state);
+        self.vector_effect.hash(state);
         self.data.custom_hash(state);
         self.abs_transform.custom_hash(state);
         self.bounding_box.custom_hash(state);
@@ -1451,6 +1706,7 @@ impl Path {
             stroke,
             paint_order,
             rendering_mode,
+            vector_effect: VectorEffect::default(),
             data,
             abs_transform,
             bounding_box,
@@ -1460,6 +1716,28 @@ impl Path {
         })
     }
 
+    pub(crate) fn with_vector_effect(mut self, vector_effect: VectorEffect) -> Option<Self> {
+        self.vector_effect = vector_effect;
+
+        // A non-scaling stroke is applied in canvas coordinates,
+        // therefore its bbox has to be calculated there as well.
+        // Note that the result is exact only when rendering at 1:1 canvas scale.
+        // Renderers have to use `non_scaling_stroke_outset` for other scales.
+        if vector_effect == VectorEffect::NonScalingStroke && self.stroke.is_some() {
+            let path2 = self.data.as_ref().clone();
+            let path2 = path2.transform(self.abs_transform)?;
+            self.abs_stroke_bounding_box =
+                Path::calculate_stroke_bbox(self.stroke.as_ref(), &path2)
+                    .unwrap_or(self.abs_bounding_box);
+            self.stroke_bounding_box = match self.abs_transform.invert() {
+                Some(ts) => self.abs_stroke_bounding_box.transform(ts)?,
+                None => self.bounding_box,
+            };
+        }
+
+        Some(self)
+    }
+
     /// Element's ID.
     ///
     /// Taken from the SVG itself.
@@ -1501,6 +1779,26 @@ impl Path {
         self.rendering_mode
     }
 
+    /// Vector effect.
+    ///
+    /// `vector-effect` in SVG.
+    pub fn vector_effect(&self) -> VectorEffect {
+        self.vector_effect
+    }
+
+    /// The maximum distance a non-scaling stroke can reach outside of the path geometry.
+    ///
+    /// In canvas units, since a non-scaling stroke is not affected by transforms.
+    /// Zero for paths without a non-scaling stroke.
+    pub fn non_scaling_stroke_outset(&self) -> f32 {
+        match self.stroke {
+            Some(ref stroke) if self.vector_effect == VectorEffect::NonScalingStroke => {
+                stroke.outset()
+            }
+            _ => 0.0,
+        }
+    }
+
     // TODO: find a better name
     /// Segments list.
     ///
@@ -1698,6 +1996,7 @@ pub struct Tree {
     pub(crate) root: Group,
     pub(crate) linear_gradients: Vec<Arc<LinearGradient>>,
     pub(crate) radial_gradients: Vec<Arc<RadialGradient>>,
+    pub(crate) mesh_gradients: Vec<Arc<MeshGradient>>,
     pub(crate) patterns: Vec<Arc<Pattern>>,
     pub(crate) clip_paths: Vec<Arc<ClipPath>>,
     pub(crate) masks: Vec<Arc<Mask>>,
@@ -1730,6 +2029,8 @@ impl Tree {
 
     /// Returns a renderable node by ID.
     ///
+    /// Also looks inside `Text::flattened`, whose transforms are relative to the text element.
+    ///
     /// If an empty ID is provided, than this method will always return `None`.
     pub fn node_by_id(&self, id: &str) -> Option<&Node> {
         if id.is_empty() {
@@ -1754,6 +2055,11 @@ impl Tree {
         &self.radial_gradients
     }
 
+    /// Returns a list of all unique [`MeshGradient`]s in the tree.
+    pub fn mesh_gradients(&self) -> &[Arc<MeshGradient>] {
+        &self.mesh_gradients
+    }
+
     /// Returns a list of all unique [`Pattern`]s in the tree.
     pub fn patterns(&self) -> &[Arc<Pattern>] {
         &self.patterns
@@ -1795,6 +2101,15 @@ impl Tree {
                     self.radial_gradients.push(rg.clone());
                 }
             }
+            Paint::MeshGradient(mg) => {
+                if !self
+                    .mesh_gradients
+                    .iter()
+                    .any(|other| Arc::ptr_eq(&mg, other))
+                {
+                    self.mesh_gradients.push(mg.clone());
+                }
+            }
             Paint::Pattern(patt) => {
                 if !self.patterns.iter().any(|other| Arc::ptr_eq(&patt, other)) {
                     self.patterns.push(patt.clone());
@@ -1815,6 +2130,13 @@ fn node_by_id<'a>(parent: &'a Group, id: &str) -> Option<&'a Node> {
                 return Some(n);
             }
         }
+
+        // Glyph groups, see `TextGrouping`.
+        if let Node::Text(ref text) = child {
+            if let Some(n) = node_by_id(&text.flattened, id) {
+                return Some(n);
+            }
+        }
     }
 
     None
@@ -1855,7 +2177,11 @@ fn loop_over_paint_servers(parent: &Group, f: &mut dyn FnMut(&Paint)) {
 
     for node in &parent.children {
         match node {
-            Node::Group(ref group) => loop_over_paint_servers(group, f),
+            Node::Group(ref group) => {
+                push(group.filter_fill.as_ref(), f);
+                push(group.filter_stroke.as_ref(), f);
+                loop_over_paint_servers(group, f);
+            }
             Node::Path(ref path) => {
                 push(path.fill.as_ref().map(|f| &f.paint), f);
                 push(path.stroke.as_ref().map(|f| &f.paint), f);
@@ -1952,12 +2278,29 @@ impl Group {
         bbox.to_non_zero_rect()
     }
 
+    pub(crate) fn calculate_object_stroke_bbox(&self) -> Option<NonZeroRect> {
+        let mut bbox = BBox::default();
+        for child in &self.children {
+            let mut c_bbox = child.stroke_bounding_box();
+            if let Node::Group(ref group) = child {
+                if let Some(r) = c_bbox.transform(group.transform) {
+                    c_bbox = r;
+                }
+            }
+
+            bbox = bbox.expand(c_bbox);
+        }
+
+        bbox.to_non_zero_rect()
+    }
+
     pub(crate) fn calculate_bounding_boxes(&mut self) -> Option<()> {
         let mut bbox = BBox::default();
         let mut abs_bbox = BBox::default();
         let mut stroke_bbox = BBox::default();
         let mut abs_stroke_bbox = BBox::default();
         let mut layer_bbox = BBox::default();
+        let mut non_scaling_stroke_outset: f32 = 0.0;
         for child in &self.children {
             {
                 let mut c_bbox = child.bounding_box();
@@ -1990,10 +2333,18 @@ impl Group {
                 if let Some(r) = r.transform(group.transform) {
                     layer_bbox = layer_bbox.expand(r);
                 }
+
+                non_scaling_stroke_outset =
+                    non_scaling_stroke_outset.max(group.non_scaling_stroke_outset);
             } else {
                 // Not a group - no need to transform.
                 layer_bbox = layer_bbox.expand(child.stroke_bounding_box());
             }
+
+            if let Node::Path(ref path) = child {
+                non_scaling_stroke_outset =
+                    non_scaling_stroke_outset.max(path.non_scaling_stroke_outset());
+            }
         }
 
         // `bbox` can be None for empty groups, but we still have to
@@ -2008,11 +2359,19 @@ impl Group {
         // Filter bbox has a higher priority than layers bbox.
         if let Some(filter_bbox) = self.filters_bounding_box() {
             self.layer_bounding_box = filter_bbox;
+            self.non_scaling_stroke_outset = 0.0;
         } else {
             self.layer_bounding_box = layer_bbox.to_non_zero_rect()?;
+            self.non_scaling_stroke_outset = non_scaling_stroke_outset;
         }
 
-        self.abs_layer_bounding_box = self.layer_bounding_box.transform(self.abs_transform)?;
+        let outset = self.non_scaling_stroke_outset;
+        self.abs_layer_bounding_box = self
+            .layer_bounding_box
+            .transform(self.abs_transform)?
+            .to_rect()
+            .outset(outset, outset)?
+            .to_non_zero_rect()?;
 
         Some(())
     }
@@ -8,7 +8,8 @@ use strict_num::NonZeroPositiveF32;
 pub use svgrtypes::FontFamily;
 
 use crate::{
-    Fill, Group, NonEmptyString, PaintOrder, Rect, Stroke, TextRendering, Transform, Visibility,
+    Fill, FontFallback, Group, NonEmptyString, PaintOrder, Rect, Stroke, TextRendering, Transform,
+    Visibility,
 };
 
 /// A font stretch property.
@@ -61,9 +62,28 @@ pub struct Font {
 }
 
 impl Font {
+    /// Creates a new font description.
+    ///
+    /// An empty `families` list is allowed and will resolve to the default serif font.
+    pub fn new(
+        families: Vec<FontFamily>,
+        style: FontStyle,
+        stretch: FontStretch,
+        weight: u16,
+    ) -> Self {
+        Font {
+            families,
+            style,
+            stretch,
+            weight,
+        }
+    }
+
     /// A list of family names.
     ///
-    /// Never empty. Uses `usvgr::Options::font_family` as fallback.
+    /// Never empty for fonts parsed from an SVG,
+    /// which use `usvgr::Options::font_family` as fallback.
+    /// Can be empty for fonts created via [`Font::new`].
     pub fn families(&self) -> &[FontFamily] {
         &self.families
     }
@@ -246,6 +266,7 @@ pub struct TextSpan {
     pub(crate) word_spacing: f32,
     pub(crate) text_length: Option<f32>,
     pub(crate) length_adjust: LengthAdjust,
+    pub(crate) language: Option<String>,
 }
 
 impl std::hash::Hash for TextSpan {
@@ -268,6 +289,7 @@ impl std::hash::Hash for TextSpan {
         self.word_spacing.to_bits().hash(state);
         self.text_length.map(|f| f.to_bits()).hash(state);
         self.length_adjust.hash(state);
+        self.language.hash(state);
     }
 }
 
@@ -371,6 +393,13 @@ impl TextSpan {
     pub fn length_adjust(&self) -> LengthAdjust {
         self.length_adjust
     }
+
+    /// A span language.
+    ///
+    /// `xml:lang` or `lang` in SVG. Inherited from ancestors.
+    pub fn language(&self) -> Option<&str> {
+        self.language.as_deref()
+    }
 }
 
 /// A text chunk anchor property.
@@ -494,6 +523,31 @@ impl TextChunk {
     }
 }
 
+/// A fallback font face that was used during text layout.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct FallbackFace {
+    pub(crate) script: &'static str,
+    pub(crate) family: String,
+    pub(crate) post_script_name: String,
+}
+
+impl FallbackFace {
+    /// An ISO 15924 code of the script the face was used for.
+    pub fn script(&self) -> &str {
+        self.script
+    }
+
+    /// A font family name.
+    pub fn family(&self) -> &str {
+        &self.family
+    }
+
+    /// A font PostScript name.
+    pub fn post_script_name(&self) -> &str {
+        &self.post_script_name
+    }
+}
+
 /// A writing mode.
 #[allow(missing_docs)]
 #[derive(Clone, Copy, PartialEq, Debug, Hash, Eq)]
@@ -514,12 +568,14 @@ pub struct Text {
     pub(crate) rotate: Vec<f32>,
     pub(crate) writing_mode: WritingMode,
     pub(crate) chunks: Vec<TextChunk>,
+    pub(crate) font_fallback: Vec<FontFallback>,
+    pub(crate) fallback_faces: Vec<FallbackFace>,
     pub(crate) abs_transform: Transform,
     pub(crate) bounding_box: Rect,
     pub(crate) abs_bounding_box: Rect,
     pub(crate) stroke_bounding_box: Rect,
     pub(crate) abs_stroke_bounding_box: Rect,
-    pub(crate) flattened: Box<Group>,
+    pub(crate) flattened: Arc<Group>,
 }
 
 impl std::hash::Hash for Text {
@@ -546,6 +602,8 @@ impl std::hash::Hash for Text {
 
         self.writing_mode.hash(state);
         self.chunks.hash(state);
+        self.font_fallback.hash(state);
+        self.fallback_faces.hash(state);
         self.abs_transform.custom_hash(state);
         self.bounding_box.custom_hash(state);
         self.abs_bounding_box.custom_hash(state);
@@ -603,6 +661,14 @@ impl Text {
         &self.chunks
     }
 
+    /// Fallback faces used for characters missing from the span fonts.
+    ///
+    /// Listed in the order they were first used, without duplicates.
+    /// Always empty when the `text` build feature was disabled.
+    pub fn fallback_faces(&self) -> &[FallbackFace] {
+        &self.fallback_faces
+    }
+
     /// Element's absolute transform.
     ///
     /// Contains all ancestors transforms excluding element's transform.
@@ -0,0 +1,264 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::ops::Range;
+
+use crate::{
+    Group, ImageKind, Node, Rect, Size, Text, TextFlow, TextSpan, Transform, Tree, ViewBox,
+    WritingMode,
+};
+
+/// A plain text content of a `Text` node.
+#[derive(Clone, Debug)]
+pub struct TextContent {
+    pub(crate) id: String,
+    pub(crate) text: String,
+    pub(crate) bounding_box: Rect,
+    pub(crate) spans: Vec<TextSpan>,
+    pub(crate) is_resource: bool,
+}
+
+impl TextContent {
+    /// `Text` node ID.
+    ///
+    /// Can be empty.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// A text in logical order.
+    ///
+    /// Text chunks that start a new line are separated by `\n`.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// `Text::abs_bounding_box`.
+    ///
+    /// Text inside nested SVG images is mapped into the parent tree coordinates.
+    /// Text inside patterns, masks and clip paths stays in their own coordinates.
+    pub fn bounding_box(&self) -> Rect {
+        self.bounding_box
+    }
+
+    /// Style spans.
+    ///
+    /// Unlike `TextChunk::spans`, span ranges point into `TextContent::text`.
+    pub fn spans(&self) -> &[TextSpan] {
+        &self.spans
+    }
+
+    /// Checks that the node is inside a pattern, mask, clip path or filter.
+    ///
+    /// Such nodes are not rendered at their own position,
+    /// so they are not sorted in reading order.
+    pub fn is_resource(&self) -> bool {
+        self.is_resource
+    }
+
+    /// Returns byte ranges of all non-overlapping `query` occurrences.
+    ///
+    /// The search is case-sensitive.
+    pub fn find(&self, query: &str) -> Vec<Range<usize>> {
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        self.text
+            .match_indices(query)
+            .map(|(idx, s)| idx..idx + s.len())
+            .collect()
+    }
+}
+
+/// A text search result.
+#[derive(Clone, Debug)]
+pub struct TextMatch {
+    pub(crate) content: TextContent,
+    pub(crate) ranges: Vec<Range<usize>>,
+}
+
+impl TextMatch {
+    /// A matched text content.
+    pub fn content(&self) -> &TextContent {
+        &self.content
+    }
+
+    /// Byte ranges of all matches inside `TextContent::text`.
+    pub fn ranges(&self) -> &[Range<usize>] {
+        &self.ranges
+    }
+}
+
+impl Tree {
+    /// Returns a plain text content of all `Text` nodes.
+    ///
+    /// Includes nodes inside nested SVG images, patterns, masks, clip paths and `use` instances.
+    ///
+    /// Document nodes are sorted in reading order: lines from top to bottom
+    /// and nodes inside a line from left to right.
+    /// Nodes belong to the same line when their vertical centers are inside the line bounds.
+    ///
+    /// Nodes inside patterns, masks, clip paths and filters follow in the document order.
+    /// See [`TextContent::is_resource`].
+    pub fn text_content(&self) -> Vec<TextContent> {
+        let mut contents = Vec::new();
+        collect_text_content(&self.root, Transform::default(), false, &mut contents);
+        let (resources, contents): (Vec<_>, Vec<_>) =
+            contents.into_iter().partition(|c| c.is_resource);
+
+        let mut contents = sort_in_reading_order(contents);
+        contents.extend(resources);
+        contents
+    }
+
+    /// Searches for a string inside all `Text` nodes.
+    ///
+    /// See [`Tree::text_content`] and [`TextContent::find`] for details.
+    pub fn find_text(&self, query: &str) -> Vec<TextMatch> {
+        self.text_content()
+            .into_iter()
+            .filter_map(|content| {
+                let ranges = content.find(query);
+                if ranges.is_empty() {
+                    return None;
+                }
+
+                Some(TextMatch { content, ranges })
+            })
+            .collect()
+    }
+}
+
+fn collect_text_content(
+    parent: &Group,
+    ts: Transform,
+    is_resource: bool,
+    contents: &mut Vec<TextContent>,
+) {
+    for node in &parent.children {
+        match node {
+            Node::Group(ref group) => collect_text_content(group, ts, is_resource, contents),
+            Node::Text(ref text) => contents.push(text_content(text, ts, is_resource)),
+            Node::Image(ref image) => {
+                if let ImageKind::SVG { ref tree, .. } = image.kind {
+                    let nested_ts = ts
+                        .pre_concat(image.abs_transform)
+                        .pre_concat(image_transform(&image.view_box, tree.size))
+                        .pre_concat(tree.view_box.to_transform(tree.size));
+                    collect_text_content(&tree.root, nested_ts, is_resource, contents);
+                }
+
+                // Nested tree is already processed.
+                continue;
+            }
+            Node::Path(_) => {}
+        }
+
+        node.subroots(|subroot| collect_text_content(subroot, ts, true, contents));
+    }
+}
+
+fn text_content(text: &Text, ts: Transform, is_resource: bool) -> TextContent {
+    let mut content = String::new();
+    let mut spans: Vec<TextSpan> = Vec::new();
+    let mut char_offset = 0;
+    let mut line_pos: Option<f32> = None;
+    let mut last_abs_pos = 0.0;
+    let mut shift = 0.0;
+    for chunk in &text.chunks {
+        // A chunk starts a new line when its position across the text flow
+        // differs from the previous chunk.
+        let (abs_pos, d) = match text.writing_mode {
+            WritingMode::LeftToRight => (chunk.y, &text.dy),
+            WritingMode::TopToBottom => (chunk.x, &text.dx),
+        };
+
+        if let Some(abs_pos) = abs_pos {
+            last_abs_pos = abs_pos;
+            shift = 0.0;
+        }
+
+        // Relative shifts are accumulated until the next absolute position.
+        let chars_count = chunk.text.chars().count();
+        let pos = last_abs_pos + shift + d.get(char_offset).copied().unwrap_or(0.0);
+        shift += d.iter().skip(char_offset).take(chars_count).sum::<f32>();
+        let is_new_line = match (line_pos, &chunk.text_flow) {
+            (Some(prev), TextFlow::Linear) => (pos - prev).abs() > f32::EPSILON,
+            _ => false,
+        };
+        line_pos = Some(pos);
+
+        if is_new_line && !content.is_empty() {
+            // Collapsed whitespaces at the end of a line are not needed.
+            content.truncate(content.trim_end().len());
+            for span in &mut spans {
+                span.start = span.start.min(content.len());
+                span.end = span.end.min(content.len());
+            }
+
+            content.push('\n');
+        }
+
+        let offset = content.len();
+        content.push_str(&chunk.text);
+        for span in &chunk.spans {
+            let mut span = span.clone();
+            span.start += offset;
+            span.end += offset;
+            spans.push(span);
+        }
+
+        char_offset += chars_count;
+    }
+
+    spans.retain(|span| span.start < span.end);
+
+    TextContent {
+        id: text.id.clone(),
+        text: content,
+        bounding_box: text
+            .abs_bounding_box
+            .transform(ts)
+            .unwrap_or(text.abs_bounding_box),
+        spans,
+        is_resource,
+    }
+}
+
+/// Returns a transform that fits a nested SVG image of `size` into the image view box.
+fn image_transform(view_box: &ViewBox, size: Size) -> Transform {
+    let r = view_box.rect;
+    let ts = ViewBox {
+        rect: size.to_non_zero_rect(0.0, 0.0),
+        aspect: view_box.aspect,
+    }
+    .to_transform(r.size());
+    Transform::from_translate(r.x(), r.y()).pre_concat(ts)
+}
+
+fn sort_in_reading_order(mut contents: Vec<TextContent>) -> Vec<TextContent> {
+    contents.sort_by(|a, b| a.bounding_box.top().total_cmp(&b.bounding_box.top()));
+
+    let mut lines: Vec<(f32, Vec<TextContent>)> = Vec::new();
+    for content in contents {
+        let bbox = content.bounding_box;
+        let center = bbox.top() + bbox.height() / 2.0;
+        match lines.last_mut() {
+            Some((bottom, line)) if center <= *bottom => {
+                *bottom = bottom.max(bbox.bottom());
+                line.push(content);
+            }
+            _ => lines.push((bbox.bottom(), vec![content])),
+        }
+    }
+
+    lines
+        .into_iter()
+        .flat_map(|(_, mut line)| {
+            line.sort_by(|a, b| a.bounding_box.left().total_cmp(&b.bounding_box.left()));
+            line
+        })
+        .collect()
+}
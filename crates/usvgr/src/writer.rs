@@ -413,6 +413,20 @@ fn write_filters(tree: &Tree, opt: &WriteOptions, xml: &mut XmlWriter) {
 
                     xml.end_element();
                 }
+                filter::Kind::Custom(ref custom) => {
+                    xml.start_svg_element(EId::FeCustom);
+                    xml.write_filter_primitive_attrs(filter.rect(), fe);
+                    xml.write_svg_attribute(AId::Name, custom.name());
+                    xml.write_filter_input(AId::In, &custom.input1);
+                    if let Some(ref input2) = custom.input2 {
+                        xml.write_filter_input(AId::In2, input2);
+                    }
+                    if !custom.args.is_empty() {
+                        xml.write_numbers(AId::Values, &custom.args);
+                    }
+                    xml.write_svg_attribute(AId::Result, &fe.result);
+                    xml.end_element();
+                }
                 filter::Kind::Morphology(ref morphology) => {
                     xml.start_svg_element(EId::FeMorphology);
                     xml.write_filter_primitive_attrs(filter.rect(), fe);
@@ -550,6 +564,10 @@ fn write_defs(tree: &Tree, opt: &WriteOptions, xml: &mut XmlWriter) {
         xml.end_element();
     }
 
+    for mg in tree.mesh_gradients() {
+        write_mesh_grad(mg, opt, xml);
+    }
+
     for pattern in tree.patterns() {
         xml.start_svg_element(EId::Pattern);
         xml.write_id_attribute(pattern.id(), opt);
@@ -860,6 +878,18 @@ fn write_group_element(g: &Group, is_clip_path: bool, opt: &WriteOptions, xml: &
         xml.write_svg_attribute(AId::Filter, &ids.join(" "));
     }
 
+    if let Some(ref paint) = g.filter_fill {
+        write_paint(AId::Fill, paint, opt, xml);
+    }
+
+    if let Some(ref paint) = g.filter_stroke {
+        write_paint(AId::Stroke, paint, opt, xml);
+    }
+
+    if g.enable_background {
+        xml.write_svg_attribute(AId::EnableBackground, "new");
+    }
+
     if g.opacity != Opacity::ONE {
         xml.write_svg_attribute(AId::Opacity, &g.opacity.get());
     }
@@ -1075,6 +1105,10 @@ impl XmlWriterExt for XmlWriter {
             match input {
                 filter::Input::SourceGraphic => "SourceGraphic",
                 filter::Input::SourceAlpha => "SourceAlpha",
+                filter::Input::BackgroundImage => "BackgroundImage",
+                filter::Input::BackgroundAlpha => "BackgroundAlpha",
+                filter::Input::FillPaint => "FillPaint",
+                filter::Input::StrokePaint => "StrokePaint",
                 filter::Input::Reference(ref s) => s,
             },
         );
@@ -1229,6 +1263,10 @@ fn write_base_grad(g: &BaseGradient, opt: &WriteOptions, xml: &mut XmlWriter) {
         SpreadMethod::Repeat => xml.write_svg_attribute(AId::SpreadMethod, "repeat"),
     }
 
+    if g.color_interpolation == filter::ColorInterpolation::LinearRGB {
+        xml.write_svg_attribute(AId::ColorInterpolation, "linearRGB");
+    }
+
     for s in &g.stops {
         xml.start_svg_element(EId::Stop);
         xml.write_svg_attribute(AId::Offset, &s.offset.get());
@@ -1241,6 +1279,59 @@ fn write_base_grad(g: &BaseGradient, opt: &WriteOptions, xml: &mut XmlWriter) {
     }
 }
 
+fn write_mesh_grad(mg: &MeshGradient, opt: &WriteOptions, xml: &mut XmlWriter) {
+    let origin = mg.patches[0].points[0];
+
+    xml.start_svg_element(EId::Meshgradient);
+    xml.write_id_attribute(mg.id(), opt);
+    xml.write_svg_attribute(AId::X, &origin.x);
+    xml.write_svg_attribute(AId::Y, &origin.y);
+    if mg.kind == MeshGradientType::Bicubic {
+        xml.write_svg_attribute(AId::Type, "bicubic");
+    }
+    xml.write_units(AId::GradientUnits, mg.units, Units::ObjectBoundingBox);
+    xml.write_transform(AId::GradientTransform, mg.transform, opt);
+
+    for (row_idx, row) in mg.patches.chunks(mg.columns).enumerate() {
+        xml.start_svg_element(EId::Meshrow);
+        for (col_idx, patch) in row.iter().enumerate() {
+            xml.start_svg_element(EId::Meshpatch);
+            // Edges shared with the top and left patches are not written.
+            for edge in 0..4 {
+                if (edge == 0 && row_idx != 0) || (edge == 3 && col_idx != 0) {
+                    continue;
+                }
+
+                xml.start_svg_element(EId::Stop);
+                xml.write_attribute_raw(AId::Path.to_str(), |buf| {
+                    buf.push(b'C');
+                    for i in 1..4 {
+                        let p = patch.points[(edge * 3 + i) % 12];
+                        buf.push(b' ');
+                        write_num(p.x, buf, opt.coordinates_precision);
+                        buf.push(b' ');
+                        write_num(p.y, buf, opt.coordinates_precision);
+                    }
+                });
+
+                // Corners shared with the previous patches have their colors already.
+                let is_known_corner = (edge == 0 && col_idx != 0) || (edge == 1 && row_idx != 0);
+                if !is_known_corner {
+                    xml.write_color(AId::StopColor, patch.colors[edge]);
+                    if patch.opacities[edge] != Opacity::ONE {
+                        xml.write_svg_attribute(AId::StopOpacity, &patch.opacities[edge].get());
+                    }
+                }
+                xml.end_element();
+            }
+            xml.end_element();
+        }
+        xml.end_element();
+    }
+
+    xml.end_element();
+}
+
 fn write_path(
     path: &Path,
     is_clip_path: bool,
@@ -1271,6 +1362,10 @@ fn write_path(
         ShapeRendering::GeometricPrecision => {}
     }
 
+    if path.vector_effect == VectorEffect::NonScalingStroke {
+        xml.write_svg_attribute(AId::VectorEffect, "non-scaling-stroke");
+    }
+
     if let Some(id) = clip_path {
         xml.write_func_iri(AId::ClipPath, id, opt);
     }
@@ -1409,6 +1504,9 @@ fn write_paint(aid: AId, paint: &Paint, opt: &WriteOptions, xml: &mut XmlWriter)
         Paint::RadialGradient(ref rg) => {
             xml.write_func_iri(aid, rg.id(), opt);
         }
+        Paint::MeshGradient(ref mg) => {
+            xml.write_func_iri(aid, mg.id(), opt);
+        }
         Paint::Pattern(ref patt) => {
             xml.write_func_iri(aid, patt.id(), opt);
         }
@@ -1576,6 +1674,10 @@ fn write_span(
         xml.write_svg_attribute(AId::FontVariant, "small-caps");
     }
 
+    if let Some(ref language) = span.language {
+        xml.write_attribute("xml:lang", language);
+    }
+
     if span.paint_order == PaintOrder::StrokeAndFill {
         xml.write_svg_attribute(AId::PaintOrder, "stroke fill");
     }
@@ -98,3 +98,615 @@ fn tree_is_send_and_sync() {
     fn ensure_send_and_sync<T: Send + Sync>() {}
     ensure_send_and_sync::<usvgr::Tree>();
 }
+
+#[test]
+fn text_layout_str() {
+    let mut fontdb = usvgr::fontdb::Database::new();
+    fontdb.load_fonts_dir("../svgr/tests/fonts");
+
+    let font = usvgr::Font::new(
+        vec![usvgr::FontFamily::Named("Noto Sans".to_string())],
+        usvgr::FontStyle::Normal,
+        usvgr::FontStretch::Normal,
+        400,
+    );
+    let font_size = usvgr::NonZeroPositiveF32::new(20.0).unwrap();
+    let chunk = usvgr::layout_str("Text \u{0438}\u{0306}", &font, font_size, &fontdb).unwrap();
+
+    // `и` with a combining breve is two code points, but a single cluster.
+    assert_eq!(chunk.clusters().len(), 6);
+    assert_eq!(chunk.clusters()[5].byte_range(), 5..9);
+    assert!(chunk.clusters()[4].bounding_box().is_none());
+
+    let width: f32 = chunk.clusters().iter().map(|c| c.advance()).sum();
+    assert!(chunk.width() > 0.0);
+    assert!((chunk.width() - width).abs() < 0.001);
+    assert!(chunk.ascent() > 0.0 && chunk.descent() < 0.0);
+
+    let last = chunk.clusters().last().unwrap();
+    assert!((last.position().0 + last.advance() - chunk.width()).abs() < 0.001);
+}
+
+#[test]
+fn text_layout_matches_tree() {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 200 200'>
+        <text id='text1' x='20' y='50' font-family='Noto Sans' font-size='20'
+              letter-spacing='5' rotate='0 30'>Text</text>
+    </svg>
+    ";
+
+    let mut fontdb = usvgr::fontdb::Database::new();
+    fontdb.load_fonts_dir("../svgr/tests/fonts");
+    let tree = usvgr::Tree::from_str(svg, &usvgr::Options::default(), &fontdb).unwrap();
+    let text = match tree.node_by_id("text1") {
+        Some(usvgr::Node::Text(ref text)) => text,
+        _ => unreachable!(),
+    };
+
+    let layout = text.layout(&fontdb);
+    assert_eq!(layout.len(), 1);
+
+    let chunk = &layout[0];
+    assert_eq!((chunk.x(), chunk.y()), (20.0, 50.0));
+    assert_eq!(chunk.clusters()[0].position(), (20.0, 50.0));
+    assert!((chunk.clusters()[1].rotation() - 30.0).abs() < 0.001);
+
+    let mut bbox = chunk.clusters()[0].bounding_box().unwrap();
+    for cluster in chunk.clusters() {
+        let r = cluster.bounding_box().unwrap();
+        bbox = usvgr::Rect::from_ltrb(
+            bbox.left().min(r.left()),
+            bbox.top().min(r.top()),
+            bbox.right().max(r.right()),
+            bbox.bottom().max(r.bottom()),
+        )
+        .unwrap();
+    }
+
+    // Glyph boxes are tight, while the text bbox is based on font metrics.
+    let text_bbox = text.bounding_box();
+    assert!(bbox.left() >= text_bbox.left() - 0.5);
+    assert!(bbox.right() <= text_bbox.right() + 0.5);
+    assert!(bbox.top() >= text_bbox.top());
+    assert!(bbox.bottom() <= text_bbox.bottom());
+}
+
+#[test]
+fn text_grouping_glyph() {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 200 200'>
+        <text id='text1' x='20' y='50' font-family='Noto Sans' font-size='20'>Te xt</text>
+    </svg>
+    ";
+
+    let mut fontdb = usvgr::fontdb::Database::new();
+    fontdb.load_fonts_dir("../svgr/tests/fonts");
+    let opt = usvgr::Options {
+        text_grouping: usvgr::TextGrouping::Glyph,
+        ..usvgr::Options::default()
+    };
+    let tree = usvgr::Tree::from_str(svg, &opt, &fontdb).unwrap();
+
+    // A space has no outline and therefore no group.
+    assert!(tree.node_by_id("text1-glyph-2").is_none());
+    let ids: Vec<_> = match tree.node_by_id("text1") {
+        Some(usvgr::Node::Text(ref text)) => text
+            .flattened()
+            .children()
+            .iter()
+            .map(|node| node.id().to_string())
+            .collect(),
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        ids,
+        [
+            "text1-glyph-0",
+            "text1-glyph-1",
+            "text1-glyph-3",
+            "text1-glyph-4"
+        ]
+    );
+
+    match tree.node_by_id("text1-glyph-0") {
+        Some(usvgr::Node::Group(ref group)) => {
+            assert_eq!(
+                group.transform(),
+                usvgr::Transform::from_translate(20.0, 50.0)
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn text_glyph_cache_reuse() {
+    fn text_paths(
+        svg: &str,
+        cache: &mut usvgr::Cache,
+        fontdb: &usvgr::fontdb::Database,
+    ) -> Vec<String> {
+        let doc = usvgr::roxmltree::Document::parse(svg).unwrap();
+        let tree =
+            usvgr::Tree::from_xmltree_with_cache(&doc, &usvgr::Options::default(), cache, fontdb)
+                .unwrap();
+        match tree.node_by_id("text1") {
+            Some(usvgr::Node::Text(ref text)) => text
+                .flattened()
+                .children()
+                .iter()
+                .map(|node| match node {
+                    usvgr::Node::Path(ref path) => format!("{:?}", path.data()),
+                    _ => unreachable!(),
+                })
+                .collect(),
+            _ => unreachable!(),
+        }
+    }
+
+    let mut fontdb = usvgr::fontdb::Database::new();
+    fontdb.load_fonts_dir("../svgr/tests/fonts");
+
+    // Only the glyph level is enabled, so each frame has to be laid out again.
+    let mut cache = usvgr::Cache::new_with_text_caches(0, 64);
+    for frame in ["Frame 1", "Frame 2", "Frame 12"] {
+        let svg = format!(
+            "<svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 200 200'>
+                <text id='text1' x='20' y='50' font-family='Noto Sans'>{}</text>
+            </svg>",
+            frame
+        );
+
+        let cached = text_paths(&svg, &mut cache, &fontdb);
+        let uncached = text_paths(&svg, &mut usvgr::Cache::default(), &fontdb);
+        assert_eq!(cached, uncached);
+    }
+}
+
+#[test]
+fn text_cache_shares_outlines() {
+    let mut fontdb = usvgr::fontdb::Database::new();
+    fontdb.load_fonts_dir("../svgr/tests/fonts");
+
+    let svg = "<svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 200 200'>
+        <linearGradient id='lg1'>
+            <stop offset='0' stop-color='green'/>
+            <stop offset='1' stop-color='blue'/>
+        </linearGradient>
+        <text id='text1' x='20' y='50' font-family='Noto Sans'>Text</text>
+        <text id='text2' x='20' y='100' font-family='Noto Sans' fill='url(#lg1)'>Text</text>
+    </svg>";
+
+    let mut cache = usvgr::Cache::new_with_text_caches(16, 64);
+    let mut parse = || {
+        let doc = usvgr::roxmltree::Document::parse(svg).unwrap();
+        usvgr::Tree::from_xmltree_with_cache(&doc, &usvgr::Options::default(), &mut cache, &fontdb)
+            .unwrap()
+    };
+    let tree1 = parse();
+    let tree2 = parse();
+
+    let flattened = |tree: &usvgr::Tree, id: &str| match tree.node_by_id(id) {
+        Some(usvgr::Node::Text(ref text)) => text.flattened() as *const usvgr::Group,
+        _ => unreachable!(),
+    };
+
+    // A solid fill doesn't require a copy.
+    assert_eq!(flattened(&tree1, "text1"), flattened(&tree2, "text1"));
+    // Paint servers are resolved per document.
+    assert_ne!(flattened(&tree1, "text2"), flattened(&tree2, "text2"));
+}
+
+#[test]
+fn text_font_face() {
+    use base64::Engine;
+
+    fn text_paths(svg: &str, opt: &usvgr::Options, fontdb: &usvgr::fontdb::Database) -> String {
+        let tree = usvgr::Tree::from_str(svg, opt, fontdb).unwrap();
+        match tree.node_by_id("text1") {
+            Some(usvgr::Node::Text(ref text)) => format!("{:?}", text.flattened().children()),
+            _ => unreachable!(),
+        }
+    }
+
+    fn text_svg(style: &str, family: &str) -> String {
+        format!(
+            "<svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 200 200'>
+                <style>{}</style>
+                <text id='text1' x='20' y='50' font-family=\"{}, Noto Sans\">Text</text>
+            </svg>",
+            style, family
+        )
+    }
+
+    // Only the fallback font is available in the shared database.
+    let mut fontdb = usvgr::fontdb::Database::new();
+    fontdb
+        .load_font_file("../svgr/tests/fonts/NotoSans-Regular.ttf")
+        .unwrap();
+
+    let mut serif_db = usvgr::fontdb::Database::new();
+    serif_db
+        .load_font_file("../svgr/tests/fonts/NotoSerif-Regular.ttf")
+        .unwrap();
+    let expected = text_paths(
+        &text_svg("", "Noto Serif"),
+        &usvgr::Options::default(),
+        &serif_db,
+    );
+    let fallback = text_paths(&text_svg("", "Custom"), &usvgr::Options::default(), &fontdb);
+    assert_ne!(expected, fallback);
+
+    let data = std::fs::read("../svgr/tests/fonts/NotoSerif-Regular.ttf").unwrap();
+    let data = base64::engine::general_purpose::STANDARD.encode(data);
+    let style = format!(
+        "@font-face {{ font-family: 'Custom'; src: url(data:font/ttf;base64,{}) format('truetype'); }}",
+        data
+    );
+    let svg = text_svg(&style, "Custom");
+    assert_eq!(
+        text_paths(&svg, &usvgr::Options::default(), &fontdb),
+        expected
+    );
+
+    let opt = usvgr::Options {
+        resources_dir: Some("../svgr/tests/fonts".into()),
+        ..usvgr::Options::default()
+    };
+    let svg = text_svg(
+        "@font-face { font-family: Custom; src: url(missing.ttf), url(NotoSerif-Regular.ttf); }",
+        "Custom",
+    );
+    assert_eq!(text_paths(&svg, &opt, &fontdb), expected);
+    // A font face from the shared database.
+    let svg = text_svg(
+        "@font-face { font-family: Custom; src: local('Noto Sans'); }",
+        "Custom",
+    );
+    assert_eq!(text_paths(&svg, &opt, &fontdb), fallback);
+
+    // Files outside of the resources directory are not allowed.
+    let svg = text_svg(
+        "@font-face { font-family: Custom; src: url(../fonts/../../../../usvgr/tests/files/x.ttf); }",
+        "Custom",
+    );
+    assert_eq!(text_paths(&svg, &opt, &fontdb), fallback);
+    // Files cannot be loaded without a resources directory.
+    let svg = text_svg(
+        "@font-face { font-family: Custom; src: url(NotoSerif-Regular.ttf); }",
+        "Custom",
+    );
+    assert_eq!(
+        text_paths(&svg, &usvgr::Options::default(), &fontdb),
+        fallback
+    );
+
+    // A family which failed to load still uses the shared database,
+    // even when other rules were loaded.
+    let svg = text_svg(
+        "@font-face { font-family: 'Noto Sans'; src: url(missing.ttf); }
+         @font-face { font-family: Other; src: url(NotoSerif-Regular.ttf); }",
+        "Noto Sans",
+    );
+    assert_eq!(text_paths(&svg, &opt, &fontdb), fallback);
+
+    // Document fonts are reused by the next document with the same rules.
+    let svg = text_svg(
+        "@font-face { font-family: Custom; src: url(NotoSerif-Regular.ttf); }",
+        "Custom",
+    );
+    let doc = usvgr::roxmltree::Document::parse(&svg).unwrap();
+    let mut cache = usvgr::Cache::default();
+    for _ in 0..2 {
+        let tree = usvgr::Tree::from_xmltree_with_cache(&doc, &opt, &mut cache, &fontdb).unwrap();
+        match tree.node_by_id("text1") {
+            Some(usvgr::Node::Text(ref text)) => {
+                assert_eq!(format!("{:?}", text.flattened().children()), expected)
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[test]
+fn text_font_fallback() {
+    fn fallback_faces(
+        lang: &str,
+        opt: &usvgr::Options,
+        fontdb: &usvgr::fontdb::Database,
+    ) -> Vec<String> {
+        let svg = format!(
+            "<svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 200 200' xml:lang='{}'>
+                <text id='text1' x='20' y='50' font-family='Noto Emoji'>Text</text>
+            </svg>",
+            lang
+        );
+
+        let tree = usvgr::Tree::from_str(&svg, opt, fontdb).unwrap();
+        match tree.node_by_id("text1") {
+            Some(usvgr::Node::Text(ref text)) => text
+                .fallback_faces()
+                .iter()
+                .map(|face| format!("{} {}", face.script(), face.post_script_name()))
+                .collect(),
+            _ => unreachable!(),
+        }
+    }
+
+    let mut fontdb = usvgr::fontdb::Database::new();
+    fontdb.load_fonts_dir("../svgr/tests/fonts");
+
+    // Without fallback chains, faces are checked in the family name order.
+    let opt = usvgr::Options::default();
+    assert_eq!(fallback_faces("de", &opt, &fontdb), ["Latn Amiri-Regular"]);
+
+    let opt = usvgr::Options {
+        font_fallback: vec![
+            usvgr::FontFallback::new("latn", &["Missing", "Noto Serif"]),
+            usvgr::FontFallback::new("Latn", &["Source Sans Pro"]).with_language("de"),
+        ],
+        ..usvgr::Options::default()
+    };
+    assert_eq!(
+        fallback_faces("en", &opt, &fontdb),
+        ["Latn NotoSerif-Regular"]
+    );
+    assert_eq!(
+        fallback_faces("de-AT", &opt, &fontdb),
+        ["Latn SourceSansPro-Regular"]
+    );
+    assert_eq!(
+        fallback_faces("dem", &opt, &fontdb),
+        ["Latn NotoSerif-Regular"]
+    );
+}
+
+#[test]
+fn text_font_fallback_for_common_characters() {
+    let mut fontdb = usvgr::fontdb::Database::new();
+    fontdb.load_fonts_dir("../svgr/tests/fonts");
+
+    let opt = usvgr::Options {
+        font_fallback: vec![usvgr::FontFallback::new("Latn", &["Noto Serif"])],
+        ..usvgr::Options::default()
+    };
+
+    // Leading punctuation must use the chain of the following Latin text.
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 200 200'>
+        <text id='text1' x='20' y='50' font-family='Noto Emoji'>?Text</text>
+    </svg>
+    ";
+
+    let tree = usvgr::Tree::from_str(svg, &opt, &fontdb).unwrap();
+    let faces: Vec<_> = match tree.node_by_id("text1") {
+        Some(usvgr::Node::Text(ref text)) => text
+            .fallback_faces()
+            .iter()
+            .map(|face| format!("{} {}", face.script(), face.post_script_name()))
+            .collect(),
+        _ => unreachable!(),
+    };
+    assert_eq!(faces, ["Latn NotoSerif-Regular"]);
+}
+
+#[test]
+fn text_content() {
+    let mut fontdb = usvgr::fontdb::Database::new();
+    fontdb.load_fonts_dir("../svgr/tests/fonts");
+
+    let nested_svg = "
+    <svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 100 100'>
+        <text id='nested' x='60' y='20' font-family='Noto Sans'>Nested</text>
+    </svg>
+    ";
+    let nested_tree =
+        usvgr::Tree::from_str(nested_svg, &usvgr::Options::default(), &fontdb).unwrap();
+    let sub_svg_data = std::collections::HashMap::from([(
+        "nested.svg".to_string(),
+        std::sync::Arc::new(nested_tree),
+    )]);
+
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg' xmlns:xlink='http://www.w3.org/1999/xlink'
+         viewBox='0 0 200 200' font-family='Noto Sans'>
+        <pattern id='patt1' patternUnits='userSpaceOnUse' width='20' height='20'>
+            <text id='pattern-text' y='15'>P</text>
+        </pattern>
+        <text id='lines' x='20' y='100'>
+            <tspan font-weight='bold'>First</tspan> line
+            <tspan x='20' dy='20'>Second</tspan>
+            <tspan x='20' dy='20'>Third</tspan>
+        </text>
+        <text id='right' x='150' y='30'>Right</text>
+        <text id='left' x='20' y='30'>Left</text>
+        <rect x='0' y='180' width='20' height='20' fill='url(#patt1)'/>
+        <image x='0' y='0' width='200' height='200' xlink:href='nested.svg'/>
+    </svg>
+    ";
+
+    let image_data = std::collections::HashMap::new();
+    let opt = usvgr::Options {
+        image_data: Some(&image_data),
+        sub_svg_data: Some(&sub_svg_data),
+        ..usvgr::Options::default()
+    };
+    let tree = usvgr::Tree::from_str(svg, &opt, &fontdb).unwrap();
+
+    let contents = tree.text_content();
+    let texts: Vec<_> = contents.iter().map(|c| (c.id(), c.text())).collect();
+    assert_eq!(
+        texts,
+        [
+            ("left", "Left"),
+            ("nested", "Nested"),
+            ("right", "Right"),
+            ("lines", "First line\nSecond\nThird"),
+            ("pattern-text", "P"),
+        ]
+    );
+    assert!(!contents[0].is_resource());
+    assert!(contents[4].is_resource());
+
+    // Nested SVG is scaled 2x.
+    let nested = &contents[1];
+    assert!(nested.bounding_box().left() > 119.0 && nested.bounding_box().left() < 122.0);
+
+    let lines = &contents[3];
+    let bold: Vec<_> = lines
+        .spans()
+        .iter()
+        .filter(|span| span.font().weight() == 700)
+        .map(|span| &lines.text()[span.start()..span.end()])
+        .collect();
+    assert_eq!(bold, ["First"]);
+
+    let matches = tree.find_text("ir");
+    let ids: Vec<_> = matches.iter().map(|m| m.content().id()).collect();
+    assert_eq!(ids, ["lines"]);
+    assert_eq!(matches[0].ranges(), [1..3, 20..22]);
+}
+
+#[test]
+fn calc_and_relative_units() {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg' width='200' height='100' viewBox='0 0 400 200'>
+        <rect id='rect1' x='calc(50% - 1em)' y='5vh' width='10vw' height='calc((1rem + 2Q) * 2)'
+              font-size='20'/>
+        <rect id='rect2' width='10ch' height='calc(1ch * 2 / 4)'
+              font-family='Noto Sans' font-size='10'/>
+        <text id='text1' x='calc(100vw - 10px) 0' y='calc(50vmin + 1vmax)'
+              font-family='Noto Sans' font-size='calc(1rem + 50%)'>Text</text>
+    </svg>
+    ";
+
+    let mut fontdb = usvgr::fontdb::Database::new();
+    fontdb.load_fonts_dir("../svgr/tests/fonts");
+    let tree = usvgr::Tree::from_str(svg, &usvgr::Options::default(), &fontdb).unwrap();
+
+    let path_bounds = |id: &str| match tree.node_by_id(id) {
+        Some(usvgr::Node::Path(ref path)) => path.data().bounds(),
+        _ => unreachable!(),
+    };
+
+    // Viewport units are relative to the root `svg` size and not to its `viewBox`.
+    let bounds = path_bounds("rect1");
+    assert_eq!((bounds.x(), bounds.y()), (180.0, 5.0));
+    assert_eq!(bounds.width(), 20.0);
+    assert!((bounds.height() - (12.0 + 2.0 * 96.0 / 101.6) * 2.0).abs() < 0.001);
+
+    // The `0` advance of Noto Sans is 0.572em.
+    let bounds = path_bounds("rect2");
+    assert!((bounds.width() - 57.2).abs() < 0.001);
+    assert!((bounds.height() - 2.86).abs() < 0.001);
+
+    match tree.node_by_id("text1") {
+        Some(usvgr::Node::Text(ref text)) => {
+            let chunk = &text.chunks()[0];
+            assert_eq!(chunk.x(), Some(190.0));
+            assert_eq!(chunk.y(), Some(52.0));
+            assert_eq!(chunk.spans()[0].font_size().get(), 18.0);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn mesh_gradient() {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg'>
+        <meshgradient id='mg1' x='10' y='20' gradientUnits='userSpaceOnUse'>
+            <meshrow>
+                <meshpatch>
+                    <stop path='l 10,0' stop-color='red'/>
+                    <stop path='c 0,5 0,5 0,10' stop-color='lime'/>
+                    <stop path='l -10,0' stop-color='blue' stop-opacity='0.5'/>
+                    <stop path='l 0,-10' stop-color='black'/>
+                </meshpatch>
+                <meshpatch>
+                    <stop path='l 10,0'/>
+                    <stop path='l 0,10' stop-color='white'/>
+                    <stop path='L 20,30' stop-color='yellow'/>
+                </meshpatch>
+            </meshrow>
+        </meshgradient>
+        <rect id='rect1' width='40' height='40' fill='url(#mg1)'/>
+    </svg>
+    ";
+
+    let fontdb = usvgr::fontdb::Database::new();
+    let tree = usvgr::Tree::from_str(svg, &usvgr::Options::default(), &fontdb).unwrap();
+
+    let mg = &tree.mesh_gradients()[0];
+    assert_eq!(mg.kind(), usvgr::MeshGradientType::Bilinear);
+    assert_eq!((mg.rows(), mg.columns()), (1, 2));
+
+    let patches = mg.patches();
+    assert_eq!(
+        patches[0].points()[6],
+        usvgr::tiny_skia_path::Point::from_xy(20.0, 30.0)
+    );
+    assert_eq!(patches[0].opacities()[2].get(), 0.5);
+    // The left edge and corners are shared with the previous patch.
+    assert_eq!(patches[1].points()[0], patches[0].points()[3]);
+    assert_eq!(patches[1].points()[11], patches[0].points()[4]);
+    assert_eq!(patches[1].colors()[0], patches[0].colors()[1]);
+    assert_eq!(patches[1].colors()[3], patches[0].colors()[2]);
+    assert_eq!(patches[1].colors()[1], usvgr::Color::white());
+
+    // Round trip.
+    let output = tree.to_string(&usvgr::WriteOptions::default());
+    let tree2 = usvgr::Tree::from_str(&output, &usvgr::Options::default(), &fontdb).unwrap();
+    let mg2 = &tree2.mesh_gradients()[0];
+    assert_eq!(mg2.columns(), 2);
+    for (p1, p2) in patches.iter().zip(mg2.patches()) {
+        for (a, b) in p1.points().iter().zip(p2.points()) {
+            assert!(a.distance(*b) < 0.001);
+        }
+        assert_eq!(p1.colors(), p2.colors());
+        assert_eq!(p1.opacities(), p2.opacities());
+    }
+}
+
+#[test]
+fn non_scaling_stroke_bbox() {
+    let svg = "
+    <svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 200 200'>
+        <g transform='scale(10)'>
+            <rect id='rect1' x='1' y='1' width='10' height='10' stroke='black' stroke-width='2'
+                  vector-effect='non-scaling-stroke'/>
+            <rect id='rect2' x='1' y='1' width='10' height='10' stroke='black' stroke-width='2'/>
+        </g>
+    </svg>
+    ";
+
+    let fontdb = usvgr::fontdb::Database::new();
+    let tree = usvgr::Tree::from_str(&svg, &usvgr::Options::default(), &fontdb).unwrap();
+
+    let path = match tree.node_by_id("rect1") {
+        Some(usvgr::Node::Path(ref path)) => path,
+        _ => unreachable!(),
+    };
+    assert_eq!(path.vector_effect(), usvgr::VectorEffect::NonScalingStroke);
+    assert_eq!(
+        path.abs_stroke_bounding_box(),
+        usvgr::Rect::from_ltrb(9.0, 9.0, 111.0, 111.0).unwrap()
+    );
+    let bbox = path.stroke_bounding_box();
+    assert!((bbox.left() - 0.9).abs() < 0.001 && (bbox.top() - 0.9).abs() < 0.001);
+    assert!((bbox.right() - 11.1).abs() < 0.001 && (bbox.bottom() - 11.1).abs() < 0.001);
+    // A half of the stroke width multiplied by the default miter limit.
+    assert_eq!(path.non_scaling_stroke_outset(), 4.0);
+    assert_eq!(tree.root().non_scaling_stroke_outset(), 4.0);
+
+    let path = match tree.node_by_id("rect2") {
+        Some(usvgr::Node::Path(ref path)) => path,
+        _ => unreachable!(),
+    };
+    assert_eq!(path.vector_effect(), usvgr::VectorEffect::None);
+    assert_eq!(
+        path.abs_stroke_bounding_box(),
+        usvgr::Rect::from_ltrb(0.0, 0.0, 120.0, 120.0).unwrap()
+    );
+}
@@ -194,3 +194,28 @@ fn preserve_text_with_complex_text_decoration() {
 fn preserve_text_with_nested_baseline_shift() {
     resave_with_text("preserve-text-with-nested-baseline-shift");
 }
+
+#[test]
+fn mesh_gradient() {
+    resave("mesh-gradient");
+}
+
+#[test]
+fn gradient_color_interpolation() {
+    resave("gradient-color-interpolation");
+}
+
+#[test]
+fn vector_effect() {
+    resave("vector-effect");
+}
+
+#[test]
+fn filter_extra_inputs() {
+    resave("filter-extra-inputs");
+}
+
+#[test]
+fn filter_custom() {
+    resave("filter-custom");
+}
@@ -14,6 +14,7 @@ git commit -m "chore(release): v$VERSION"
 cd crates/svgrtypes && cargo publish;
 cd ../usvgr  && cargo publish;
 cd ../svgr && cargo publish;
+cd ../svgr-pdf && cargo publish;
 
 git tag "v$VERSION"
 